@@ -14,6 +14,103 @@ export type AccessControl = {
     repository: "https://github.com/upsideos/upsideos-solana-rwa"
   };
   instructions: [
+    {
+      name: "addExemptAccount";
+      discriminator: [
+        171,
+        68,
+        21,
+        48,
+        208,
+        51,
+        170,
+        53
+      ];
+      accounts: [
+        {
+          name: "exemptAccountRegistry";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  101,
+                  109,
+                  112,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "accessControlAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  97,
+                  99
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "tokenAccount"
+        },
+        {
+          name: "tokenlockAccount";
+          docs: [
+            "tokenlock escrow accounts"
+          ];
+          optional: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: [
+        {
+          name: "kind";
+          type: "u8"
+        }
+      ]
+    },
     {
       name: "burnSecurities";
       discriminator: [
@@ -86,67 +183,49 @@ export type AccessControl = {
         },
         {
           name: "targetAccount";
-          writable: true;
+          writable: true
+        },
+        {
+          name: "targetAuthority"
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          name: "exemptAccountRegistry";
+          docs: [
+            "Optional: Required once the exempt account registry has exempt accounts"
+          ];
+          optional: true;
           pda: {
             seeds: [
               {
-                kind: "account";
-                path: "targetAuthority"
-              },
-              {
-                kind: "account";
-                path: "tokenProgram"
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  101,
+                  109,
+                  112,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  115
+                ]
               },
               {
                 kind: "account";
                 path: "securityMint"
               }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
-        },
-        {
-          name: "targetAuthority"
-        },
-        {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         }
       ];
       args: [
@@ -171,60 +250,7 @@ export type AccessControl = {
       accounts: [
         {
           name: "sourceAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "account";
-                path: "sourceAuthority"
-              },
-              {
-                kind: "account";
-                path: "tokenProgram"
-              },
-              {
-                kind: "account";
-                path: "securityMint"
-              }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          writable: true
         },
         {
           name: "securityMint";
@@ -232,60 +258,7 @@ export type AccessControl = {
         },
         {
           name: "destinationAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "account";
-                path: "destinationAuthority"
-              },
-              {
-                kind: "account";
-                path: "tokenProgram"
-              },
-              {
-                kind: "account";
-                path: "securityMint"
-              }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          writable: true
         },
         {
           name: "authority";
@@ -349,6 +322,41 @@ export type AccessControl = {
         {
           name: "tokenProgram";
           address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          name: "exemptAccountRegistry";
+          docs: [
+            "Optional: Required once the exempt account registry has exempt accounts"
+          ];
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  101,
+                  109,
+                  112,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityMint"
+              }
+            ]
+          }
         }
       ];
       args: [
@@ -430,67 +438,49 @@ export type AccessControl = {
         },
         {
           name: "targetAccount";
-          writable: true;
+          writable: true
+        },
+        {
+          name: "targetAuthority"
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          name: "exemptAccountRegistry";
+          docs: [
+            "Optional: Required once the exempt account registry has exempt accounts"
+          ];
+          optional: true;
           pda: {
             seeds: [
               {
-                kind: "account";
-                path: "targetAuthority"
-              },
-              {
-                kind: "account";
-                path: "tokenProgram"
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  101,
+                  109,
+                  112,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  115
+                ]
               },
               {
                 kind: "account";
                 path: "securityMint"
               }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
-        },
-        {
-          name: "targetAuthority"
-        },
-        {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         }
       ];
       args: []
@@ -713,6 +703,56 @@ export type AccessControl = {
         }
       ]
     },
+    {
+      name: "migrateAccessControl";
+      discriminator: [
+        9,
+        226,
+        131,
+        152,
+        244,
+        16,
+        25,
+        133
+      ];
+      accounts: [
+        {
+          name: "accessControlAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  97,
+                  99
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
     {
       name: "mintSecurities";
       discriminator: [
@@ -785,72 +825,77 @@ export type AccessControl = {
         },
         {
           name: "destinationAccount";
+          writable: true
+        },
+        {
+          name: "destinationAuthority"
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          name: "securityAssociatedAccount";
+          docs: [
+            "This account must be initialized before minting (holder, holder_group, and SAA must exist)",
+            "Optional: Not required when minting to lockup_escrow_account, mandatory otherwise"
+          ];
           writable: true;
+          optional: true
+        },
+        {
+          name: "exemptAccountRegistry";
+          docs: [
+            "Optional: Required when minting to a tokenlock escrow registered in the exempt account registry"
+          ];
+          optional: true;
           pda: {
             seeds: [
               {
-                kind: "account";
-                path: "destinationAuthority"
-              },
-              {
-                kind: "account";
-                path: "tokenProgram"
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  101,
+                  109,
+                  112,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  115
+                ]
               },
               {
                 kind: "account";
                 path: "securityMint"
               }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          name: "destinationAuthority"
+          name: "transferRestrictionsProgram";
+          docs: [
+            "Optional: Not required when minting to lockup_escrow_account, mandatory otherwise"
+          ];
+          optional: true
         },
         {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+          name: "transferRestrictionData";
+          docs: [
+            "Optional: Not required when minting to lockup_escrow_account, mandatory otherwise"
+          ];
+          optional: true
         },
         {
-          name: "securityAssociatedAccount";
+          name: "transferRestrictionGroup";
           docs: [
-            "This account must be initialized before minting (holder, holder_group, and SAA must exist)",
+            "transfer hook program",
             "Optional: Not required when minting to lockup_escrow_account, mandatory otherwise"
           ];
           optional: true
@@ -863,6 +908,89 @@ export type AccessControl = {
         }
       ]
     },
+    {
+      name: "removeExemptAccount";
+      discriminator: [
+        161,
+        179,
+        151,
+        32,
+        58,
+        230,
+        234,
+        214
+      ];
+      accounts: [
+        {
+          name: "exemptAccountRegistry";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  101,
+                  109,
+                  112,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "accessControlAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  97,
+                  99
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8"
+        }
+      ]
+    },
     {
       name: "revokeRole";
       discriminator: [
@@ -1155,60 +1283,7 @@ export type AccessControl = {
         },
         {
           name: "targetAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "account";
-                path: "targetAuthority"
-              },
-              {
-                kind: "account";
-                path: "tokenProgram"
-              },
-              {
-                kind: "account";
-                path: "securityMint"
-              }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          writable: true
         },
         {
           name: "targetAuthority"
@@ -1235,6 +1310,19 @@ export type AccessControl = {
         132
       ]
     },
+    {
+      name: "exemptAccountRegistry";
+      discriminator: [
+        30,
+        3,
+        32,
+        95,
+        12,
+        63,
+        85,
+        63
+      ]
+    },
     {
       name: "walletRole";
       discriminator: [
@@ -1339,6 +1427,51 @@ export type AccessControl = {
       code: 6017;
       name: "invalidWalletRoleAccountOwner";
       msg: "Invalid wallet role account owner"
+    },
+    {
+      code: 6018;
+      name: "invalidExemptAccountKind";
+      msg: "Invalid exempt account kind"
+    },
+    {
+      code: 6019;
+      name: "exemptAccountAlreadyRegistered";
+      msg: "Token account is already registered as exempt account"
+    },
+    {
+      code: 6020;
+      name: "exemptAccountsLimitReached";
+      msg: "Exempt accounts limit reached"
+    },
+    {
+      code: 6021;
+      name: "invalidExemptAccountIndex";
+      msg: "Invalid exempt account index"
+    },
+    {
+      code: 6022;
+      name: "cannotFreezeExemptAccount";
+      msg: "Cannot freeze exempt account"
+    },
+    {
+      code: 6023;
+      name: "cantForceTransferBetweenExemptAccounts";
+      msg: "Cannot force transfer between exempt accounts"
+    },
+    {
+      code: 6024;
+      name: "transferRestrictionAccountsRequired";
+      msg: "Transfer restriction accounts are required"
+    },
+    {
+      code: 6025;
+      name: "invalidTransferRestrictionsProgram";
+      msg: "Invalid transfer restrictions program"
+    },
+    {
+      code: 6026;
+      name: "exemptAccountRegistryRequired";
+      msg: "Exempt account registry is required"
     }
   ];
   types: [
@@ -1364,6 +1497,76 @@ export type AccessControl = {
             type: {
               option: "pubkey"
             }
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "exemptAccountsCount";
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                31
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: "exemptAccount";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "tokenAccount";
+            type: "pubkey"
+          },
+          {
+            name: "kind";
+            type: "u8"
+          }
+        ]
+      }
+    },
+    {
+      name: "exemptAccountRegistry";
+      docs: [
+        "System token accounts of the security which are exempt from investor transfer restrictions.",
+        "Fields are read at fixed offsets by the transfer hook, new fields must be appended."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "accessControl";
+            type: "pubkey"
+          },
+          {
+            name: "exemptAccountsCount";
+            type: "u8"
+          },
+          {
+            name: "exemptAccounts";
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: "exemptAccount"
+                  }
+                },
+                16
+              ]
+            }
+          },
+          {
+            name: "bump";
+            type: "u8"
           }
         ]
       }
@@ -327,6 +327,86 @@ export type Dividends = {
           docs: [
             "SPL [Token] program."
           ]
+        },
+        {
+          name: "accessControl";
+          docs: [
+            "Access Control of the security token.",
+            "Optional: Required when the distributed mint is the security token."
+          ];
+          optional: true
+        },
+        {
+          name: "exemptAccountRegistry";
+          docs: [
+            "Optional: Required when the distributed mint is the security token."
+          ];
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  101,
+                  109,
+                  112,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                52,
+                73,
+                111,
+                144,
+                68,
+                201,
+                194,
+                136,
+                142,
+                176,
+                110,
+                98,
+                34,
+                243,
+                63,
+                165,
+                30,
+                161,
+                45,
+                199,
+                24,
+                46,
+                67,
+                87,
+                76,
+                194,
+                73,
+                243,
+                192,
+                121,
+                138,
+                252
+              ]
+            }
+          }
         }
       ];
       args: [
@@ -336,6 +416,59 @@ export type Dividends = {
         }
       ]
     },
+    {
+      name: "migrateDistributor";
+      docs: [
+        "Upgrades the [MerkleDistributor] in place to the current layout version."
+      ];
+      discriminator: [
+        64,
+        53,
+        16,
+        171,
+        17,
+        224,
+        4,
+        40
+      ];
+      accounts: [
+        {
+          name: "distributor";
+          docs: [
+            "[MerkleDistributor], can have an older layout."
+          ];
+          writable: true
+        },
+        {
+          name: "accessControl";
+          docs: [
+            "Access Control for Security Token."
+          ]
+        },
+        {
+          name: "authorityWalletRole";
+          docs: [
+            "Authority wallet role to migrate the distributor."
+          ]
+        },
+        {
+          name: "payer";
+          docs: [
+            "Payer of the rent for the added space."
+          ];
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          docs: [
+            "The [System] program."
+          ];
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
     {
       name: "newDistributor";
       docs: [
@@ -1075,6 +1208,16 @@ export type Dividends = {
       code: 6016;
       name: "unauthorizedOwnershipTransfer";
       msg: "Unauthorized ownership transfer"
+    },
+    {
+      code: 6017;
+      name: "accessControlRequired";
+      msg: "Access control is required to distribute the security token"
+    },
+    {
+      code: 6018;
+      name: "distributorAccountNotExempt";
+      msg: "Distributor token account is not a distribution vault of the security token"
     }
   ];
   types: [
@@ -1100,6 +1243,23 @@ export type Dividends = {
             type: {
               option: "pubkey"
             }
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "exemptAccountsCount";
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                31
+              ]
+            }
           }
         ]
       }
@@ -1302,6 +1462,25 @@ export type Dividends = {
               "IPFS hash of the serialized merkle tree."
             ];
             type: "string"
+          },
+          {
+            name: "version";
+            docs: [
+              "Account layout version, 0 for accounts created before the version was stored."
+            ];
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            docs: [
+              "Space reserved for fields added in later layout versions."
+            ];
+            type: {
+              array: [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
@@ -353,6 +353,13 @@ export type Tokenlock = {
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111"
+        },
+        {
+          name: "exemptAccountRegistry";
+          docs: [
+            "Required when the escrow account is registered in the exempt account registry"
+          ];
+          optional: true
         }
       ];
       args: [
@@ -806,6 +813,23 @@ export type Tokenlock = {
             type: {
               option: "pubkey"
             }
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "exemptAccountsCount";
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                31
+              ]
+            }
           }
         ]
       }
@@ -1001,6 +1025,39 @@ export type Tokenlock = {
             type: {
               option: "pubkey"
             }
+          },
+          {
+            name: "countHoldersByBalance";
+            type: "bool"
+          },
+          {
+            name: "fundedHoldersCount";
+            type: "u64"
+          },
+          {
+            name: "bump";
+            type: "u8"
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "policyProgram";
+            type: "pubkey"
+          },
+          {
+            name: "allowIntraHolderTransfers";
+            type: "bool"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
@@ -15,145 +15,41 @@ export type TransferRestrictions = {
   };
   instructions: [
     {
-      name: "enforceTransferRestrictions";
+      name: "addAttestationIssuer";
       discriminator: [
-        77,
-        50,
-        36,
-        109,
-        250,
-        175,
         122,
-        22
+        43,
+        35,
+        54,
+        59,
+        108,
+        142,
+        149
       ];
       accounts: [
         {
-          name: "sourceAccount"
-        },
-        {
-          name: "mint"
-        },
-        {
-          name: "destinationAccount"
-        },
-        {
-          name: "transferRestrictionData";
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  100
-                ]
-              },
-              {
-                kind: "account";
-                path: "mint"
-              }
-            ]
-          }
-        },
-        {
-          name: "securityAssociatedAccountFrom";
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  115,
-                  97,
-                  97
-                ]
-              },
-              {
-                kind: "account";
-                path: "sourceAccount"
-              }
-            ]
-          }
-        },
-        {
-          name: "securityAssociatedAccountTo";
+          name: "attestationIssuerRegistry";
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
-                  115,
                   97,
-                  97
-                ]
-              },
-              {
-                kind: "account";
-                path: "destinationAccount"
-              }
-            ]
-          }
-        },
-        {
-          name: "transferRule";
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
+                  105,
                   114
                 ]
               },
               {
                 kind: "account";
-                path: "transferRestrictionData"
-              },
-              {
-                kind: "account";
-                path: "security_associated_account_from.group";
-                account: "securityAssociatedAccount"
-              },
-              {
-                kind: "account";
-                path: "security_associated_account_to.group";
-                account: "securityAssociatedAccount"
+                path: "securityToken"
               }
             ]
           }
-        }
-      ];
-      args: []
-    },
-    {
-      name: "executeTransaction";
-      docs: [
-        "execute transfer hook"
-      ];
-      discriminator: [
-        105,
-        37,
-        101,
-        197,
-        75,
-        251,
-        102,
-        26
-      ];
-      accounts: [
-        {
-          name: "sourceAccount"
-        },
-        {
-          name: "mint"
-        },
-        {
-          name: "destinationAccount"
-        },
-        {
-          name: "ownerDelegate"
         },
         {
           name: "extraMetasAccount";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -182,46 +78,80 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "mint"
+                path: "securityToken"
               }
             ]
           }
         },
         {
-          name: "transferRestrictionData"
+          name: "securityToken"
         },
         {
-          name: "securityAssociatedAccountFrom"
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
         },
         {
-          name: "securityAssociatedAccountTo"
+          name: "authorityWalletRole"
         },
         {
-          name: "transferRule"
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
         }
       ];
       args: [
         {
-          name: "amount";
-          type: "u64"
+          name: "programId";
+          type: "pubkey"
+        },
+        {
+          name: "schema";
+          type: "pubkey"
+        },
+        {
+          name: "layout";
+          type: {
+            defined: {
+              name: "attestationLayout"
+            }
+          }
         }
       ]
     },
     {
-      name: "initializeDefaultSecurityAccounts";
+      name: "addBlackoutWindow";
       discriminator: [
-        81,
-        54,
-        165,
-        92,
+        96,
+        97,
+        58,
+        70,
+        173,
         129,
-        149,
-        40,
-        235
+        107,
+        185
       ];
       accounts: [
         {
-          name: "transferRestrictionHolder";
+          name: "blackoutCalendar";
           writable: true;
           pda: {
             seeds: [
@@ -229,24 +159,19 @@ export type TransferRestrictions = {
                 kind: "const";
                 value: [
                   116,
-                  114,
-                  104
+                  98,
+                  99
                 ]
               },
               {
                 kind: "account";
                 path: "transferRestrictionData"
-              },
-              {
-                kind: "arg";
-                path: "holderId"
               }
             ]
           }
         },
         {
-          name: "holderGroup";
-          writable: true;
+          name: "transferRestrictionData";
           pda: {
             seeds: [
               {
@@ -254,30 +179,49 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  104,
-                  103
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "transferRestrictionHolder"
-              },
-              {
-                kind: "const";
-                value: [
-                  0,
-                  0,
-                  0,
-                  0,
-                  0,
-                  0,
-                  0,
-                  0
-                ]
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
               }
             ]
           }
         },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "window";
+          type: {
+            defined: {
+              name: "blackoutWindow"
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: "addWalletHold";
+      discriminator: [
+        251,
+        228,
+        153,
+        149,
+        31,
+        234,
+        235,
+        119
+      ];
+      accounts: [
         {
           name: "securityAssociatedAccount";
           writable: true;
@@ -293,14 +237,16 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "associatedTokenAccount"
+                path: "tokenAccount"
               }
             ]
           }
         },
         {
-          name: "group";
-          writable: true;
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
           pda: {
             seeds: [
               {
@@ -308,39 +254,165 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  103
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "transferRestrictionData"
-              },
-              {
-                kind: "const";
-                value: [
-                  0,
-                  0,
-                  0,
-                  0,
-                  0,
-                  0,
-                  0,
-                  0
-                ]
+                path: "securityToken"
               }
             ]
           }
         },
         {
-          name: "securityToken"
+          name: "tokenAccount"
         },
         {
-          name: "transferRestrictionData";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "hold";
+          type: {
+            defined: {
+              name: "walletHold"
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: "detectTransferRestriction";
+      discriminator: [
+        151,
+        28,
+        141,
+        250,
+        88,
+        74,
+        27,
+        74
+      ];
+      accounts: [
+        {
+          name: "sourceAccount"
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "destinationAccount"
+        },
+        {
+          name: "transferRestrictionData"
+        },
+        {
+          name: "securityAssociatedAccountFrom"
+        },
+        {
+          name: "securityAssociatedAccountTo"
+        },
+        {
+          name: "transferRule"
+        },
+        {
+          name: "blackoutCalendar"
+        },
+        {
+          name: "transferRestrictionGroupFrom"
+        },
+        {
+          name: "transferRestrictionGroupTo"
+        },
+        {
+          name: "transferRestrictionHolderFrom"
+        },
+        {
+          name: "transferRestrictionHolderTo"
+        },
+        {
+          name: "holderGroupFrom"
+        },
+        {
+          name: "holderGroupTo"
+        },
+        {
+          name: "transferRuleFromAny"
+        },
+        {
+          name: "transferRuleToAny"
+        },
+        {
+          name: "transferRuleAny"
+        },
+        {
+          name: "transferApproval"
+        },
+        {
+          name: "accessControlProgram";
+          address: "4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3"
+        },
+        {
+          name: "exemptAccountRegistry"
+        },
+        {
+          name: "attestationIssuerRegistry"
+        },
+        {
+          name: "attestationProgram"
+        },
+        {
+          name: "attestationFrom"
+        },
+        {
+          name: "attestationTo"
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64"
+        }
+      ];
+      returns: {
+        defined: {
+          name: "transferRestrictionDetection"
+        }
+      }
+    },
+    {
+      name: "enforceTransferRestrictions";
+      discriminator: [
+        77,
+        50,
+        36,
+        109,
+        250,
+        175,
+        122,
+        22
+      ];
+      accounts: [
+        {
+          name: "sourceAccount"
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "destinationAccount"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
                 value: [
                   116,
                   114,
@@ -349,143 +421,93 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "securityToken"
+                path: "mint"
               }
             ]
           }
         },
         {
-          name: "userWallet"
-        },
-        {
-          name: "associatedTokenAccount";
+          name: "securityAssociatedAccountFrom";
           pda: {
             seeds: [
-              {
-                kind: "account";
-                path: "userWallet"
-              },
               {
                 kind: "const";
                 value: [
-                  6,
-                  221,
-                  246,
-                  225,
-                  238,
-                  117,
-                  143,
-                  222,
-                  24,
-                  66,
-                  93,
-                  188,
-                  228,
-                  108,
-                  205,
-                  218,
-                  182,
-                  26,
-                  252,
-                  77,
-                  131,
-                  185,
-                  13,
-                  39,
-                  254,
-                  189,
-                  249,
-                  40,
-                  216,
-                  161,
-                  139,
-                  252
+                  115,
+                  97,
+                  97
                 ]
               },
               {
                 kind: "account";
-                path: "securityToken"
+                path: "sourceAccount"
               }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          name: "authorityWalletRole"
-        },
-        {
-          name: "authority";
-          signer: true
-        },
-        {
-          name: "payer";
-          writable: true;
-          signer: true
+          name: "securityAssociatedAccountTo";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "destinationAccount"
+              }
+            ]
+          }
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111"
+          name: "transferRule";
+          docs: [
+            "Default transfer rule candidates are passed as remaining accounts."
+          ]
         }
       ];
       args: [
         {
-          name: "holderId";
+          name: "amount";
           type: "u64"
         }
       ]
     },
     {
-      name: "initializeExtraAccountMetaList";
+      name: "executeTransaction";
+      docs: [
+        "execute transfer hook"
+      ];
       discriminator: [
-        92,
-        197,
-        174,
+        105,
+        37,
+        101,
         197,
-        41,
-        124,
-        19,
-        3
+        75,
+        251,
+        102,
+        26
       ];
       accounts: [
+        {
+          name: "sourceAccount"
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "destinationAccount"
+        },
+        {
+          name: "ownerDelegate"
+        },
         {
           name: "extraMetasAccount";
-          writable: true;
           pda: {
             seeds: [
               {
@@ -514,94 +536,146 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "securityMint"
+                path: "mint"
               }
             ]
           }
         },
         {
-          name: "securityMint"
+          name: "transferRestrictionData";
+          writable: true
         },
         {
-          name: "authorityWalletRole"
+          name: "securityAssociatedAccountFrom";
+          writable: true
         },
         {
-          name: "accessControl"
+          name: "securityAssociatedAccountTo";
+          writable: true
         },
         {
-          name: "payer";
-          writable: true;
-          signer: true
+          name: "transferRule"
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111"
-        }
-      ];
-      args: []
-    },
-    {
-      name: "initializeHolderGroup";
-      discriminator: [
-        236,
-        173,
-        120,
-        20,
-        217,
-        85,
-        57,
-        26
-      ];
-      accounts: [
+          name: "blackoutCalendar"
+        },
         {
-          name: "holderGroup";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  104,
-                  103
-                ]
-              },
-              {
-                kind: "account";
-                path: "holder"
-              },
-              {
-                kind: "account";
-                path: "group.id";
-                account: "transferRestrictionGroup"
-              }
-            ]
-          }
+          name: "transferRestrictionGroupFrom";
+          writable: true
         },
         {
-          name: "transferRestrictionData";
+          name: "transferRestrictionGroupTo";
+          writable: true
+        },
+        {
+          name: "transferRestrictionHolderFrom";
+          writable: true
+        },
+        {
+          name: "transferRestrictionHolderTo";
+          writable: true
+        },
+        {
+          name: "holderGroupFrom";
+          writable: true
+        },
+        {
+          name: "holderGroupTo";
+          writable: true
+        },
+        {
+          name: "transferRuleFromAny"
+        },
+        {
+          name: "transferRuleToAny"
+        },
+        {
+          name: "transferRuleAny"
+        },
+        {
+          name: "transferApproval";
+          writable: true
+        },
+        {
+          name: "accessControlProgram";
+          address: "4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3"
+        },
+        {
+          name: "exemptAccountRegistry"
+        },
+        {
+          name: "attestationIssuerRegistry"
+        },
+        {
+          name: "attestationProgram"
+        },
+        {
+          name: "attestationFrom"
+        },
+        {
+          name: "attestationTo"
+        },
+        {
+          name: "policyProgram"
+        },
+        {
+          name: "policyConfig"
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "initializeApprovedVenue";
+      discriminator: [
+        114,
+        149,
+        107,
+        206,
+        226,
+        152,
+        252,
+        49
+      ];
+      accounts: [
+        {
+          name: "approvedVenue";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
-                  116,
-                  114,
-                  100
+                  118,
+                  101,
+                  110,
+                  117,
+                  101
                 ]
               },
               {
                 kind: "account";
-                path: "transfer_restriction_data.security_token_mint";
-                account: "transferRestrictionData"
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "arg";
+                path: "venue"
               }
             ]
           }
         },
         {
-          name: "group";
+          name: "group"
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
           pda: {
             seeds: [
               {
@@ -609,25 +683,16 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  103
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "transferRestrictionData"
-              },
-              {
-                kind: "account";
-                path: "group.id";
-                account: "transferRestrictionGroup"
+                path: "securityToken"
               }
             ]
           }
         },
-        {
-          name: "holder";
-          writable: true
-        },
         {
           name: "authorityWalletRole"
         },
@@ -645,23 +710,56 @@ export type TransferRestrictions = {
           address: "11111111111111111111111111111111"
         }
       ];
-      args: []
+      args: [
+        {
+          name: "venue";
+          type: "pubkey"
+        },
+        {
+          name: "groupId";
+          type: "u64"
+        }
+      ]
     },
     {
-      name: "initializeSecurityAssociatedAccount";
+      name: "initializeBeneficialOwnerPosition";
       discriminator: [
-        154,
-        169,
-        189,
-        28,
-        30,
-        71,
-        161,
-        50
+        12,
+        185,
+        32,
+        52,
+        150,
+        207,
+        46,
+        11
       ];
       accounts: [
         {
-          name: "securityAssociatedAccount";
+          name: "beneficialOwnerPosition";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  98,
+                  111,
+                  112
+                ]
+              },
+              {
+                kind: "account";
+                path: "omnibusSecurityAssociatedAccount"
+              },
+              {
+                kind: "account";
+                path: "holder"
+              }
+            ]
+          }
+        },
+        {
+          name: "omnibusSecurityAssociatedAccount";
           writable: true;
           pda: {
             seeds: [
@@ -675,11 +773,14 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "associatedTokenAccount"
+                path: "omnibusTokenAccount"
               }
             ]
           }
         },
+        {
+          name: "omnibusTokenAccount"
+        },
         {
           name: "group";
           writable: true
@@ -715,102 +816,20 @@ export type TransferRestrictions = {
           }
         },
         {
-          name: "userWallet"
-        },
-        {
-          name: "associatedTokenAccount";
-          pda: {
-            seeds: [
-              {
-                kind: "account";
-                path: "userWallet"
-              },
-              {
-                kind: "const";
-                value: [
-                  6,
-                  221,
-                  246,
-                  225,
-                  238,
-                  117,
-                  143,
-                  222,
-                  24,
-                  66,
-                  93,
-                  188,
-                  228,
-                  108,
-                  205,
-                  218,
-                  182,
-                  26,
-                  252,
-                  77,
-                  131,
-                  185,
-                  13,
-                  39,
-                  254,
-                  189,
-                  249,
-                  40,
-                  216,
-                  161,
-                  139,
-                  252
-                ]
-              },
-              {
-                kind: "account";
-                path: "securityToken"
-              }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          name: "operator";
+          docs: [
+            "Owner of the omnibus token account maintaining its sub-ledger"
+          ];
+          signer: true
         },
         {
           name: "authorityWalletRole"
         },
         {
           name: "authority";
+          docs: [
+            "Wallets Admin or Transfer Admin approving the link between the omnibus account and holder"
+          ];
           signer: true
         },
         {
@@ -827,28 +846,24 @@ export type TransferRestrictions = {
         {
           name: "groupId";
           type: "u64"
-        },
-        {
-          name: "holderId";
-          type: "u64"
         }
       ]
     },
     {
-      name: "initializeTransferRestrictionGroup";
+      name: "initializeBlackoutCalendar";
       discriminator: [
-        62,
-        223,
-        111,
-        8,
-        59,
-        225,
-        31,
-        108
+        243,
+        135,
+        238,
+        164,
+        188,
+        37,
+        162,
+        198
       ];
       accounts: [
         {
-          name: "transferRestrictionGroup";
+          name: "blackoutCalendar";
           writable: true;
           pda: {
             seeds: [
@@ -856,24 +871,19 @@ export type TransferRestrictions = {
                 kind: "const";
                 value: [
                   116,
-                  114,
-                  103
+                  98,
+                  99
                 ]
               },
               {
                 kind: "account";
                 path: "transferRestrictionData"
-              },
-              {
-                kind: "arg";
-                path: "id"
               }
             ]
           }
         },
         {
           name: "transferRestrictionData";
-          writable: true;
           pda: {
             seeds: [
               {
@@ -886,15 +896,12 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "access_control_account.mint";
-                account: "accessControl"
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
               }
             ]
           }
         },
-        {
-          name: "accessControlAccount"
-        },
         {
           name: "authorityWalletRole"
         },
@@ -912,24 +919,19 @@ export type TransferRestrictions = {
           address: "11111111111111111111111111111111"
         }
       ];
-      args: [
-        {
-          name: "id";
-          type: "u64"
-        }
-      ]
+      args: []
     },
     {
-      name: "initializeTransferRestrictionHolder";
+      name: "initializeDefaultSecurityAccounts";
       discriminator: [
-        184,
-        97,
-        123,
-        132,
-        240,
-        132,
-        91,
-        118
+        81,
+        54,
+        165,
+        92,
+        129,
+        149,
+        40,
+        235
       ];
       accounts: [
         {
@@ -951,13 +953,13 @@ export type TransferRestrictions = {
               },
               {
                 kind: "arg";
-                path: "id"
+                path: "holderId"
               }
             ]
           }
         },
         {
-          name: "transferRestrictionData";
+          name: "holderGroup";
           writable: true;
           pda: {
             seeds: [
@@ -966,79 +968,52 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  100
+                  104,
+                  103
                 ]
               },
               {
                 kind: "account";
-                path: "access_control_account.mint";
-                account: "accessControl"
+                path: "transferRestrictionHolder"
+              },
+              {
+                kind: "const";
+                value: [
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
               }
             ]
           }
         },
         {
-          name: "accessControlAccount"
-        },
-        {
-          name: "authorityWalletRole"
-        },
-        {
-          name: "authority";
-          signer: true
-        },
-        {
-          name: "payer";
-          writable: true;
-          signer: true
-        },
-        {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111"
-        }
-      ];
-      args: [
-        {
-          name: "id";
-          type: "u64"
-        }
-      ]
-    },
-    {
-      name: "initializeTransferRestrictionsData";
-      discriminator: [
-        214,
-        241,
-        131,
-        83,
-        138,
-        120,
-        171,
-        133
-      ];
-      accounts: [
-        {
-          name: "transferRestrictionData";
+          name: "securityAssociatedAccount";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
-                  116,
-                  114,
-                  100
+                  115,
+                  97,
+                  97
                 ]
               },
               {
                 kind: "account";
-                path: "mint"
+                path: "associatedTokenAccount"
               }
             ]
           }
         },
         {
-          name: "zeroTransferRestrictionGroup";
+          name: "group";
           writable: true;
           pda: {
             seeds: [
@@ -1071,83 +1046,116 @@ export type TransferRestrictions = {
           }
         },
         {
-          name: "mint"
+          name: "securityToken"
         },
         {
-          name: "accessControlAccount"
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "userWallet"
+        },
+        {
+          name: "associatedTokenAccount"
         },
         {
           name: "authorityWalletRole"
         },
         {
-          name: "payer";
-          writable: true;
+          name: "authority";
           signer: true
         },
         {
-          name: "authority";
+          name: "payer";
+          writable: true;
           signer: true
         },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111"
-        },
-        {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         }
       ];
       args: [
         {
-          name: "maxHolders";
+          name: "holderId";
           type: "u64"
         }
       ]
     },
     {
-      name: "initializeTransferRule";
+      name: "initializeExemptSecurityAssociatedAccount";
       discriminator: [
-        24,
-        28,
-        16,
+        204,
         18,
-        72,
-        26,
-        87,
-        49
+        100,
+        253,
+        106,
+        243,
+        177,
+        143
       ];
       accounts: [
         {
-          name: "transferRule";
-          writable: true;
+          name: "transferRestrictionData";
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
                   116,
-                  114
+                  114,
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "transferRestrictionData"
-              },
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
               {
-                kind: "account";
-                path: "transfer_restriction_group_from.id";
-                account: "transferRestrictionGroup"
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
               },
               {
                 kind: "account";
-                path: "transfer_restriction_group_to.id";
-                account: "transferRestrictionGroup"
+                path: "exemptAccount"
               }
             ]
           }
         },
         {
-          name: "transferRestrictionData";
+          name: "group";
+          docs: [
+            "Group 0 which keeps the exempt account"
+          ];
+          writable: true;
           pda: {
             seeds: [
               {
@@ -1155,22 +1163,31 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  100
+                  103
                 ]
               },
               {
                 kind: "account";
-                path: "access_control_account.mint";
-                account: "accessControl"
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "const";
+                value: [
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
               }
             ]
           }
         },
         {
-          name: "transferRestrictionGroupFrom"
-        },
-        {
-          name: "transferRestrictionGroupTo"
+          name: "mint"
         },
         {
           name: "accessControlAccount"
@@ -1179,61 +1196,132 @@ export type TransferRestrictions = {
           name: "authorityWalletRole"
         },
         {
-          name: "authority";
-          signer: true
+          name: "exemptAccount"
+        },
+        {
+          name: "exemptAccountRegistry";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  101,
+                  109,
+                  112,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                52,
+                73,
+                111,
+                144,
+                68,
+                201,
+                194,
+                136,
+                142,
+                176,
+                110,
+                98,
+                34,
+                243,
+                63,
+                165,
+                30,
+                161,
+                45,
+                199,
+                24,
+                46,
+                67,
+                87,
+                76,
+                194,
+                73,
+                243,
+                192,
+                121,
+                138,
+                252
+              ]
+            }
+          }
         },
         {
           name: "payer";
           writable: true;
           signer: true
         },
+        {
+          name: "authority";
+          signer: true
+        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111"
         }
       ];
-      args: [
-        {
-          name: "transferGroupIdFrom";
-          type: "u64"
-        },
-        {
-          name: "transferGroupIdTo";
-          type: "u64"
-        },
-        {
-          name: "lockUntil";
-          type: "u64"
-        }
-      ]
+      args: []
     },
     {
-      name: "pause";
+      name: "initializeExtraAccountMetaList";
       discriminator: [
-        211,
-        22,
-        221,
-        251,
-        74,
-        121,
-        193,
-        47
+        92,
+        197,
+        174,
+        197,
+        41,
+        124,
+        19,
+        3
       ];
       accounts: [
         {
-          name: "securityMint"
-        },
-        {
-          name: "transferRestrictionData";
+          name: "extraMetasAccount";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
+                  101,
+                  120,
                   116,
                   114,
-                  100
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
                 ]
               },
               {
@@ -1244,90 +1332,13 @@ export type TransferRestrictions = {
           }
         },
         {
-          name: "accessControlAccount"
+          name: "securityMint"
         },
         {
           name: "authorityWalletRole"
-        },
-        {
-          name: "payer";
-          writable: true;
-          signer: true
-        }
-      ];
-      args: [
-        {
-          name: "paused";
-          type: "bool"
-        }
-      ]
-    },
-    {
-      name: "revokeHolder";
-      discriminator: [
-        250,
-        238,
-        38,
-        18,
-        138,
-        55,
-        227,
-        111
-      ];
-      accounts: [
-        {
-          name: "holder";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  104
-                ]
-              },
-              {
-                kind: "account";
-                path: "transferRestrictionData"
-              },
-              {
-                kind: "account";
-                path: "holder.id";
-                account: "transferRestrictionHolder"
-              }
-            ]
-          }
-        },
-        {
-          name: "transferRestrictionData";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  100
-                ]
-              },
-              {
-                kind: "account";
-                path: "transfer_restriction_data.security_token_mint";
-                account: "transferRestrictionData"
-              }
-            ]
-          }
-        },
-        {
-          name: "authorityWalletRole";
-          writable: true
-        },
-        {
-          name: "authority";
-          signer: true
+        },
+        {
+          name: "accessControl"
         },
         {
           name: "payer";
@@ -1342,43 +1353,18 @@ export type TransferRestrictions = {
       args: []
     },
     {
-      name: "revokeHolderGroup";
+      name: "initializeHolderGroup";
       discriminator: [
-        33,
-        153,
-        183,
-        187,
-        204,
+        236,
+        173,
         120,
-        164,
-        40
+        20,
+        217,
+        85,
+        57,
+        26
       ];
       accounts: [
-        {
-          name: "holder";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  104
-                ]
-              },
-              {
-                kind: "account";
-                path: "transferRestrictionData"
-              },
-              {
-                kind: "account";
-                path: "holder.id";
-                account: "transferRestrictionHolder"
-              }
-            ]
-          }
-        },
         {
           name: "holderGroup";
           writable: true;
@@ -1451,9 +1437,12 @@ export type TransferRestrictions = {
           }
         },
         {
-          name: "authorityWalletRole";
+          name: "holder";
           writable: true
         },
+        {
+          name: "authorityWalletRole"
+        },
         {
           name: "authority";
           signer: true
@@ -1471,16 +1460,16 @@ export type TransferRestrictions = {
       args: []
     },
     {
-      name: "revokeSecurityAssociatedAccount";
+      name: "initializeSecurityAssociatedAccount";
       discriminator: [
-        75,
-        206,
-        46,
-        31,
-        84,
-        165,
-        44,
-        66
+        154,
+        169,
+        189,
+        28,
+        30,
+        71,
+        161,
+        50
       ];
       accounts: [
         {
@@ -1541,93 +1530,7 @@ export type TransferRestrictions = {
           name: "userWallet"
         },
         {
-          name: "associatedTokenAccount";
-          pda: {
-            seeds: [
-              {
-                kind: "account";
-                path: "userWallet"
-              },
-              {
-                kind: "const";
-                value: [
-                  6,
-                  221,
-                  246,
-                  225,
-                  238,
-                  117,
-                  143,
-                  222,
-                  24,
-                  66,
-                  93,
-                  188,
-                  228,
-                  108,
-                  205,
-                  218,
-                  182,
-                  26,
-                  252,
-                  77,
-                  131,
-                  185,
-                  13,
-                  39,
-                  254,
-                  189,
-                  249,
-                  40,
-                  216,
-                  161,
-                  139,
-                  252
-                ]
-              },
-              {
-                kind: "account";
-                path: "securityToken"
-              }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          name: "associatedTokenAccount"
         },
         {
           name: "authorityWalletRole"
@@ -1646,43 +1549,32 @@ export type TransferRestrictions = {
           address: "11111111111111111111111111111111"
         }
       ];
-      args: []
+      args: [
+        {
+          name: "groupId";
+          type: "u64"
+        },
+        {
+          name: "holderId";
+          type: "u64"
+        }
+      ]
     },
     {
-      name: "setAddressPermission";
+      name: "initializeTransferApproval";
       discriminator: [
-        206,
-        98,
-        48,
-        240,
-        226,
-        102,
-        10,
-        236
+        245,
+        144,
+        99,
+        58,
+        99,
+        1,
+        237,
+        146
       ];
       accounts: [
         {
-          name: "securityAssociatedAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  115,
-                  97,
-                  97
-                ]
-              },
-              {
-                kind: "account";
-                path: "userAssociatedTokenAccount"
-              }
-            ]
-          }
-        },
-        {
-          name: "transferRestrictionGroupNew";
+          name: "transferApproval";
           writable: true;
           pda: {
             seeds: [
@@ -1690,50 +1582,36 @@ export type TransferRestrictions = {
                 kind: "const";
                 value: [
                   116,
-                  114,
-                  103
+                  97,
+                  112
                 ]
               },
               {
                 kind: "account";
                 path: "transferRestrictionData"
               },
-              {
-                kind: "arg";
-                path: "groupId"
-              }
-            ]
-          }
-        },
-        {
-          name: "transferRestrictionGroupCurrent";
-          writable: true;
-          optional: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  103
-                ]
-              },
               {
                 kind: "account";
-                path: "transferRestrictionData"
+                path: "sourceAccount"
               },
               {
                 kind: "account";
-                path: "security_associated_account.group";
-                account: "securityAssociatedAccount"
+                path: "destinationAccount"
               }
             ]
           }
         },
         {
-          name: "transferRestrictionHolder";
-          writable: true;
+          name: "sourceAccount"
+        },
+        {
+          name: "destinationAccount"
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
           pda: {
             seeds: [
               {
@@ -1741,50 +1619,60 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  104
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "transferRestrictionData"
-              },
-              {
-                kind: "account";
-                path: "transfer_restriction_holder.id";
-                account: "transferRestrictionHolder"
+                path: "securityToken"
               }
             ]
           }
         },
         {
-          name: "holderGroupNew";
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
           writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  104,
-                  103
-                ]
-              },
-              {
-                kind: "account";
-                path: "transferRestrictionHolder"
-              },
-              {
-                kind: "arg";
-                path: "groupId"
-              }
-            ]
-          }
+          signer: true
         },
         {
-          name: "holderGroupCurrent";
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64"
+        },
+        {
+          name: "expiresAt";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "initializeTransferRestrictionGroup";
+      discriminator: [
+        62,
+        223,
+        111,
+        8,
+        59,
+        225,
+        31,
+        108
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionGroup";
           writable: true;
-          optional: true;
           pda: {
             seeds: [
               {
@@ -1792,26 +1680,20 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  104,
                   103
                 ]
               },
               {
                 kind: "account";
-                path: "security_associated_account.holder";
-                account: "securityAssociatedAccount"
+                path: "transferRestrictionData"
               },
               {
-                kind: "account";
-                path: "security_associated_account.group";
-                account: "securityAssociatedAccount"
+                kind: "arg";
+                path: "id"
               }
             ]
           }
         },
-        {
-          name: "securityToken"
-        },
         {
           name: "transferRestrictionData";
           writable: true;
@@ -1827,150 +1709,27 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "securityToken"
+                path: "access_control_account.mint";
+                account: "accessControl"
               }
             ]
           }
         },
         {
-          name: "userWallet"
-        },
-        {
-          name: "userAssociatedTokenAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "account";
-                path: "userWallet"
-              },
-              {
-                kind: "const";
-                value: [
-                  6,
-                  221,
-                  246,
-                  225,
-                  238,
-                  117,
-                  143,
-                  222,
-                  24,
-                  66,
-                  93,
-                  188,
-                  228,
-                  108,
-                  205,
-                  218,
-                  182,
-                  26,
-                  252,
-                  77,
-                  131,
-                  185,
-                  13,
-                  39,
-                  254,
-                  189,
-                  249,
-                  40,
-                  216,
-                  161,
-                  139,
-                  252
-                ]
-              },
-              {
-                kind: "account";
-                path: "securityToken"
-              }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          name: "accessControlAccount"
         },
         {
           name: "authorityWalletRole"
         },
         {
-          name: "securityMint";
-          writable: true
-        },
-        {
-          name: "accessControlAccount";
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  97,
-                  99
-                ]
-              },
-              {
-                kind: "account";
-                path: "securityMint"
-              }
-            ];
-            program: {
-              kind: "account";
-              path: "accessControlProgram"
-            }
-          }
-        },
-        {
-          name: "accessControlProgram";
-          address: "4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3"
-        },
-        {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+          name: "authority";
+          signer: true
         },
         {
           name: "payer";
           writable: true;
           signer: true
         },
-        {
-          name: "authority";
-          signer: true
-        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111"
@@ -1978,30 +1737,26 @@ export type TransferRestrictions = {
       ];
       args: [
         {
-          name: "groupId";
+          name: "id";
           type: "u64"
-        },
-        {
-          name: "frozen";
-          type: "bool"
         }
       ]
     },
     {
-      name: "setAllowTransferRule";
+      name: "initializeTransferRestrictionHolder";
       discriminator: [
-        4,
-        83,
-        246,
-        172,
-        106,
-        193,
-        31,
-        116
+        184,
+        97,
+        123,
+        132,
+        240,
+        132,
+        91,
+        118
       ];
       accounts: [
         {
-          name: "transferRule";
+          name: "transferRestrictionHolder";
           writable: true;
           pda: {
             seeds: [
@@ -2009,7 +1764,8 @@ export type TransferRestrictions = {
                 kind: "const";
                 value: [
                   116,
-                  114
+                  114,
+                  104
                 ]
               },
               {
@@ -2017,20 +1773,15 @@ export type TransferRestrictions = {
                 path: "transferRestrictionData"
               },
               {
-                kind: "account";
-                path: "transfer_restriction_group_from.id";
-                account: "transferRestrictionGroup"
-              },
-              {
-                kind: "account";
-                path: "transfer_restriction_group_to.id";
-                account: "transferRestrictionGroup"
+                kind: "arg";
+                path: "id"
               }
             ]
           }
         },
         {
           name: "transferRestrictionData";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -2049,54 +1800,49 @@ export type TransferRestrictions = {
             ]
           }
         },
-        {
-          name: "transferRestrictionGroupFrom"
-        },
-        {
-          name: "transferRestrictionGroupTo"
-        },
         {
           name: "accessControlAccount"
         },
         {
           name: "authorityWalletRole"
         },
+        {
+          name: "authority";
+          signer: true
+        },
         {
           name: "payer";
           writable: true;
           signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
         }
       ];
       args: [
         {
-          name: "transferGroupIdFrom";
-          type: "u64"
-        },
-        {
-          name: "transferGroupIdTo";
-          type: "u64"
-        },
-        {
-          name: "lockedUntil";
+          name: "id";
           type: "u64"
         }
       ]
     },
     {
-      name: "setHolderGroupMax";
+      name: "initializeTransferRestrictionsData";
       discriminator: [
+        214,
+        241,
+        131,
         83,
-        33,
-        238,
-        145,
-        212,
-        216,
-        16,
-        197
+        138,
+        120,
+        171,
+        133
       ];
       accounts: [
         {
           name: "transferRestrictionData";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -2115,16 +1861,7 @@ export type TransferRestrictions = {
           }
         },
         {
-          name: "mint"
-        },
-        {
-          name: "accessControlAccount"
-        },
-        {
-          name: "authorityWalletRole"
-        },
-        {
-          name: "group";
+          name: "zeroTransferRestrictionGroup";
           writable: true;
           pda: {
             seeds: [
@@ -2141,46 +1878,97 @@ export type TransferRestrictions = {
                 path: "transferRestrictionData"
               },
               {
-                kind: "account";
-                path: "group.id";
-                account: "transferRestrictionGroup"
+                kind: "const";
+                value: [
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
               }
             ]
           }
         },
         {
-          name: "payer";
-          writable: true;
-          signer: true
-        }
-      ];
-      args: [
-        {
-          name: "groupId";
-          type: "u64"
+          name: "mint"
         },
         {
-          name: "holderGroupMax";
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ];
+      args: [
+        {
+          name: "maxHolders";
           type: "u64"
         }
       ]
     },
     {
-      name: "setHolderMax";
+      name: "initializeTransferRule";
       discriminator: [
-        254,
-        104,
-        250,
-        53,
-        13,
-        151,
-        2,
-        161
+        24,
+        28,
+        16,
+        18,
+        72,
+        26,
+        87,
+        49
       ];
       accounts: [
         {
-          name: "transferRestrictionData";
+          name: "transferRule";
           writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "arg";
+                path: "transferGroupIdFrom"
+              },
+              {
+                kind: "arg";
+                path: "transferGroupIdTo"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
           pda: {
             seeds: [
               {
@@ -2193,13 +1981,19 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "mint"
+                path: "access_control_account.mint";
+                account: "accessControl"
               }
             ]
           }
         },
         {
-          name: "mint"
+          name: "transferRestrictionGroupFrom";
+          optional: true
+        },
+        {
+          name: "transferRestrictionGroupTo";
+          optional: true
         },
         {
           name: "accessControlAccount"
@@ -2207,34 +2001,50 @@ export type TransferRestrictions = {
         {
           name: "authorityWalletRole"
         },
+        {
+          name: "authority";
+          signer: true
+        },
         {
           name: "payer";
           writable: true;
           signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
         }
       ];
       args: [
         {
-          name: "holderMax";
+          name: "transferGroupIdFrom";
+          type: "u64"
+        },
+        {
+          name: "transferGroupIdTo";
+          type: "u64"
+        },
+        {
+          name: "lockUntil";
           type: "u64"
         }
       ]
     },
     {
-      name: "setLockupEscrowAccount";
+      name: "mergeHolders";
       discriminator: [
-        134,
-        172,
-        249,
-        223,
-        25,
-        118,
-        55,
-        93
+        209,
+        130,
+        155,
+        238,
+        90,
+        0,
+        171,
+        22
       ];
       accounts: [
         {
-          name: "transferRestrictionData";
+          name: "sourceHolder";
           writable: true;
           pda: {
             seeds: [
@@ -2243,122 +2053,139 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  100
+                  104
                 ]
               },
               {
                 kind: "account";
-                path: "mint"
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "source_holder.id";
+                account: "transferRestrictionHolder"
               }
             ]
           }
         },
         {
-          name: "escrowSecurityAssociatedAccount";
+          name: "targetHolder";
+          writable: true
+        },
+        {
+          name: "transferRestrictionData";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
-                  115,
-                  97,
-                  97
+                  116,
+                  114,
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "escrowAccount"
+                path: "securityToken"
               }
             ]
           }
         },
         {
-          name: "mint"
-        },
-        {
-          name: "accessControlAccount"
+          name: "securityToken"
         },
         {
           name: "authorityWalletRole"
         },
         {
-          name: "escrowAccount"
-        },
-        {
-          name: "tokenlockAccount"
+          name: "authority";
+          signer: true
         },
         {
           name: "payer";
           writable: true;
           signer: true
         },
-        {
-          name: "authority";
-          signer: true
-        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111"
         }
       ];
-      args: []
+      args: [
+        {
+          name: "holderGroupsCount";
+          type: "u8"
+        }
+      ]
     },
     {
-      name: "updateWalletGroup";
+      name: "migrateAccounts";
       discriminator: [
-        225,
-        33,
-        252,
-        93,
-        186,
-        129,
-        24,
-        241
+        216,
+        43,
+        0,
+        164,
+        197,
+        239,
+        168,
+        37
       ];
       accounts: [
         {
-          name: "securityAssociatedAccount";
+          name: "transferRestrictionData";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
-                  115,
-                  97,
-                  97
+                  116,
+                  114,
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "userAssociatedTokenAccount"
+                path: "securityMint"
               }
             ]
           }
         },
         {
-          name: "securityToken"
+          name: "securityMint"
         },
         {
-          name: "transferRestrictionData";
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  100
-                ]
-              },
-              {
-                kind: "account";
-                path: "securityToken"
-              }
-            ]
-          }
+          name: "authorityWalletRole"
+        },
+        {
+          name: "accessControl"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
         },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
+    {
+      name: "migrateWalletsGroup";
+      discriminator: [
+        146,
+        96,
+        49,
+        153,
+        3,
+        118,
+        201,
+        238
+      ];
+      accounts: [
         {
           name: "transferRestrictionGroupCurrent";
           writable: true;
@@ -2377,9 +2204,8 @@ export type TransferRestrictions = {
                 path: "transferRestrictionData"
               },
               {
-                kind: "account";
-                path: "transfer_restriction_group_current.id";
-                account: "transferRestrictionGroup"
+                kind: "arg";
+                path: "currentGroupId"
               }
             ]
           }
@@ -2402,16 +2228,14 @@ export type TransferRestrictions = {
                 path: "transferRestrictionData"
               },
               {
-                kind: "account";
-                path: "transfer_restriction_group_new.id";
-                account: "transferRestrictionGroup"
+                kind: "arg";
+                path: "newGroupId"
               }
             ]
           }
         },
         {
-          name: "holderGroupCurrent";
-          writable: true;
+          name: "transferRestrictionData";
           pda: {
             seeds: [
               {
@@ -2419,145 +2243,3364 @@ export type TransferRestrictions = {
                 value: [
                   116,
                   114,
-                  104,
-                  103
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "security_associated_account.holder";
-                account: "securityAssociatedAccount"
-              },
-              {
-                kind: "account";
-                path: "security_associated_account.group";
-                account: "securityAssociatedAccount"
+                path: "securityToken"
               }
             ]
           }
         },
         {
-          name: "holderGroupNew";
+          name: "securityToken"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
           writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: [
+        {
+          name: "currentGroupId";
+          type: "u64"
+        },
+        {
+          name: "newGroupId";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "onboardInvestors";
+      discriminator: [
+        2,
+        49,
+        226,
+        205,
+        28,
+        216,
+        57,
+        110
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
                   114,
-                  104,
-                  103
+                  100
                 ]
               },
               {
                 kind: "account";
-                path: "security_associated_account.holder";
-                account: "securityAssociatedAccount"
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: [
+        {
+          name: "entries";
+          type: {
+            vec: {
+              defined: {
+                name: "onboardingEntry"
+              }
+            }
+          }
+        }
+      ];
+      returns: {
+        vec: "bool"
+      }
+    },
+    {
+      name: "pause";
+      discriminator: [
+        211,
+        22,
+        221,
+        251,
+        74,
+        121,
+        193,
+        47
+      ];
+      accounts: [
+        {
+          name: "securityMint"
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
               },
               {
                 kind: "account";
-                path: "transfer_restriction_group_new.id";
-                account: "transferRestrictionGroup"
+                path: "securityMint"
               }
             ]
           }
         },
+        {
+          name: "accessControlAccount"
+        },
         {
           name: "authorityWalletRole"
         },
         {
-          name: "userWallet"
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "paused";
+          type: "bool"
+        }
+      ]
+    },
+    {
+      name: "recordMintAcquisition";
+      docs: [
+        "record minted tokens as an acquisition lot, called by the access control program"
+      ];
+      discriminator: [
+        103,
+        3,
+        124,
+        134,
+        222,
+        181,
+        87,
+        181
+      ];
+      accounts: [
+        {
+          name: "accessControlAccount";
+          docs: [
+            "Access control account of the security, signs as the mint authority in `mint_securities`"
+          ];
+          signer: true
         },
         {
-          name: "userAssociatedTokenAccount";
+          name: "transferRestrictionData";
           pda: {
             seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
               {
                 kind: "account";
-                path: "userWallet"
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
+              }
+            ]
+          }
+        },
+        {
+          name: "tokenAccount"
+        },
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
               },
+              {
+                kind: "account";
+                path: "tokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "group"
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "registerVenueTokenAccount";
+      discriminator: [
+        188,
+        229,
+        191,
+        144,
+        109,
+        63,
+        99,
+        226
+      ];
+      accounts: [
+        {
+          name: "approvedVenue";
+          pda: {
+            seeds: [
               {
                 kind: "const";
                 value: [
-                  6,
-                  221,
-                  246,
-                  225,
-                  238,
+                  118,
+                  101,
+                  110,
                   117,
-                  143,
-                  222,
-                  24,
-                  66,
-                  93,
-                  188,
-                  228,
-                  108,
-                  205,
-                  218,
-                  182,
-                  26,
-                  252,
-                  77,
-                  131,
-                  185,
-                  13,
-                  39,
-                  254,
-                  189,
-                  249,
-                  40,
-                  216,
-                  161,
-                  139,
-                  252
+                  101
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "approved_venue.venue";
+                account: "approvedVenue"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "tokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "tokenAccount"
+        },
+        {
+          name: "venueAuthority"
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
                 ]
               },
               {
                 kind: "account";
                 path: "securityToken"
               }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
+          }
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "approved_venue.group";
+                account: "approvedVenue"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole";
+          docs: [
+            "Wallet role of the authority, not required when the venue authority signs"
+          ];
+          optional: true
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: [
+        {
+          name: "authoritySeeds";
+          type: {
+            vec: "bytes"
+          }
+        }
+      ]
+    },
+    {
+      name: "removeAttestationIssuer";
+      discriminator: [
+        172,
+        182,
+        61,
+        78,
+        33,
+        243,
+        103,
+        24
+      ];
+      accounts: [
+        {
+          name: "attestationIssuerRegistry";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  97,
+                  105,
+                  114
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "extraMetasAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  116,
+                  114,
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8"
+        }
+      ]
+    },
+    {
+      name: "removeBlackoutWindow";
+      discriminator: [
+        150,
+        178,
+        225,
+        79,
+        125,
+        28,
+        67,
+        126
+      ];
+      accounts: [
+        {
+          name: "blackoutCalendar";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  98,
+                  99
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "window";
+          type: {
+            defined: {
+              name: "blackoutWindow"
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: "removeWalletHold";
+      discriminator: [
+        154,
+        84,
+        89,
+        196,
+        174,
+        63,
+        229,
+        87
+      ];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "tokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "tokenAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8"
+        }
+      ]
+    },
+    {
+      name: "revokeApprovedVenue";
+      discriminator: [
+        8,
+        157,
+        129,
+        7,
+        161,
+        11,
+        71,
+        0
+      ];
+      accounts: [
+        {
+          name: "approvedVenue";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  118,
+                  101,
+                  110,
+                  117,
+                  101
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "approved_venue.venue";
+                account: "approvedVenue"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: []
+    },
+    {
+      name: "revokeBeneficialOwnerPosition";
+      discriminator: [
+        162,
+        86,
+        10,
+        55,
+        39,
+        212,
+        140,
+        86
+      ];
+      accounts: [
+        {
+          name: "beneficialOwnerPosition";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  98,
+                  111,
+                  112
+                ]
+              },
+              {
+                kind: "account";
+                path: "omnibusSecurityAssociatedAccount"
+              },
+              {
+                kind: "account";
+                path: "holder"
+              }
+            ]
+          }
+        },
+        {
+          name: "omnibusSecurityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "omnibusTokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "omnibusTokenAccount"
+        },
+        {
+          name: "group";
+          writable: true
+        },
+        {
+          name: "holder";
+          writable: true
+        },
+        {
+          name: "holderGroup";
+          writable: true
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "operator";
+          docs: [
+            "Owner of the omnibus token account maintaining its sub-ledger"
+          ];
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
+    {
+      name: "revokeHolder";
+      discriminator: [
+        250,
+        238,
+        38,
+        18,
+        138,
+        55,
+        227,
+        111
+      ];
+      accounts: [
+        {
+          name: "holder";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "holder.id";
+                account: "transferRestrictionHolder"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole";
+          writable: true
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
+    {
+      name: "revokeHolderGroup";
+      discriminator: [
+        33,
+        153,
+        183,
+        187,
+        204,
+        120,
+        164,
+        40
+      ];
+      accounts: [
+        {
+          name: "holder";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "holder.id";
+                account: "transferRestrictionHolder"
+              }
+            ]
+          }
+        },
+        {
+          name: "holderGroup";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "holder"
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
+              }
+            ]
+          }
+        },
+        {
+          name: "group";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole";
+          writable: true
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
+    {
+      name: "revokeSecurityAssociatedAccount";
+      discriminator: [
+        75,
+        206,
+        46,
+        31,
+        84,
+        165,
+        44,
+        66
+      ];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "associatedTokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "group";
+          writable: true
+        },
+        {
+          name: "holder";
+          writable: true
+        },
+        {
+          name: "holderGroup";
+          writable: true
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "userWallet"
+        },
+        {
+          name: "associatedTokenAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
+    {
+      name: "revokeTransferApproval";
+      discriminator: [
+        129,
+        126,
+        193,
+        169,
+        79,
+        234,
+        96,
+        147
+      ];
+      accounts: [
+        {
+          name: "transferApproval";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  97,
+                  112
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "transfer_approval.source_account";
+                account: "transferApproval"
+              },
+              {
+                kind: "account";
+                path: "transfer_approval.destination_account";
+                account: "transferApproval"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: []
+    },
+    {
+      name: "revokeTransferRestrictionGroup";
+      discriminator: [
+        13,
+        3,
+        188,
+        119,
+        14,
+        51,
+        131,
+        124
+      ];
+      accounts: [
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "receiver";
+          writable: true
+        }
+      ];
+      args: []
+    },
+    {
+      name: "revokeTransferRule";
+      discriminator: [
+        188,
+        61,
+        201,
+        116,
+        238,
+        47,
+        112,
+        11
+      ];
+      accounts: [
+        {
+          name: "transferRule";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "transfer_rule.transfer_group_id_from";
+                account: "transferRule"
+              },
+              {
+                kind: "account";
+                path: "transfer_rule.transfer_group_id_to";
+                account: "transferRule"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "receiver";
+          writable: true
+        }
+      ];
+      args: []
+    },
+    {
+      name: "revokeVenueTokenAccount";
+      discriminator: [
+        231,
+        171,
+        38,
+        143,
+        26,
+        16,
+        50,
+        3
+      ];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "tokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "tokenAccount"
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.group";
+                account: "securityAssociatedAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: []
+    },
+    {
+      name: "setAddressPermission";
+      discriminator: [
+        206,
+        98,
+        48,
+        240,
+        226,
+        102,
+        10,
+        236
+      ];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "userAssociatedTokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionGroupNew";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "arg";
+                path: "groupId"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionGroupCurrent";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.group";
+                account: "securityAssociatedAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionHolder";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_holder.id";
+                account: "transferRestrictionHolder"
+              }
+            ]
+          }
+        },
+        {
+          name: "holderGroupNew";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionHolder"
+              },
+              {
+                kind: "arg";
+                path: "groupId"
+              }
+            ]
+          }
+        },
+        {
+          name: "holderGroupCurrent";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.holder";
+                account: "securityAssociatedAccount"
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.group";
+                account: "securityAssociatedAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "userWallet"
+        },
+        {
+          name: "userAssociatedTokenAccount";
+          writable: true
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "securityMint";
+          writable: true
+        },
+        {
+          name: "accessControlAccount";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  97,
+                  99
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityMint"
+              }
+            ];
+            program: {
+              kind: "account";
+              path: "accessControlProgram"
+            }
+          }
+        },
+        {
+          name: "accessControlProgram";
+          address: "4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3"
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        },
+        {
+          name: "exemptAccountRegistry";
+          docs: [
+            "control program"
+          ]
+        }
+      ];
+      args: [
+        {
+          name: "groupId";
+          type: "u64"
+        },
+        {
+          name: "frozen";
+          type: "bool"
+        }
+      ]
+    },
+    {
+      name: "setAllowIntraHolderTransfers";
+      discriminator: [
+        103,
+        108,
+        220,
+        212,
+        203,
+        150,
+        239,
+        110
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "enabled";
+          type: "bool"
+        }
+      ]
+    },
+    {
+      name: "setAllowTransferRule";
+      discriminator: [
+        4,
+        83,
+        246,
+        172,
+        106,
+        193,
+        31,
+        116
+      ];
+      accounts: [
+        {
+          name: "transferRule";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "arg";
+                path: "transferGroupIdFrom"
+              },
+              {
+                kind: "arg";
+                path: "transferGroupIdTo"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "access_control_account.mint";
+                account: "accessControl"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+          optional: true
+        },
+        {
+          name: "transferRestrictionGroupTo";
+          optional: true
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "transferGroupIdFrom";
+          type: "u64"
+        },
+        {
+          name: "transferGroupIdTo";
+          type: "u64"
+        },
+        {
+          name: "lockedUntil";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "setCountHoldersByBalance";
+      discriminator: [
+        110,
+        18,
+        226,
+        211,
+        207,
+        224,
+        96,
+        238
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "extraMetasAccount";
+          docs: [
+            "holders are counted by balance"
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  116,
+                  114,
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "attestationIssuerRegistry"
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: [
+        {
+          name: "enabled";
+          type: "bool"
+        }
+      ]
+    },
+    {
+      name: "setGroupPaused";
+      discriminator: [
+        41,
+        7,
+        218,
+        220,
+        12,
+        146,
+        134,
+        20
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "groupId";
+          type: "u64"
+        },
+        {
+          name: "pausedDirection";
+          type: "u8"
+        }
+      ]
+    },
+    {
+      name: "setHolderFrozen";
+      discriminator: [
+        131,
+        67,
+        117,
+        236,
+        241,
+        205,
+        102,
+        91
+      ];
+      accounts: [
+        {
+          name: "holder";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "holder.id";
+                account: "transferRestrictionHolder"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityMint"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityMint";
+          writable: true
+        },
+        {
+          name: "accessControlAccount";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  97,
+                  99
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityMint"
+              }
+            ];
+            program: {
+              kind: "account";
+              path: "accessControlProgram"
+            }
+          }
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "accessControlProgram";
+          address: "4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3"
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          name: "exemptAccountRegistry";
+          docs: [
+            "control program"
+          ]
+        }
+      ];
+      args: [
+        {
+          name: "frozen";
+          type: "bool"
+        }
+      ]
+    },
+    {
+      name: "setHolderGroupMax";
+      discriminator: [
+        83,
+        33,
+        238,
+        145,
+        212,
+        216,
+        16,
+        197
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "groupId";
+          type: "u64"
+        },
+        {
+          name: "holderGroupMax";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "setHolderMax";
+      discriminator: [
+        254,
+        104,
+        250,
+        53,
+        13,
+        151,
+        2,
+        161
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "holderMax";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "setHoldingPeriod";
+      discriminator: [
+        6,
+        111,
+        165,
+        220,
+        239,
+        242,
+        146,
+        1
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "groupId";
+          type: "u64"
+        },
+        {
+          name: "holdingPeriod";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "setLockupEscrowAccount";
+      discriminator: [
+        134,
+        172,
+        249,
+        223,
+        25,
+        118,
+        55,
+        93
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "escrowSecurityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "escrowAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "escrowAccount"
+        },
+        {
+          name: "tokenlockAccount"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "authority";
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
+    {
+      name: "setMinHolding";
+      discriminator: [
+        165,
+        255,
+        148,
+        251,
+        75,
+        221,
+        3,
+        199
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "mint"
+              }
+            ]
+          }
+        },
+        {
+          name: "mint"
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "groupId";
+          type: "u64"
+        },
+        {
+          name: "minHolding";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "setOmnibusAccount";
+      discriminator: [
+        2,
+        61,
+        238,
+        83,
+        185,
+        33,
+        12,
+        17
+      ];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "tokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "tokenAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "authority";
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "omnibus";
+          type: "bool"
+        }
+      ]
+    },
+    {
+      name: "setPolicyProgram";
+      docs: [
+        "set the program called by the transfer hook after the built-in checks"
+      ];
+      discriminator: [
+        221,
+        164,
+        107,
+        199,
+        247,
+        226,
+        212,
+        107
+      ];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "extraMetasAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  116,
+                  114,
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "policyProgram"
+        },
+        {
+          name: "attestationIssuerRegistry"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
+    {
+      name: "setTransferRuleApprovalRequired";
+      discriminator: [
+        61,
+        19,
+        187,
+        249,
+        43,
+        69,
+        197,
+        50
+      ];
+      accounts: [
+        {
+          name: "transferRule";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "arg";
+                path: "groupIdFrom"
+              },
+              {
+                kind: "arg";
+                path: "groupIdTo"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "access_control_account.mint";
+                account: "accessControl"
+              }
+            ]
+          }
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "groupIdFrom";
+          type: "u64"
+        },
+        {
+          name: "groupIdTo";
+          type: "u64"
+        },
+        {
+          name: "approvalRequired";
+          type: "bool"
+        }
+      ]
+    },
+    {
+      name: "setTransferRuleValidity";
+      discriminator: [
+        97,
+        151,
+        251,
+        27,
+        116,
+        219,
+        146,
+        116
+      ];
+      accounts: [
+        {
+          name: "transferRule";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "arg";
+                path: "transferGroupIdFrom"
+              },
+              {
+                kind: "arg";
+                path: "transferGroupIdTo"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "access_control_account.mint";
+                account: "accessControl"
+              }
+            ]
+          }
+        },
+        {
+          name: "accessControlAccount"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "transferGroupIdFrom";
+          type: "u64"
+        },
+        {
+          name: "transferGroupIdTo";
+          type: "u64"
+        },
+        {
+          name: "validUntil";
+          type: "u64"
+        },
+        {
+          name: "closedIntervals";
+          type: {
+            vec: {
+              defined: {
+                name: "transferRuleClosedInterval"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: "syncFundedWallet";
+      discriminator: [
+        120,
+        48,
+        197,
+        36,
+        58,
+        66,
+        49,
+        39
+      ];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "tokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "tokenAccount"
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData"
+              }
+            ]
+          }
+        },
+        {
+          name: "group";
+          writable: true
+        },
+        {
+          name: "holder";
+          writable: true
+        },
+        {
+          name: "holderGroup";
+          writable: true
+        }
+      ];
+      args: []
+    },
+    {
+      name: "updateBeneficialOwnerPosition";
+      discriminator: [
+        55,
+        244,
+        110,
+        40,
+        39,
+        11,
+        242,
+        43
+      ];
+      accounts: [
+        {
+          name: "beneficialOwnerPosition";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  98,
+                  111,
+                  112
+                ]
+              },
+              {
+                kind: "account";
+                path: "omnibusSecurityAssociatedAccount"
+              },
+              {
+                kind: "account";
+                path: "holder"
+              }
+            ]
+          }
+        },
+        {
+          name: "omnibusSecurityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "omnibusTokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "omnibusTokenAccount"
+        },
+        {
+          name: "group";
+          writable: true
+        },
+        {
+          name: "holder";
+          writable: true
+        },
+        {
+          name: "holderGroup";
+          writable: true
+        },
+        {
+          name: "transferRule";
+          docs: [
+            "uninitialized when a default rule applies. Default transfer rule candidates are passed as",
+            "remaining accounts. Checked by the instruction when the position is increased."
+          ]
+        },
+        {
+          name: "blackoutCalendar";
+          docs: [
+            "is increased."
+          ]
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "operator";
+          docs: [
+            "Owner of the omnibus token account maintaining its sub-ledger"
+          ];
+          signer: true
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64"
+        }
+      ]
+    },
+    {
+      name: "updateExtraAccountMetaList";
+      discriminator: [
+        44,
+        125,
+        141,
+        226,
+        97,
+        179,
+        166,
+        96
+      ];
+      accounts: [
+        {
+          name: "extraMetasAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  116,
+                  114,
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityMint"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityMint"
+        },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "accessControl"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityMint"
+              }
+            ]
+          }
+        },
+        {
+          name: "attestationIssuerRegistry"
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111"
+        }
+      ];
+      args: []
+    },
+    {
+      name: "updateWalletGroup";
+      discriminator: [
+        225,
+        33,
+        252,
+        93,
+        186,
+        129,
+        24,
+        241
+      ];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115,
+                  97,
+                  97
+                ]
+              },
+              {
+                kind: "account";
+                path: "userAssociatedTokenAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "securityToken"
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  100
+                ]
+              },
+              {
+                kind: "account";
+                path: "securityToken"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionGroupCurrent";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_group_current.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "transferRestrictionGroupNew";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData"
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_group_new.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
+          }
+        },
+        {
+          name: "holderGroupCurrent";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.holder";
+                account: "securityAssociatedAccount"
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.group";
+                account: "securityAssociatedAccount"
+              }
+            ]
+          }
+        },
+        {
+          name: "holderGroupNew";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  104,
+                  103
+                ]
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.holder";
+                account: "securityAssociatedAccount"
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_group_new.id";
+                account: "transferRestrictionGroup"
+              }
+            ]
           }
         },
+        {
+          name: "authorityWalletRole"
+        },
+        {
+          name: "userWallet"
+        },
+        {
+          name: "userAssociatedTokenAccount"
+        },
         {
           name: "payer";
           writable: true;
@@ -2590,6 +5633,58 @@ export type TransferRestrictions = {
         132
       ]
     },
+    {
+      name: "approvedVenue";
+      discriminator: [
+        224,
+        141,
+        22,
+        39,
+        234,
+        148,
+        222,
+        70
+      ]
+    },
+    {
+      name: "attestationIssuerRegistry";
+      discriminator: [
+        31,
+        165,
+        235,
+        106,
+        193,
+        201,
+        137,
+        95
+      ]
+    },
+    {
+      name: "beneficialOwnerPosition";
+      discriminator: [
+        248,
+        206,
+        52,
+        64,
+        41,
+        111,
+        59,
+        161
+      ]
+    },
+    {
+      name: "blackoutCalendar";
+      discriminator: [
+        101,
+        66,
+        206,
+        56,
+        158,
+        127,
+        145,
+        134
+      ]
+    },
     {
       name: "holderGroup";
       discriminator: [
@@ -2616,6 +5711,19 @@ export type TransferRestrictions = {
         124
       ]
     },
+    {
+      name: "transferApproval";
+      discriminator: [
+        153,
+        181,
+        122,
+        112,
+        80,
+        6,
+        154,
+        216
+      ]
+    },
     {
       name: "transferRestrictionData";
       discriminator: [
@@ -2684,158 +5792,669 @@ export type TransferRestrictions = {
   ];
   errors: [
     {
-      code: 6000;
-      name: "unauthorized";
-      msg: "unauthorized"
+      code: 6000;
+      name: "unauthorized";
+      msg: "unauthorized"
+    },
+    {
+      code: 6001;
+      name: "maxHoldersReached";
+      msg: "Max holders reached"
+    },
+    {
+      code: 6002;
+      name: "transferRuleNotAllowedUntilLater";
+      msg: "Transfer rule not allowed until later"
+    },
+    {
+      code: 6003;
+      name: "invalidRole";
+      msg: "Invalid role"
+    },
+    {
+      code: 6004;
+      name: "allTransfersPaused";
+      msg: "All transfers are paused"
+    },
+    {
+      code: 6005;
+      name: "invalidPda";
+      msg: "Invalid PDA"
+    },
+    {
+      code: 6006;
+      name: "balanceIsTooLow";
+      msg: "Balance is too low"
+    },
+    {
+      code: 6007;
+      name: "currentWalletsCountMustBeZero";
+      msg: "Current wallets count must be zero"
+    },
+    {
+      code: 6008;
+      name: "mismatchedEscrowAccount";
+      msg: "Mismatched escrow account"
+    },
+    {
+      code: 6009;
+      name: "invalidHolderIndex";
+      msg: "Invalid transfer restriction holder index"
+    },
+    {
+      code: 6010;
+      name: "maxHoldersReachedInsideTheGroup";
+      msg: "Max holders reached inside the group"
+    },
+    {
+      code: 6011;
+      name: "transferGroupNotApproved";
+      msg: "Transfer group not approved"
+    },
+    {
+      code: 6012;
+      name: "incorrectTokenlockAccount";
+      msg: "Wrong tokenlock account"
+    },
+    {
+      code: 6013;
+      name: "transferRuleAccountDataIsEmtpy";
+      msg: "Transfer rule account data is empty"
+    },
+    {
+      code: 6014;
+      name: "securityAssociatedAccountDataIsEmtpy";
+      msg: "Security associated account data is empty"
+    },
+    {
+      code: 6015;
+      name: "transferRestrictionsAccountDataIsEmtpy";
+      msg: "Transfer restrictions account data is empty"
+    },
+    {
+      code: 6016;
+      name: "noWalletsInGroup";
+      msg: "No wallets in group"
+    },
+    {
+      code: 6017;
+      name: "newGroupIsTheSameAsTheCurrentGroup";
+      msg: "New group is the same as the current group"
+    },
+    {
+      code: 6018;
+      name: "newHolderMaxMustExceedCurrentHolderCount";
+      msg: "New holder max must exceed current holder count"
+    },
+    {
+      code: 6019;
+      name: "newHolderGroupMaxMustExceedCurrentHolderGroupCount";
+      msg: "New holder group max must exceed current holder group count"
+    },
+    {
+      code: 6020;
+      name: "zeroGroupHolderGroupMaxCannotBeNonZero";
+      msg: "Zero group holder group max cannot be non-zero"
+    },
+    {
+      code: 6021;
+      name: "nonPositiveHolderGroupCount";
+      msg: "Non-positive holder group count"
+    },
+    {
+      code: 6022;
+      name: "currentHolderGroupCountMustBeZero";
+      msg: "Current holder group count must be zero"
+    },
+    {
+      code: 6023;
+      name: "valueUnchanged";
+      msg: "The provided value is already set. No changes were made"
+    },
+    {
+      code: 6024;
+      name: "currentGroupRequiredForExistingWallet";
+      msg: "Current group and holder group must be provided for existing wallet"
+    },
+    {
+      code: 6025;
+      name: "holderGroupAlreadyInitialized";
+      msg: "Holder group already initialized"
+    },
+    {
+      code: 6026;
+      name: "transferRuleExpired";
+      msg: "Transfer rule has expired"
+    },
+    {
+      code: 6027;
+      name: "transferRuleClosedInterval";
+      msg: "Transfer rule is inside a closed interval"
+    },
+    {
+      code: 6028;
+      name: "tooManyClosedIntervals";
+      msg: "Too many closed intervals"
+    },
+    {
+      code: 6029;
+      name: "invalidClosedInterval";
+      msg: "Invalid closed interval"
+    },
+    {
+      code: 6030;
+      name: "invalidBlackoutWindow";
+      msg: "Invalid blackout window"
+    },
+    {
+      code: 6031;
+      name: "invalidTransferDirection";
+      msg: "Invalid transfer direction"
+    },
+    {
+      code: 6032;
+      name: "tooManyBlackoutWindows";
+      msg: "Too many blackout windows"
+    },
+    {
+      code: 6033;
+      name: "blackoutWindowNotFound";
+      msg: "Blackout window not found"
+    },
+    {
+      code: 6034;
+      name: "transferBlackoutActive";
+      msg: "Transfers are blocked by a blackout window"
+    },
+    {
+      code: 6035;
+      name: "holdingPeriodNotElapsed";
+      msg: "Tokens are still inside the holding period"
+    },
+    {
+      code: 6036;
+      name: "notTransferring";
+      msg: "Transfer hook can be executed only during a token transfer"
+    },
+    {
+      code: 6037;
+      name: "holderCountingByBalanceDisabled";
+      msg: "Holder counting by balance is disabled"
+    },
+    {
+      code: 6038;
+      name: "defaultTransferRuleAccountMissing";
+      msg: "Default transfer rule account is missing"
+    },
+    {
+      code: 6039;
+      name: "transferRestrictionGroupMissing";
+      msg: "Transfer restriction group account is missing"
+    },
+    {
+      code: 6040;
+      name: "invalidTransferGroupId";
+      msg: "Invalid transfer group id"
+    },
+    {
+      code: 6041;
+      name: "invalidRemainingAccounts";
+      msg: "Invalid remaining accounts"
+    },
+    {
+      code: 6042;
+      name: "invalidTokenAccount";
+      msg: "Invalid token account"
+    },
+    {
+      code: 6043;
+      name: "notOmnibusAccount";
+      msg: "Token account is not an omnibus account"
+    },
+    {
+      code: 6044;
+      name: "omnibusAccountHasBeneficialOwners";
+      msg: "Omnibus account has beneficial owners"
+    },
+    {
+      code: 6045;
+      name: "omnibusBalanceBelowBeneficialOwnership";
+      msg: "Omnibus account balance does not cover beneficial owner positions"
+    },
+    {
+      code: 6046;
+      name: "beneficialOwnerPositionNotEmpty";
+      msg: "Beneficial owner position is not empty"
+    },
+    {
+      code: 6047;
+      name: "cannotMergeHolderIntoItself";
+      msg: "Cannot merge a holder into itself"
+    },
+    {
+      code: 6048;
+      name: "tokenAccountNotApprovedVenue";
+      msg: "Token account is not owned by the approved venue"
+    },
+    {
+      code: 6049;
+      name: "notVenueTokenAccount";
+      msg: "Token account is not a venue token account"
+    },
+    {
+      code: 6050;
+      name: "groupTransfersPaused";
+      msg: "Transfers of the group are paused"
+    },
+    {
+      code: 6051;
+      name: "holderFrozen";
+      msg: "Holder is frozen"
+    },
+    {
+      code: 6052;
+      name: "invalidWalletHold";
+      msg: "Invalid wallet hold"
+    },
+    {
+      code: 6053;
+      name: "tooManyWalletHolds";
+      msg: "Too many wallet holds"
+    },
+    {
+      code: 6054;
+      name: "invalidWalletHoldIndex";
+      msg: "Invalid wallet hold index"
+    },
+    {
+      code: 6055;
+      name: "transferBlockedByWalletHold";
+      msg: "Transfer is blocked by a wallet hold"
+    },
+    {
+      code: 6056;
+      name: "groupHasWallets";
+      msg: "Group still has wallets"
+    },
+    {
+      code: 6057;
+      name: "transferApprovalRequired";
+      msg: "Transfer requires a transfer approval"
+    },
+    {
+      code: 6058;
+      name: "invalidTransferApproval";
+      msg: "Invalid transfer approval"
     },
     {
-      code: 6001;
-      name: "maxHoldersReached";
-      msg: "Max holders reached"
+      code: 6059;
+      name: "transferApprovalExpired";
+      msg: "Transfer approval expired"
     },
     {
-      code: 6002;
-      name: "transferRuleNotAllowedUntilLater";
-      msg: "Transfer rule not allowed until later"
+      code: 6060;
+      name: "transferApprovalAmountExceeded";
+      msg: "Transfer amount exceeds the transfer approval"
     },
     {
-      code: 6003;
-      name: "invalidRole";
-      msg: "Invalid role"
+      code: 6061;
+      name: "notExemptAccount";
+      msg: "Token account is not an exempt account"
     },
     {
-      code: 6004;
-      name: "allTransfersPaused";
-      msg: "All transfers are paused"
+      code: 6062;
+      name: "invalidMigrationAccount";
+      msg: "Account cannot be migrated with the transfer restriction data"
     },
     {
-      code: 6005;
-      name: "invalidPda";
-      msg: "Invalid PDA"
+      code: 6063;
+      name: "invalidAttestationIssuer";
+      msg: "Invalid attestation issuer"
     },
     {
-      code: 6006;
-      name: "balanceIsTooLow";
-      msg: "Balance is too low"
+      code: 6064;
+      name: "attestationIssuerAlreadyRegistered";
+      msg: "Attestation issuer is already registered"
     },
     {
-      code: 6007;
-      name: "currentWalletsCountMustBeZero";
-      msg: "Current wallets count must be zero"
+      code: 6065;
+      name: "attestationIssuersLimitReached";
+      msg: "Attestation issuers limit reached"
     },
     {
-      code: 6008;
-      name: "mismatchedEscrowAccount";
-      msg: "Mismatched escrow account"
+      code: 6066;
+      name: "invalidAttestationIssuerIndex";
+      msg: "Invalid attestation issuer index"
     },
     {
-      code: 6009;
-      name: "invalidHolderIndex";
-      msg: "Invalid transfer restriction holder index"
+      code: 6067;
+      name: "walletNotAttested";
+      msg: "Wallet has no valid attestation of a trusted issuer"
     },
     {
-      code: 6010;
-      name: "maxHoldersReachedInsideTheGroup";
-      msg: "Max holders reached inside the group"
+      code: 6068;
+      name: "invalidPolicyProgram";
+      msg: "Invalid policy program"
     },
     {
-      code: 6011;
-      name: "transferGroupNotApproved";
-      msg: "Transfer group not approved"
+      code: 6069;
+      name: "senderBalanceBelowMinHolding";
+      msg: "Sender balance after the transfer is below the minimum holding"
     },
     {
-      code: 6012;
-      name: "incorrectTokenlockAccount";
-      msg: "Wrong tokenlock account"
+      code: 6070;
+      name: "receiverBalanceBelowMinHolding";
+      msg: "Receiver balance after the transfer is below the minimum holding"
     },
     {
-      code: 6013;
-      name: "transferRuleAccountDataIsEmtpy";
-      msg: "Transfer rule account data is empty"
+      code: 6071;
+      name: "holderHasBeneficialOwnerPositions";
+      msg: "Holder has beneficial owner positions"
     },
     {
-      code: 6014;
-      name: "securityAssociatedAccountDataIsEmtpy";
-      msg: "Security associated account data is empty"
+      code: 6072;
+      name: "invalidHolderAccount";
+      msg: "Holder account does not match the holder of the wallet"
     },
     {
-      code: 6015;
-      name: "transferRestrictionsAccountDataIsEmtpy";
-      msg: "Transfer restrictions account data is empty"
+      code: 6073;
+      name: "attestationProgramMismatch";
+      msg: "Attestation issuers must use the same attestation program"
     },
     {
-      code: 6016;
-      name: "noWalletsInGroup";
-      msg: "No wallets in group"
+      code: 6074;
+      name: "transferDependsOnPolicyProgram";
+      msg: "Transfer passes the transfer restrictions and depends on the policy program"
     },
     {
-      code: 6017;
-      name: "newGroupIsTheSameAsTheCurrentGroup";
-      msg: "New group is the same as the current group"
+      code: 6075;
+      name: "mutableTokenAccountOwner";
+      msg: "Token account owner can be changed"
     },
     {
-      code: 6018;
-      name: "newHolderMaxMustExceedCurrentHolderCount";
-      msg: "New holder max must exceed current holder count"
+      code: 6076;
+      name: "holdersCountOverflow";
+      msg: "Holders count overflow"
+    }
+  ];
+  types: [
+    {
+      name: "accessControl";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "pubkey"
+          },
+          {
+            name: "authority";
+            type: "pubkey"
+          },
+          {
+            name: "maxTotalSupply";
+            type: "u64"
+          },
+          {
+            name: "lockupEscrowAccount";
+            type: {
+              option: "pubkey"
+            }
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "exemptAccountsCount";
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                31
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      code: 6019;
-      name: "newHolderGroupMaxMustExceedCurrentHolderGroupCount";
-      msg: "New holder group max must exceed current holder group count"
+      name: "acquisitionLot";
+      docs: [
+        "Amount of tokens received at `acquired_at` which is still inside the holding period"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "acquiredAt";
+            type: "u64"
+          },
+          {
+            name: "amount";
+            type: "u64"
+          }
+        ]
+      }
     },
     {
-      code: 6020;
-      name: "zeroGroupHolderGroupMaxCannotBeNonZero";
-      msg: "Zero group holder group max cannot be non-zero"
+      name: "approvedVenue";
+      docs: [
+        "Program or specific token account of a regulated venue whose token accounts are mapped to `group`"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "transferRestrictionData";
+            type: "pubkey"
+          },
+          {
+            name: "venue";
+            type: "pubkey"
+          },
+          {
+            name: "group";
+            type: "u64"
+          }
+        ]
+      }
     },
     {
-      code: 6021;
-      name: "nonPositiveHolderGroupCount";
-      msg: "Non-positive holder group count"
+      name: "attestationIssuer";
+      docs: [
+        "Third-party verifier trusted to attest wallets of the security"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "programId";
+            type: "pubkey"
+          },
+          {
+            name: "schema";
+            type: "pubkey"
+          },
+          {
+            name: "layout";
+            type: {
+              defined: {
+                name: "attestationLayout"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      code: 6022;
-      name: "currentHolderGroupCountMustBeZero";
-      msg: "Current holder group count must be zero"
+      name: "attestationIssuerRegistry";
+      docs: [
+        "Attestation issuers trusted by the security. Once an issuer is registered, wallets with a",
+        "holder need a valid attestation of one of the issuers to send and receive tokens. The issuers",
+        "share the attestation program and are told apart by their schema."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "transferRestrictionData";
+            type: "pubkey"
+          },
+          {
+            name: "issuersCount";
+            type: "u8"
+          },
+          {
+            name: "issuers";
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: "attestationIssuer"
+                  }
+                },
+                2
+              ]
+            }
+          },
+          {
+            name: "bump";
+            type: "u8"
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      code: 6023;
-      name: "valueUnchanged";
-      msg: "The provided value is already set. No changes were made"
+      name: "attestationLayout";
+      docs: [
+        "Positions of the attested fields in the attestation accounts published by the issuer"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "discriminator";
+            type: {
+              array: [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            name: "schemaOffset";
+            type: "u16"
+          },
+          {
+            name: "walletOffset";
+            type: "u16"
+          },
+          {
+            name: "expiresAtOffset";
+            type: "u16"
+          },
+          {
+            name: "revokedOffset";
+            type: "u16"
+          }
+        ]
+      }
     },
     {
-      code: 6024;
-      name: "currentGroupRequiredForExistingWallet";
-      msg: "Current group and holder group must be provided for existing wallet"
+      name: "beneficialOwnerPosition";
+      docs: [
+        "Position of a beneficial owner inside an omnibus token account sub-ledger"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "omnibusAccount";
+            type: "pubkey"
+          },
+          {
+            name: "holder";
+            type: "pubkey"
+          },
+          {
+            name: "group";
+            type: "u64"
+          },
+          {
+            name: "amount";
+            type: "u64"
+          }
+        ]
+      }
     },
     {
-      code: 6025;
-      name: "holderGroupAlreadyInitialized";
-      msg: "Holder group already initialized"
-    }
-  ];
-  types: [
+      name: "blackoutCalendar";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "transferRestrictionData";
+            type: "pubkey"
+          },
+          {
+            name: "windows";
+            type: {
+              vec: {
+                defined: {
+                  name: "blackoutWindow"
+                }
+              }
+            }
+          },
+          {
+            name: "bump";
+            type: "u8"
+          }
+        ]
+      }
+    },
     {
-      name: "accessControl";
+      name: "blackoutWindow";
+      docs: [
+        "Time range during which transfers of the listed groups are blocked.",
+        "An empty `groups` list applies the window to all groups."
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "mint";
-            type: "pubkey"
+            name: "start";
+            type: "u64"
           },
           {
-            name: "authority";
-            type: "pubkey"
+            name: "end";
+            type: "u64"
           },
           {
-            name: "maxTotalSupply";
-            type: "u64"
+            name: "direction";
+            type: "u8"
           },
           {
-            name: "lockupEscrowAccount";
+            name: "groups";
             type: {
-              option: "pubkey"
+              vec: "u64"
             }
           }
         ]
@@ -2857,6 +6476,42 @@ export type TransferRestrictions = {
           {
             name: "currentWalletsCount";
             type: "u64"
+          },
+          {
+            name: "fundedWalletsCount";
+            type: "u64"
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: "onboardingEntry";
+      docs: [
+        "Investor wallet to onboard, accounts are passed as remaining accounts in the same order"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holderId";
+            type: "u64"
+          },
+          {
+            name: "groupId";
+            type: "u64"
           }
         ]
       }
@@ -2875,6 +6530,113 @@ export type TransferRestrictions = {
             type: {
               option: "pubkey"
             }
+          },
+          {
+            name: "acquisitionLotsCount";
+            type: "u8"
+          },
+          {
+            name: "acquisitionLots";
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: "acquisitionLot"
+                  }
+                },
+                8
+              ]
+            }
+          },
+          {
+            name: "funded";
+            type: "bool"
+          },
+          {
+            name: "holderId";
+            type: "u64"
+          },
+          {
+            name: "omnibus";
+            type: "bool"
+          },
+          {
+            name: "beneficialOwnersCount";
+            type: "u64"
+          },
+          {
+            name: "beneficialOwnedAmount";
+            type: "u64"
+          },
+          {
+            name: "holdsCount";
+            type: "u8"
+          },
+          {
+            name: "holds";
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: "walletHold"
+                  }
+                },
+                4
+              ]
+            }
+          },
+          {
+            name: "bump";
+            type: "u8"
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: "transferApproval";
+      docs: [
+        "Transfer admin sign-off for transfers from `source_account` to `destination_account`, required",
+        "by transfer rules with `approval_required`. Every approved transfer decreases `remaining_amount`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "transferRestrictionData";
+            type: "pubkey"
+          },
+          {
+            name: "sourceAccount";
+            type: "pubkey"
+          },
+          {
+            name: "destinationAccount";
+            type: "pubkey"
+          },
+          {
+            name: "remainingAmount";
+            type: "u64"
+          },
+          {
+            name: "expiresAt";
+            type: "u64"
+          },
+          {
+            name: "bump";
+            type: "u8"
           }
         ]
       }
@@ -2913,6 +6675,59 @@ export type TransferRestrictions = {
             type: {
               option: "pubkey"
             }
+          },
+          {
+            name: "countHoldersByBalance";
+            type: "bool"
+          },
+          {
+            name: "fundedHoldersCount";
+            type: "u64"
+          },
+          {
+            name: "bump";
+            type: "u8"
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "policyProgram";
+            type: "pubkey"
+          },
+          {
+            name: "allowIntraHolderTransfers";
+            type: "bool"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: "transferRestrictionDetection";
+      docs: [
+        "Result of the transfer restriction detection.",
+        "`code` is the error code the transfer hook would fail with or 0 when the transfer is allowed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "code";
+            type: "u32"
+          },
+          {
+            name: "message";
+            type: "string"
           }
         ]
       }
@@ -2937,6 +6752,43 @@ export type TransferRestrictions = {
           {
             name: "transferRestrictionData";
             type: "pubkey"
+          },
+          {
+            name: "holdingPeriod";
+            type: "u64"
+          },
+          {
+            name: "fundedHoldersCount";
+            type: "u64"
+          },
+          {
+            name: "pausedDirection";
+            type: "u8"
+          },
+          {
+            name: "venueWalletsCount";
+            type: "u64"
+          },
+          {
+            name: "bump";
+            type: "u8"
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "minHolding";
+            type: "u64"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                24
+              ]
+            }
           }
         ]
       }
@@ -2965,6 +6817,31 @@ export type TransferRestrictions = {
           {
             name: "active";
             type: "bool"
+          },
+          {
+            name: "fundedWalletsCount";
+            type: "u64"
+          },
+          {
+            name: "frozen";
+            type: "bool"
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "beneficialOwnerPositionsCount";
+            type: "u64"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                24
+              ]
+            }
           }
         ]
       }
@@ -2989,6 +6866,104 @@ export type TransferRestrictions = {
           {
             name: "lockedUntil";
             type: "u64"
+          },
+          {
+            name: "validUntil";
+            type: "u64"
+          },
+          {
+            name: "closedIntervalsCount";
+            type: "u8"
+          },
+          {
+            name: "closedIntervals";
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: "transferRuleClosedInterval"
+                  }
+                },
+                8
+              ]
+            }
+          },
+          {
+            name: "bump";
+            type: "u8"
+          },
+          {
+            name: "approvalRequired";
+            type: "bool"
+          },
+          {
+            name: "version";
+            type: "u8"
+          },
+          {
+            name: "reserved";
+            type: {
+              array: [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: "transferRuleClosedInterval";
+      docs: [
+        "Time range during which a transfer rule does not allow transfers.",
+        "A non-zero `period` makes the interval repeat every `period` seconds starting from `start`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "start";
+            type: "u64"
+          },
+          {
+            name: "end";
+            type: "u64"
+          },
+          {
+            name: "period";
+            type: "u64"
+          }
+        ]
+      }
+    },
+    {
+      name: "walletHold";
+      docs: [
+        "Regulatory hold blocking transfers of the wallet in `direction` until `expires_at`"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "reasonCode";
+            type: "u16"
+          },
+          {
+            name: "referenceHash";
+            type: {
+              array: [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            name: "direction";
+            type: "u8"
+          },
+          {
+            name: "expiresAt";
+            type: "u64"
           }
         ]
       }
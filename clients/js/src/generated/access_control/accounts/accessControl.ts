@@ -25,6 +25,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -57,6 +59,9 @@ export type AccessControl = {
   authority: Address;
   maxTotalSupply: bigint;
   lockupEscrowAccount: Option<Address>;
+  version: number;
+  exemptAccountsCount: number;
+  reserved: ReadonlyUint8Array;
 };
 
 export type AccessControlArgs = {
@@ -64,6 +69,9 @@ export type AccessControlArgs = {
   authority: Address;
   maxTotalSupply: number | bigint;
   lockupEscrowAccount: OptionOrNullable<Address>;
+  version: number;
+  exemptAccountsCount: number;
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link AccessControlArgs} account data. */
@@ -75,6 +83,9 @@ export function getAccessControlEncoder(): Encoder<AccessControlArgs> {
       ['authority', getAddressEncoder()],
       ['maxTotalSupply', getU64Encoder()],
       ['lockupEscrowAccount', getOptionEncoder(getAddressEncoder())],
+      ['version', getU8Encoder()],
+      ['exemptAccountsCount', getU8Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 31)],
     ]),
     (value) => ({ ...value, discriminator: ACCESS_CONTROL_DISCRIMINATOR })
   );
@@ -88,6 +99,9 @@ export function getAccessControlDecoder(): Decoder<AccessControl> {
     ['authority', getAddressDecoder()],
     ['maxTotalSupply', getU64Decoder()],
     ['lockupEscrowAccount', getOptionDecoder(getAddressDecoder())],
+    ['version', getU8Decoder()],
+    ['exemptAccountsCount', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 31)],
   ]);
 }
 
@@ -7,4 +7,5 @@
  */
 
 export * from './accessControl';
+export * from './exemptAccountRegistry';
 export * from './walletRole';
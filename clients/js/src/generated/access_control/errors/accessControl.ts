@@ -50,17 +50,43 @@ export const ACCESS_CONTROL_ERROR__CANNOT_REVOKE_ROLE = 0x177f; // 6015
 export const ACCESS_CONTROL_ERROR__INVALID_ACCESS_CONTROL = 0x1780; // 6016
 /** InvalidWalletRoleAccountOwner: Invalid wallet role account owner */
 export const ACCESS_CONTROL_ERROR__INVALID_WALLET_ROLE_ACCOUNT_OWNER = 0x1781; // 6017
+/** InvalidExemptAccountKind: Invalid exempt account kind */
+export const ACCESS_CONTROL_ERROR__INVALID_EXEMPT_ACCOUNT_KIND = 0x1782; // 6018
+/** ExemptAccountAlreadyRegistered: Token account is already registered as exempt account */
+export const ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNT_ALREADY_REGISTERED = 0x1783; // 6019
+/** ExemptAccountsLimitReached: Exempt accounts limit reached */
+export const ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNTS_LIMIT_REACHED = 0x1784; // 6020
+/** InvalidExemptAccountIndex: Invalid exempt account index */
+export const ACCESS_CONTROL_ERROR__INVALID_EXEMPT_ACCOUNT_INDEX = 0x1785; // 6021
+/** CannotFreezeExemptAccount: Cannot freeze exempt account */
+export const ACCESS_CONTROL_ERROR__CANNOT_FREEZE_EXEMPT_ACCOUNT = 0x1786; // 6022
+/** CantForceTransferBetweenExemptAccounts: Cannot force transfer between exempt accounts */
+export const ACCESS_CONTROL_ERROR__CANT_FORCE_TRANSFER_BETWEEN_EXEMPT_ACCOUNTS = 0x1787; // 6023
+/** TransferRestrictionAccountsRequired: Transfer restriction accounts are required */
+export const ACCESS_CONTROL_ERROR__TRANSFER_RESTRICTION_ACCOUNTS_REQUIRED = 0x1788; // 6024
+/** InvalidTransferRestrictionsProgram: Invalid transfer restrictions program */
+export const ACCESS_CONTROL_ERROR__INVALID_TRANSFER_RESTRICTIONS_PROGRAM = 0x1789; // 6025
+/** ExemptAccountRegistryRequired: Exempt account registry is required */
+export const ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNT_REGISTRY_REQUIRED = 0x178a; // 6026
 
 export type AccessControlError =
   | typeof ACCESS_CONTROL_ERROR__ALREADY_HAS_ROLE
+  | typeof ACCESS_CONTROL_ERROR__CANNOT_FREEZE_EXEMPT_ACCOUNT
   | typeof ACCESS_CONTROL_ERROR__CANNOT_FREEZE_LOCKUP_ESCROW_ACCOUNT
   | typeof ACCESS_CONTROL_ERROR__CANNOT_REVOKE_ROLE
   | typeof ACCESS_CONTROL_ERROR__CANT_BURN_SECURITIES_WITHIN_LOCKUP
+  | typeof ACCESS_CONTROL_ERROR__CANT_FORCE_TRANSFER_BETWEEN_EXEMPT_ACCOUNTS
   | typeof ACCESS_CONTROL_ERROR__CANT_FORCE_TRANSFER_BETWEEN_LOCKUP
+  | typeof ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNTS_LIMIT_REACHED
+  | typeof ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNT_ALREADY_REGISTERED
+  | typeof ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNT_REGISTRY_REQUIRED
   | typeof ACCESS_CONTROL_ERROR__INCORRECT_TOKENLOCK_ACCOUNT
   | typeof ACCESS_CONTROL_ERROR__INVALID_ACCESS_CONTROL
+  | typeof ACCESS_CONTROL_ERROR__INVALID_EXEMPT_ACCOUNT_INDEX
+  | typeof ACCESS_CONTROL_ERROR__INVALID_EXEMPT_ACCOUNT_KIND
   | typeof ACCESS_CONTROL_ERROR__INVALID_ROLE
   | typeof ACCESS_CONTROL_ERROR__INVALID_SECURITY_ASSOCIATED_ACCOUNT
+  | typeof ACCESS_CONTROL_ERROR__INVALID_TRANSFER_RESTRICTIONS_PROGRAM
   | typeof ACCESS_CONTROL_ERROR__INVALID_WALLET_ROLE_ACCOUNT_OWNER
   | typeof ACCESS_CONTROL_ERROR__MINT_EXCEEDS_MAX_TOTAL_SUPPLY
   | typeof ACCESS_CONTROL_ERROR__MISMATCHED_ESCROW_ACCOUNT
@@ -68,6 +94,7 @@ export type AccessControlError =
   | typeof ACCESS_CONTROL_ERROR__SECURITY_ASSOCIATED_ACCOUNT_NOT_INITIALIZED
   | typeof ACCESS_CONTROL_ERROR__SECURITY_ASSOCIATED_ACCOUNT_REQUIRED
   | typeof ACCESS_CONTROL_ERROR__TRANSFER_HOOK_NOT_CONFIGURED
+  | typeof ACCESS_CONTROL_ERROR__TRANSFER_RESTRICTION_ACCOUNTS_REQUIRED
   | typeof ACCESS_CONTROL_ERROR__UNAUTHORIZED
   | typeof ACCESS_CONTROL_ERROR__VALUE_UNCHANGED;
 
@@ -75,14 +102,22 @@ let accessControlErrorMessages: Record<AccessControlError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   accessControlErrorMessages = {
     [ACCESS_CONTROL_ERROR__ALREADY_HAS_ROLE]: `Wallet already has this role`,
+    [ACCESS_CONTROL_ERROR__CANNOT_FREEZE_EXEMPT_ACCOUNT]: `Cannot freeze exempt account`,
     [ACCESS_CONTROL_ERROR__CANNOT_FREEZE_LOCKUP_ESCROW_ACCOUNT]: `Cannot freeze lockup escrow account`,
     [ACCESS_CONTROL_ERROR__CANNOT_REVOKE_ROLE]: `Cannot revoke role that wallet does not have`,
     [ACCESS_CONTROL_ERROR__CANT_BURN_SECURITIES_WITHIN_LOCKUP]: `Cannot burn securities within lockup; cancel the lockup first`,
+    [ACCESS_CONTROL_ERROR__CANT_FORCE_TRANSFER_BETWEEN_EXEMPT_ACCOUNTS]: `Cannot force transfer between exempt accounts`,
     [ACCESS_CONTROL_ERROR__CANT_FORCE_TRANSFER_BETWEEN_LOCKUP]: `Cannot force transfer between lockup accounts`,
+    [ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNTS_LIMIT_REACHED]: `Exempt accounts limit reached`,
+    [ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNT_ALREADY_REGISTERED]: `Token account is already registered as exempt account`,
+    [ACCESS_CONTROL_ERROR__EXEMPT_ACCOUNT_REGISTRY_REQUIRED]: `Exempt account registry is required`,
     [ACCESS_CONTROL_ERROR__INCORRECT_TOKENLOCK_ACCOUNT]: `Wrong tokenlock account`,
     [ACCESS_CONTROL_ERROR__INVALID_ACCESS_CONTROL]: `Invalid access control`,
+    [ACCESS_CONTROL_ERROR__INVALID_EXEMPT_ACCOUNT_INDEX]: `Invalid exempt account index`,
+    [ACCESS_CONTROL_ERROR__INVALID_EXEMPT_ACCOUNT_KIND]: `Invalid exempt account kind`,
     [ACCESS_CONTROL_ERROR__INVALID_ROLE]: `Invalid role`,
     [ACCESS_CONTROL_ERROR__INVALID_SECURITY_ASSOCIATED_ACCOUNT]: `Invalid security associated account`,
+    [ACCESS_CONTROL_ERROR__INVALID_TRANSFER_RESTRICTIONS_PROGRAM]: `Invalid transfer restrictions program`,
     [ACCESS_CONTROL_ERROR__INVALID_WALLET_ROLE_ACCOUNT_OWNER]: `Invalid wallet role account owner`,
     [ACCESS_CONTROL_ERROR__MINT_EXCEEDS_MAX_TOTAL_SUPPLY]: `Cannot mint more than max total supply`,
     [ACCESS_CONTROL_ERROR__MISMATCHED_ESCROW_ACCOUNT]: `Mismatched escrow account`,
@@ -90,6 +125,7 @@ if (process.env.NODE_ENV !== 'production') {
     [ACCESS_CONTROL_ERROR__SECURITY_ASSOCIATED_ACCOUNT_NOT_INITIALIZED]: `Security associated account not initialized`,
     [ACCESS_CONTROL_ERROR__SECURITY_ASSOCIATED_ACCOUNT_REQUIRED]: `Security associated account is required`,
     [ACCESS_CONTROL_ERROR__TRANSFER_HOOK_NOT_CONFIGURED]: `Transfer hook not configured on mint`,
+    [ACCESS_CONTROL_ERROR__TRANSFER_RESTRICTION_ACCOUNTS_REQUIRED]: `Transfer restriction accounts are required`,
     [ACCESS_CONTROL_ERROR__UNAUTHORIZED]: `Unauthorized`,
     [ACCESS_CONTROL_ERROR__VALUE_UNCHANGED]: `The provided value is already set. No changes were made`,
   };
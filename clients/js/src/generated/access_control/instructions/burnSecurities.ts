@@ -61,6 +61,7 @@ export type BurnSecuritiesInstruction<
   TAccountTargetAuthority extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TAccountExemptAccountRegistry extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -88,6 +89,9 @@ export type BurnSecuritiesInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountExemptAccountRegistry extends string
+        ? ReadonlyAccount<TAccountExemptAccountRegistry>
+        : TAccountExemptAccountRegistry,
       ...TRemainingAccounts,
     ]
   >;
@@ -134,14 +138,17 @@ export type BurnSecuritiesAsyncInput<
   TAccountTargetAccount extends string = string,
   TAccountTargetAuthority extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   authorityWalletRole?: Address<TAccountAuthorityWalletRole>;
   accessControl?: Address<TAccountAccessControl>;
   securityMint: Address<TAccountSecurityMint>;
-  targetAccount?: Address<TAccountTargetAccount>;
+  targetAccount: Address<TAccountTargetAccount>;
   targetAuthority: Address<TAccountTargetAuthority>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Optional: Required once the exempt account registry has exempt accounts */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
   amount: BurnSecuritiesInstructionDataArgs['amount'];
 };
 
@@ -153,6 +160,7 @@ export async function getBurnSecuritiesInstructionAsync<
   TAccountTargetAccount extends string,
   TAccountTargetAuthority extends string,
   TAccountTokenProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof ACCESS_CONTROL_PROGRAM_ADDRESS,
 >(
   input: BurnSecuritiesAsyncInput<
@@ -162,7 +170,8 @@ export async function getBurnSecuritiesInstructionAsync<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -174,7 +183,8 @@ export async function getBurnSecuritiesInstructionAsync<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >
 > {
   // Program address.
@@ -196,6 +206,10 @@ export async function getBurnSecuritiesInstructionAsync<
       isWritable: false,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -231,15 +245,16 @@ export async function getBurnSecuritiesInstructionAsync<
     accounts.tokenProgram.value =
       'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
   }
-  if (!accounts.targetAccount.value) {
-    accounts.targetAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+  if (!accounts.exemptAccountRegistry.value) {
+    accounts.exemptAccountRegistry.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(
-          expectAddress(accounts.targetAuthority.value)
+        getBytesEncoder().encode(
+          new Uint8Array([
+            101, 120, 101, 109, 112, 116, 95, 97, 99, 99, 111, 117, 110, 116,
+            115,
+          ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(expectAddress(accounts.securityMint.value)),
       ],
     });
@@ -255,6 +270,7 @@ export async function getBurnSecuritiesInstructionAsync<
       getAccountMeta(accounts.targetAccount),
       getAccountMeta(accounts.targetAuthority),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getBurnSecuritiesInstructionDataEncoder().encode(
       args as BurnSecuritiesInstructionDataArgs
@@ -268,7 +284,8 @@ export async function getBurnSecuritiesInstructionAsync<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -280,6 +297,7 @@ export type BurnSecuritiesInput<
   TAccountTargetAccount extends string = string,
   TAccountTargetAuthority extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   authorityWalletRole: Address<TAccountAuthorityWalletRole>;
@@ -288,6 +306,8 @@ export type BurnSecuritiesInput<
   targetAccount: Address<TAccountTargetAccount>;
   targetAuthority: Address<TAccountTargetAuthority>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Optional: Required once the exempt account registry has exempt accounts */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
   amount: BurnSecuritiesInstructionDataArgs['amount'];
 };
 
@@ -299,6 +319,7 @@ export function getBurnSecuritiesInstruction<
   TAccountTargetAccount extends string,
   TAccountTargetAuthority extends string,
   TAccountTokenProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof ACCESS_CONTROL_PROGRAM_ADDRESS,
 >(
   input: BurnSecuritiesInput<
@@ -308,7 +329,8 @@ export function getBurnSecuritiesInstruction<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): BurnSecuritiesInstruction<
@@ -319,7 +341,8 @@ export function getBurnSecuritiesInstruction<
   TAccountSecurityMint,
   TAccountTargetAccount,
   TAccountTargetAuthority,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountExemptAccountRegistry
 > {
   // Program address.
   const programAddress =
@@ -340,6 +363,10 @@ export function getBurnSecuritiesInstruction<
       isWritable: false,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -365,6 +392,7 @@ export function getBurnSecuritiesInstruction<
       getAccountMeta(accounts.targetAccount),
       getAccountMeta(accounts.targetAuthority),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getBurnSecuritiesInstructionDataEncoder().encode(
       args as BurnSecuritiesInstructionDataArgs
@@ -378,7 +406,8 @@ export function getBurnSecuritiesInstruction<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -395,6 +424,8 @@ export type ParsedBurnSecuritiesInstruction<
     targetAccount: TAccountMetas[4];
     targetAuthority: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
+    /** Optional: Required once the exempt account registry has exempt accounts */
+    exemptAccountRegistry?: TAccountMetas[7] | undefined;
   };
   data: BurnSecuritiesInstructionData;
 };
@@ -407,7 +438,7 @@ export function parseBurnSecuritiesInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBurnSecuritiesInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -417,6 +448,12 @@ export function parseBurnSecuritiesInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ACCESS_CONTROL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -427,6 +464,7 @@ export function parseBurnSecuritiesInstruction<
       targetAccount: getNextAccount(),
       targetAuthority: getNextAccount(),
       tokenProgram: getNextAccount(),
+      exemptAccountRegistry: getNextOptionalAccount(),
     },
     data: getBurnSecuritiesInstructionDataDecoder().decode(instruction.data),
   };
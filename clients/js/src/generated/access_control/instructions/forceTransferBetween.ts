@@ -63,6 +63,7 @@ export type ForceTransferBetweenInstruction<
   TAccountDestinationAuthority extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TAccountExemptAccountRegistry extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -96,6 +97,9 @@ export type ForceTransferBetweenInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountExemptAccountRegistry extends string
+        ? ReadonlyAccount<TAccountExemptAccountRegistry>
+        : TAccountExemptAccountRegistry,
       ...TRemainingAccounts,
     ]
   >;
@@ -149,16 +153,19 @@ export type ForceTransferBetweenAsyncInput<
   TAccountSourceAuthority extends string = string,
   TAccountDestinationAuthority extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
-  sourceAccount?: Address<TAccountSourceAccount>;
+  sourceAccount: Address<TAccountSourceAccount>;
   securityMint: Address<TAccountSecurityMint>;
-  destinationAccount?: Address<TAccountDestinationAccount>;
+  destinationAccount: Address<TAccountDestinationAccount>;
   authority: TransactionSigner<TAccountAuthority>;
   authorityWalletRole?: Address<TAccountAuthorityWalletRole>;
   accessControlAccount?: Address<TAccountAccessControlAccount>;
   sourceAuthority: Address<TAccountSourceAuthority>;
   destinationAuthority: Address<TAccountDestinationAuthority>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Optional: Required once the exempt account registry has exempt accounts */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
   amount: ForceTransferBetweenInstructionDataArgs['amount'];
 };
 
@@ -172,6 +179,7 @@ export async function getForceTransferBetweenInstructionAsync<
   TAccountSourceAuthority extends string,
   TAccountDestinationAuthority extends string,
   TAccountTokenProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof ACCESS_CONTROL_PROGRAM_ADDRESS,
 >(
   input: ForceTransferBetweenAsyncInput<
@@ -183,7 +191,8 @@ export async function getForceTransferBetweenInstructionAsync<
     TAccountAccessControlAccount,
     TAccountSourceAuthority,
     TAccountDestinationAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -197,7 +206,8 @@ export async function getForceTransferBetweenInstructionAsync<
     TAccountAccessControlAccount,
     TAccountSourceAuthority,
     TAccountDestinationAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >
 > {
   // Program address.
@@ -230,6 +240,10 @@ export async function getForceTransferBetweenInstructionAsync<
       isWritable: false,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -240,36 +254,6 @@ export async function getForceTransferBetweenInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
-  }
-  if (!accounts.sourceAccount.value) {
-    accounts.sourceAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
-      seeds: [
-        getAddressEncoder().encode(
-          expectAddress(accounts.sourceAuthority.value)
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
-        getAddressEncoder().encode(expectAddress(accounts.securityMint.value)),
-      ],
-    });
-  }
-  if (!accounts.destinationAccount.value) {
-    accounts.destinationAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
-      seeds: [
-        getAddressEncoder().encode(
-          expectAddress(accounts.destinationAuthority.value)
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
-        getAddressEncoder().encode(expectAddress(accounts.securityMint.value)),
-      ],
-    });
-  }
   if (!accounts.authorityWalletRole.value) {
     accounts.authorityWalletRole.value = await getProgramDerivedAddress({
       programAddress,
@@ -291,6 +275,24 @@ export async function getForceTransferBetweenInstructionAsync<
       ],
     });
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+  if (!accounts.exemptAccountRegistry.value) {
+    accounts.exemptAccountRegistry.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            101, 120, 101, 109, 112, 116, 95, 97, 99, 99, 111, 117, 110, 116,
+            115,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.securityMint.value)),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -304,6 +306,7 @@ export async function getForceTransferBetweenInstructionAsync<
       getAccountMeta(accounts.sourceAuthority),
       getAccountMeta(accounts.destinationAuthority),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getForceTransferBetweenInstructionDataEncoder().encode(
       args as ForceTransferBetweenInstructionDataArgs
@@ -319,7 +322,8 @@ export async function getForceTransferBetweenInstructionAsync<
     TAccountAccessControlAccount,
     TAccountSourceAuthority,
     TAccountDestinationAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -333,6 +337,7 @@ export type ForceTransferBetweenInput<
   TAccountSourceAuthority extends string = string,
   TAccountDestinationAuthority extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   sourceAccount: Address<TAccountSourceAccount>;
   securityMint: Address<TAccountSecurityMint>;
@@ -343,6 +348,8 @@ export type ForceTransferBetweenInput<
   sourceAuthority: Address<TAccountSourceAuthority>;
   destinationAuthority: Address<TAccountDestinationAuthority>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Optional: Required once the exempt account registry has exempt accounts */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
   amount: ForceTransferBetweenInstructionDataArgs['amount'];
 };
 
@@ -356,6 +363,7 @@ export function getForceTransferBetweenInstruction<
   TAccountSourceAuthority extends string,
   TAccountDestinationAuthority extends string,
   TAccountTokenProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof ACCESS_CONTROL_PROGRAM_ADDRESS,
 >(
   input: ForceTransferBetweenInput<
@@ -367,7 +375,8 @@ export function getForceTransferBetweenInstruction<
     TAccountAccessControlAccount,
     TAccountSourceAuthority,
     TAccountDestinationAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): ForceTransferBetweenInstruction<
@@ -380,7 +389,8 @@ export function getForceTransferBetweenInstruction<
   TAccountAccessControlAccount,
   TAccountSourceAuthority,
   TAccountDestinationAuthority,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountExemptAccountRegistry
 > {
   // Program address.
   const programAddress =
@@ -412,6 +422,10 @@ export function getForceTransferBetweenInstruction<
       isWritable: false,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -439,6 +453,7 @@ export function getForceTransferBetweenInstruction<
       getAccountMeta(accounts.sourceAuthority),
       getAccountMeta(accounts.destinationAuthority),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getForceTransferBetweenInstructionDataEncoder().encode(
       args as ForceTransferBetweenInstructionDataArgs
@@ -454,7 +469,8 @@ export function getForceTransferBetweenInstruction<
     TAccountAccessControlAccount,
     TAccountSourceAuthority,
     TAccountDestinationAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -473,6 +489,8 @@ export type ParsedForceTransferBetweenInstruction<
     sourceAuthority: TAccountMetas[6];
     destinationAuthority: TAccountMetas[7];
     tokenProgram: TAccountMetas[8];
+    /** Optional: Required once the exempt account registry has exempt accounts */
+    exemptAccountRegistry?: TAccountMetas[9] | undefined;
   };
   data: ForceTransferBetweenInstructionData;
 };
@@ -485,7 +503,7 @@ export function parseForceTransferBetweenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedForceTransferBetweenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -495,6 +513,12 @@ export function parseForceTransferBetweenInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ACCESS_CONTROL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -507,6 +531,7 @@ export function parseForceTransferBetweenInstruction<
       sourceAuthority: getNextAccount(),
       destinationAuthority: getNextAccount(),
       tokenProgram: getNextAccount(),
+      exemptAccountRegistry: getNextOptionalAccount(),
     },
     data: getForceTransferBetweenInstructionDataDecoder().decode(
       instruction.data
@@ -59,6 +59,7 @@ export type FreezeWalletInstruction<
   TAccountTargetAuthority extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TAccountExemptAccountRegistry extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -86,6 +87,9 @@ export type FreezeWalletInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountExemptAccountRegistry extends string
+        ? ReadonlyAccount<TAccountExemptAccountRegistry>
+        : TAccountExemptAccountRegistry,
       ...TRemainingAccounts,
     ]
   >;
@@ -125,14 +129,17 @@ export type FreezeWalletAsyncInput<
   TAccountTargetAccount extends string = string,
   TAccountTargetAuthority extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   authorityWalletRole?: Address<TAccountAuthorityWalletRole>;
   accessControl?: Address<TAccountAccessControl>;
   securityMint: Address<TAccountSecurityMint>;
-  targetAccount?: Address<TAccountTargetAccount>;
+  targetAccount: Address<TAccountTargetAccount>;
   targetAuthority: Address<TAccountTargetAuthority>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Optional: Required once the exempt account registry has exempt accounts */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
 };
 
 export async function getFreezeWalletInstructionAsync<
@@ -143,6 +150,7 @@ export async function getFreezeWalletInstructionAsync<
   TAccountTargetAccount extends string,
   TAccountTargetAuthority extends string,
   TAccountTokenProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof ACCESS_CONTROL_PROGRAM_ADDRESS,
 >(
   input: FreezeWalletAsyncInput<
@@ -152,7 +160,8 @@ export async function getFreezeWalletInstructionAsync<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -164,7 +173,8 @@ export async function getFreezeWalletInstructionAsync<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >
 > {
   // Program address.
@@ -186,6 +196,10 @@ export async function getFreezeWalletInstructionAsync<
       isWritable: false,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -218,15 +232,16 @@ export async function getFreezeWalletInstructionAsync<
     accounts.tokenProgram.value =
       'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
   }
-  if (!accounts.targetAccount.value) {
-    accounts.targetAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+  if (!accounts.exemptAccountRegistry.value) {
+    accounts.exemptAccountRegistry.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(
-          expectAddress(accounts.targetAuthority.value)
+        getBytesEncoder().encode(
+          new Uint8Array([
+            101, 120, 101, 109, 112, 116, 95, 97, 99, 99, 111, 117, 110, 116,
+            115,
+          ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(expectAddress(accounts.securityMint.value)),
       ],
     });
@@ -242,6 +257,7 @@ export async function getFreezeWalletInstructionAsync<
       getAccountMeta(accounts.targetAccount),
       getAccountMeta(accounts.targetAuthority),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getFreezeWalletInstructionDataEncoder().encode({}),
     programAddress,
@@ -253,7 +269,8 @@ export async function getFreezeWalletInstructionAsync<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -265,6 +282,7 @@ export type FreezeWalletInput<
   TAccountTargetAccount extends string = string,
   TAccountTargetAuthority extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   authorityWalletRole: Address<TAccountAuthorityWalletRole>;
@@ -273,6 +291,8 @@ export type FreezeWalletInput<
   targetAccount: Address<TAccountTargetAccount>;
   targetAuthority: Address<TAccountTargetAuthority>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Optional: Required once the exempt account registry has exempt accounts */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
 };
 
 export function getFreezeWalletInstruction<
@@ -283,6 +303,7 @@ export function getFreezeWalletInstruction<
   TAccountTargetAccount extends string,
   TAccountTargetAuthority extends string,
   TAccountTokenProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof ACCESS_CONTROL_PROGRAM_ADDRESS,
 >(
   input: FreezeWalletInput<
@@ -292,7 +313,8 @@ export function getFreezeWalletInstruction<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): FreezeWalletInstruction<
@@ -303,7 +325,8 @@ export function getFreezeWalletInstruction<
   TAccountSecurityMint,
   TAccountTargetAccount,
   TAccountTargetAuthority,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountExemptAccountRegistry
 > {
   // Program address.
   const programAddress =
@@ -324,6 +347,10 @@ export function getFreezeWalletInstruction<
       isWritable: false,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -346,6 +373,7 @@ export function getFreezeWalletInstruction<
       getAccountMeta(accounts.targetAccount),
       getAccountMeta(accounts.targetAuthority),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getFreezeWalletInstructionDataEncoder().encode({}),
     programAddress,
@@ -357,7 +385,8 @@ export function getFreezeWalletInstruction<
     TAccountSecurityMint,
     TAccountTargetAccount,
     TAccountTargetAuthority,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -374,6 +403,8 @@ export type ParsedFreezeWalletInstruction<
     targetAccount: TAccountMetas[4];
     targetAuthority: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
+    /** Optional: Required once the exempt account registry has exempt accounts */
+    exemptAccountRegistry?: TAccountMetas[7] | undefined;
   };
   data: FreezeWalletInstructionData;
 };
@@ -386,7 +417,7 @@ export function parseFreezeWalletInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFreezeWalletInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -396,6 +427,12 @@ export function parseFreezeWalletInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ACCESS_CONTROL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -406,6 +443,7 @@ export function parseFreezeWalletInstruction<
       targetAccount: getNextAccount(),
       targetAuthority: getNextAccount(),
       tokenProgram: getNextAccount(),
+      exemptAccountRegistry: getNextOptionalAccount(),
     },
     data: getFreezeWalletInstructionDataDecoder().decode(instruction.data),
   };
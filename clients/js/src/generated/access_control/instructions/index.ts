@@ -6,12 +6,15 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addExemptAccount';
 export * from './burnSecurities';
 export * from './forceTransferBetween';
 export * from './freezeWallet';
 export * from './grantRole';
 export * from './initializeAccessControl';
+export * from './migrateAccessControl';
 export * from './mintSecurities';
+export * from './removeExemptAccount';
 export * from './revokeRole';
 export * from './setLockupEscrowAccount';
 export * from './setMaxTotalSupply';
@@ -63,6 +63,12 @@ export type MintSecuritiesInstruction<
     'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
   TAccountSecurityAssociatedAccount extends string | AccountMeta<string> =
     string,
+  TAccountExemptAccountRegistry extends string | AccountMeta<string> = string,
+  TAccountTransferRestrictionsProgram extends string | AccountMeta<string> =
+    string,
+  TAccountTransferRestrictionData extends string | AccountMeta<string> = string,
+  TAccountTransferRestrictionGroup extends string | AccountMeta<string> =
+    string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -91,8 +97,20 @@ export type MintSecuritiesInstruction<
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
       TAccountSecurityAssociatedAccount extends string
-        ? ReadonlyAccount<TAccountSecurityAssociatedAccount>
+        ? WritableAccount<TAccountSecurityAssociatedAccount>
         : TAccountSecurityAssociatedAccount,
+      TAccountExemptAccountRegistry extends string
+        ? ReadonlyAccount<TAccountExemptAccountRegistry>
+        : TAccountExemptAccountRegistry,
+      TAccountTransferRestrictionsProgram extends string
+        ? ReadonlyAccount<TAccountTransferRestrictionsProgram>
+        : TAccountTransferRestrictionsProgram,
+      TAccountTransferRestrictionData extends string
+        ? ReadonlyAccount<TAccountTransferRestrictionData>
+        : TAccountTransferRestrictionData,
+      TAccountTransferRestrictionGroup extends string
+        ? ReadonlyAccount<TAccountTransferRestrictionGroup>
+        : TAccountTransferRestrictionGroup,
       ...TRemainingAccounts,
     ]
   >;
@@ -140,12 +158,16 @@ export type MintSecuritiesAsyncInput<
   TAccountDestinationAuthority extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSecurityAssociatedAccount extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
+  TAccountTransferRestrictionsProgram extends string = string,
+  TAccountTransferRestrictionData extends string = string,
+  TAccountTransferRestrictionGroup extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   authorityWalletRole?: Address<TAccountAuthorityWalletRole>;
   accessControl?: Address<TAccountAccessControl>;
   securityMint: Address<TAccountSecurityMint>;
-  destinationAccount?: Address<TAccountDestinationAccount>;
+  destinationAccount: Address<TAccountDestinationAccount>;
   destinationAuthority: Address<TAccountDestinationAuthority>;
   tokenProgram?: Address<TAccountTokenProgram>;
   /**
@@ -153,6 +175,17 @@ export type MintSecuritiesAsyncInput<
    * Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
    */
   securityAssociatedAccount?: Address<TAccountSecurityAssociatedAccount>;
+  /** Optional: Required when minting to a tokenlock escrow registered in the exempt account registry */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
+  /** Optional: Not required when minting to lockup_escrow_account, mandatory otherwise */
+  transferRestrictionsProgram?: Address<TAccountTransferRestrictionsProgram>;
+  /** Optional: Not required when minting to lockup_escrow_account, mandatory otherwise */
+  transferRestrictionData?: Address<TAccountTransferRestrictionData>;
+  /**
+   * transfer hook program
+   * Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+   */
+  transferRestrictionGroup?: Address<TAccountTransferRestrictionGroup>;
   amount: MintSecuritiesInstructionDataArgs['amount'];
 };
 
@@ -165,6 +198,10 @@ export async function getMintSecuritiesInstructionAsync<
   TAccountDestinationAuthority extends string,
   TAccountTokenProgram extends string,
   TAccountSecurityAssociatedAccount extends string,
+  TAccountExemptAccountRegistry extends string,
+  TAccountTransferRestrictionsProgram extends string,
+  TAccountTransferRestrictionData extends string,
+  TAccountTransferRestrictionGroup extends string,
   TProgramAddress extends Address = typeof ACCESS_CONTROL_PROGRAM_ADDRESS,
 >(
   input: MintSecuritiesAsyncInput<
@@ -175,7 +212,11 @@ export async function getMintSecuritiesInstructionAsync<
     TAccountDestinationAccount,
     TAccountDestinationAuthority,
     TAccountTokenProgram,
-    TAccountSecurityAssociatedAccount
+    TAccountSecurityAssociatedAccount,
+    TAccountExemptAccountRegistry,
+    TAccountTransferRestrictionsProgram,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroup
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -188,7 +229,11 @@ export async function getMintSecuritiesInstructionAsync<
     TAccountDestinationAccount,
     TAccountDestinationAuthority,
     TAccountTokenProgram,
-    TAccountSecurityAssociatedAccount
+    TAccountSecurityAssociatedAccount,
+    TAccountExemptAccountRegistry,
+    TAccountTransferRestrictionsProgram,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroup
   >
 > {
   // Program address.
@@ -215,6 +260,22 @@ export async function getMintSecuritiesInstructionAsync<
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     securityAssociatedAccount: {
       value: input.securityAssociatedAccount ?? null,
+      isWritable: true,
+    },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
+    transferRestrictionsProgram: {
+      value: input.transferRestrictionsProgram ?? null,
+      isWritable: false,
+    },
+    transferRestrictionData: {
+      value: input.transferRestrictionData ?? null,
+      isWritable: false,
+    },
+    transferRestrictionGroup: {
+      value: input.transferRestrictionGroup ?? null,
       isWritable: false,
     },
   };
@@ -252,15 +313,16 @@ export async function getMintSecuritiesInstructionAsync<
     accounts.tokenProgram.value =
       'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
   }
-  if (!accounts.destinationAccount.value) {
-    accounts.destinationAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+  if (!accounts.exemptAccountRegistry.value) {
+    accounts.exemptAccountRegistry.value = await getProgramDerivedAddress({
+      programAddress,
       seeds: [
-        getAddressEncoder().encode(
-          expectAddress(accounts.destinationAuthority.value)
+        getBytesEncoder().encode(
+          new Uint8Array([
+            101, 120, 101, 109, 112, 116, 95, 97, 99, 99, 111, 117, 110, 116,
+            115,
+          ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(expectAddress(accounts.securityMint.value)),
       ],
     });
@@ -277,6 +339,10 @@ export async function getMintSecuritiesInstructionAsync<
       getAccountMeta(accounts.destinationAuthority),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.securityAssociatedAccount),
+      getAccountMeta(accounts.exemptAccountRegistry),
+      getAccountMeta(accounts.transferRestrictionsProgram),
+      getAccountMeta(accounts.transferRestrictionData),
+      getAccountMeta(accounts.transferRestrictionGroup),
     ],
     data: getMintSecuritiesInstructionDataEncoder().encode(
       args as MintSecuritiesInstructionDataArgs
@@ -291,7 +357,11 @@ export async function getMintSecuritiesInstructionAsync<
     TAccountDestinationAccount,
     TAccountDestinationAuthority,
     TAccountTokenProgram,
-    TAccountSecurityAssociatedAccount
+    TAccountSecurityAssociatedAccount,
+    TAccountExemptAccountRegistry,
+    TAccountTransferRestrictionsProgram,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroup
   >);
 }
 
@@ -304,6 +374,10 @@ export type MintSecuritiesInput<
   TAccountDestinationAuthority extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSecurityAssociatedAccount extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
+  TAccountTransferRestrictionsProgram extends string = string,
+  TAccountTransferRestrictionData extends string = string,
+  TAccountTransferRestrictionGroup extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   authorityWalletRole: Address<TAccountAuthorityWalletRole>;
@@ -317,6 +391,17 @@ export type MintSecuritiesInput<
    * Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
    */
   securityAssociatedAccount?: Address<TAccountSecurityAssociatedAccount>;
+  /** Optional: Required when minting to a tokenlock escrow registered in the exempt account registry */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
+  /** Optional: Not required when minting to lockup_escrow_account, mandatory otherwise */
+  transferRestrictionsProgram?: Address<TAccountTransferRestrictionsProgram>;
+  /** Optional: Not required when minting to lockup_escrow_account, mandatory otherwise */
+  transferRestrictionData?: Address<TAccountTransferRestrictionData>;
+  /**
+   * transfer hook program
+   * Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+   */
+  transferRestrictionGroup?: Address<TAccountTransferRestrictionGroup>;
   amount: MintSecuritiesInstructionDataArgs['amount'];
 };
 
@@ -329,6 +414,10 @@ export function getMintSecuritiesInstruction<
   TAccountDestinationAuthority extends string,
   TAccountTokenProgram extends string,
   TAccountSecurityAssociatedAccount extends string,
+  TAccountExemptAccountRegistry extends string,
+  TAccountTransferRestrictionsProgram extends string,
+  TAccountTransferRestrictionData extends string,
+  TAccountTransferRestrictionGroup extends string,
   TProgramAddress extends Address = typeof ACCESS_CONTROL_PROGRAM_ADDRESS,
 >(
   input: MintSecuritiesInput<
@@ -339,7 +428,11 @@ export function getMintSecuritiesInstruction<
     TAccountDestinationAccount,
     TAccountDestinationAuthority,
     TAccountTokenProgram,
-    TAccountSecurityAssociatedAccount
+    TAccountSecurityAssociatedAccount,
+    TAccountExemptAccountRegistry,
+    TAccountTransferRestrictionsProgram,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroup
   >,
   config?: { programAddress?: TProgramAddress }
 ): MintSecuritiesInstruction<
@@ -351,7 +444,11 @@ export function getMintSecuritiesInstruction<
   TAccountDestinationAccount,
   TAccountDestinationAuthority,
   TAccountTokenProgram,
-  TAccountSecurityAssociatedAccount
+  TAccountSecurityAssociatedAccount,
+  TAccountExemptAccountRegistry,
+  TAccountTransferRestrictionsProgram,
+  TAccountTransferRestrictionData,
+  TAccountTransferRestrictionGroup
 > {
   // Program address.
   const programAddress =
@@ -377,6 +474,22 @@ export function getMintSecuritiesInstruction<
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     securityAssociatedAccount: {
       value: input.securityAssociatedAccount ?? null,
+      isWritable: true,
+    },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
+    transferRestrictionsProgram: {
+      value: input.transferRestrictionsProgram ?? null,
+      isWritable: false,
+    },
+    transferRestrictionData: {
+      value: input.transferRestrictionData ?? null,
+      isWritable: false,
+    },
+    transferRestrictionGroup: {
+      value: input.transferRestrictionGroup ?? null,
       isWritable: false,
     },
   };
@@ -405,6 +518,10 @@ export function getMintSecuritiesInstruction<
       getAccountMeta(accounts.destinationAuthority),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.securityAssociatedAccount),
+      getAccountMeta(accounts.exemptAccountRegistry),
+      getAccountMeta(accounts.transferRestrictionsProgram),
+      getAccountMeta(accounts.transferRestrictionData),
+      getAccountMeta(accounts.transferRestrictionGroup),
     ],
     data: getMintSecuritiesInstructionDataEncoder().encode(
       args as MintSecuritiesInstructionDataArgs
@@ -419,7 +536,11 @@ export function getMintSecuritiesInstruction<
     TAccountDestinationAccount,
     TAccountDestinationAuthority,
     TAccountTokenProgram,
-    TAccountSecurityAssociatedAccount
+    TAccountSecurityAssociatedAccount,
+    TAccountExemptAccountRegistry,
+    TAccountTransferRestrictionsProgram,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroup
   >);
 }
 
@@ -441,6 +562,17 @@ export type ParsedMintSecuritiesInstruction<
      * Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
      */
     securityAssociatedAccount?: TAccountMetas[7] | undefined;
+    /** Optional: Required when minting to a tokenlock escrow registered in the exempt account registry */
+    exemptAccountRegistry?: TAccountMetas[8] | undefined;
+    /** Optional: Not required when minting to lockup_escrow_account, mandatory otherwise */
+    transferRestrictionsProgram?: TAccountMetas[9] | undefined;
+    /** Optional: Not required when minting to lockup_escrow_account, mandatory otherwise */
+    transferRestrictionData?: TAccountMetas[10] | undefined;
+    /**
+     * transfer hook program
+     * Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+     */
+    transferRestrictionGroup?: TAccountMetas[11] | undefined;
   };
   data: MintSecuritiesInstructionData;
 };
@@ -453,7 +585,7 @@ export function parseMintSecuritiesInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedMintSecuritiesInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -480,6 +612,10 @@ export function parseMintSecuritiesInstruction<
       destinationAuthority: getNextAccount(),
       tokenProgram: getNextAccount(),
       securityAssociatedAccount: getNextOptionalAccount(),
+      exemptAccountRegistry: getNextOptionalAccount(),
+      transferRestrictionsProgram: getNextOptionalAccount(),
+      transferRestrictionData: getNextOptionalAccount(),
+      transferRestrictionGroup: getNextOptionalAccount(),
     },
     data: getMintSecuritiesInstructionDataDecoder().decode(instruction.data),
   };
@@ -128,7 +128,7 @@ export type ThawWalletAsyncInput<
   authorityWalletRole?: Address<TAccountAuthorityWalletRole>;
   accessControl?: Address<TAccountAccessControl>;
   securityMint: Address<TAccountSecurityMint>;
-  targetAccount?: Address<TAccountTargetAccount>;
+  targetAccount: Address<TAccountTargetAccount>;
   targetAuthority: Address<TAccountTargetAuthority>;
   tokenProgram?: Address<TAccountTokenProgram>;
 };
@@ -216,19 +216,6 @@ export async function getThawWalletInstructionAsync<
     accounts.tokenProgram.value =
       'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
   }
-  if (!accounts.targetAccount.value) {
-    accounts.targetAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
-      seeds: [
-        getAddressEncoder().encode(
-          expectAddress(accounts.targetAuthority.value)
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
-        getAddressEncoder().encode(expectAddress(accounts.securityMint.value)),
-      ],
-    });
-  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -14,12 +14,15 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAddExemptAccountInstruction,
   type ParsedBurnSecuritiesInstruction,
   type ParsedForceTransferBetweenInstruction,
   type ParsedFreezeWalletInstruction,
   type ParsedGrantRoleInstruction,
   type ParsedInitializeAccessControlInstruction,
+  type ParsedMigrateAccessControlInstruction,
   type ParsedMintSecuritiesInstruction,
+  type ParsedRemoveExemptAccountInstruction,
   type ParsedRevokeRoleInstruction,
   type ParsedSetLockupEscrowAccountInstruction,
   type ParsedSetMaxTotalSupplyInstruction,
@@ -31,6 +34,7 @@ export const ACCESS_CONTROL_PROGRAM_ADDRESS =
 
 export enum AccessControlAccount {
   AccessControl,
+  ExemptAccountRegistry,
   WalletRole,
 }
 
@@ -49,6 +53,17 @@ export function identifyAccessControlAccount(
   ) {
     return AccessControlAccount.AccessControl;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([30, 3, 32, 95, 12, 63, 85, 63])
+      ),
+      0
+    )
+  ) {
+    return AccessControlAccount.ExemptAccountRegistry;
+  }
   if (
     containsBytes(
       data,
@@ -66,12 +81,15 @@ export function identifyAccessControlAccount(
 }
 
 export enum AccessControlInstruction {
+  AddExemptAccount,
   BurnSecurities,
   ForceTransferBetween,
   FreezeWallet,
   GrantRole,
   InitializeAccessControl,
+  MigrateAccessControl,
   MintSecurities,
+  RemoveExemptAccount,
   RevokeRole,
   SetLockupEscrowAccount,
   SetMaxTotalSupply,
@@ -82,6 +100,17 @@ export function identifyAccessControlInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): AccessControlInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([171, 68, 21, 48, 208, 51, 170, 53])
+      ),
+      0
+    )
+  ) {
+    return AccessControlInstruction.AddExemptAccount;
+  }
   if (
     containsBytes(
       data,
@@ -137,6 +166,17 @@ export function identifyAccessControlInstruction(
   ) {
     return AccessControlInstruction.InitializeAccessControl;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([9, 226, 131, 152, 244, 16, 25, 133])
+      ),
+      0
+    )
+  ) {
+    return AccessControlInstruction.MigrateAccessControl;
+  }
   if (
     containsBytes(
       data,
@@ -148,6 +188,17 @@ export function identifyAccessControlInstruction(
   ) {
     return AccessControlInstruction.MintSecurities;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([161, 179, 151, 32, 58, 230, 234, 214])
+      ),
+      0
+    )
+  ) {
+    return AccessControlInstruction.RemoveExemptAccount;
+  }
   if (
     containsBytes(
       data,
@@ -200,6 +251,9 @@ export function identifyAccessControlInstruction(
 export type ParsedAccessControlInstruction<
   TProgram extends string = '4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3',
 > =
+  | ({
+      instructionType: AccessControlInstruction.AddExemptAccount;
+    } & ParsedAddExemptAccountInstruction<TProgram>)
   | ({
       instructionType: AccessControlInstruction.BurnSecurities;
     } & ParsedBurnSecuritiesInstruction<TProgram>)
@@ -215,9 +269,15 @@ export type ParsedAccessControlInstruction<
   | ({
       instructionType: AccessControlInstruction.InitializeAccessControl;
     } & ParsedInitializeAccessControlInstruction<TProgram>)
+  | ({
+      instructionType: AccessControlInstruction.MigrateAccessControl;
+    } & ParsedMigrateAccessControlInstruction<TProgram>)
   | ({
       instructionType: AccessControlInstruction.MintSecurities;
     } & ParsedMintSecuritiesInstruction<TProgram>)
+  | ({
+      instructionType: AccessControlInstruction.RemoveExemptAccount;
+    } & ParsedRemoveExemptAccountInstruction<TProgram>)
   | ({
       instructionType: AccessControlInstruction.RevokeRole;
     } & ParsedRevokeRoleInstruction<TProgram>)
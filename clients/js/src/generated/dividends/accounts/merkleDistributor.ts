@@ -83,6 +83,10 @@ export type MerkleDistributor = {
   readyToClaim: boolean;
   /** IPFS hash of the serialized merkle tree. */
   ipfsHash: string;
+  /** Account layout version, 0 for accounts created before the version was stored. */
+  version: number;
+  /** Space reserved for fields added in later layout versions. */
+  reserved: ReadonlyUint8Array;
 };
 
 export type MerkleDistributorArgs = {
@@ -110,6 +114,10 @@ export type MerkleDistributorArgs = {
   readyToClaim: boolean;
   /** IPFS hash of the serialized merkle tree. */
   ipfsHash: string;
+  /** Account layout version, 0 for accounts created before the version was stored. */
+  version: number;
+  /** Space reserved for fields added in later layout versions. */
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link MerkleDistributorArgs} account data. */
@@ -129,6 +137,8 @@ export function getMerkleDistributorEncoder(): Encoder<MerkleDistributorArgs> {
       ['paused', getBooleanEncoder()],
       ['readyToClaim', getBooleanEncoder()],
       ['ipfsHash', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['version', getU8Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
     (value) => ({ ...value, discriminator: MERKLE_DISTRIBUTOR_DISCRIMINATOR })
   );
@@ -150,6 +160,8 @@ export function getMerkleDistributorDecoder(): Decoder<MerkleDistributor> {
     ['paused', getBooleanDecoder()],
     ['readyToClaim', getBooleanDecoder()],
     ['ipfsHash', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['version', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
@@ -48,9 +48,15 @@ export const DIVIDENDS_ERROR__NUM_NODES_CLAIMED_OVERFLOW = 0x177e; // 6014
 export const DIVIDENDS_ERROR__NO_PENDING_OWNERSHIP_TRANSFER = 0x177f; // 6015
 /** UnauthorizedOwnershipTransfer: Unauthorized ownership transfer */
 export const DIVIDENDS_ERROR__UNAUTHORIZED_OWNERSHIP_TRANSFER = 0x1780; // 6016
+/** AccessControlRequired: Access control is required to distribute the security token */
+export const DIVIDENDS_ERROR__ACCESS_CONTROL_REQUIRED = 0x1781; // 6017
+/** DistributorAccountNotExempt: Distributor token account is not a distribution vault of the security token */
+export const DIVIDENDS_ERROR__DISTRIBUTOR_ACCOUNT_NOT_EXEMPT = 0x1782; // 6018
 
 export type DividendsError =
+  | typeof DIVIDENDS_ERROR__ACCESS_CONTROL_REQUIRED
   | typeof DIVIDENDS_ERROR__DISTRIBUTION_PAUSED
+  | typeof DIVIDENDS_ERROR__DISTRIBUTOR_ACCOUNT_NOT_EXEMPT
   | typeof DIVIDENDS_ERROR__DISTRIBUTOR_NOT_READY_TO_CLAIM
   | typeof DIVIDENDS_ERROR__DROP_ALREADY_CLAIMED
   | typeof DIVIDENDS_ERROR__EXCEEDED_MAX_CLAIM
@@ -71,7 +77,9 @@ export type DividendsError =
 let dividendsErrorMessages: Record<DividendsError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   dividendsErrorMessages = {
+    [DIVIDENDS_ERROR__ACCESS_CONTROL_REQUIRED]: `Access control is required to distribute the security token`,
     [DIVIDENDS_ERROR__DISTRIBUTION_PAUSED]: `Distribution is paused`,
+    [DIVIDENDS_ERROR__DISTRIBUTOR_ACCOUNT_NOT_EXEMPT]: `Distributor token account is not a distribution vault of the security token`,
     [DIVIDENDS_ERROR__DISTRIBUTOR_NOT_READY_TO_CLAIM]: `Distributor is not ready to claim`,
     [DIVIDENDS_ERROR__DROP_ALREADY_CLAIMED]: `Drop already claimed`,
     [DIVIDENDS_ERROR__EXCEEDED_MAX_CLAIM]: `Exceeded maximum claim amount`,
@@ -10,8 +10,10 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -34,7 +36,11 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { DIVIDENDS_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const FUND_DIVIDENDS_DISCRIMINATOR = new Uint8Array([
   80, 231, 140, 123, 85, 15, 70, 166,
@@ -56,6 +62,8 @@ export type FundDividendsInstruction<
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountAccessControl extends string | AccountMeta<string> = string,
+  TAccountExemptAccountRegistry extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -82,6 +90,12 @@ export type FundDividendsInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountAccessControl extends string
+        ? ReadonlyAccount<TAccountAccessControl>
+        : TAccountAccessControl,
+      TAccountExemptAccountRegistry extends string
+        ? ReadonlyAccount<TAccountExemptAccountRegistry>
+        : TAccountExemptAccountRegistry,
       ...TRemainingAccounts,
     ]
   >;
@@ -120,6 +134,156 @@ export function getFundDividendsInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type FundDividendsAsyncInput<
+  TAccountDistributor extends string = string,
+  TAccountFrom extends string = string,
+  TAccountTo extends string = string,
+  TAccountFunder extends string = string,
+  TAccountPayer extends string = string,
+  TAccountMint extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAccessControl extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
+> = {
+  /** The [MerkleDistributor]. */
+  distributor: Address<TAccountDistributor>;
+  /** Account which send the funding tokens. */
+  from: Address<TAccountFrom>;
+  /** Distributor ATA containing the tokens to distribute. */
+  to: Address<TAccountTo>;
+  /** Who is funding the tokens. */
+  funder: TransactionSigner<TAccountFunder>;
+  /** Payer of the fund dividends. */
+  payer: TransactionSigner<TAccountPayer>;
+  mint: Address<TAccountMint>;
+  /** SPL [Token] program. */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /**
+   * Access Control of the security token.
+   * Optional: Required when the distributed mint is the security token.
+   */
+  accessControl?: Address<TAccountAccessControl>;
+  /** Optional: Required when the distributed mint is the security token. */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
+  amount: FundDividendsInstructionDataArgs['amount'];
+};
+
+export async function getFundDividendsInstructionAsync<
+  TAccountDistributor extends string,
+  TAccountFrom extends string,
+  TAccountTo extends string,
+  TAccountFunder extends string,
+  TAccountPayer extends string,
+  TAccountMint extends string,
+  TAccountTokenProgram extends string,
+  TAccountAccessControl extends string,
+  TAccountExemptAccountRegistry extends string,
+  TProgramAddress extends Address = typeof DIVIDENDS_PROGRAM_ADDRESS,
+>(
+  input: FundDividendsAsyncInput<
+    TAccountDistributor,
+    TAccountFrom,
+    TAccountTo,
+    TAccountFunder,
+    TAccountPayer,
+    TAccountMint,
+    TAccountTokenProgram,
+    TAccountAccessControl,
+    TAccountExemptAccountRegistry
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  FundDividendsInstruction<
+    TProgramAddress,
+    TAccountDistributor,
+    TAccountFrom,
+    TAccountTo,
+    TAccountFunder,
+    TAccountPayer,
+    TAccountMint,
+    TAccountTokenProgram,
+    TAccountAccessControl,
+    TAccountExemptAccountRegistry
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? DIVIDENDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    distributor: { value: input.distributor ?? null, isWritable: true },
+    from: { value: input.from ?? null, isWritable: true },
+    to: { value: input.to ?? null, isWritable: true },
+    funder: { value: input.funder ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    accessControl: { value: input.accessControl ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.exemptAccountRegistry.value) {
+    accounts.exemptAccountRegistry.value = await getProgramDerivedAddress({
+      programAddress:
+        '4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3' as Address<'4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3'>,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            101, 120, 101, 109, 112, 116, 95, 97, 99, 99, 111, 117, 110, 116,
+            115,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.distributor),
+      getAccountMeta(accounts.from),
+      getAccountMeta(accounts.to),
+      getAccountMeta(accounts.funder),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.accessControl),
+      getAccountMeta(accounts.exemptAccountRegistry),
+    ],
+    data: getFundDividendsInstructionDataEncoder().encode(
+      args as FundDividendsInstructionDataArgs
+    ),
+    programAddress,
+  } as FundDividendsInstruction<
+    TProgramAddress,
+    TAccountDistributor,
+    TAccountFrom,
+    TAccountTo,
+    TAccountFunder,
+    TAccountPayer,
+    TAccountMint,
+    TAccountTokenProgram,
+    TAccountAccessControl,
+    TAccountExemptAccountRegistry
+  >);
+}
+
 export type FundDividendsInput<
   TAccountDistributor extends string = string,
   TAccountFrom extends string = string,
@@ -128,6 +292,8 @@ export type FundDividendsInput<
   TAccountPayer extends string = string,
   TAccountMint extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountAccessControl extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   /** The [MerkleDistributor]. */
   distributor: Address<TAccountDistributor>;
@@ -142,6 +308,13 @@ export type FundDividendsInput<
   mint: Address<TAccountMint>;
   /** SPL [Token] program. */
   tokenProgram?: Address<TAccountTokenProgram>;
+  /**
+   * Access Control of the security token.
+   * Optional: Required when the distributed mint is the security token.
+   */
+  accessControl?: Address<TAccountAccessControl>;
+  /** Optional: Required when the distributed mint is the security token. */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
   amount: FundDividendsInstructionDataArgs['amount'];
 };
 
@@ -153,6 +326,8 @@ export function getFundDividendsInstruction<
   TAccountPayer extends string,
   TAccountMint extends string,
   TAccountTokenProgram extends string,
+  TAccountAccessControl extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof DIVIDENDS_PROGRAM_ADDRESS,
 >(
   input: FundDividendsInput<
@@ -162,7 +337,9 @@ export function getFundDividendsInstruction<
     TAccountFunder,
     TAccountPayer,
     TAccountMint,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountAccessControl,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): FundDividendsInstruction<
@@ -173,7 +350,9 @@ export function getFundDividendsInstruction<
   TAccountFunder,
   TAccountPayer,
   TAccountMint,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountAccessControl,
+  TAccountExemptAccountRegistry
 > {
   // Program address.
   const programAddress = config?.programAddress ?? DIVIDENDS_PROGRAM_ADDRESS;
@@ -187,6 +366,11 @@ export function getFundDividendsInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    accessControl: { value: input.accessControl ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -212,6 +396,8 @@ export function getFundDividendsInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.accessControl),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getFundDividendsInstructionDataEncoder().encode(
       args as FundDividendsInstructionDataArgs
@@ -225,7 +411,9 @@ export function getFundDividendsInstruction<
     TAccountFunder,
     TAccountPayer,
     TAccountMint,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountAccessControl,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -248,6 +436,13 @@ export type ParsedFundDividendsInstruction<
     mint: TAccountMetas[5];
     /** SPL [Token] program. */
     tokenProgram: TAccountMetas[6];
+    /**
+     * Access Control of the security token.
+     * Optional: Required when the distributed mint is the security token.
+     */
+    accessControl?: TAccountMetas[7] | undefined;
+    /** Optional: Required when the distributed mint is the security token. */
+    exemptAccountRegistry?: TAccountMetas[8] | undefined;
   };
   data: FundDividendsInstructionData;
 };
@@ -260,7 +455,7 @@ export function parseFundDividendsInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFundDividendsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -270,6 +465,12 @@ export function parseFundDividendsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === DIVIDENDS_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -280,6 +481,8 @@ export function parseFundDividendsInstruction<
       payer: getNextAccount(),
       mint: getNextAccount(),
       tokenProgram: getNextAccount(),
+      accessControl: getNextOptionalAccount(),
+      exemptAccountRegistry: getNextOptionalAccount(),
     },
     data: getFundDividendsInstructionDataDecoder().decode(instruction.data),
   };
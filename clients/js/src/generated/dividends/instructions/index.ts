@@ -9,6 +9,7 @@
 export * from './acceptReclaimerOwnership';
 export * from './claim';
 export * from './fundDividends';
+export * from './migrateDistributor';
 export * from './newDistributor';
 export * from './pause';
 export * from './proposeReclaimer';
@@ -17,6 +17,7 @@ import {
   type ParsedAcceptReclaimerOwnershipInstruction,
   type ParsedClaimInstruction,
   type ParsedFundDividendsInstruction,
+  type ParsedMigrateDistributorInstruction,
   type ParsedNewDistributorInstruction,
   type ParsedPauseInstruction,
   type ParsedProposeReclaimerInstruction,
@@ -102,6 +103,7 @@ export enum DividendsInstruction {
   AcceptReclaimerOwnership,
   Claim,
   FundDividends,
+  MigrateDistributor,
   NewDistributor,
   Pause,
   ProposeReclaimer,
@@ -145,6 +147,17 @@ export function identifyDividendsInstruction(
   ) {
     return DividendsInstruction.FundDividends;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([64, 53, 16, 171, 17, 224, 4, 40])
+      ),
+      0
+    )
+  ) {
+    return DividendsInstruction.MigrateDistributor;
+  }
   if (
     containsBytes(
       data,
@@ -206,6 +219,9 @@ export type ParsedDividendsInstruction<
   | ({
       instructionType: DividendsInstruction.FundDividends;
     } & ParsedFundDividendsInstruction<TProgram>)
+  | ({
+      instructionType: DividendsInstruction.MigrateDistributor;
+    } & ParsedMigrateDistributorInstruction<TProgram>)
   | ({
       instructionType: DividendsInstruction.NewDistributor;
     } & ParsedNewDistributorInstruction<TProgram>)
@@ -75,6 +75,7 @@ export type MintReleaseScheduleInstruction<
     '4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3',
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountExemptAccountRegistry extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -119,6 +120,9 @@ export type MintReleaseScheduleInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountExemptAccountRegistry extends string
+        ? ReadonlyAccount<TAccountExemptAccountRegistry>
+        : TAccountExemptAccountRegistry,
       ...TRemainingAccounts,
     ]
   >;
@@ -192,6 +196,7 @@ export type MintReleaseScheduleAsyncInput<
   TAccountTokenProgram extends string = string,
   TAccountAccessControlProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   tokenlockAccount: Address<TAccountTokenlockAccount>;
   timelockAccount: Address<TAccountTimelockAccount>;
@@ -207,6 +212,8 @@ export type MintReleaseScheduleAsyncInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   accessControlProgram?: Address<TAccountAccessControlProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the escrow account is registered in the exempt account registry */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
   uuid: MintReleaseScheduleInstructionDataArgs['uuid'];
   amount: MintReleaseScheduleInstructionDataArgs['amount'];
   commencementTimestamp: MintReleaseScheduleInstructionDataArgs['commencementTimestamp'];
@@ -228,6 +235,7 @@ export async function getMintReleaseScheduleInstructionAsync<
   TAccountTokenProgram extends string,
   TAccountAccessControlProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof TOKENLOCK_PROGRAM_ADDRESS,
 >(
   input: MintReleaseScheduleAsyncInput<
@@ -243,7 +251,8 @@ export async function getMintReleaseScheduleInstructionAsync<
     TAccountTo,
     TAccountTokenProgram,
     TAccountAccessControlProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -261,7 +270,8 @@ export async function getMintReleaseScheduleInstructionAsync<
     TAccountTo,
     TAccountTokenProgram,
     TAccountAccessControlProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >
 > {
   // Program address.
@@ -294,6 +304,10 @@ export async function getMintReleaseScheduleInstructionAsync<
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -347,6 +361,7 @@ export async function getMintReleaseScheduleInstructionAsync<
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.accessControlProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getMintReleaseScheduleInstructionDataEncoder().encode(
       args as MintReleaseScheduleInstructionDataArgs
@@ -366,7 +381,8 @@ export async function getMintReleaseScheduleInstructionAsync<
     TAccountTo,
     TAccountTokenProgram,
     TAccountAccessControlProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -384,6 +400,7 @@ export type MintReleaseScheduleInput<
   TAccountTokenProgram extends string = string,
   TAccountAccessControlProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   tokenlockAccount: Address<TAccountTokenlockAccount>;
   timelockAccount: Address<TAccountTimelockAccount>;
@@ -399,6 +416,8 @@ export type MintReleaseScheduleInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   accessControlProgram?: Address<TAccountAccessControlProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the escrow account is registered in the exempt account registry */
+  exemptAccountRegistry?: Address<TAccountExemptAccountRegistry>;
   uuid: MintReleaseScheduleInstructionDataArgs['uuid'];
   amount: MintReleaseScheduleInstructionDataArgs['amount'];
   commencementTimestamp: MintReleaseScheduleInstructionDataArgs['commencementTimestamp'];
@@ -420,6 +439,7 @@ export function getMintReleaseScheduleInstruction<
   TAccountTokenProgram extends string,
   TAccountAccessControlProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address = typeof TOKENLOCK_PROGRAM_ADDRESS,
 >(
   input: MintReleaseScheduleInput<
@@ -435,7 +455,8 @@ export function getMintReleaseScheduleInstruction<
     TAccountTo,
     TAccountTokenProgram,
     TAccountAccessControlProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): MintReleaseScheduleInstruction<
@@ -452,7 +473,8 @@ export function getMintReleaseScheduleInstruction<
   TAccountTo,
   TAccountTokenProgram,
   TAccountAccessControlProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountExemptAccountRegistry
 > {
   // Program address.
   const programAddress = config?.programAddress ?? TOKENLOCK_PROGRAM_ADDRESS;
@@ -484,6 +506,10 @@ export function getMintReleaseScheduleInstruction<
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -523,6 +549,7 @@ export function getMintReleaseScheduleInstruction<
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.accessControlProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getMintReleaseScheduleInstructionDataEncoder().encode(
       args as MintReleaseScheduleInstructionDataArgs
@@ -542,7 +569,8 @@ export function getMintReleaseScheduleInstruction<
     TAccountTo,
     TAccountTokenProgram,
     TAccountAccessControlProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -566,6 +594,8 @@ export type ParsedMintReleaseScheduleInstruction<
     tokenProgram: TAccountMetas[10];
     accessControlProgram: TAccountMetas[11];
     systemProgram: TAccountMetas[12];
+    /** Required when the escrow account is registered in the exempt account registry */
+    exemptAccountRegistry?: TAccountMetas[13] | undefined;
   };
   data: MintReleaseScheduleInstructionData;
 };
@@ -578,7 +608,7 @@ export function parseMintReleaseScheduleInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedMintReleaseScheduleInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 13) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -588,6 +618,12 @@ export function parseMintReleaseScheduleInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === TOKENLOCK_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -604,6 +640,7 @@ export function parseMintReleaseScheduleInstruction<
       tokenProgram: getNextAccount(),
       accessControlProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      exemptAccountRegistry: getNextOptionalAccount(),
     },
     data: getMintReleaseScheduleInstructionDataDecoder().decode(
       instruction.data
@@ -23,6 +23,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -52,12 +54,18 @@ export type HolderGroup = {
   group: bigint;
   holder: Address;
   currentWalletsCount: bigint;
+  fundedWalletsCount: bigint;
+  version: number;
+  reserved: ReadonlyUint8Array;
 };
 
 export type HolderGroupArgs = {
   group: number | bigint;
   holder: Address;
   currentWalletsCount: number | bigint;
+  fundedWalletsCount: number | bigint;
+  version: number;
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link HolderGroupArgs} account data. */
@@ -68,6 +76,9 @@ export function getHolderGroupEncoder(): FixedSizeEncoder<HolderGroupArgs> {
       ['group', getU64Encoder()],
       ['holder', getAddressEncoder()],
       ['currentWalletsCount', getU64Encoder()],
+      ['fundedWalletsCount', getU64Encoder()],
+      ['version', getU8Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
     (value) => ({ ...value, discriminator: HOLDER_GROUP_DISCRIMINATOR })
   );
@@ -80,6 +91,9 @@ export function getHolderGroupDecoder(): FixedSizeDecoder<HolderGroup> {
     ['group', getU64Decoder()],
     ['holder', getAddressDecoder()],
     ['currentWalletsCount', getU64Decoder()],
+    ['fundedWalletsCount', getU64Decoder()],
+    ['version', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
@@ -145,5 +159,5 @@ export async function fetchAllMaybeHolderGroup(
 }
 
 export function getHolderGroupSize(): number {
-  return 56;
+  return 97;
 }
@@ -7,8 +7,13 @@
  */
 
 export * from './accessControl';
+export * from './approvedVenue';
+export * from './attestationIssuerRegistry';
+export * from './beneficialOwnerPosition';
+export * from './blackoutCalendar';
 export * from './holderGroup';
 export * from './securityAssociatedAccount';
+export * from './transferApproval';
 export * from './transferRestrictionData';
 export * from './transferRestrictionGroup';
 export * from './transferRestrictionHolder';
@@ -17,6 +17,10 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getOptionDecoder,
@@ -25,6 +29,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -40,6 +46,16 @@ import {
   type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getAcquisitionLotDecoder,
+  getAcquisitionLotEncoder,
+  getWalletHoldDecoder,
+  getWalletHoldEncoder,
+  type AcquisitionLot,
+  type AcquisitionLotArgs,
+  type WalletHold,
+  type WalletHoldArgs,
+} from '../types';
 
 export const SECURITY_ASSOCIATED_ACCOUNT_DISCRIMINATOR = new Uint8Array([
   68, 169, 137, 56, 226, 21, 69, 124,
@@ -55,11 +71,35 @@ export type SecurityAssociatedAccount = {
   discriminator: ReadonlyUint8Array;
   group: bigint;
   holder: Option<Address>;
+  acquisitionLotsCount: number;
+  acquisitionLots: Array<AcquisitionLot>;
+  funded: boolean;
+  holderId: bigint;
+  omnibus: boolean;
+  beneficialOwnersCount: bigint;
+  beneficialOwnedAmount: bigint;
+  holdsCount: number;
+  holds: Array<WalletHold>;
+  bump: number;
+  version: number;
+  reserved: ReadonlyUint8Array;
 };
 
 export type SecurityAssociatedAccountArgs = {
   group: number | bigint;
   holder: OptionOrNullable<Address>;
+  acquisitionLotsCount: number;
+  acquisitionLots: Array<AcquisitionLotArgs>;
+  funded: boolean;
+  holderId: number | bigint;
+  omnibus: boolean;
+  beneficialOwnersCount: number | bigint;
+  beneficialOwnedAmount: number | bigint;
+  holdsCount: number;
+  holds: Array<WalletHoldArgs>;
+  bump: number;
+  version: number;
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link SecurityAssociatedAccountArgs} account data. */
@@ -69,6 +109,21 @@ export function getSecurityAssociatedAccountEncoder(): Encoder<SecurityAssociate
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['group', getU64Encoder()],
       ['holder', getOptionEncoder(getAddressEncoder())],
+      ['acquisitionLotsCount', getU8Encoder()],
+      [
+        'acquisitionLots',
+        getArrayEncoder(getAcquisitionLotEncoder(), { size: 8 }),
+      ],
+      ['funded', getBooleanEncoder()],
+      ['holderId', getU64Encoder()],
+      ['omnibus', getBooleanEncoder()],
+      ['beneficialOwnersCount', getU64Encoder()],
+      ['beneficialOwnedAmount', getU64Encoder()],
+      ['holdsCount', getU8Encoder()],
+      ['holds', getArrayEncoder(getWalletHoldEncoder(), { size: 4 })],
+      ['bump', getU8Encoder()],
+      ['version', getU8Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
     (value) => ({
       ...value,
@@ -83,6 +138,21 @@ export function getSecurityAssociatedAccountDecoder(): Decoder<SecurityAssociate
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['group', getU64Decoder()],
     ['holder', getOptionDecoder(getAddressDecoder())],
+    ['acquisitionLotsCount', getU8Decoder()],
+    [
+      'acquisitionLots',
+      getArrayDecoder(getAcquisitionLotDecoder(), { size: 8 }),
+    ],
+    ['funded', getBooleanDecoder()],
+    ['holderId', getU64Decoder()],
+    ['omnibus', getBooleanDecoder()],
+    ['beneficialOwnersCount', getU64Decoder()],
+    ['beneficialOwnedAmount', getU64Decoder()],
+    ['holdsCount', getU8Decoder()],
+    ['holds', getArrayDecoder(getWalletHoldDecoder(), { size: 4 })],
+    ['bump', getU8Decoder()],
+    ['version', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
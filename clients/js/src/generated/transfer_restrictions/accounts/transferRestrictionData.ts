@@ -27,6 +27,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -62,6 +64,13 @@ export type TransferRestrictionData = {
   maxHolders: bigint;
   paused: boolean;
   lockupEscrowAccount: Option<Address>;
+  countHoldersByBalance: boolean;
+  fundedHoldersCount: bigint;
+  bump: number;
+  version: number;
+  policyProgram: Address;
+  allowIntraHolderTransfers: boolean;
+  reserved: ReadonlyUint8Array;
 };
 
 export type TransferRestrictionDataArgs = {
@@ -72,6 +81,13 @@ export type TransferRestrictionDataArgs = {
   maxHolders: number | bigint;
   paused: boolean;
   lockupEscrowAccount: OptionOrNullable<Address>;
+  countHoldersByBalance: boolean;
+  fundedHoldersCount: number | bigint;
+  bump: number;
+  version: number;
+  policyProgram: Address;
+  allowIntraHolderTransfers: boolean;
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link TransferRestrictionDataArgs} account data. */
@@ -86,6 +102,13 @@ export function getTransferRestrictionDataEncoder(): Encoder<TransferRestriction
       ['maxHolders', getU64Encoder()],
       ['paused', getBooleanEncoder()],
       ['lockupEscrowAccount', getOptionEncoder(getAddressEncoder())],
+      ['countHoldersByBalance', getBooleanEncoder()],
+      ['fundedHoldersCount', getU64Encoder()],
+      ['bump', getU8Encoder()],
+      ['version', getU8Encoder()],
+      ['policyProgram', getAddressEncoder()],
+      ['allowIntraHolderTransfers', getBooleanEncoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
     (value) => ({
       ...value,
@@ -105,6 +128,13 @@ export function getTransferRestrictionDataDecoder(): Decoder<TransferRestriction
     ['maxHolders', getU64Decoder()],
     ['paused', getBooleanDecoder()],
     ['lockupEscrowAccount', getOptionDecoder(getAddressDecoder())],
+    ['countHoldersByBalance', getBooleanDecoder()],
+    ['fundedHoldersCount', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['version', getU8Decoder()],
+    ['policyProgram', getAddressDecoder()],
+    ['allowIntraHolderTransfers', getBooleanDecoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
@@ -23,6 +23,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -53,6 +55,14 @@ export type TransferRestrictionGroup = {
   currentHoldersCount: bigint;
   maxHolders: bigint;
   transferRestrictionData: Address;
+  holdingPeriod: bigint;
+  fundedHoldersCount: bigint;
+  pausedDirection: number;
+  venueWalletsCount: bigint;
+  bump: number;
+  version: number;
+  minHolding: bigint;
+  reserved: ReadonlyUint8Array;
 };
 
 export type TransferRestrictionGroupArgs = {
@@ -60,6 +70,14 @@ export type TransferRestrictionGroupArgs = {
   currentHoldersCount: number | bigint;
   maxHolders: number | bigint;
   transferRestrictionData: Address;
+  holdingPeriod: number | bigint;
+  fundedHoldersCount: number | bigint;
+  pausedDirection: number;
+  venueWalletsCount: number | bigint;
+  bump: number;
+  version: number;
+  minHolding: number | bigint;
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link TransferRestrictionGroupArgs} account data. */
@@ -71,6 +89,14 @@ export function getTransferRestrictionGroupEncoder(): FixedSizeEncoder<TransferR
       ['currentHoldersCount', getU64Encoder()],
       ['maxHolders', getU64Encoder()],
       ['transferRestrictionData', getAddressEncoder()],
+      ['holdingPeriod', getU64Encoder()],
+      ['fundedHoldersCount', getU64Encoder()],
+      ['pausedDirection', getU8Encoder()],
+      ['venueWalletsCount', getU64Encoder()],
+      ['bump', getU8Encoder()],
+      ['version', getU8Encoder()],
+      ['minHolding', getU64Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 24)],
     ]),
     (value) => ({
       ...value,
@@ -87,6 +113,14 @@ export function getTransferRestrictionGroupDecoder(): FixedSizeDecoder<TransferR
     ['currentHoldersCount', getU64Decoder()],
     ['maxHolders', getU64Decoder()],
     ['transferRestrictionData', getAddressDecoder()],
+    ['holdingPeriod', getU64Decoder()],
+    ['fundedHoldersCount', getU64Decoder()],
+    ['pausedDirection', getU8Decoder()],
+    ['venueWalletsCount', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['version', getU8Decoder()],
+    ['minHolding', getU64Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 24)],
   ]);
 }
 
@@ -177,5 +211,5 @@ export async function fetchAllMaybeTransferRestrictionGroup(
 }
 
 export function getTransferRestrictionGroupSize(): number {
-  return 64;
+  return 123;
 }
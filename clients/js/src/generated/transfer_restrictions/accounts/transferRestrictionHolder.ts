@@ -25,6 +25,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -56,6 +58,11 @@ export type TransferRestrictionHolder = {
   currentHolderGroupCount: bigint;
   id: bigint;
   active: boolean;
+  fundedWalletsCount: bigint;
+  frozen: boolean;
+  version: number;
+  beneficialOwnerPositionsCount: bigint;
+  reserved: ReadonlyUint8Array;
 };
 
 export type TransferRestrictionHolderArgs = {
@@ -64,6 +71,11 @@ export type TransferRestrictionHolderArgs = {
   currentHolderGroupCount: number | bigint;
   id: number | bigint;
   active: boolean;
+  fundedWalletsCount: number | bigint;
+  frozen: boolean;
+  version: number;
+  beneficialOwnerPositionsCount: number | bigint;
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link TransferRestrictionHolderArgs} account data. */
@@ -76,6 +88,11 @@ export function getTransferRestrictionHolderEncoder(): FixedSizeEncoder<Transfer
       ['currentHolderGroupCount', getU64Encoder()],
       ['id', getU64Encoder()],
       ['active', getBooleanEncoder()],
+      ['fundedWalletsCount', getU64Encoder()],
+      ['frozen', getBooleanEncoder()],
+      ['version', getU8Encoder()],
+      ['beneficialOwnerPositionsCount', getU64Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 24)],
     ]),
     (value) => ({
       ...value,
@@ -93,6 +110,11 @@ export function getTransferRestrictionHolderDecoder(): FixedSizeDecoder<Transfer
     ['currentHolderGroupCount', getU64Decoder()],
     ['id', getU64Decoder()],
     ['active', getBooleanDecoder()],
+    ['fundedWalletsCount', getU64Decoder()],
+    ['frozen', getBooleanDecoder()],
+    ['version', getU8Decoder()],
+    ['beneficialOwnerPositionsCount', getU64Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 24)],
   ]);
 }
 
@@ -183,5 +205,5 @@ export async function fetchAllMaybeTransferRestrictionHolder(
 }
 
 export function getTransferRestrictionHolderSize(): number {
-  return 65;
+  return 107;
 }
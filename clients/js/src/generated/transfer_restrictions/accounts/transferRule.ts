@@ -17,12 +17,18 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -36,6 +42,12 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getTransferRuleClosedIntervalDecoder,
+  getTransferRuleClosedIntervalEncoder,
+  type TransferRuleClosedInterval,
+  type TransferRuleClosedIntervalArgs,
+} from '../types';
 
 export const TRANSFER_RULE_DISCRIMINATOR = new Uint8Array([
   200, 231, 114, 91, 84, 241, 109, 172,
@@ -53,6 +65,13 @@ export type TransferRule = {
   transferGroupIdFrom: bigint;
   transferGroupIdTo: bigint;
   lockedUntil: bigint;
+  validUntil: bigint;
+  closedIntervalsCount: number;
+  closedIntervals: Array<TransferRuleClosedInterval>;
+  bump: number;
+  approvalRequired: boolean;
+  version: number;
+  reserved: ReadonlyUint8Array;
 };
 
 export type TransferRuleArgs = {
@@ -60,6 +79,13 @@ export type TransferRuleArgs = {
   transferGroupIdFrom: number | bigint;
   transferGroupIdTo: number | bigint;
   lockedUntil: number | bigint;
+  validUntil: number | bigint;
+  closedIntervalsCount: number;
+  closedIntervals: Array<TransferRuleClosedIntervalArgs>;
+  bump: number;
+  approvalRequired: boolean;
+  version: number;
+  reserved: ReadonlyUint8Array;
 };
 
 /** Gets the encoder for {@link TransferRuleArgs} account data. */
@@ -71,6 +97,16 @@ export function getTransferRuleEncoder(): FixedSizeEncoder<TransferRuleArgs> {
       ['transferGroupIdFrom', getU64Encoder()],
       ['transferGroupIdTo', getU64Encoder()],
       ['lockedUntil', getU64Encoder()],
+      ['validUntil', getU64Encoder()],
+      ['closedIntervalsCount', getU8Encoder()],
+      [
+        'closedIntervals',
+        getArrayEncoder(getTransferRuleClosedIntervalEncoder(), { size: 8 }),
+      ],
+      ['bump', getU8Encoder()],
+      ['approvalRequired', getBooleanEncoder()],
+      ['version', getU8Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
     (value) => ({ ...value, discriminator: TRANSFER_RULE_DISCRIMINATOR })
   );
@@ -84,6 +120,16 @@ export function getTransferRuleDecoder(): FixedSizeDecoder<TransferRule> {
     ['transferGroupIdFrom', getU64Decoder()],
     ['transferGroupIdTo', getU64Decoder()],
     ['lockedUntil', getU64Decoder()],
+    ['validUntil', getU64Decoder()],
+    ['closedIntervalsCount', getU8Decoder()],
+    [
+      'closedIntervals',
+      getArrayDecoder(getTransferRuleClosedIntervalDecoder(), { size: 8 }),
+    ],
+    ['bump', getU8Decoder()],
+    ['approvalRequired', getBooleanDecoder()],
+    ['version', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
@@ -149,5 +195,5 @@ export async function fetchAllMaybeTransferRule(
 }
 
 export function getTransferRuleSize(): number {
-  return 64;
+  return 300;
 }
@@ -66,33 +66,186 @@ export const TRANSFER_RESTRICTIONS_ERROR__VALUE_UNCHANGED = 0x1787; // 6023
 export const TRANSFER_RESTRICTIONS_ERROR__CURRENT_GROUP_REQUIRED_FOR_EXISTING_WALLET = 0x1788; // 6024
 /** HolderGroupAlreadyInitialized: Holder group already initialized */
 export const TRANSFER_RESTRICTIONS_ERROR__HOLDER_GROUP_ALREADY_INITIALIZED = 0x1789; // 6025
+/** TransferRuleExpired: Transfer rule has expired */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_EXPIRED = 0x178a; // 6026
+/** TransferRuleClosedInterval: Transfer rule is inside a closed interval */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_CLOSED_INTERVAL = 0x178b; // 6027
+/** TooManyClosedIntervals: Too many closed intervals */
+export const TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_CLOSED_INTERVALS = 0x178c; // 6028
+/** InvalidClosedInterval: Invalid closed interval */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_CLOSED_INTERVAL = 0x178d; // 6029
+/** InvalidBlackoutWindow: Invalid blackout window */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_BLACKOUT_WINDOW = 0x178e; // 6030
+/** InvalidTransferDirection: Invalid transfer direction */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_DIRECTION = 0x178f; // 6031
+/** TooManyBlackoutWindows: Too many blackout windows */
+export const TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_BLACKOUT_WINDOWS = 0x1790; // 6032
+/** BlackoutWindowNotFound: Blackout window not found */
+export const TRANSFER_RESTRICTIONS_ERROR__BLACKOUT_WINDOW_NOT_FOUND = 0x1791; // 6033
+/** TransferBlackoutActive: Transfers are blocked by a blackout window */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_BLACKOUT_ACTIVE = 0x1792; // 6034
+/** HoldingPeriodNotElapsed: Tokens are still inside the holding period */
+export const TRANSFER_RESTRICTIONS_ERROR__HOLDING_PERIOD_NOT_ELAPSED = 0x1793; // 6035
+/** NotTransferring: Transfer hook can be executed only during a token transfer */
+export const TRANSFER_RESTRICTIONS_ERROR__NOT_TRANSFERRING = 0x1794; // 6036
+/** HolderCountingByBalanceDisabled: Holder counting by balance is disabled */
+export const TRANSFER_RESTRICTIONS_ERROR__HOLDER_COUNTING_BY_BALANCE_DISABLED = 0x1795; // 6037
+/** DefaultTransferRuleAccountMissing: Default transfer rule account is missing */
+export const TRANSFER_RESTRICTIONS_ERROR__DEFAULT_TRANSFER_RULE_ACCOUNT_MISSING = 0x1796; // 6038
+/** TransferRestrictionGroupMissing: Transfer restriction group account is missing */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RESTRICTION_GROUP_MISSING = 0x1797; // 6039
+/** InvalidTransferGroupId: Invalid transfer group id */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_GROUP_ID = 0x1798; // 6040
+/** InvalidRemainingAccounts: Invalid remaining accounts */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_REMAINING_ACCOUNTS = 0x1799; // 6041
+/** InvalidTokenAccount: Invalid token account */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_TOKEN_ACCOUNT = 0x179a; // 6042
+/** NotOmnibusAccount: Token account is not an omnibus account */
+export const TRANSFER_RESTRICTIONS_ERROR__NOT_OMNIBUS_ACCOUNT = 0x179b; // 6043
+/** OmnibusAccountHasBeneficialOwners: Omnibus account has beneficial owners */
+export const TRANSFER_RESTRICTIONS_ERROR__OMNIBUS_ACCOUNT_HAS_BENEFICIAL_OWNERS = 0x179c; // 6044
+/** OmnibusBalanceBelowBeneficialOwnership: Omnibus account balance does not cover beneficial owner positions */
+export const TRANSFER_RESTRICTIONS_ERROR__OMNIBUS_BALANCE_BELOW_BENEFICIAL_OWNERSHIP = 0x179d; // 6045
+/** BeneficialOwnerPositionNotEmpty: Beneficial owner position is not empty */
+export const TRANSFER_RESTRICTIONS_ERROR__BENEFICIAL_OWNER_POSITION_NOT_EMPTY = 0x179e; // 6046
+/** CannotMergeHolderIntoItself: Cannot merge a holder into itself */
+export const TRANSFER_RESTRICTIONS_ERROR__CANNOT_MERGE_HOLDER_INTO_ITSELF = 0x179f; // 6047
+/** TokenAccountNotApprovedVenue: Token account is not owned by the approved venue */
+export const TRANSFER_RESTRICTIONS_ERROR__TOKEN_ACCOUNT_NOT_APPROVED_VENUE = 0x17a0; // 6048
+/** NotVenueTokenAccount: Token account is not a venue token account */
+export const TRANSFER_RESTRICTIONS_ERROR__NOT_VENUE_TOKEN_ACCOUNT = 0x17a1; // 6049
+/** GroupTransfersPaused: Transfers of the group are paused */
+export const TRANSFER_RESTRICTIONS_ERROR__GROUP_TRANSFERS_PAUSED = 0x17a2; // 6050
+/** HolderFrozen: Holder is frozen */
+export const TRANSFER_RESTRICTIONS_ERROR__HOLDER_FROZEN = 0x17a3; // 6051
+/** InvalidWalletHold: Invalid wallet hold */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_WALLET_HOLD = 0x17a4; // 6052
+/** TooManyWalletHolds: Too many wallet holds */
+export const TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_WALLET_HOLDS = 0x17a5; // 6053
+/** InvalidWalletHoldIndex: Invalid wallet hold index */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_WALLET_HOLD_INDEX = 0x17a6; // 6054
+/** TransferBlockedByWalletHold: Transfer is blocked by a wallet hold */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_BLOCKED_BY_WALLET_HOLD = 0x17a7; // 6055
+/** GroupHasWallets: Group still has wallets */
+export const TRANSFER_RESTRICTIONS_ERROR__GROUP_HAS_WALLETS = 0x17a8; // 6056
+/** TransferApprovalRequired: Transfer requires a transfer approval */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_REQUIRED = 0x17a9; // 6057
+/** InvalidTransferApproval: Invalid transfer approval */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_APPROVAL = 0x17aa; // 6058
+/** TransferApprovalExpired: Transfer approval expired */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_EXPIRED = 0x17ab; // 6059
+/** TransferApprovalAmountExceeded: Transfer amount exceeds the transfer approval */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_AMOUNT_EXCEEDED = 0x17ac; // 6060
+/** NotExemptAccount: Token account is not an exempt account */
+export const TRANSFER_RESTRICTIONS_ERROR__NOT_EXEMPT_ACCOUNT = 0x17ad; // 6061
+/** InvalidMigrationAccount: Account cannot be migrated with the transfer restriction data */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_MIGRATION_ACCOUNT = 0x17ae; // 6062
+/** InvalidAttestationIssuer: Invalid attestation issuer */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_ATTESTATION_ISSUER = 0x17af; // 6063
+/** AttestationIssuerAlreadyRegistered: Attestation issuer is already registered */
+export const TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_ISSUER_ALREADY_REGISTERED = 0x17b0; // 6064
+/** AttestationIssuersLimitReached: Attestation issuers limit reached */
+export const TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_ISSUERS_LIMIT_REACHED = 0x17b1; // 6065
+/** InvalidAttestationIssuerIndex: Invalid attestation issuer index */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_ATTESTATION_ISSUER_INDEX = 0x17b2; // 6066
+/** WalletNotAttested: Wallet has no valid attestation of a trusted issuer */
+export const TRANSFER_RESTRICTIONS_ERROR__WALLET_NOT_ATTESTED = 0x17b3; // 6067
+/** InvalidPolicyProgram: Invalid policy program */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_POLICY_PROGRAM = 0x17b4; // 6068
+/** SenderBalanceBelowMinHolding: Sender balance after the transfer is below the minimum holding */
+export const TRANSFER_RESTRICTIONS_ERROR__SENDER_BALANCE_BELOW_MIN_HOLDING = 0x17b5; // 6069
+/** ReceiverBalanceBelowMinHolding: Receiver balance after the transfer is below the minimum holding */
+export const TRANSFER_RESTRICTIONS_ERROR__RECEIVER_BALANCE_BELOW_MIN_HOLDING = 0x17b6; // 6070
+/** HolderHasBeneficialOwnerPositions: Holder has beneficial owner positions */
+export const TRANSFER_RESTRICTIONS_ERROR__HOLDER_HAS_BENEFICIAL_OWNER_POSITIONS = 0x17b7; // 6071
+/** InvalidHolderAccount: Holder account does not match the holder of the wallet */
+export const TRANSFER_RESTRICTIONS_ERROR__INVALID_HOLDER_ACCOUNT = 0x17b8; // 6072
+/** AttestationProgramMismatch: Attestation issuers must use the same attestation program */
+export const TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_PROGRAM_MISMATCH = 0x17b9; // 6073
+/** TransferDependsOnPolicyProgram: Transfer passes the transfer restrictions and depends on the policy program */
+export const TRANSFER_RESTRICTIONS_ERROR__TRANSFER_DEPENDS_ON_POLICY_PROGRAM = 0x17ba; // 6074
+/** MutableTokenAccountOwner: Token account owner can be changed */
+export const TRANSFER_RESTRICTIONS_ERROR__MUTABLE_TOKEN_ACCOUNT_OWNER = 0x17bb; // 6075
+/** HoldersCountOverflow: Holders count overflow */
+export const TRANSFER_RESTRICTIONS_ERROR__HOLDERS_COUNT_OVERFLOW = 0x17bc; // 6076
 
 export type TransferRestrictionsError =
   | typeof TRANSFER_RESTRICTIONS_ERROR__ALL_TRANSFERS_PAUSED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_ISSUERS_LIMIT_REACHED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_ISSUER_ALREADY_REGISTERED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_PROGRAM_MISMATCH
   | typeof TRANSFER_RESTRICTIONS_ERROR__BALANCE_IS_TOO_LOW
+  | typeof TRANSFER_RESTRICTIONS_ERROR__BENEFICIAL_OWNER_POSITION_NOT_EMPTY
+  | typeof TRANSFER_RESTRICTIONS_ERROR__BLACKOUT_WINDOW_NOT_FOUND
+  | typeof TRANSFER_RESTRICTIONS_ERROR__CANNOT_MERGE_HOLDER_INTO_ITSELF
   | typeof TRANSFER_RESTRICTIONS_ERROR__CURRENT_GROUP_REQUIRED_FOR_EXISTING_WALLET
   | typeof TRANSFER_RESTRICTIONS_ERROR__CURRENT_HOLDER_GROUP_COUNT_MUST_BE_ZERO
   | typeof TRANSFER_RESTRICTIONS_ERROR__CURRENT_WALLETS_COUNT_MUST_BE_ZERO
+  | typeof TRANSFER_RESTRICTIONS_ERROR__DEFAULT_TRANSFER_RULE_ACCOUNT_MISSING
+  | typeof TRANSFER_RESTRICTIONS_ERROR__GROUP_HAS_WALLETS
+  | typeof TRANSFER_RESTRICTIONS_ERROR__GROUP_TRANSFERS_PAUSED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__HOLDERS_COUNT_OVERFLOW
+  | typeof TRANSFER_RESTRICTIONS_ERROR__HOLDER_COUNTING_BY_BALANCE_DISABLED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__HOLDER_FROZEN
   | typeof TRANSFER_RESTRICTIONS_ERROR__HOLDER_GROUP_ALREADY_INITIALIZED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__HOLDER_HAS_BENEFICIAL_OWNER_POSITIONS
+  | typeof TRANSFER_RESTRICTIONS_ERROR__HOLDING_PERIOD_NOT_ELAPSED
   | typeof TRANSFER_RESTRICTIONS_ERROR__INCORRECT_TOKENLOCK_ACCOUNT
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_ATTESTATION_ISSUER
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_ATTESTATION_ISSUER_INDEX
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_BLACKOUT_WINDOW
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_CLOSED_INTERVAL
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_HOLDER_ACCOUNT
   | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_HOLDER_INDEX
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_MIGRATION_ACCOUNT
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_POLICY_PROGRAM
   | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_P_D_A
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_REMAINING_ACCOUNTS
   | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_ROLE
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_TOKEN_ACCOUNT
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_APPROVAL
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_DIRECTION
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_GROUP_ID
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_WALLET_HOLD
+  | typeof TRANSFER_RESTRICTIONS_ERROR__INVALID_WALLET_HOLD_INDEX
   | typeof TRANSFER_RESTRICTIONS_ERROR__MAX_HOLDERS_REACHED
   | typeof TRANSFER_RESTRICTIONS_ERROR__MAX_HOLDERS_REACHED_INSIDE_THE_GROUP
   | typeof TRANSFER_RESTRICTIONS_ERROR__MISMATCHED_ESCROW_ACCOUNT
+  | typeof TRANSFER_RESTRICTIONS_ERROR__MUTABLE_TOKEN_ACCOUNT_OWNER
   | typeof TRANSFER_RESTRICTIONS_ERROR__NEW_GROUP_IS_THE_SAME_AS_THE_CURRENT_GROUP
   | typeof TRANSFER_RESTRICTIONS_ERROR__NEW_HOLDER_GROUP_MAX_MUST_EXCEED_CURRENT_HOLDER_GROUP_COUNT
   | typeof TRANSFER_RESTRICTIONS_ERROR__NEW_HOLDER_MAX_MUST_EXCEED_CURRENT_HOLDER_COUNT
   | typeof TRANSFER_RESTRICTIONS_ERROR__NON_POSITIVE_HOLDER_GROUP_COUNT
+  | typeof TRANSFER_RESTRICTIONS_ERROR__NOT_EXEMPT_ACCOUNT
+  | typeof TRANSFER_RESTRICTIONS_ERROR__NOT_OMNIBUS_ACCOUNT
+  | typeof TRANSFER_RESTRICTIONS_ERROR__NOT_TRANSFERRING
+  | typeof TRANSFER_RESTRICTIONS_ERROR__NOT_VENUE_TOKEN_ACCOUNT
   | typeof TRANSFER_RESTRICTIONS_ERROR__NO_WALLETS_IN_GROUP
+  | typeof TRANSFER_RESTRICTIONS_ERROR__OMNIBUS_ACCOUNT_HAS_BENEFICIAL_OWNERS
+  | typeof TRANSFER_RESTRICTIONS_ERROR__OMNIBUS_BALANCE_BELOW_BENEFICIAL_OWNERSHIP
+  | typeof TRANSFER_RESTRICTIONS_ERROR__RECEIVER_BALANCE_BELOW_MIN_HOLDING
   | typeof TRANSFER_RESTRICTIONS_ERROR__SECURITY_ASSOCIATED_ACCOUNT_DATA_IS_EMTPY
+  | typeof TRANSFER_RESTRICTIONS_ERROR__SENDER_BALANCE_BELOW_MIN_HOLDING
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TOKEN_ACCOUNT_NOT_APPROVED_VENUE
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_BLACKOUT_WINDOWS
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_CLOSED_INTERVALS
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_WALLET_HOLDS
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_AMOUNT_EXCEEDED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_EXPIRED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_REQUIRED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_BLACKOUT_ACTIVE
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_BLOCKED_BY_WALLET_HOLD
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_DEPENDS_ON_POLICY_PROGRAM
   | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_GROUP_NOT_APPROVED
   | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RESTRICTIONS_ACCOUNT_DATA_IS_EMTPY
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RESTRICTION_GROUP_MISSING
   | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_ACCOUNT_DATA_IS_EMTPY
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_CLOSED_INTERVAL
+  | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_EXPIRED
   | typeof TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_NOT_ALLOWED_UNTIL_LATER
   | typeof TRANSFER_RESTRICTIONS_ERROR__UNAUTHORIZED
   | typeof TRANSFER_RESTRICTIONS_ERROR__VALUE_UNCHANGED
+  | typeof TRANSFER_RESTRICTIONS_ERROR__WALLET_NOT_ATTESTED
   | typeof TRANSFER_RESTRICTIONS_ERROR__ZERO_GROUP_HOLDER_GROUP_MAX_CANNOT_BE_NON_ZERO;
 
 let transferRestrictionsErrorMessages:
@@ -101,30 +254,81 @@ let transferRestrictionsErrorMessages:
 if (process.env.NODE_ENV !== 'production') {
   transferRestrictionsErrorMessages = {
     [TRANSFER_RESTRICTIONS_ERROR__ALL_TRANSFERS_PAUSED]: `All transfers are paused`,
+    [TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_ISSUERS_LIMIT_REACHED]: `Attestation issuers limit reached`,
+    [TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_ISSUER_ALREADY_REGISTERED]: `Attestation issuer is already registered`,
+    [TRANSFER_RESTRICTIONS_ERROR__ATTESTATION_PROGRAM_MISMATCH]: `Attestation issuers must use the same attestation program`,
     [TRANSFER_RESTRICTIONS_ERROR__BALANCE_IS_TOO_LOW]: `Balance is too low`,
+    [TRANSFER_RESTRICTIONS_ERROR__BENEFICIAL_OWNER_POSITION_NOT_EMPTY]: `Beneficial owner position is not empty`,
+    [TRANSFER_RESTRICTIONS_ERROR__BLACKOUT_WINDOW_NOT_FOUND]: `Blackout window not found`,
+    [TRANSFER_RESTRICTIONS_ERROR__CANNOT_MERGE_HOLDER_INTO_ITSELF]: `Cannot merge a holder into itself`,
     [TRANSFER_RESTRICTIONS_ERROR__CURRENT_GROUP_REQUIRED_FOR_EXISTING_WALLET]: `Current group and holder group must be provided for existing wallet`,
     [TRANSFER_RESTRICTIONS_ERROR__CURRENT_HOLDER_GROUP_COUNT_MUST_BE_ZERO]: `Current holder group count must be zero`,
     [TRANSFER_RESTRICTIONS_ERROR__CURRENT_WALLETS_COUNT_MUST_BE_ZERO]: `Current wallets count must be zero`,
+    [TRANSFER_RESTRICTIONS_ERROR__DEFAULT_TRANSFER_RULE_ACCOUNT_MISSING]: `Default transfer rule account is missing`,
+    [TRANSFER_RESTRICTIONS_ERROR__GROUP_HAS_WALLETS]: `Group still has wallets`,
+    [TRANSFER_RESTRICTIONS_ERROR__GROUP_TRANSFERS_PAUSED]: `Transfers of the group are paused`,
+    [TRANSFER_RESTRICTIONS_ERROR__HOLDERS_COUNT_OVERFLOW]: `Holders count overflow`,
+    [TRANSFER_RESTRICTIONS_ERROR__HOLDER_COUNTING_BY_BALANCE_DISABLED]: `Holder counting by balance is disabled`,
+    [TRANSFER_RESTRICTIONS_ERROR__HOLDER_FROZEN]: `Holder is frozen`,
     [TRANSFER_RESTRICTIONS_ERROR__HOLDER_GROUP_ALREADY_INITIALIZED]: `Holder group already initialized`,
+    [TRANSFER_RESTRICTIONS_ERROR__HOLDER_HAS_BENEFICIAL_OWNER_POSITIONS]: `Holder has beneficial owner positions`,
+    [TRANSFER_RESTRICTIONS_ERROR__HOLDING_PERIOD_NOT_ELAPSED]: `Tokens are still inside the holding period`,
     [TRANSFER_RESTRICTIONS_ERROR__INCORRECT_TOKENLOCK_ACCOUNT]: `Wrong tokenlock account`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_ATTESTATION_ISSUER]: `Invalid attestation issuer`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_ATTESTATION_ISSUER_INDEX]: `Invalid attestation issuer index`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_BLACKOUT_WINDOW]: `Invalid blackout window`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_CLOSED_INTERVAL]: `Invalid closed interval`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_HOLDER_ACCOUNT]: `Holder account does not match the holder of the wallet`,
     [TRANSFER_RESTRICTIONS_ERROR__INVALID_HOLDER_INDEX]: `Invalid transfer restriction holder index`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_MIGRATION_ACCOUNT]: `Account cannot be migrated with the transfer restriction data`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_POLICY_PROGRAM]: `Invalid policy program`,
     [TRANSFER_RESTRICTIONS_ERROR__INVALID_P_D_A]: `Invalid PDA`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_REMAINING_ACCOUNTS]: `Invalid remaining accounts`,
     [TRANSFER_RESTRICTIONS_ERROR__INVALID_ROLE]: `Invalid role`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_TOKEN_ACCOUNT]: `Invalid token account`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_APPROVAL]: `Invalid transfer approval`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_DIRECTION]: `Invalid transfer direction`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_TRANSFER_GROUP_ID]: `Invalid transfer group id`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_WALLET_HOLD]: `Invalid wallet hold`,
+    [TRANSFER_RESTRICTIONS_ERROR__INVALID_WALLET_HOLD_INDEX]: `Invalid wallet hold index`,
     [TRANSFER_RESTRICTIONS_ERROR__MAX_HOLDERS_REACHED]: `Max holders reached`,
     [TRANSFER_RESTRICTIONS_ERROR__MAX_HOLDERS_REACHED_INSIDE_THE_GROUP]: `Max holders reached inside the group`,
     [TRANSFER_RESTRICTIONS_ERROR__MISMATCHED_ESCROW_ACCOUNT]: `Mismatched escrow account`,
+    [TRANSFER_RESTRICTIONS_ERROR__MUTABLE_TOKEN_ACCOUNT_OWNER]: `Token account owner can be changed`,
     [TRANSFER_RESTRICTIONS_ERROR__NEW_GROUP_IS_THE_SAME_AS_THE_CURRENT_GROUP]: `New group is the same as the current group`,
     [TRANSFER_RESTRICTIONS_ERROR__NEW_HOLDER_GROUP_MAX_MUST_EXCEED_CURRENT_HOLDER_GROUP_COUNT]: `New holder group max must exceed current holder group count`,
     [TRANSFER_RESTRICTIONS_ERROR__NEW_HOLDER_MAX_MUST_EXCEED_CURRENT_HOLDER_COUNT]: `New holder max must exceed current holder count`,
     [TRANSFER_RESTRICTIONS_ERROR__NON_POSITIVE_HOLDER_GROUP_COUNT]: `Non-positive holder group count`,
+    [TRANSFER_RESTRICTIONS_ERROR__NOT_EXEMPT_ACCOUNT]: `Token account is not an exempt account`,
+    [TRANSFER_RESTRICTIONS_ERROR__NOT_OMNIBUS_ACCOUNT]: `Token account is not an omnibus account`,
+    [TRANSFER_RESTRICTIONS_ERROR__NOT_TRANSFERRING]: `Transfer hook can be executed only during a token transfer`,
+    [TRANSFER_RESTRICTIONS_ERROR__NOT_VENUE_TOKEN_ACCOUNT]: `Token account is not a venue token account`,
     [TRANSFER_RESTRICTIONS_ERROR__NO_WALLETS_IN_GROUP]: `No wallets in group`,
+    [TRANSFER_RESTRICTIONS_ERROR__OMNIBUS_ACCOUNT_HAS_BENEFICIAL_OWNERS]: `Omnibus account has beneficial owners`,
+    [TRANSFER_RESTRICTIONS_ERROR__OMNIBUS_BALANCE_BELOW_BENEFICIAL_OWNERSHIP]: `Omnibus account balance does not cover beneficial owner positions`,
+    [TRANSFER_RESTRICTIONS_ERROR__RECEIVER_BALANCE_BELOW_MIN_HOLDING]: `Receiver balance after the transfer is below the minimum holding`,
     [TRANSFER_RESTRICTIONS_ERROR__SECURITY_ASSOCIATED_ACCOUNT_DATA_IS_EMTPY]: `Security associated account data is empty`,
+    [TRANSFER_RESTRICTIONS_ERROR__SENDER_BALANCE_BELOW_MIN_HOLDING]: `Sender balance after the transfer is below the minimum holding`,
+    [TRANSFER_RESTRICTIONS_ERROR__TOKEN_ACCOUNT_NOT_APPROVED_VENUE]: `Token account is not owned by the approved venue`,
+    [TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_BLACKOUT_WINDOWS]: `Too many blackout windows`,
+    [TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_CLOSED_INTERVALS]: `Too many closed intervals`,
+    [TRANSFER_RESTRICTIONS_ERROR__TOO_MANY_WALLET_HOLDS]: `Too many wallet holds`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_AMOUNT_EXCEEDED]: `Transfer amount exceeds the transfer approval`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_EXPIRED]: `Transfer approval expired`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_APPROVAL_REQUIRED]: `Transfer requires a transfer approval`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_BLACKOUT_ACTIVE]: `Transfers are blocked by a blackout window`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_BLOCKED_BY_WALLET_HOLD]: `Transfer is blocked by a wallet hold`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_DEPENDS_ON_POLICY_PROGRAM]: `Transfer passes the transfer restrictions and depends on the policy program`,
     [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_GROUP_NOT_APPROVED]: `Transfer group not approved`,
     [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RESTRICTIONS_ACCOUNT_DATA_IS_EMTPY]: `Transfer restrictions account data is empty`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RESTRICTION_GROUP_MISSING]: `Transfer restriction group account is missing`,
     [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_ACCOUNT_DATA_IS_EMTPY]: `Transfer rule account data is empty`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_CLOSED_INTERVAL]: `Transfer rule is inside a closed interval`,
+    [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_EXPIRED]: `Transfer rule has expired`,
     [TRANSFER_RESTRICTIONS_ERROR__TRANSFER_RULE_NOT_ALLOWED_UNTIL_LATER]: `Transfer rule not allowed until later`,
     [TRANSFER_RESTRICTIONS_ERROR__UNAUTHORIZED]: `Unauthorized`,
     [TRANSFER_RESTRICTIONS_ERROR__VALUE_UNCHANGED]: `The provided value is already set. No changes were made`,
+    [TRANSFER_RESTRICTIONS_ERROR__WALLET_NOT_ATTESTED]: `Wallet has no valid attestation of a trusted issuer`,
     [TRANSFER_RESTRICTIONS_ERROR__ZERO_GROUP_HOLDER_GROUP_MAX_CANNOT_BE_NON_ZERO]: `Zero group holder group max cannot be non-zero`,
   };
 }
@@ -16,6 +16,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type Address,
@@ -88,13 +90,19 @@ export type EnforceTransferRestrictionsInstruction<
 
 export type EnforceTransferRestrictionsInstructionData = {
   discriminator: ReadonlyUint8Array;
+  amount: bigint;
 };
 
-export type EnforceTransferRestrictionsInstructionDataArgs = {};
+export type EnforceTransferRestrictionsInstructionDataArgs = {
+  amount: number | bigint;
+};
 
 export function getEnforceTransferRestrictionsInstructionDataEncoder(): FixedSizeEncoder<EnforceTransferRestrictionsInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['amount', getU64Encoder()],
+    ]),
     (value) => ({
       ...value,
       discriminator: ENFORCE_TRANSFER_RESTRICTIONS_DISCRIMINATOR,
@@ -105,6 +113,7 @@ export function getEnforceTransferRestrictionsInstructionDataEncoder(): FixedSiz
 export function getEnforceTransferRestrictionsInstructionDataDecoder(): FixedSizeDecoder<EnforceTransferRestrictionsInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['amount', getU64Decoder()],
   ]);
 }
 
@@ -133,7 +142,9 @@ export type EnforceTransferRestrictionsAsyncInput<
   transferRestrictionData?: Address<TAccountTransferRestrictionData>;
   securityAssociatedAccountFrom?: Address<TAccountSecurityAssociatedAccountFrom>;
   securityAssociatedAccountTo?: Address<TAccountSecurityAssociatedAccountTo>;
+  /** Default transfer rule candidates are passed as remaining accounts. */
   transferRule: Address<TAccountTransferRule>;
+  amount: EnforceTransferRestrictionsInstructionDataArgs['amount'];
 };
 
 export async function getEnforceTransferRestrictionsInstructionAsync<
@@ -200,6 +211,9 @@ export async function getEnforceTransferRestrictionsInstructionAsync<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.transferRestrictionData.value) {
     accounts.transferRestrictionData.value = await getProgramDerivedAddress({
@@ -247,7 +261,9 @@ export async function getEnforceTransferRestrictionsInstructionAsync<
       getAccountMeta(accounts.securityAssociatedAccountTo),
       getAccountMeta(accounts.transferRule),
     ],
-    data: getEnforceTransferRestrictionsInstructionDataEncoder().encode({}),
+    data: getEnforceTransferRestrictionsInstructionDataEncoder().encode(
+      args as EnforceTransferRestrictionsInstructionDataArgs
+    ),
     programAddress,
   } as EnforceTransferRestrictionsInstruction<
     TProgramAddress,
@@ -276,7 +292,9 @@ export type EnforceTransferRestrictionsInput<
   transferRestrictionData: Address<TAccountTransferRestrictionData>;
   securityAssociatedAccountFrom: Address<TAccountSecurityAssociatedAccountFrom>;
   securityAssociatedAccountTo: Address<TAccountSecurityAssociatedAccountTo>;
+  /** Default transfer rule candidates are passed as remaining accounts. */
   transferRule: Address<TAccountTransferRule>;
+  amount: EnforceTransferRestrictionsInstructionDataArgs['amount'];
 };
 
 export function getEnforceTransferRestrictionsInstruction<
@@ -341,6 +359,9 @@ export function getEnforceTransferRestrictionsInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
@@ -352,7 +373,9 @@ export function getEnforceTransferRestrictionsInstruction<
       getAccountMeta(accounts.securityAssociatedAccountTo),
       getAccountMeta(accounts.transferRule),
     ],
-    data: getEnforceTransferRestrictionsInstructionDataEncoder().encode({}),
+    data: getEnforceTransferRestrictionsInstructionDataEncoder().encode(
+      args as EnforceTransferRestrictionsInstructionDataArgs
+    ),
     programAddress,
   } as EnforceTransferRestrictionsInstruction<
     TProgramAddress,
@@ -378,6 +401,7 @@ export type ParsedEnforceTransferRestrictionsInstruction<
     transferRestrictionData: TAccountMetas[3];
     securityAssociatedAccountFrom: TAccountMetas[4];
     securityAssociatedAccountTo: TAccountMetas[5];
+    /** Default transfer rule candidates are passed as remaining accounts. */
     transferRule: TAccountMetas[6];
   };
   data: EnforceTransferRestrictionsInstructionData;
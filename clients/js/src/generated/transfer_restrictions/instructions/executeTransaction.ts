@@ -29,6 +29,7 @@ import {
   type InstructionWithData,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type WritableAccount,
 } from '@solana/kit';
 import { TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS } from '../programs';
 import {
@@ -60,6 +61,31 @@ export type ExecuteTransactionInstruction<
   TAccountSecurityAssociatedAccountTo extends string | AccountMeta<string> =
     string,
   TAccountTransferRule extends string | AccountMeta<string> = string,
+  TAccountBlackoutCalendar extends string | AccountMeta<string> = string,
+  TAccountTransferRestrictionGroupFrom extends string | AccountMeta<string> =
+    string,
+  TAccountTransferRestrictionGroupTo extends string | AccountMeta<string> =
+    string,
+  TAccountTransferRestrictionHolderFrom extends string | AccountMeta<string> =
+    string,
+  TAccountTransferRestrictionHolderTo extends string | AccountMeta<string> =
+    string,
+  TAccountHolderGroupFrom extends string | AccountMeta<string> = string,
+  TAccountHolderGroupTo extends string | AccountMeta<string> = string,
+  TAccountTransferRuleFromAny extends string | AccountMeta<string> = string,
+  TAccountTransferRuleToAny extends string | AccountMeta<string> = string,
+  TAccountTransferRuleAny extends string | AccountMeta<string> = string,
+  TAccountTransferApproval extends string | AccountMeta<string> = string,
+  TAccountAccessControlProgram extends string | AccountMeta<string> =
+    '4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3',
+  TAccountExemptAccountRegistry extends string | AccountMeta<string> = string,
+  TAccountAttestationIssuerRegistry extends string | AccountMeta<string> =
+    string,
+  TAccountAttestationProgram extends string | AccountMeta<string> = string,
+  TAccountAttestationFrom extends string | AccountMeta<string> = string,
+  TAccountAttestationTo extends string | AccountMeta<string> = string,
+  TAccountPolicyProgram extends string | AccountMeta<string> = string,
+  TAccountPolicyConfig extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -81,17 +107,74 @@ export type ExecuteTransactionInstruction<
         ? ReadonlyAccount<TAccountExtraMetasAccount>
         : TAccountExtraMetasAccount,
       TAccountTransferRestrictionData extends string
-        ? ReadonlyAccount<TAccountTransferRestrictionData>
+        ? WritableAccount<TAccountTransferRestrictionData>
         : TAccountTransferRestrictionData,
       TAccountSecurityAssociatedAccountFrom extends string
-        ? ReadonlyAccount<TAccountSecurityAssociatedAccountFrom>
+        ? WritableAccount<TAccountSecurityAssociatedAccountFrom>
         : TAccountSecurityAssociatedAccountFrom,
       TAccountSecurityAssociatedAccountTo extends string
-        ? ReadonlyAccount<TAccountSecurityAssociatedAccountTo>
+        ? WritableAccount<TAccountSecurityAssociatedAccountTo>
         : TAccountSecurityAssociatedAccountTo,
       TAccountTransferRule extends string
         ? ReadonlyAccount<TAccountTransferRule>
         : TAccountTransferRule,
+      TAccountBlackoutCalendar extends string
+        ? ReadonlyAccount<TAccountBlackoutCalendar>
+        : TAccountBlackoutCalendar,
+      TAccountTransferRestrictionGroupFrom extends string
+        ? WritableAccount<TAccountTransferRestrictionGroupFrom>
+        : TAccountTransferRestrictionGroupFrom,
+      TAccountTransferRestrictionGroupTo extends string
+        ? WritableAccount<TAccountTransferRestrictionGroupTo>
+        : TAccountTransferRestrictionGroupTo,
+      TAccountTransferRestrictionHolderFrom extends string
+        ? WritableAccount<TAccountTransferRestrictionHolderFrom>
+        : TAccountTransferRestrictionHolderFrom,
+      TAccountTransferRestrictionHolderTo extends string
+        ? WritableAccount<TAccountTransferRestrictionHolderTo>
+        : TAccountTransferRestrictionHolderTo,
+      TAccountHolderGroupFrom extends string
+        ? WritableAccount<TAccountHolderGroupFrom>
+        : TAccountHolderGroupFrom,
+      TAccountHolderGroupTo extends string
+        ? WritableAccount<TAccountHolderGroupTo>
+        : TAccountHolderGroupTo,
+      TAccountTransferRuleFromAny extends string
+        ? ReadonlyAccount<TAccountTransferRuleFromAny>
+        : TAccountTransferRuleFromAny,
+      TAccountTransferRuleToAny extends string
+        ? ReadonlyAccount<TAccountTransferRuleToAny>
+        : TAccountTransferRuleToAny,
+      TAccountTransferRuleAny extends string
+        ? ReadonlyAccount<TAccountTransferRuleAny>
+        : TAccountTransferRuleAny,
+      TAccountTransferApproval extends string
+        ? WritableAccount<TAccountTransferApproval>
+        : TAccountTransferApproval,
+      TAccountAccessControlProgram extends string
+        ? ReadonlyAccount<TAccountAccessControlProgram>
+        : TAccountAccessControlProgram,
+      TAccountExemptAccountRegistry extends string
+        ? ReadonlyAccount<TAccountExemptAccountRegistry>
+        : TAccountExemptAccountRegistry,
+      TAccountAttestationIssuerRegistry extends string
+        ? ReadonlyAccount<TAccountAttestationIssuerRegistry>
+        : TAccountAttestationIssuerRegistry,
+      TAccountAttestationProgram extends string
+        ? ReadonlyAccount<TAccountAttestationProgram>
+        : TAccountAttestationProgram,
+      TAccountAttestationFrom extends string
+        ? ReadonlyAccount<TAccountAttestationFrom>
+        : TAccountAttestationFrom,
+      TAccountAttestationTo extends string
+        ? ReadonlyAccount<TAccountAttestationTo>
+        : TAccountAttestationTo,
+      TAccountPolicyProgram extends string
+        ? ReadonlyAccount<TAccountPolicyProgram>
+        : TAccountPolicyProgram,
+      TAccountPolicyConfig extends string
+        ? ReadonlyAccount<TAccountPolicyConfig>
+        : TAccountPolicyConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -140,6 +223,25 @@ export type ExecuteTransactionAsyncInput<
   TAccountSecurityAssociatedAccountFrom extends string = string,
   TAccountSecurityAssociatedAccountTo extends string = string,
   TAccountTransferRule extends string = string,
+  TAccountBlackoutCalendar extends string = string,
+  TAccountTransferRestrictionGroupFrom extends string = string,
+  TAccountTransferRestrictionGroupTo extends string = string,
+  TAccountTransferRestrictionHolderFrom extends string = string,
+  TAccountTransferRestrictionHolderTo extends string = string,
+  TAccountHolderGroupFrom extends string = string,
+  TAccountHolderGroupTo extends string = string,
+  TAccountTransferRuleFromAny extends string = string,
+  TAccountTransferRuleToAny extends string = string,
+  TAccountTransferRuleAny extends string = string,
+  TAccountTransferApproval extends string = string,
+  TAccountAccessControlProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
+  TAccountAttestationIssuerRegistry extends string = string,
+  TAccountAttestationProgram extends string = string,
+  TAccountAttestationFrom extends string = string,
+  TAccountAttestationTo extends string = string,
+  TAccountPolicyProgram extends string = string,
+  TAccountPolicyConfig extends string = string,
 > = {
   sourceAccount: Address<TAccountSourceAccount>;
   mint: Address<TAccountMint>;
@@ -150,6 +252,25 @@ export type ExecuteTransactionAsyncInput<
   securityAssociatedAccountFrom: Address<TAccountSecurityAssociatedAccountFrom>;
   securityAssociatedAccountTo: Address<TAccountSecurityAssociatedAccountTo>;
   transferRule: Address<TAccountTransferRule>;
+  blackoutCalendar: Address<TAccountBlackoutCalendar>;
+  transferRestrictionGroupFrom: Address<TAccountTransferRestrictionGroupFrom>;
+  transferRestrictionGroupTo: Address<TAccountTransferRestrictionGroupTo>;
+  transferRestrictionHolderFrom: Address<TAccountTransferRestrictionHolderFrom>;
+  transferRestrictionHolderTo: Address<TAccountTransferRestrictionHolderTo>;
+  holderGroupFrom: Address<TAccountHolderGroupFrom>;
+  holderGroupTo: Address<TAccountHolderGroupTo>;
+  transferRuleFromAny: Address<TAccountTransferRuleFromAny>;
+  transferRuleToAny: Address<TAccountTransferRuleToAny>;
+  transferRuleAny: Address<TAccountTransferRuleAny>;
+  transferApproval: Address<TAccountTransferApproval>;
+  accessControlProgram?: Address<TAccountAccessControlProgram>;
+  exemptAccountRegistry: Address<TAccountExemptAccountRegistry>;
+  attestationIssuerRegistry: Address<TAccountAttestationIssuerRegistry>;
+  attestationProgram: Address<TAccountAttestationProgram>;
+  attestationFrom: Address<TAccountAttestationFrom>;
+  attestationTo: Address<TAccountAttestationTo>;
+  policyProgram: Address<TAccountPolicyProgram>;
+  policyConfig: Address<TAccountPolicyConfig>;
   amount: ExecuteTransactionInstructionDataArgs['amount'];
 };
 
@@ -163,6 +284,25 @@ export async function getExecuteTransactionInstructionAsync<
   TAccountSecurityAssociatedAccountFrom extends string,
   TAccountSecurityAssociatedAccountTo extends string,
   TAccountTransferRule extends string,
+  TAccountBlackoutCalendar extends string,
+  TAccountTransferRestrictionGroupFrom extends string,
+  TAccountTransferRestrictionGroupTo extends string,
+  TAccountTransferRestrictionHolderFrom extends string,
+  TAccountTransferRestrictionHolderTo extends string,
+  TAccountHolderGroupFrom extends string,
+  TAccountHolderGroupTo extends string,
+  TAccountTransferRuleFromAny extends string,
+  TAccountTransferRuleToAny extends string,
+  TAccountTransferRuleAny extends string,
+  TAccountTransferApproval extends string,
+  TAccountAccessControlProgram extends string,
+  TAccountExemptAccountRegistry extends string,
+  TAccountAttestationIssuerRegistry extends string,
+  TAccountAttestationProgram extends string,
+  TAccountAttestationFrom extends string,
+  TAccountAttestationTo extends string,
+  TAccountPolicyProgram extends string,
+  TAccountPolicyConfig extends string,
   TProgramAddress extends Address =
     typeof TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS,
 >(
@@ -175,7 +315,26 @@ export async function getExecuteTransactionInstructionAsync<
     TAccountTransferRestrictionData,
     TAccountSecurityAssociatedAccountFrom,
     TAccountSecurityAssociatedAccountTo,
-    TAccountTransferRule
+    TAccountTransferRule,
+    TAccountBlackoutCalendar,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountTransferRestrictionHolderFrom,
+    TAccountTransferRestrictionHolderTo,
+    TAccountHolderGroupFrom,
+    TAccountHolderGroupTo,
+    TAccountTransferRuleFromAny,
+    TAccountTransferRuleToAny,
+    TAccountTransferRuleAny,
+    TAccountTransferApproval,
+    TAccountAccessControlProgram,
+    TAccountExemptAccountRegistry,
+    TAccountAttestationIssuerRegistry,
+    TAccountAttestationProgram,
+    TAccountAttestationFrom,
+    TAccountAttestationTo,
+    TAccountPolicyProgram,
+    TAccountPolicyConfig
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -189,7 +348,26 @@ export async function getExecuteTransactionInstructionAsync<
     TAccountTransferRestrictionData,
     TAccountSecurityAssociatedAccountFrom,
     TAccountSecurityAssociatedAccountTo,
-    TAccountTransferRule
+    TAccountTransferRule,
+    TAccountBlackoutCalendar,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountTransferRestrictionHolderFrom,
+    TAccountTransferRestrictionHolderTo,
+    TAccountHolderGroupFrom,
+    TAccountHolderGroupTo,
+    TAccountTransferRuleFromAny,
+    TAccountTransferRuleToAny,
+    TAccountTransferRuleAny,
+    TAccountTransferApproval,
+    TAccountAccessControlProgram,
+    TAccountExemptAccountRegistry,
+    TAccountAttestationIssuerRegistry,
+    TAccountAttestationProgram,
+    TAccountAttestationFrom,
+    TAccountAttestationTo,
+    TAccountPolicyProgram,
+    TAccountPolicyConfig
   >
 > {
   // Program address.
@@ -211,17 +389,78 @@ export async function getExecuteTransactionInstructionAsync<
     },
     transferRestrictionData: {
       value: input.transferRestrictionData ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     securityAssociatedAccountFrom: {
       value: input.securityAssociatedAccountFrom ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     securityAssociatedAccountTo: {
       value: input.securityAssociatedAccountTo ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     transferRule: { value: input.transferRule ?? null, isWritable: false },
+    blackoutCalendar: {
+      value: input.blackoutCalendar ?? null,
+      isWritable: false,
+    },
+    transferRestrictionGroupFrom: {
+      value: input.transferRestrictionGroupFrom ?? null,
+      isWritable: true,
+    },
+    transferRestrictionGroupTo: {
+      value: input.transferRestrictionGroupTo ?? null,
+      isWritable: true,
+    },
+    transferRestrictionHolderFrom: {
+      value: input.transferRestrictionHolderFrom ?? null,
+      isWritable: true,
+    },
+    transferRestrictionHolderTo: {
+      value: input.transferRestrictionHolderTo ?? null,
+      isWritable: true,
+    },
+    holderGroupFrom: { value: input.holderGroupFrom ?? null, isWritable: true },
+    holderGroupTo: { value: input.holderGroupTo ?? null, isWritable: true },
+    transferRuleFromAny: {
+      value: input.transferRuleFromAny ?? null,
+      isWritable: false,
+    },
+    transferRuleToAny: {
+      value: input.transferRuleToAny ?? null,
+      isWritable: false,
+    },
+    transferRuleAny: {
+      value: input.transferRuleAny ?? null,
+      isWritable: false,
+    },
+    transferApproval: {
+      value: input.transferApproval ?? null,
+      isWritable: true,
+    },
+    accessControlProgram: {
+      value: input.accessControlProgram ?? null,
+      isWritable: false,
+    },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
+    attestationIssuerRegistry: {
+      value: input.attestationIssuerRegistry ?? null,
+      isWritable: false,
+    },
+    attestationProgram: {
+      value: input.attestationProgram ?? null,
+      isWritable: false,
+    },
+    attestationFrom: {
+      value: input.attestationFrom ?? null,
+      isWritable: false,
+    },
+    attestationTo: { value: input.attestationTo ?? null, isWritable: false },
+    policyProgram: { value: input.policyProgram ?? null, isWritable: false },
+    policyConfig: { value: input.policyConfig ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -246,6 +485,10 @@ export async function getExecuteTransactionInstructionAsync<
       ],
     });
   }
+  if (!accounts.accessControlProgram.value) {
+    accounts.accessControlProgram.value =
+      '4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3' as Address<'4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -259,6 +502,25 @@ export async function getExecuteTransactionInstructionAsync<
       getAccountMeta(accounts.securityAssociatedAccountFrom),
       getAccountMeta(accounts.securityAssociatedAccountTo),
       getAccountMeta(accounts.transferRule),
+      getAccountMeta(accounts.blackoutCalendar),
+      getAccountMeta(accounts.transferRestrictionGroupFrom),
+      getAccountMeta(accounts.transferRestrictionGroupTo),
+      getAccountMeta(accounts.transferRestrictionHolderFrom),
+      getAccountMeta(accounts.transferRestrictionHolderTo),
+      getAccountMeta(accounts.holderGroupFrom),
+      getAccountMeta(accounts.holderGroupTo),
+      getAccountMeta(accounts.transferRuleFromAny),
+      getAccountMeta(accounts.transferRuleToAny),
+      getAccountMeta(accounts.transferRuleAny),
+      getAccountMeta(accounts.transferApproval),
+      getAccountMeta(accounts.accessControlProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
+      getAccountMeta(accounts.attestationIssuerRegistry),
+      getAccountMeta(accounts.attestationProgram),
+      getAccountMeta(accounts.attestationFrom),
+      getAccountMeta(accounts.attestationTo),
+      getAccountMeta(accounts.policyProgram),
+      getAccountMeta(accounts.policyConfig),
     ],
     data: getExecuteTransactionInstructionDataEncoder().encode(
       args as ExecuteTransactionInstructionDataArgs
@@ -274,7 +536,26 @@ export async function getExecuteTransactionInstructionAsync<
     TAccountTransferRestrictionData,
     TAccountSecurityAssociatedAccountFrom,
     TAccountSecurityAssociatedAccountTo,
-    TAccountTransferRule
+    TAccountTransferRule,
+    TAccountBlackoutCalendar,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountTransferRestrictionHolderFrom,
+    TAccountTransferRestrictionHolderTo,
+    TAccountHolderGroupFrom,
+    TAccountHolderGroupTo,
+    TAccountTransferRuleFromAny,
+    TAccountTransferRuleToAny,
+    TAccountTransferRuleAny,
+    TAccountTransferApproval,
+    TAccountAccessControlProgram,
+    TAccountExemptAccountRegistry,
+    TAccountAttestationIssuerRegistry,
+    TAccountAttestationProgram,
+    TAccountAttestationFrom,
+    TAccountAttestationTo,
+    TAccountPolicyProgram,
+    TAccountPolicyConfig
   >);
 }
 
@@ -288,6 +569,25 @@ export type ExecuteTransactionInput<
   TAccountSecurityAssociatedAccountFrom extends string = string,
   TAccountSecurityAssociatedAccountTo extends string = string,
   TAccountTransferRule extends string = string,
+  TAccountBlackoutCalendar extends string = string,
+  TAccountTransferRestrictionGroupFrom extends string = string,
+  TAccountTransferRestrictionGroupTo extends string = string,
+  TAccountTransferRestrictionHolderFrom extends string = string,
+  TAccountTransferRestrictionHolderTo extends string = string,
+  TAccountHolderGroupFrom extends string = string,
+  TAccountHolderGroupTo extends string = string,
+  TAccountTransferRuleFromAny extends string = string,
+  TAccountTransferRuleToAny extends string = string,
+  TAccountTransferRuleAny extends string = string,
+  TAccountTransferApproval extends string = string,
+  TAccountAccessControlProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
+  TAccountAttestationIssuerRegistry extends string = string,
+  TAccountAttestationProgram extends string = string,
+  TAccountAttestationFrom extends string = string,
+  TAccountAttestationTo extends string = string,
+  TAccountPolicyProgram extends string = string,
+  TAccountPolicyConfig extends string = string,
 > = {
   sourceAccount: Address<TAccountSourceAccount>;
   mint: Address<TAccountMint>;
@@ -298,6 +598,25 @@ export type ExecuteTransactionInput<
   securityAssociatedAccountFrom: Address<TAccountSecurityAssociatedAccountFrom>;
   securityAssociatedAccountTo: Address<TAccountSecurityAssociatedAccountTo>;
   transferRule: Address<TAccountTransferRule>;
+  blackoutCalendar: Address<TAccountBlackoutCalendar>;
+  transferRestrictionGroupFrom: Address<TAccountTransferRestrictionGroupFrom>;
+  transferRestrictionGroupTo: Address<TAccountTransferRestrictionGroupTo>;
+  transferRestrictionHolderFrom: Address<TAccountTransferRestrictionHolderFrom>;
+  transferRestrictionHolderTo: Address<TAccountTransferRestrictionHolderTo>;
+  holderGroupFrom: Address<TAccountHolderGroupFrom>;
+  holderGroupTo: Address<TAccountHolderGroupTo>;
+  transferRuleFromAny: Address<TAccountTransferRuleFromAny>;
+  transferRuleToAny: Address<TAccountTransferRuleToAny>;
+  transferRuleAny: Address<TAccountTransferRuleAny>;
+  transferApproval: Address<TAccountTransferApproval>;
+  accessControlProgram?: Address<TAccountAccessControlProgram>;
+  exemptAccountRegistry: Address<TAccountExemptAccountRegistry>;
+  attestationIssuerRegistry: Address<TAccountAttestationIssuerRegistry>;
+  attestationProgram: Address<TAccountAttestationProgram>;
+  attestationFrom: Address<TAccountAttestationFrom>;
+  attestationTo: Address<TAccountAttestationTo>;
+  policyProgram: Address<TAccountPolicyProgram>;
+  policyConfig: Address<TAccountPolicyConfig>;
   amount: ExecuteTransactionInstructionDataArgs['amount'];
 };
 
@@ -311,6 +630,25 @@ export function getExecuteTransactionInstruction<
   TAccountSecurityAssociatedAccountFrom extends string,
   TAccountSecurityAssociatedAccountTo extends string,
   TAccountTransferRule extends string,
+  TAccountBlackoutCalendar extends string,
+  TAccountTransferRestrictionGroupFrom extends string,
+  TAccountTransferRestrictionGroupTo extends string,
+  TAccountTransferRestrictionHolderFrom extends string,
+  TAccountTransferRestrictionHolderTo extends string,
+  TAccountHolderGroupFrom extends string,
+  TAccountHolderGroupTo extends string,
+  TAccountTransferRuleFromAny extends string,
+  TAccountTransferRuleToAny extends string,
+  TAccountTransferRuleAny extends string,
+  TAccountTransferApproval extends string,
+  TAccountAccessControlProgram extends string,
+  TAccountExemptAccountRegistry extends string,
+  TAccountAttestationIssuerRegistry extends string,
+  TAccountAttestationProgram extends string,
+  TAccountAttestationFrom extends string,
+  TAccountAttestationTo extends string,
+  TAccountPolicyProgram extends string,
+  TAccountPolicyConfig extends string,
   TProgramAddress extends Address =
     typeof TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS,
 >(
@@ -323,7 +661,26 @@ export function getExecuteTransactionInstruction<
     TAccountTransferRestrictionData,
     TAccountSecurityAssociatedAccountFrom,
     TAccountSecurityAssociatedAccountTo,
-    TAccountTransferRule
+    TAccountTransferRule,
+    TAccountBlackoutCalendar,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountTransferRestrictionHolderFrom,
+    TAccountTransferRestrictionHolderTo,
+    TAccountHolderGroupFrom,
+    TAccountHolderGroupTo,
+    TAccountTransferRuleFromAny,
+    TAccountTransferRuleToAny,
+    TAccountTransferRuleAny,
+    TAccountTransferApproval,
+    TAccountAccessControlProgram,
+    TAccountExemptAccountRegistry,
+    TAccountAttestationIssuerRegistry,
+    TAccountAttestationProgram,
+    TAccountAttestationFrom,
+    TAccountAttestationTo,
+    TAccountPolicyProgram,
+    TAccountPolicyConfig
   >,
   config?: { programAddress?: TProgramAddress }
 ): ExecuteTransactionInstruction<
@@ -336,7 +693,26 @@ export function getExecuteTransactionInstruction<
   TAccountTransferRestrictionData,
   TAccountSecurityAssociatedAccountFrom,
   TAccountSecurityAssociatedAccountTo,
-  TAccountTransferRule
+  TAccountTransferRule,
+  TAccountBlackoutCalendar,
+  TAccountTransferRestrictionGroupFrom,
+  TAccountTransferRestrictionGroupTo,
+  TAccountTransferRestrictionHolderFrom,
+  TAccountTransferRestrictionHolderTo,
+  TAccountHolderGroupFrom,
+  TAccountHolderGroupTo,
+  TAccountTransferRuleFromAny,
+  TAccountTransferRuleToAny,
+  TAccountTransferRuleAny,
+  TAccountTransferApproval,
+  TAccountAccessControlProgram,
+  TAccountExemptAccountRegistry,
+  TAccountAttestationIssuerRegistry,
+  TAccountAttestationProgram,
+  TAccountAttestationFrom,
+  TAccountAttestationTo,
+  TAccountPolicyProgram,
+  TAccountPolicyConfig
 > {
   // Program address.
   const programAddress =
@@ -357,17 +733,78 @@ export function getExecuteTransactionInstruction<
     },
     transferRestrictionData: {
       value: input.transferRestrictionData ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     securityAssociatedAccountFrom: {
       value: input.securityAssociatedAccountFrom ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     securityAssociatedAccountTo: {
       value: input.securityAssociatedAccountTo ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     transferRule: { value: input.transferRule ?? null, isWritable: false },
+    blackoutCalendar: {
+      value: input.blackoutCalendar ?? null,
+      isWritable: false,
+    },
+    transferRestrictionGroupFrom: {
+      value: input.transferRestrictionGroupFrom ?? null,
+      isWritable: true,
+    },
+    transferRestrictionGroupTo: {
+      value: input.transferRestrictionGroupTo ?? null,
+      isWritable: true,
+    },
+    transferRestrictionHolderFrom: {
+      value: input.transferRestrictionHolderFrom ?? null,
+      isWritable: true,
+    },
+    transferRestrictionHolderTo: {
+      value: input.transferRestrictionHolderTo ?? null,
+      isWritable: true,
+    },
+    holderGroupFrom: { value: input.holderGroupFrom ?? null, isWritable: true },
+    holderGroupTo: { value: input.holderGroupTo ?? null, isWritable: true },
+    transferRuleFromAny: {
+      value: input.transferRuleFromAny ?? null,
+      isWritable: false,
+    },
+    transferRuleToAny: {
+      value: input.transferRuleToAny ?? null,
+      isWritable: false,
+    },
+    transferRuleAny: {
+      value: input.transferRuleAny ?? null,
+      isWritable: false,
+    },
+    transferApproval: {
+      value: input.transferApproval ?? null,
+      isWritable: true,
+    },
+    accessControlProgram: {
+      value: input.accessControlProgram ?? null,
+      isWritable: false,
+    },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
+    attestationIssuerRegistry: {
+      value: input.attestationIssuerRegistry ?? null,
+      isWritable: false,
+    },
+    attestationProgram: {
+      value: input.attestationProgram ?? null,
+      isWritable: false,
+    },
+    attestationFrom: {
+      value: input.attestationFrom ?? null,
+      isWritable: false,
+    },
+    attestationTo: { value: input.attestationTo ?? null, isWritable: false },
+    policyProgram: { value: input.policyProgram ?? null, isWritable: false },
+    policyConfig: { value: input.policyConfig ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -377,6 +814,12 @@ export function getExecuteTransactionInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.accessControlProgram.value) {
+    accounts.accessControlProgram.value =
+      '4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3' as Address<'4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
@@ -389,6 +832,25 @@ export function getExecuteTransactionInstruction<
       getAccountMeta(accounts.securityAssociatedAccountFrom),
       getAccountMeta(accounts.securityAssociatedAccountTo),
       getAccountMeta(accounts.transferRule),
+      getAccountMeta(accounts.blackoutCalendar),
+      getAccountMeta(accounts.transferRestrictionGroupFrom),
+      getAccountMeta(accounts.transferRestrictionGroupTo),
+      getAccountMeta(accounts.transferRestrictionHolderFrom),
+      getAccountMeta(accounts.transferRestrictionHolderTo),
+      getAccountMeta(accounts.holderGroupFrom),
+      getAccountMeta(accounts.holderGroupTo),
+      getAccountMeta(accounts.transferRuleFromAny),
+      getAccountMeta(accounts.transferRuleToAny),
+      getAccountMeta(accounts.transferRuleAny),
+      getAccountMeta(accounts.transferApproval),
+      getAccountMeta(accounts.accessControlProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
+      getAccountMeta(accounts.attestationIssuerRegistry),
+      getAccountMeta(accounts.attestationProgram),
+      getAccountMeta(accounts.attestationFrom),
+      getAccountMeta(accounts.attestationTo),
+      getAccountMeta(accounts.policyProgram),
+      getAccountMeta(accounts.policyConfig),
     ],
     data: getExecuteTransactionInstructionDataEncoder().encode(
       args as ExecuteTransactionInstructionDataArgs
@@ -404,7 +866,26 @@ export function getExecuteTransactionInstruction<
     TAccountTransferRestrictionData,
     TAccountSecurityAssociatedAccountFrom,
     TAccountSecurityAssociatedAccountTo,
-    TAccountTransferRule
+    TAccountTransferRule,
+    TAccountBlackoutCalendar,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountTransferRestrictionHolderFrom,
+    TAccountTransferRestrictionHolderTo,
+    TAccountHolderGroupFrom,
+    TAccountHolderGroupTo,
+    TAccountTransferRuleFromAny,
+    TAccountTransferRuleToAny,
+    TAccountTransferRuleAny,
+    TAccountTransferApproval,
+    TAccountAccessControlProgram,
+    TAccountExemptAccountRegistry,
+    TAccountAttestationIssuerRegistry,
+    TAccountAttestationProgram,
+    TAccountAttestationFrom,
+    TAccountAttestationTo,
+    TAccountPolicyProgram,
+    TAccountPolicyConfig
   >);
 }
 
@@ -423,6 +904,25 @@ export type ParsedExecuteTransactionInstruction<
     securityAssociatedAccountFrom: TAccountMetas[6];
     securityAssociatedAccountTo: TAccountMetas[7];
     transferRule: TAccountMetas[8];
+    blackoutCalendar: TAccountMetas[9];
+    transferRestrictionGroupFrom: TAccountMetas[10];
+    transferRestrictionGroupTo: TAccountMetas[11];
+    transferRestrictionHolderFrom: TAccountMetas[12];
+    transferRestrictionHolderTo: TAccountMetas[13];
+    holderGroupFrom: TAccountMetas[14];
+    holderGroupTo: TAccountMetas[15];
+    transferRuleFromAny: TAccountMetas[16];
+    transferRuleToAny: TAccountMetas[17];
+    transferRuleAny: TAccountMetas[18];
+    transferApproval: TAccountMetas[19];
+    accessControlProgram: TAccountMetas[20];
+    exemptAccountRegistry: TAccountMetas[21];
+    attestationIssuerRegistry: TAccountMetas[22];
+    attestationProgram: TAccountMetas[23];
+    attestationFrom: TAccountMetas[24];
+    attestationTo: TAccountMetas[25];
+    policyProgram: TAccountMetas[26];
+    policyConfig: TAccountMetas[27];
   };
   data: ExecuteTransactionInstructionData;
 };
@@ -435,7 +935,7 @@ export function parseExecuteTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedExecuteTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 28) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -457,6 +957,25 @@ export function parseExecuteTransactionInstruction<
       securityAssociatedAccountFrom: getNextAccount(),
       securityAssociatedAccountTo: getNextAccount(),
       transferRule: getNextAccount(),
+      blackoutCalendar: getNextAccount(),
+      transferRestrictionGroupFrom: getNextAccount(),
+      transferRestrictionGroupTo: getNextAccount(),
+      transferRestrictionHolderFrom: getNextAccount(),
+      transferRestrictionHolderTo: getNextAccount(),
+      holderGroupFrom: getNextAccount(),
+      holderGroupTo: getNextAccount(),
+      transferRuleFromAny: getNextAccount(),
+      transferRuleToAny: getNextAccount(),
+      transferRuleAny: getNextAccount(),
+      transferApproval: getNextAccount(),
+      accessControlProgram: getNextAccount(),
+      exemptAccountRegistry: getNextAccount(),
+      attestationIssuerRegistry: getNextAccount(),
+      attestationProgram: getNextAccount(),
+      attestationFrom: getNextAccount(),
+      attestationTo: getNextAccount(),
+      policyProgram: getNextAccount(),
+      policyConfig: getNextAccount(),
     },
     data: getExecuteTransactionInstructionDataDecoder().decode(
       instruction.data
@@ -6,23 +6,60 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addAttestationIssuer';
+export * from './addBlackoutWindow';
+export * from './addWalletHold';
+export * from './detectTransferRestriction';
 export * from './enforceTransferRestrictions';
 export * from './executeTransaction';
+export * from './initializeApprovedVenue';
+export * from './initializeBeneficialOwnerPosition';
+export * from './initializeBlackoutCalendar';
 export * from './initializeDefaultSecurityAccounts';
+export * from './initializeExemptSecurityAssociatedAccount';
 export * from './initializeExtraAccountMetaList';
 export * from './initializeHolderGroup';
 export * from './initializeSecurityAssociatedAccount';
+export * from './initializeTransferApproval';
 export * from './initializeTransferRestrictionGroup';
 export * from './initializeTransferRestrictionHolder';
 export * from './initializeTransferRestrictionsData';
 export * from './initializeTransferRule';
+export * from './mergeHolders';
+export * from './migrateAccounts';
+export * from './migrateWalletsGroup';
+export * from './onboardInvestors';
 export * from './pause';
+export * from './recordMintAcquisition';
+export * from './registerVenueTokenAccount';
+export * from './removeAttestationIssuer';
+export * from './removeBlackoutWindow';
+export * from './removeWalletHold';
+export * from './revokeApprovedVenue';
+export * from './revokeBeneficialOwnerPosition';
 export * from './revokeHolder';
 export * from './revokeHolderGroup';
 export * from './revokeSecurityAssociatedAccount';
+export * from './revokeTransferApproval';
+export * from './revokeTransferRestrictionGroup';
+export * from './revokeTransferRule';
+export * from './revokeVenueTokenAccount';
 export * from './setAddressPermission';
+export * from './setAllowIntraHolderTransfers';
 export * from './setAllowTransferRule';
+export * from './setCountHoldersByBalance';
+export * from './setGroupPaused';
+export * from './setHolderFrozen';
 export * from './setHolderGroupMax';
 export * from './setHolderMax';
+export * from './setHoldingPeriod';
 export * from './setLockupEscrowAccount';
+export * from './setMinHolding';
+export * from './setOmnibusAccount';
+export * from './setPolicyProgram';
+export * from './setTransferRuleApprovalRequired';
+export * from './setTransferRuleValidity';
+export * from './syncFundedWallet';
+export * from './updateBeneficialOwnerPosition';
+export * from './updateExtraAccountMetaList';
 export * from './updateWalletGroup';
@@ -10,8 +10,10 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -34,7 +36,12 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  expectSome,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_TRANSFER_RULE_DISCRIMINATOR = new Uint8Array([
   24, 28, 16, 18, 72, 26, 87, 49,
@@ -145,6 +152,159 @@ export function getInitializeTransferRuleInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type InitializeTransferRuleAsyncInput<
+  TAccountTransferRule extends string = string,
+  TAccountTransferRestrictionData extends string = string,
+  TAccountTransferRestrictionGroupFrom extends string = string,
+  TAccountTransferRestrictionGroupTo extends string = string,
+  TAccountAccessControlAccount extends string = string,
+  TAccountAuthorityWalletRole extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  transferRule?: Address<TAccountTransferRule>;
+  transferRestrictionData: Address<TAccountTransferRestrictionData>;
+  transferRestrictionGroupFrom?: Address<TAccountTransferRestrictionGroupFrom>;
+  transferRestrictionGroupTo?: Address<TAccountTransferRestrictionGroupTo>;
+  accessControlAccount: Address<TAccountAccessControlAccount>;
+  authorityWalletRole: Address<TAccountAuthorityWalletRole>;
+  authority: TransactionSigner<TAccountAuthority>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  transferGroupIdFrom: InitializeTransferRuleInstructionDataArgs['transferGroupIdFrom'];
+  transferGroupIdTo: InitializeTransferRuleInstructionDataArgs['transferGroupIdTo'];
+  lockUntil: InitializeTransferRuleInstructionDataArgs['lockUntil'];
+};
+
+export async function getInitializeTransferRuleInstructionAsync<
+  TAccountTransferRule extends string,
+  TAccountTransferRestrictionData extends string,
+  TAccountTransferRestrictionGroupFrom extends string,
+  TAccountTransferRestrictionGroupTo extends string,
+  TAccountAccessControlAccount extends string,
+  TAccountAuthorityWalletRole extends string,
+  TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address =
+    typeof TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS,
+>(
+  input: InitializeTransferRuleAsyncInput<
+    TAccountTransferRule,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountAccessControlAccount,
+    TAccountAuthorityWalletRole,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  InitializeTransferRuleInstruction<
+    TProgramAddress,
+    TAccountTransferRule,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountAccessControlAccount,
+    TAccountAuthorityWalletRole,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    transferRule: { value: input.transferRule ?? null, isWritable: true },
+    transferRestrictionData: {
+      value: input.transferRestrictionData ?? null,
+      isWritable: false,
+    },
+    transferRestrictionGroupFrom: {
+      value: input.transferRestrictionGroupFrom ?? null,
+      isWritable: false,
+    },
+    transferRestrictionGroupTo: {
+      value: input.transferRestrictionGroupTo ?? null,
+      isWritable: false,
+    },
+    accessControlAccount: {
+      value: input.accessControlAccount ?? null,
+      isWritable: false,
+    },
+    authorityWalletRole: {
+      value: input.authorityWalletRole ?? null,
+      isWritable: false,
+    },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.transferRule.value) {
+    accounts.transferRule.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([116, 114])),
+        getAddressEncoder().encode(
+          expectAddress(accounts.transferRestrictionData.value)
+        ),
+        getU64Encoder().encode(expectSome(args.transferGroupIdFrom)),
+        getU64Encoder().encode(expectSome(args.transferGroupIdTo)),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.transferRule),
+      getAccountMeta(accounts.transferRestrictionData),
+      getAccountMeta(accounts.transferRestrictionGroupFrom),
+      getAccountMeta(accounts.transferRestrictionGroupTo),
+      getAccountMeta(accounts.accessControlAccount),
+      getAccountMeta(accounts.authorityWalletRole),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    data: getInitializeTransferRuleInstructionDataEncoder().encode(
+      args as InitializeTransferRuleInstructionDataArgs
+    ),
+    programAddress,
+  } as InitializeTransferRuleInstruction<
+    TProgramAddress,
+    TAccountTransferRule,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountAccessControlAccount,
+    TAccountAuthorityWalletRole,
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
+  >);
+}
+
 export type InitializeTransferRuleInput<
   TAccountTransferRule extends string = string,
   TAccountTransferRestrictionData extends string = string,
@@ -158,8 +318,8 @@ export type InitializeTransferRuleInput<
 > = {
   transferRule: Address<TAccountTransferRule>;
   transferRestrictionData: Address<TAccountTransferRestrictionData>;
-  transferRestrictionGroupFrom: Address<TAccountTransferRestrictionGroupFrom>;
-  transferRestrictionGroupTo: Address<TAccountTransferRestrictionGroupTo>;
+  transferRestrictionGroupFrom?: Address<TAccountTransferRestrictionGroupFrom>;
+  transferRestrictionGroupTo?: Address<TAccountTransferRestrictionGroupTo>;
   accessControlAccount: Address<TAccountAccessControlAccount>;
   authorityWalletRole: Address<TAccountAuthorityWalletRole>;
   authority: TransactionSigner<TAccountAuthority>;
@@ -291,8 +451,8 @@ export type ParsedInitializeTransferRuleInstruction<
   accounts: {
     transferRule: TAccountMetas[0];
     transferRestrictionData: TAccountMetas[1];
-    transferRestrictionGroupFrom: TAccountMetas[2];
-    transferRestrictionGroupTo: TAccountMetas[3];
+    transferRestrictionGroupFrom?: TAccountMetas[2] | undefined;
+    transferRestrictionGroupTo?: TAccountMetas[3] | undefined;
     accessControlAccount: TAccountMetas[4];
     authorityWalletRole: TAccountMetas[5];
     authority: TAccountMetas[6];
@@ -320,13 +480,19 @@ export function parseInitializeTransferRuleInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       transferRule: getNextAccount(),
       transferRestrictionData: getNextAccount(),
-      transferRestrictionGroupFrom: getNextAccount(),
-      transferRestrictionGroupTo: getNextAccount(),
+      transferRestrictionGroupFrom: getNextOptionalAccount(),
+      transferRestrictionGroupTo: getNextOptionalAccount(),
       accessControlAccount: getNextAccount(),
       authorityWalletRole: getNextAccount(),
       authority: getNextAccount(),
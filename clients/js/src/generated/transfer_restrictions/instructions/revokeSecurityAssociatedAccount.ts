@@ -87,7 +87,7 @@ export type RevokeSecurityAssociatedAccountInstruction<
         ? ReadonlyAccount<TAccountSecurityToken>
         : TAccountSecurityToken,
       TAccountTransferRestrictionData extends string
-        ? ReadonlyAccount<TAccountTransferRestrictionData>
+        ? WritableAccount<TAccountTransferRestrictionData>
         : TAccountTransferRestrictionData,
       TAccountUserWallet extends string
         ? ReadonlyAccount<TAccountUserWallet>
@@ -166,7 +166,7 @@ export type RevokeSecurityAssociatedAccountAsyncInput<
   securityToken: Address<TAccountSecurityToken>;
   transferRestrictionData?: Address<TAccountTransferRestrictionData>;
   userWallet: Address<TAccountUserWallet>;
-  associatedTokenAccount?: Address<TAccountAssociatedTokenAccount>;
+  associatedTokenAccount: Address<TAccountAssociatedTokenAccount>;
   authorityWalletRole: Address<TAccountAuthorityWalletRole>;
   authority: TransactionSigner<TAccountAuthority>;
   payer: TransactionSigner<TAccountPayer>;
@@ -237,7 +237,7 @@ export async function getRevokeSecurityAssociatedAccountInstructionAsync<
     securityToken: { value: input.securityToken ?? null, isWritable: false },
     transferRestrictionData: {
       value: input.transferRestrictionData ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     userWallet: { value: input.userWallet ?? null, isWritable: false },
     associatedTokenAccount: {
@@ -258,23 +258,6 @@ export async function getRevokeSecurityAssociatedAccountInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.associatedTokenAccount.value) {
-    accounts.associatedTokenAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
-      seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.userWallet.value)),
-        getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108,
-            205, 218, 182, 26, 252, 77, 131, 185, 13, 39, 254, 189, 249, 40,
-            216, 161, 139, 252,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.securityToken.value)),
-      ],
-    });
-  }
   if (!accounts.securityAssociatedAccount.value) {
     accounts.securityAssociatedAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -425,7 +408,7 @@ export function getRevokeSecurityAssociatedAccountInstruction<
     securityToken: { value: input.securityToken ?? null, isWritable: false },
     transferRestrictionData: {
       value: input.transferRestrictionData ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     userWallet: { value: input.userWallet ?? null, isWritable: false },
     associatedTokenAccount: {
@@ -83,6 +83,7 @@ export type SetAddressPermissionInstruction<
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountExemptAccountRegistry extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -144,6 +145,9 @@ export type SetAddressPermissionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountExemptAccountRegistry extends string
+        ? ReadonlyAccount<TAccountExemptAccountRegistry>
+        : TAccountExemptAccountRegistry,
       ...TRemainingAccounts,
     ]
   >;
@@ -210,6 +214,7 @@ export type SetAddressPermissionAsyncInput<
   TAccountPayer extends string = string,
   TAccountAuthority extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   securityAssociatedAccount?: Address<TAccountSecurityAssociatedAccount>;
   transferRestrictionGroupNew?: Address<TAccountTransferRestrictionGroupNew>;
@@ -220,7 +225,7 @@ export type SetAddressPermissionAsyncInput<
   securityToken: Address<TAccountSecurityToken>;
   transferRestrictionData?: Address<TAccountTransferRestrictionData>;
   userWallet: Address<TAccountUserWallet>;
-  userAssociatedTokenAccount?: Address<TAccountUserAssociatedTokenAccount>;
+  userAssociatedTokenAccount: Address<TAccountUserAssociatedTokenAccount>;
   authorityWalletRole: Address<TAccountAuthorityWalletRole>;
   securityMint: Address<TAccountSecurityMint>;
   accessControlAccount?: Address<TAccountAccessControlAccount>;
@@ -229,6 +234,8 @@ export type SetAddressPermissionAsyncInput<
   payer: TransactionSigner<TAccountPayer>;
   authority: TransactionSigner<TAccountAuthority>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** control program */
+  exemptAccountRegistry: Address<TAccountExemptAccountRegistry>;
   groupId: SetAddressPermissionInstructionDataArgs['groupId'];
   frozen: SetAddressPermissionInstructionDataArgs['frozen'];
 };
@@ -252,6 +259,7 @@ export async function getSetAddressPermissionInstructionAsync<
   TAccountPayer extends string,
   TAccountAuthority extends string,
   TAccountSystemProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address =
     typeof TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS,
 >(
@@ -273,7 +281,8 @@ export async function getSetAddressPermissionInstructionAsync<
     TAccountTokenProgram,
     TAccountPayer,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -296,7 +305,8 @@ export async function getSetAddressPermissionInstructionAsync<
     TAccountTokenProgram,
     TAccountPayer,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >
 > {
   // Program address.
@@ -353,6 +363,10 @@ export async function getSetAddressPermissionInstructionAsync<
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -363,23 +377,6 @@ export async function getSetAddressPermissionInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.userAssociatedTokenAccount.value) {
-    accounts.userAssociatedTokenAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
-      seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.userWallet.value)),
-        getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108,
-            205, 218, 182, 26, 252, 77, 131, 185, 13, 39, 254, 189, 249, 40,
-            216, 161, 139, 252,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.securityToken.value)),
-      ],
-    });
-  }
   if (!accounts.securityAssociatedAccount.value) {
     accounts.securityAssociatedAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -469,6 +466,7 @@ export async function getSetAddressPermissionInstructionAsync<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getSetAddressPermissionInstructionDataEncoder().encode(
       args as SetAddressPermissionInstructionDataArgs
@@ -493,7 +491,8 @@ export async function getSetAddressPermissionInstructionAsync<
     TAccountTokenProgram,
     TAccountPayer,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -516,6 +515,7 @@ export type SetAddressPermissionInput<
   TAccountPayer extends string = string,
   TAccountAuthority extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountExemptAccountRegistry extends string = string,
 > = {
   securityAssociatedAccount: Address<TAccountSecurityAssociatedAccount>;
   transferRestrictionGroupNew: Address<TAccountTransferRestrictionGroupNew>;
@@ -535,6 +535,8 @@ export type SetAddressPermissionInput<
   payer: TransactionSigner<TAccountPayer>;
   authority: TransactionSigner<TAccountAuthority>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** control program */
+  exemptAccountRegistry: Address<TAccountExemptAccountRegistry>;
   groupId: SetAddressPermissionInstructionDataArgs['groupId'];
   frozen: SetAddressPermissionInstructionDataArgs['frozen'];
 };
@@ -558,6 +560,7 @@ export function getSetAddressPermissionInstruction<
   TAccountPayer extends string,
   TAccountAuthority extends string,
   TAccountSystemProgram extends string,
+  TAccountExemptAccountRegistry extends string,
   TProgramAddress extends Address =
     typeof TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS,
 >(
@@ -579,7 +582,8 @@ export function getSetAddressPermissionInstruction<
     TAccountTokenProgram,
     TAccountPayer,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetAddressPermissionInstruction<
@@ -601,7 +605,8 @@ export function getSetAddressPermissionInstruction<
   TAccountTokenProgram,
   TAccountPayer,
   TAccountAuthority,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountExemptAccountRegistry
 > {
   // Program address.
   const programAddress =
@@ -657,6 +662,10 @@ export function getSetAddressPermissionInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    exemptAccountRegistry: {
+      value: input.exemptAccountRegistry ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -701,6 +710,7 @@ export function getSetAddressPermissionInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.exemptAccountRegistry),
     ],
     data: getSetAddressPermissionInstructionDataEncoder().encode(
       args as SetAddressPermissionInstructionDataArgs
@@ -725,7 +735,8 @@ export function getSetAddressPermissionInstruction<
     TAccountTokenProgram,
     TAccountPayer,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountExemptAccountRegistry
   >);
 }
 
@@ -753,6 +764,8 @@ export type ParsedSetAddressPermissionInstruction<
     payer: TAccountMetas[15];
     authority: TAccountMetas[16];
     systemProgram: TAccountMetas[17];
+    /** control program */
+    exemptAccountRegistry: TAccountMetas[18];
   };
   data: SetAddressPermissionInstructionData;
 };
@@ -765,7 +778,7 @@ export function parseSetAddressPermissionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetAddressPermissionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 18) {
+  if (instruction.accounts.length < 19) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -802,6 +815,7 @@ export function parseSetAddressPermissionInstruction<
       payer: getNextAccount(),
       authority: getNextAccount(),
       systemProgram: getNextAccount(),
+      exemptAccountRegistry: getNextAccount(),
     },
     data: getSetAddressPermissionInstructionDataDecoder().decode(
       instruction.data
@@ -10,8 +10,10 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -33,7 +35,12 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  expectSome,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const SET_ALLOW_TRANSFER_RULE_DISCRIMINATOR = new Uint8Array([
   4, 83, 246, 172, 106, 193, 31, 116,
@@ -134,6 +141,139 @@ export function getSetAllowTransferRuleInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type SetAllowTransferRuleAsyncInput<
+  TAccountTransferRule extends string = string,
+  TAccountTransferRestrictionData extends string = string,
+  TAccountTransferRestrictionGroupFrom extends string = string,
+  TAccountTransferRestrictionGroupTo extends string = string,
+  TAccountAccessControlAccount extends string = string,
+  TAccountAuthorityWalletRole extends string = string,
+  TAccountPayer extends string = string,
+> = {
+  transferRule?: Address<TAccountTransferRule>;
+  transferRestrictionData: Address<TAccountTransferRestrictionData>;
+  transferRestrictionGroupFrom?: Address<TAccountTransferRestrictionGroupFrom>;
+  transferRestrictionGroupTo?: Address<TAccountTransferRestrictionGroupTo>;
+  accessControlAccount: Address<TAccountAccessControlAccount>;
+  authorityWalletRole: Address<TAccountAuthorityWalletRole>;
+  payer: TransactionSigner<TAccountPayer>;
+  transferGroupIdFrom: SetAllowTransferRuleInstructionDataArgs['transferGroupIdFrom'];
+  transferGroupIdTo: SetAllowTransferRuleInstructionDataArgs['transferGroupIdTo'];
+  lockedUntil: SetAllowTransferRuleInstructionDataArgs['lockedUntil'];
+};
+
+export async function getSetAllowTransferRuleInstructionAsync<
+  TAccountTransferRule extends string,
+  TAccountTransferRestrictionData extends string,
+  TAccountTransferRestrictionGroupFrom extends string,
+  TAccountTransferRestrictionGroupTo extends string,
+  TAccountAccessControlAccount extends string,
+  TAccountAuthorityWalletRole extends string,
+  TAccountPayer extends string,
+  TProgramAddress extends Address =
+    typeof TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS,
+>(
+  input: SetAllowTransferRuleAsyncInput<
+    TAccountTransferRule,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountAccessControlAccount,
+    TAccountAuthorityWalletRole,
+    TAccountPayer
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  SetAllowTransferRuleInstruction<
+    TProgramAddress,
+    TAccountTransferRule,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountAccessControlAccount,
+    TAccountAuthorityWalletRole,
+    TAccountPayer
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    transferRule: { value: input.transferRule ?? null, isWritable: true },
+    transferRestrictionData: {
+      value: input.transferRestrictionData ?? null,
+      isWritable: false,
+    },
+    transferRestrictionGroupFrom: {
+      value: input.transferRestrictionGroupFrom ?? null,
+      isWritable: false,
+    },
+    transferRestrictionGroupTo: {
+      value: input.transferRestrictionGroupTo ?? null,
+      isWritable: false,
+    },
+    accessControlAccount: {
+      value: input.accessControlAccount ?? null,
+      isWritable: false,
+    },
+    authorityWalletRole: {
+      value: input.authorityWalletRole ?? null,
+      isWritable: false,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.transferRule.value) {
+    accounts.transferRule.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([116, 114])),
+        getAddressEncoder().encode(
+          expectAddress(accounts.transferRestrictionData.value)
+        ),
+        getU64Encoder().encode(expectSome(args.transferGroupIdFrom)),
+        getU64Encoder().encode(expectSome(args.transferGroupIdTo)),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.transferRule),
+      getAccountMeta(accounts.transferRestrictionData),
+      getAccountMeta(accounts.transferRestrictionGroupFrom),
+      getAccountMeta(accounts.transferRestrictionGroupTo),
+      getAccountMeta(accounts.accessControlAccount),
+      getAccountMeta(accounts.authorityWalletRole),
+      getAccountMeta(accounts.payer),
+    ],
+    data: getSetAllowTransferRuleInstructionDataEncoder().encode(
+      args as SetAllowTransferRuleInstructionDataArgs
+    ),
+    programAddress,
+  } as SetAllowTransferRuleInstruction<
+    TProgramAddress,
+    TAccountTransferRule,
+    TAccountTransferRestrictionData,
+    TAccountTransferRestrictionGroupFrom,
+    TAccountTransferRestrictionGroupTo,
+    TAccountAccessControlAccount,
+    TAccountAuthorityWalletRole,
+    TAccountPayer
+  >);
+}
+
 export type SetAllowTransferRuleInput<
   TAccountTransferRule extends string = string,
   TAccountTransferRestrictionData extends string = string,
@@ -145,8 +285,8 @@ export type SetAllowTransferRuleInput<
 > = {
   transferRule: Address<TAccountTransferRule>;
   transferRestrictionData: Address<TAccountTransferRestrictionData>;
-  transferRestrictionGroupFrom: Address<TAccountTransferRestrictionGroupFrom>;
-  transferRestrictionGroupTo: Address<TAccountTransferRestrictionGroupTo>;
+  transferRestrictionGroupFrom?: Address<TAccountTransferRestrictionGroupFrom>;
+  transferRestrictionGroupTo?: Address<TAccountTransferRestrictionGroupTo>;
   accessControlAccount: Address<TAccountAccessControlAccount>;
   authorityWalletRole: Address<TAccountAuthorityWalletRole>;
   payer: TransactionSigner<TAccountPayer>;
@@ -258,8 +398,8 @@ export type ParsedSetAllowTransferRuleInstruction<
   accounts: {
     transferRule: TAccountMetas[0];
     transferRestrictionData: TAccountMetas[1];
-    transferRestrictionGroupFrom: TAccountMetas[2];
-    transferRestrictionGroupTo: TAccountMetas[3];
+    transferRestrictionGroupFrom?: TAccountMetas[2] | undefined;
+    transferRestrictionGroupTo?: TAccountMetas[3] | undefined;
     accessControlAccount: TAccountMetas[4];
     authorityWalletRole: TAccountMetas[5];
     payer: TAccountMetas[6];
@@ -285,13 +425,19 @@ export function parseSetAllowTransferRuleInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       transferRule: getNextAccount(),
       transferRestrictionData: getNextAccount(),
-      transferRestrictionGroupFrom: getNextAccount(),
-      transferRestrictionGroupTo: getNextAccount(),
+      transferRestrictionGroupFrom: getNextOptionalAccount(),
+      transferRestrictionGroupTo: getNextOptionalAccount(),
       accessControlAccount: getNextAccount(),
       authorityWalletRole: getNextAccount(),
       payer: getNextAccount(),
@@ -175,7 +175,7 @@ export type UpdateWalletGroupAsyncInput<
   holderGroupNew: Address<TAccountHolderGroupNew>;
   authorityWalletRole: Address<TAccountAuthorityWalletRole>;
   userWallet: Address<TAccountUserWallet>;
-  userAssociatedTokenAccount?: Address<TAccountUserAssociatedTokenAccount>;
+  userAssociatedTokenAccount: Address<TAccountUserAssociatedTokenAccount>;
   payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   newGroupId: UpdateWalletGroupInstructionDataArgs['newGroupId'];
@@ -278,23 +278,6 @@ export async function getUpdateWalletGroupInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.userAssociatedTokenAccount.value) {
-    accounts.userAssociatedTokenAccount.value = await getProgramDerivedAddress({
-      programAddress:
-        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
-      seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.userWallet.value)),
-        getBytesEncoder().encode(
-          new Uint8Array([
-            6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108,
-            205, 218, 182, 26, 252, 77, 131, 185, 13, 39, 254, 189, 249, 40,
-            216, 161, 139, 252,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.securityToken.value)),
-      ],
-    });
-  }
   if (!accounts.securityAssociatedAccount.value) {
     accounts.securityAssociatedAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -14,25 +14,62 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAddAttestationIssuerInstruction,
+  type ParsedAddBlackoutWindowInstruction,
+  type ParsedAddWalletHoldInstruction,
+  type ParsedDetectTransferRestrictionInstruction,
   type ParsedEnforceTransferRestrictionsInstruction,
   type ParsedExecuteTransactionInstruction,
+  type ParsedInitializeApprovedVenueInstruction,
+  type ParsedInitializeBeneficialOwnerPositionInstruction,
+  type ParsedInitializeBlackoutCalendarInstruction,
   type ParsedInitializeDefaultSecurityAccountsInstruction,
+  type ParsedInitializeExemptSecurityAssociatedAccountInstruction,
   type ParsedInitializeExtraAccountMetaListInstruction,
   type ParsedInitializeHolderGroupInstruction,
   type ParsedInitializeSecurityAssociatedAccountInstruction,
+  type ParsedInitializeTransferApprovalInstruction,
   type ParsedInitializeTransferRestrictionGroupInstruction,
   type ParsedInitializeTransferRestrictionHolderInstruction,
   type ParsedInitializeTransferRestrictionsDataInstruction,
   type ParsedInitializeTransferRuleInstruction,
+  type ParsedMergeHoldersInstruction,
+  type ParsedMigrateAccountsInstruction,
+  type ParsedMigrateWalletsGroupInstruction,
+  type ParsedOnboardInvestorsInstruction,
   type ParsedPauseInstruction,
+  type ParsedRecordMintAcquisitionInstruction,
+  type ParsedRegisterVenueTokenAccountInstruction,
+  type ParsedRemoveAttestationIssuerInstruction,
+  type ParsedRemoveBlackoutWindowInstruction,
+  type ParsedRemoveWalletHoldInstruction,
+  type ParsedRevokeApprovedVenueInstruction,
+  type ParsedRevokeBeneficialOwnerPositionInstruction,
   type ParsedRevokeHolderGroupInstruction,
   type ParsedRevokeHolderInstruction,
   type ParsedRevokeSecurityAssociatedAccountInstruction,
+  type ParsedRevokeTransferApprovalInstruction,
+  type ParsedRevokeTransferRestrictionGroupInstruction,
+  type ParsedRevokeTransferRuleInstruction,
+  type ParsedRevokeVenueTokenAccountInstruction,
   type ParsedSetAddressPermissionInstruction,
+  type ParsedSetAllowIntraHolderTransfersInstruction,
   type ParsedSetAllowTransferRuleInstruction,
+  type ParsedSetCountHoldersByBalanceInstruction,
+  type ParsedSetGroupPausedInstruction,
+  type ParsedSetHolderFrozenInstruction,
   type ParsedSetHolderGroupMaxInstruction,
   type ParsedSetHolderMaxInstruction,
+  type ParsedSetHoldingPeriodInstruction,
   type ParsedSetLockupEscrowAccountInstruction,
+  type ParsedSetMinHoldingInstruction,
+  type ParsedSetOmnibusAccountInstruction,
+  type ParsedSetPolicyProgramInstruction,
+  type ParsedSetTransferRuleApprovalRequiredInstruction,
+  type ParsedSetTransferRuleValidityInstruction,
+  type ParsedSyncFundedWalletInstruction,
+  type ParsedUpdateBeneficialOwnerPositionInstruction,
+  type ParsedUpdateExtraAccountMetaListInstruction,
   type ParsedUpdateWalletGroupInstruction,
 } from '../instructions';
 
@@ -41,8 +78,13 @@ export const TRANSFER_RESTRICTIONS_PROGRAM_ADDRESS =
 
 export enum TransferRestrictionsAccount {
   AccessControl,
+  ApprovedVenue,
+  AttestationIssuerRegistry,
+  BeneficialOwnerPosition,
+  BlackoutCalendar,
   HolderGroup,
   SecurityAssociatedAccount,
+  TransferApproval,
   TransferRestrictionData,
   TransferRestrictionGroup,
   TransferRestrictionHolder,
@@ -65,6 +107,50 @@ export function identifyTransferRestrictionsAccount(
   ) {
     return TransferRestrictionsAccount.AccessControl;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([224, 141, 22, 39, 234, 148, 222, 70])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsAccount.ApprovedVenue;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([31, 165, 235, 106, 193, 201, 137, 95])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsAccount.AttestationIssuerRegistry;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([248, 206, 52, 64, 41, 111, 59, 161])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsAccount.BeneficialOwnerPosition;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 66, 206, 56, 158, 127, 145, 134])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsAccount.BlackoutCalendar;
+  }
   if (
     containsBytes(
       data,
@@ -87,6 +173,17 @@ export function identifyTransferRestrictionsAccount(
   ) {
     return TransferRestrictionsAccount.SecurityAssociatedAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([153, 181, 122, 112, 80, 6, 154, 216])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsAccount.TransferApproval;
+  }
   if (
     containsBytes(
       data,
@@ -148,25 +245,62 @@ export function identifyTransferRestrictionsAccount(
 }
 
 export enum TransferRestrictionsInstruction {
+  AddAttestationIssuer,
+  AddBlackoutWindow,
+  AddWalletHold,
+  DetectTransferRestriction,
   EnforceTransferRestrictions,
   ExecuteTransaction,
+  InitializeApprovedVenue,
+  InitializeBeneficialOwnerPosition,
+  InitializeBlackoutCalendar,
   InitializeDefaultSecurityAccounts,
+  InitializeExemptSecurityAssociatedAccount,
   InitializeExtraAccountMetaList,
   InitializeHolderGroup,
   InitializeSecurityAssociatedAccount,
+  InitializeTransferApproval,
   InitializeTransferRestrictionGroup,
   InitializeTransferRestrictionHolder,
   InitializeTransferRestrictionsData,
   InitializeTransferRule,
+  MergeHolders,
+  MigrateAccounts,
+  MigrateWalletsGroup,
+  OnboardInvestors,
   Pause,
+  RecordMintAcquisition,
+  RegisterVenueTokenAccount,
+  RemoveAttestationIssuer,
+  RemoveBlackoutWindow,
+  RemoveWalletHold,
+  RevokeApprovedVenue,
+  RevokeBeneficialOwnerPosition,
   RevokeHolder,
   RevokeHolderGroup,
   RevokeSecurityAssociatedAccount,
+  RevokeTransferApproval,
+  RevokeTransferRestrictionGroup,
+  RevokeTransferRule,
+  RevokeVenueTokenAccount,
   SetAddressPermission,
+  SetAllowIntraHolderTransfers,
   SetAllowTransferRule,
+  SetCountHoldersByBalance,
+  SetGroupPaused,
+  SetHolderFrozen,
   SetHolderGroupMax,
   SetHolderMax,
+  SetHoldingPeriod,
   SetLockupEscrowAccount,
+  SetMinHolding,
+  SetOmnibusAccount,
+  SetPolicyProgram,
+  SetTransferRuleApprovalRequired,
+  SetTransferRuleValidity,
+  SyncFundedWallet,
+  UpdateBeneficialOwnerPosition,
+  UpdateExtraAccountMetaList,
   UpdateWalletGroup,
 }
 
@@ -174,6 +308,50 @@ export function identifyTransferRestrictionsInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): TransferRestrictionsInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 43, 35, 54, 59, 108, 142, 149])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.AddAttestationIssuer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([96, 97, 58, 70, 173, 129, 107, 185])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.AddBlackoutWindow;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([251, 228, 153, 149, 31, 234, 235, 119])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.AddWalletHold;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([151, 28, 141, 250, 88, 74, 27, 74])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.DetectTransferRestriction;
+  }
   if (
     containsBytes(
       data,
@@ -196,6 +374,39 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.ExecuteTransaction;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([114, 149, 107, 206, 226, 152, 252, 49])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.InitializeApprovedVenue;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([12, 185, 32, 52, 150, 207, 46, 11])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.InitializeBeneficialOwnerPosition;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([243, 135, 238, 164, 188, 37, 162, 198])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.InitializeBlackoutCalendar;
+  }
   if (
     containsBytes(
       data,
@@ -207,6 +418,17 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.InitializeDefaultSecurityAccounts;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([204, 18, 100, 253, 106, 243, 177, 143])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.InitializeExemptSecurityAssociatedAccount;
+  }
   if (
     containsBytes(
       data,
@@ -240,6 +462,17 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.InitializeSecurityAssociatedAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([245, 144, 99, 58, 99, 1, 237, 146])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.InitializeTransferApproval;
+  }
   if (
     containsBytes(
       data,
@@ -284,6 +517,50 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.InitializeTransferRule;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([209, 130, 155, 238, 90, 0, 171, 22])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.MergeHolders;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([216, 43, 0, 164, 197, 239, 168, 37])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.MigrateAccounts;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([146, 96, 49, 153, 3, 118, 201, 238])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.MigrateWalletsGroup;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([2, 49, 226, 205, 28, 216, 57, 110])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.OnboardInvestors;
+  }
   if (
     containsBytes(
       data,
@@ -295,6 +572,83 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.Pause;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([103, 3, 124, 134, 222, 181, 87, 181])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RecordMintAcquisition;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([188, 229, 191, 144, 109, 63, 99, 226])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RegisterVenueTokenAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 182, 61, 78, 33, 243, 103, 24])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RemoveAttestationIssuer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([150, 178, 225, 79, 125, 28, 67, 126])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RemoveBlackoutWindow;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([154, 84, 89, 196, 174, 63, 229, 87])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RemoveWalletHold;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([8, 157, 129, 7, 161, 11, 71, 0])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RevokeApprovedVenue;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([162, 86, 10, 55, 39, 212, 140, 86])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RevokeBeneficialOwnerPosition;
+  }
   if (
     containsBytes(
       data,
@@ -328,6 +682,50 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.RevokeSecurityAssociatedAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([129, 126, 193, 169, 79, 234, 96, 147])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RevokeTransferApproval;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([13, 3, 188, 119, 14, 51, 131, 124])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RevokeTransferRestrictionGroup;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([188, 61, 201, 116, 238, 47, 112, 11])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RevokeTransferRule;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([231, 171, 38, 143, 26, 16, 50, 3])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.RevokeVenueTokenAccount;
+  }
   if (
     containsBytes(
       data,
@@ -339,6 +737,17 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.SetAddressPermission;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([103, 108, 220, 212, 203, 150, 239, 110])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetAllowIntraHolderTransfers;
+  }
   if (
     containsBytes(
       data,
@@ -350,6 +759,39 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.SetAllowTransferRule;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([110, 18, 226, 211, 207, 224, 96, 238])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetCountHoldersByBalance;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([41, 7, 218, 220, 12, 146, 134, 20])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetGroupPaused;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([131, 67, 117, 236, 241, 205, 102, 91])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetHolderFrozen;
+  }
   if (
     containsBytes(
       data,
@@ -372,6 +814,17 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.SetHolderMax;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([6, 111, 165, 220, 239, 242, 146, 1])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetHoldingPeriod;
+  }
   if (
     containsBytes(
       data,
@@ -383,6 +836,94 @@ export function identifyTransferRestrictionsInstruction(
   ) {
     return TransferRestrictionsInstruction.SetLockupEscrowAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([165, 255, 148, 251, 75, 221, 3, 199])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetMinHolding;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([2, 61, 238, 83, 185, 33, 12, 17])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetOmnibusAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([221, 164, 107, 199, 247, 226, 212, 107])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetPolicyProgram;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([61, 19, 187, 249, 43, 69, 197, 50])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetTransferRuleApprovalRequired;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([97, 151, 251, 27, 116, 219, 146, 116])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SetTransferRuleValidity;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([120, 48, 197, 36, 58, 66, 49, 39])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.SyncFundedWallet;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([55, 244, 110, 40, 39, 11, 242, 43])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.UpdateBeneficialOwnerPosition;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([44, 125, 141, 226, 97, 179, 166, 96])
+      ),
+      0
+    )
+  ) {
+    return TransferRestrictionsInstruction.UpdateExtraAccountMetaList;
+  }
   if (
     containsBytes(
       data,
@@ -402,15 +943,39 @@ export function identifyTransferRestrictionsInstruction(
 export type ParsedTransferRestrictionsInstruction<
   TProgram extends string = '6yEnqdEjX3zBBDkzhwTRGJwv1jRaN4QE4gywmgdcfPBZ',
 > =
+  | ({
+      instructionType: TransferRestrictionsInstruction.AddAttestationIssuer;
+    } & ParsedAddAttestationIssuerInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.AddBlackoutWindow;
+    } & ParsedAddBlackoutWindowInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.AddWalletHold;
+    } & ParsedAddWalletHoldInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.DetectTransferRestriction;
+    } & ParsedDetectTransferRestrictionInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.EnforceTransferRestrictions;
     } & ParsedEnforceTransferRestrictionsInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.ExecuteTransaction;
     } & ParsedExecuteTransactionInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.InitializeApprovedVenue;
+    } & ParsedInitializeApprovedVenueInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.InitializeBeneficialOwnerPosition;
+    } & ParsedInitializeBeneficialOwnerPositionInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.InitializeBlackoutCalendar;
+    } & ParsedInitializeBlackoutCalendarInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.InitializeDefaultSecurityAccounts;
     } & ParsedInitializeDefaultSecurityAccountsInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.InitializeExemptSecurityAssociatedAccount;
+    } & ParsedInitializeExemptSecurityAssociatedAccountInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.InitializeExtraAccountMetaList;
     } & ParsedInitializeExtraAccountMetaListInstruction<TProgram>)
@@ -420,6 +985,9 @@ export type ParsedTransferRestrictionsInstruction<
   | ({
       instructionType: TransferRestrictionsInstruction.InitializeSecurityAssociatedAccount;
     } & ParsedInitializeSecurityAssociatedAccountInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.InitializeTransferApproval;
+    } & ParsedInitializeTransferApprovalInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.InitializeTransferRestrictionGroup;
     } & ParsedInitializeTransferRestrictionGroupInstruction<TProgram>)
@@ -432,9 +1000,42 @@ export type ParsedTransferRestrictionsInstruction<
   | ({
       instructionType: TransferRestrictionsInstruction.InitializeTransferRule;
     } & ParsedInitializeTransferRuleInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.MergeHolders;
+    } & ParsedMergeHoldersInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.MigrateAccounts;
+    } & ParsedMigrateAccountsInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.MigrateWalletsGroup;
+    } & ParsedMigrateWalletsGroupInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.OnboardInvestors;
+    } & ParsedOnboardInvestorsInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.Pause;
     } & ParsedPauseInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RecordMintAcquisition;
+    } & ParsedRecordMintAcquisitionInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RegisterVenueTokenAccount;
+    } & ParsedRegisterVenueTokenAccountInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RemoveAttestationIssuer;
+    } & ParsedRemoveAttestationIssuerInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RemoveBlackoutWindow;
+    } & ParsedRemoveBlackoutWindowInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RemoveWalletHold;
+    } & ParsedRemoveWalletHoldInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RevokeApprovedVenue;
+    } & ParsedRevokeApprovedVenueInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RevokeBeneficialOwnerPosition;
+    } & ParsedRevokeBeneficialOwnerPositionInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.RevokeHolder;
     } & ParsedRevokeHolderInstruction<TProgram>)
@@ -444,21 +1045,72 @@ export type ParsedTransferRestrictionsInstruction<
   | ({
       instructionType: TransferRestrictionsInstruction.RevokeSecurityAssociatedAccount;
     } & ParsedRevokeSecurityAssociatedAccountInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RevokeTransferApproval;
+    } & ParsedRevokeTransferApprovalInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RevokeTransferRestrictionGroup;
+    } & ParsedRevokeTransferRestrictionGroupInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RevokeTransferRule;
+    } & ParsedRevokeTransferRuleInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.RevokeVenueTokenAccount;
+    } & ParsedRevokeVenueTokenAccountInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.SetAddressPermission;
     } & ParsedSetAddressPermissionInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetAllowIntraHolderTransfers;
+    } & ParsedSetAllowIntraHolderTransfersInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.SetAllowTransferRule;
     } & ParsedSetAllowTransferRuleInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetCountHoldersByBalance;
+    } & ParsedSetCountHoldersByBalanceInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetGroupPaused;
+    } & ParsedSetGroupPausedInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetHolderFrozen;
+    } & ParsedSetHolderFrozenInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.SetHolderGroupMax;
     } & ParsedSetHolderGroupMaxInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.SetHolderMax;
     } & ParsedSetHolderMaxInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetHoldingPeriod;
+    } & ParsedSetHoldingPeriodInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.SetLockupEscrowAccount;
     } & ParsedSetLockupEscrowAccountInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetMinHolding;
+    } & ParsedSetMinHoldingInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetOmnibusAccount;
+    } & ParsedSetOmnibusAccountInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetPolicyProgram;
+    } & ParsedSetPolicyProgramInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetTransferRuleApprovalRequired;
+    } & ParsedSetTransferRuleApprovalRequiredInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SetTransferRuleValidity;
+    } & ParsedSetTransferRuleValidityInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.SyncFundedWallet;
+    } & ParsedSyncFundedWalletInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.UpdateBeneficialOwnerPosition;
+    } & ParsedUpdateBeneficialOwnerPositionInstruction<TProgram>)
+  | ({
+      instructionType: TransferRestrictionsInstruction.UpdateExtraAccountMetaList;
+    } & ParsedUpdateExtraAccountMetaListInstruction<TProgram>)
   | ({
       instructionType: TransferRestrictionsInstruction.UpdateWalletGroup;
     } & ParsedUpdateWalletGroupInstruction<TProgram>);
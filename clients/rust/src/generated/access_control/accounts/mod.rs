@@ -6,7 +6,9 @@
 //!
 
 pub(crate) mod r#access_control;
+pub(crate) mod r#exempt_account_registry;
 pub(crate) mod r#wallet_role;
 
 pub use self::r#access_control::*;
+pub use self::r#exempt_account_registry::*;
 pub use self::r#wallet_role::*;
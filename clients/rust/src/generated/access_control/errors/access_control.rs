@@ -64,6 +64,33 @@ pub enum AccessControlError {
     /// 6017 - Invalid wallet role account owner
     #[error("Invalid wallet role account owner")]
     InvalidWalletRoleAccountOwner = 0x1781,
+    /// 6018 - Invalid exempt account kind
+    #[error("Invalid exempt account kind")]
+    InvalidExemptAccountKind = 0x1782,
+    /// 6019 - Token account is already registered as exempt account
+    #[error("Token account is already registered as exempt account")]
+    ExemptAccountAlreadyRegistered = 0x1783,
+    /// 6020 - Exempt accounts limit reached
+    #[error("Exempt accounts limit reached")]
+    ExemptAccountsLimitReached = 0x1784,
+    /// 6021 - Invalid exempt account index
+    #[error("Invalid exempt account index")]
+    InvalidExemptAccountIndex = 0x1785,
+    /// 6022 - Cannot freeze exempt account
+    #[error("Cannot freeze exempt account")]
+    CannotFreezeExemptAccount = 0x1786,
+    /// 6023 - Cannot force transfer between exempt accounts
+    #[error("Cannot force transfer between exempt accounts")]
+    CantForceTransferBetweenExemptAccounts = 0x1787,
+    /// 6024 - Transfer restriction accounts are required
+    #[error("Transfer restriction accounts are required")]
+    TransferRestrictionAccountsRequired = 0x1788,
+    /// 6025 - Invalid transfer restrictions program
+    #[error("Invalid transfer restrictions program")]
+    InvalidTransferRestrictionsProgram = 0x1789,
+    /// 6026 - Exempt account registry is required
+    #[error("Exempt account registry is required")]
+    ExemptAccountRegistryRequired = 0x178A,
 }
 
 impl From<AccessControlError> for solana_program_error::ProgramError {
@@ -26,6 +26,8 @@ pub struct BurnSecurities {
     pub target_authority: solana_pubkey::Pubkey,
 
     pub token_program: solana_pubkey::Pubkey,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<solana_pubkey::Pubkey>,
 }
 
 impl BurnSecurities {
@@ -42,7 +44,7 @@ impl BurnSecurities {
         args: BurnSecuritiesInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
             true,
@@ -71,6 +73,17 @@ impl BurnSecurities {
             self.token_program,
             false,
         ));
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                exempt_account_registry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = BurnSecuritiesInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -131,6 +144,7 @@ impl BurnSecuritiesInstructionArgs {
 ///   4. `[writable]` target_account
 ///   5. `[]` target_authority
 ///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   7. `[optional]` exempt_account_registry
 #[derive(Clone, Debug, Default)]
 pub struct BurnSecuritiesBuilder {
     authority: Option<solana_pubkey::Pubkey>,
@@ -140,6 +154,7 @@ pub struct BurnSecuritiesBuilder {
     target_account: Option<solana_pubkey::Pubkey>,
     target_authority: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    exempt_account_registry: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -187,6 +202,16 @@ impl BurnSecuritiesBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Optional: Required once the exempt account registry has exempt accounts
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.exempt_account_registry = exempt_account_registry;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -221,6 +246,7 @@ impl BurnSecuritiesBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            exempt_account_registry: self.exempt_account_registry,
         };
         let args = BurnSecuritiesInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -245,6 +271,8 @@ pub struct BurnSecuritiesCpiAccounts<'a, 'b> {
     pub target_authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `burn_securities` CPI instruction.
@@ -265,6 +293,8 @@ pub struct BurnSecuritiesCpi<'a, 'b> {
     pub target_authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: BurnSecuritiesInstructionArgs,
 }
@@ -284,6 +314,7 @@ impl<'a, 'b> BurnSecuritiesCpi<'a, 'b> {
             target_account: accounts.target_account,
             target_authority: accounts.target_authority,
             token_program: accounts.token_program,
+            exempt_account_registry: accounts.exempt_account_registry,
             __args: args,
         }
     }
@@ -310,7 +341,7 @@ impl<'a, 'b> BurnSecuritiesCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true,
@@ -339,6 +370,17 @@ impl<'a, 'b> BurnSecuritiesCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *exempt_account_registry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -355,7 +397,7 @@ impl<'a, 'b> BurnSecuritiesCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.authority_wallet_role.clone());
@@ -364,6 +406,9 @@ impl<'a, 'b> BurnSecuritiesCpi<'a, 'b> {
         account_infos.push(self.target_account.clone());
         account_infos.push(self.target_authority.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            account_infos.push(exempt_account_registry.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -387,6 +432,7 @@ impl<'a, 'b> BurnSecuritiesCpi<'a, 'b> {
 ///   4. `[writable]` target_account
 ///   5. `[]` target_authority
 ///   6. `[]` token_program
+///   7. `[optional]` exempt_account_registry
 #[derive(Clone, Debug)]
 pub struct BurnSecuritiesCpiBuilder<'a, 'b> {
     instruction: Box<BurnSecuritiesCpiBuilderInstruction<'a, 'b>>,
@@ -403,6 +449,7 @@ impl<'a, 'b> BurnSecuritiesCpiBuilder<'a, 'b> {
             target_account: None,
             target_authority: None,
             token_program: None,
+            exempt_account_registry: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -461,6 +508,16 @@ impl<'a, 'b> BurnSecuritiesCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Optional: Required once the exempt account registry has exempt accounts
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.exempt_account_registry = exempt_account_registry;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -537,6 +594,8 @@ impl<'a, 'b> BurnSecuritiesCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            exempt_account_registry: self.instruction.exempt_account_registry,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -556,6 +615,7 @@ struct BurnSecuritiesCpiBuilderInstruction<'a, 'b> {
     target_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     target_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
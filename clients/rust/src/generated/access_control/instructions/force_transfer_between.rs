@@ -30,6 +30,8 @@ pub struct ForceTransferBetween {
     pub destination_authority: solana_pubkey::Pubkey,
 
     pub token_program: solana_pubkey::Pubkey,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<solana_pubkey::Pubkey>,
 }
 
 impl ForceTransferBetween {
@@ -46,7 +48,7 @@ impl ForceTransferBetween {
         args: ForceTransferBetweenInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.source_account,
             false,
@@ -83,6 +85,17 @@ impl ForceTransferBetween {
             self.token_program,
             false,
         ));
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                exempt_account_registry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ForceTransferBetweenInstructionData::new()
             .try_to_vec()
@@ -147,6 +160,7 @@ impl ForceTransferBetweenInstructionArgs {
 ///   6. `[]` source_authority
 ///   7. `[]` destination_authority
 ///   8. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   9. `[optional]` exempt_account_registry
 #[derive(Clone, Debug, Default)]
 pub struct ForceTransferBetweenBuilder {
     source_account: Option<solana_pubkey::Pubkey>,
@@ -158,6 +172,7 @@ pub struct ForceTransferBetweenBuilder {
     source_authority: Option<solana_pubkey::Pubkey>,
     destination_authority: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    exempt_account_registry: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -221,6 +236,16 @@ impl ForceTransferBetweenBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Optional: Required once the exempt account registry has exempt accounts
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.exempt_account_registry = exempt_account_registry;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -263,6 +288,7 @@ impl ForceTransferBetweenBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            exempt_account_registry: self.exempt_account_registry,
         };
         let args = ForceTransferBetweenInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -291,6 +317,8 @@ pub struct ForceTransferBetweenCpiAccounts<'a, 'b> {
     pub destination_authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `force_transfer_between` CPI instruction.
@@ -315,6 +343,8 @@ pub struct ForceTransferBetweenCpi<'a, 'b> {
     pub destination_authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ForceTransferBetweenInstructionArgs,
 }
@@ -336,6 +366,7 @@ impl<'a, 'b> ForceTransferBetweenCpi<'a, 'b> {
             source_authority: accounts.source_authority,
             destination_authority: accounts.destination_authority,
             token_program: accounts.token_program,
+            exempt_account_registry: accounts.exempt_account_registry,
             __args: args,
         }
     }
@@ -362,7 +393,7 @@ impl<'a, 'b> ForceTransferBetweenCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.source_account.key,
             false,
@@ -399,6 +430,17 @@ impl<'a, 'b> ForceTransferBetweenCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *exempt_account_registry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -417,7 +459,7 @@ impl<'a, 'b> ForceTransferBetweenCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.source_account.clone());
         account_infos.push(self.security_mint.clone());
@@ -428,6 +470,9 @@ impl<'a, 'b> ForceTransferBetweenCpi<'a, 'b> {
         account_infos.push(self.source_authority.clone());
         account_infos.push(self.destination_authority.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            account_infos.push(exempt_account_registry.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -453,6 +498,7 @@ impl<'a, 'b> ForceTransferBetweenCpi<'a, 'b> {
 ///   6. `[]` source_authority
 ///   7. `[]` destination_authority
 ///   8. `[]` token_program
+///   9. `[optional]` exempt_account_registry
 #[derive(Clone, Debug)]
 pub struct ForceTransferBetweenCpiBuilder<'a, 'b> {
     instruction: Box<ForceTransferBetweenCpiBuilderInstruction<'a, 'b>>,
@@ -471,6 +517,7 @@ impl<'a, 'b> ForceTransferBetweenCpiBuilder<'a, 'b> {
             source_authority: None,
             destination_authority: None,
             token_program: None,
+            exempt_account_registry: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -545,6 +592,16 @@ impl<'a, 'b> ForceTransferBetweenCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Optional: Required once the exempt account registry has exempt accounts
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.exempt_account_registry = exempt_account_registry;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -631,6 +688,8 @@ impl<'a, 'b> ForceTransferBetweenCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            exempt_account_registry: self.instruction.exempt_account_registry,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -652,6 +711,7 @@ struct ForceTransferBetweenCpiBuilderInstruction<'a, 'b> {
     source_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     destination_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
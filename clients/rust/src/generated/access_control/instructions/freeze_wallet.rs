@@ -26,6 +26,8 @@ pub struct FreezeWallet {
     pub target_authority: solana_pubkey::Pubkey,
 
     pub token_program: solana_pubkey::Pubkey,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<solana_pubkey::Pubkey>,
 }
 
 impl FreezeWallet {
@@ -38,7 +40,7 @@ impl FreezeWallet {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
             true,
@@ -67,6 +69,17 @@ impl FreezeWallet {
             self.token_program,
             false,
         ));
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                exempt_account_registry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = FreezeWalletInstructionData::new().try_to_vec().unwrap();
 
@@ -113,6 +126,7 @@ impl Default for FreezeWalletInstructionData {
 ///   4. `[writable]` target_account
 ///   5. `[]` target_authority
 ///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   7. `[optional]` exempt_account_registry
 #[derive(Clone, Debug, Default)]
 pub struct FreezeWalletBuilder {
     authority: Option<solana_pubkey::Pubkey>,
@@ -122,6 +136,7 @@ pub struct FreezeWalletBuilder {
     target_account: Option<solana_pubkey::Pubkey>,
     target_authority: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    exempt_account_registry: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -168,6 +183,16 @@ impl FreezeWalletBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Optional: Required once the exempt account registry has exempt accounts
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.exempt_account_registry = exempt_account_registry;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -197,6 +222,7 @@ impl FreezeWalletBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            exempt_account_registry: self.exempt_account_registry,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -218,6 +244,8 @@ pub struct FreezeWalletCpiAccounts<'a, 'b> {
     pub target_authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `freeze_wallet` CPI instruction.
@@ -238,6 +266,8 @@ pub struct FreezeWalletCpi<'a, 'b> {
     pub target_authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Optional: Required once the exempt account registry has exempt accounts
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> FreezeWalletCpi<'a, 'b> {
@@ -254,6 +284,7 @@ impl<'a, 'b> FreezeWalletCpi<'a, 'b> {
             target_account: accounts.target_account,
             target_authority: accounts.target_authority,
             token_program: accounts.token_program,
+            exempt_account_registry: accounts.exempt_account_registry,
         }
     }
     #[inline(always)]
@@ -279,7 +310,7 @@ impl<'a, 'b> FreezeWalletCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true,
@@ -308,6 +339,17 @@ impl<'a, 'b> FreezeWalletCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *exempt_account_registry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -322,7 +364,7 @@ impl<'a, 'b> FreezeWalletCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.authority_wallet_role.clone());
@@ -331,6 +373,9 @@ impl<'a, 'b> FreezeWalletCpi<'a, 'b> {
         account_infos.push(self.target_account.clone());
         account_infos.push(self.target_authority.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            account_infos.push(exempt_account_registry.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -354,6 +399,7 @@ impl<'a, 'b> FreezeWalletCpi<'a, 'b> {
 ///   4. `[writable]` target_account
 ///   5. `[]` target_authority
 ///   6. `[]` token_program
+///   7. `[optional]` exempt_account_registry
 #[derive(Clone, Debug)]
 pub struct FreezeWalletCpiBuilder<'a, 'b> {
     instruction: Box<FreezeWalletCpiBuilderInstruction<'a, 'b>>,
@@ -370,6 +416,7 @@ impl<'a, 'b> FreezeWalletCpiBuilder<'a, 'b> {
             target_account: None,
             target_authority: None,
             token_program: None,
+            exempt_account_registry: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -427,6 +474,16 @@ impl<'a, 'b> FreezeWalletCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Optional: Required once the exempt account registry has exempt accounts
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.exempt_account_registry = exempt_account_registry;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -495,6 +552,8 @@ impl<'a, 'b> FreezeWalletCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            exempt_account_registry: self.instruction.exempt_account_registry,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -513,6 +572,7 @@ struct FreezeWalletCpiBuilderInstruction<'a, 'b> {
     target_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     target_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
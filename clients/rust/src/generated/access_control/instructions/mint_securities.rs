@@ -29,6 +29,15 @@ pub struct MintSecurities {
     /// This account must be initialized before minting (holder, holder_group, and SAA must exist)
     /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
     pub security_associated_account: Option<solana_pubkey::Pubkey>,
+    /// Optional: Required when minting to a tokenlock escrow registered in the exempt account registry
+    pub exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restrictions_program: Option<solana_pubkey::Pubkey>,
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restriction_data: Option<solana_pubkey::Pubkey>,
+    /// transfer hook program
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restriction_group: Option<solana_pubkey::Pubkey>,
 }
 
 impl MintSecurities {
@@ -45,7 +54,7 @@ impl MintSecurities {
         args: MintSecuritiesInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
             true,
@@ -75,7 +84,7 @@ impl MintSecurities {
             false,
         ));
         if let Some(security_associated_account) = self.security_associated_account {
-            accounts.push(solana_instruction::AccountMeta::new_readonly(
+            accounts.push(solana_instruction::AccountMeta::new(
                 security_associated_account,
                 false,
             ));
@@ -85,6 +94,50 @@ impl MintSecurities {
                 false,
             ));
         }
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                exempt_account_registry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restrictions_program) = self.transfer_restrictions_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transfer_restrictions_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restriction_data) = self.transfer_restriction_data {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transfer_restriction_data,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restriction_group) = self.transfer_restriction_group {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transfer_restriction_group,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = MintSecuritiesInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -145,7 +198,11 @@ impl MintSecuritiesInstructionArgs {
 ///   4. `[writable]` destination_account
 ///   5. `[]` destination_authority
 ///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
-///   7. `[optional]` security_associated_account
+///   7. `[writable, optional]` security_associated_account
+///   8. `[optional]` exempt_account_registry
+///   9. `[optional]` transfer_restrictions_program
+///   10. `[optional]` transfer_restriction_data
+///   11. `[optional]` transfer_restriction_group
 #[derive(Clone, Debug, Default)]
 pub struct MintSecuritiesBuilder {
     authority: Option<solana_pubkey::Pubkey>,
@@ -156,6 +213,10 @@ pub struct MintSecuritiesBuilder {
     destination_authority: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
     security_associated_account: Option<solana_pubkey::Pubkey>,
+    exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    transfer_restrictions_program: Option<solana_pubkey::Pubkey>,
+    transfer_restriction_data: Option<solana_pubkey::Pubkey>,
+    transfer_restriction_group: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -217,6 +278,47 @@ impl MintSecuritiesBuilder {
         self.security_associated_account = security_associated_account;
         self
     }
+    /// `[optional account]`
+    /// Optional: Required when minting to a tokenlock escrow registered in the exempt account registry
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.exempt_account_registry = exempt_account_registry;
+        self
+    }
+    /// `[optional account]`
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    #[inline(always)]
+    pub fn transfer_restrictions_program(
+        &mut self,
+        transfer_restrictions_program: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.transfer_restrictions_program = transfer_restrictions_program;
+        self
+    }
+    /// `[optional account]`
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    #[inline(always)]
+    pub fn transfer_restriction_data(
+        &mut self,
+        transfer_restriction_data: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.transfer_restriction_data = transfer_restriction_data;
+        self
+    }
+    /// `[optional account]`
+    /// transfer hook program
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    #[inline(always)]
+    pub fn transfer_restriction_group(
+        &mut self,
+        transfer_restriction_group: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.transfer_restriction_group = transfer_restriction_group;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -256,6 +358,10 @@ impl MintSecuritiesBuilder {
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
             security_associated_account: self.security_associated_account,
+            exempt_account_registry: self.exempt_account_registry,
+            transfer_restrictions_program: self.transfer_restrictions_program,
+            transfer_restriction_data: self.transfer_restriction_data,
+            transfer_restriction_group: self.transfer_restriction_group,
         };
         let args = MintSecuritiesInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -283,6 +389,15 @@ pub struct MintSecuritiesCpiAccounts<'a, 'b> {
     /// This account must be initialized before minting (holder, holder_group, and SAA must exist)
     /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
     pub security_associated_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional: Required when minting to a tokenlock escrow registered in the exempt account registry
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restrictions_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restriction_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// transfer hook program
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restriction_group: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `mint_securities` CPI instruction.
@@ -306,6 +421,15 @@ pub struct MintSecuritiesCpi<'a, 'b> {
     /// This account must be initialized before minting (holder, holder_group, and SAA must exist)
     /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
     pub security_associated_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional: Required when minting to a tokenlock escrow registered in the exempt account registry
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restrictions_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restriction_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// transfer hook program
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restriction_group: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: MintSecuritiesInstructionArgs,
 }
@@ -326,6 +450,10 @@ impl<'a, 'b> MintSecuritiesCpi<'a, 'b> {
             destination_authority: accounts.destination_authority,
             token_program: accounts.token_program,
             security_associated_account: accounts.security_associated_account,
+            exempt_account_registry: accounts.exempt_account_registry,
+            transfer_restrictions_program: accounts.transfer_restrictions_program,
+            transfer_restriction_data: accounts.transfer_restriction_data,
+            transfer_restriction_group: accounts.transfer_restriction_group,
             __args: args,
         }
     }
@@ -352,7 +480,7 @@ impl<'a, 'b> MintSecuritiesCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true,
@@ -382,7 +510,7 @@ impl<'a, 'b> MintSecuritiesCpi<'a, 'b> {
             false,
         ));
         if let Some(security_associated_account) = self.security_associated_account {
-            accounts.push(solana_instruction::AccountMeta::new_readonly(
+            accounts.push(solana_instruction::AccountMeta::new(
                 *security_associated_account.key,
                 false,
             ));
@@ -392,6 +520,50 @@ impl<'a, 'b> MintSecuritiesCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *exempt_account_registry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restrictions_program) = self.transfer_restrictions_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transfer_restrictions_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restriction_data) = self.transfer_restriction_data {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transfer_restriction_data.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restriction_group) = self.transfer_restriction_group {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transfer_restriction_group.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ACCESS_CONTROL_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -408,7 +580,7 @@ impl<'a, 'b> MintSecuritiesCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.authority_wallet_role.clone());
@@ -420,6 +592,18 @@ impl<'a, 'b> MintSecuritiesCpi<'a, 'b> {
         if let Some(security_associated_account) = self.security_associated_account {
             account_infos.push(security_associated_account.clone());
         }
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            account_infos.push(exempt_account_registry.clone());
+        }
+        if let Some(transfer_restrictions_program) = self.transfer_restrictions_program {
+            account_infos.push(transfer_restrictions_program.clone());
+        }
+        if let Some(transfer_restriction_data) = self.transfer_restriction_data {
+            account_infos.push(transfer_restriction_data.clone());
+        }
+        if let Some(transfer_restriction_group) = self.transfer_restriction_group {
+            account_infos.push(transfer_restriction_group.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -443,7 +627,11 @@ impl<'a, 'b> MintSecuritiesCpi<'a, 'b> {
 ///   4. `[writable]` destination_account
 ///   5. `[]` destination_authority
 ///   6. `[]` token_program
-///   7. `[optional]` security_associated_account
+///   7. `[writable, optional]` security_associated_account
+///   8. `[optional]` exempt_account_registry
+///   9. `[optional]` transfer_restrictions_program
+///   10. `[optional]` transfer_restriction_data
+///   11. `[optional]` transfer_restriction_group
 #[derive(Clone, Debug)]
 pub struct MintSecuritiesCpiBuilder<'a, 'b> {
     instruction: Box<MintSecuritiesCpiBuilderInstruction<'a, 'b>>,
@@ -461,6 +649,10 @@ impl<'a, 'b> MintSecuritiesCpiBuilder<'a, 'b> {
             destination_authority: None,
             token_program: None,
             security_associated_account: None,
+            exempt_account_registry: None,
+            transfer_restrictions_program: None,
+            transfer_restriction_data: None,
+            transfer_restriction_group: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -530,6 +722,47 @@ impl<'a, 'b> MintSecuritiesCpiBuilder<'a, 'b> {
         self.instruction.security_associated_account = security_associated_account;
         self
     }
+    /// `[optional account]`
+    /// Optional: Required when minting to a tokenlock escrow registered in the exempt account registry
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.exempt_account_registry = exempt_account_registry;
+        self
+    }
+    /// `[optional account]`
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    #[inline(always)]
+    pub fn transfer_restrictions_program(
+        &mut self,
+        transfer_restrictions_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.transfer_restrictions_program = transfer_restrictions_program;
+        self
+    }
+    /// `[optional account]`
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    #[inline(always)]
+    pub fn transfer_restriction_data(
+        &mut self,
+        transfer_restriction_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.transfer_restriction_data = transfer_restriction_data;
+        self
+    }
+    /// `[optional account]`
+    /// transfer hook program
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    #[inline(always)]
+    pub fn transfer_restriction_group(
+        &mut self,
+        transfer_restriction_group: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.transfer_restriction_group = transfer_restriction_group;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -608,6 +841,14 @@ impl<'a, 'b> MintSecuritiesCpiBuilder<'a, 'b> {
                 .expect("token_program is not set"),
 
             security_associated_account: self.instruction.security_associated_account,
+
+            exempt_account_registry: self.instruction.exempt_account_registry,
+
+            transfer_restrictions_program: self.instruction.transfer_restrictions_program,
+
+            transfer_restriction_data: self.instruction.transfer_restriction_data,
+
+            transfer_restriction_group: self.instruction.transfer_restriction_group,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -628,6 +869,10 @@ struct MintSecuritiesCpiBuilderInstruction<'a, 'b> {
     destination_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     security_associated_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_restrictions_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_restriction_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_restriction_group: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -5,23 +5,29 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#add_exempt_account;
 pub(crate) mod r#burn_securities;
 pub(crate) mod r#force_transfer_between;
 pub(crate) mod r#freeze_wallet;
 pub(crate) mod r#grant_role;
 pub(crate) mod r#initialize_access_control;
+pub(crate) mod r#migrate_access_control;
 pub(crate) mod r#mint_securities;
+pub(crate) mod r#remove_exempt_account;
 pub(crate) mod r#revoke_role;
 pub(crate) mod r#set_lockup_escrow_account;
 pub(crate) mod r#set_max_total_supply;
 pub(crate) mod r#thaw_wallet;
 
+pub use self::r#add_exempt_account::*;
 pub use self::r#burn_securities::*;
 pub use self::r#force_transfer_between::*;
 pub use self::r#freeze_wallet::*;
 pub use self::r#grant_role::*;
 pub use self::r#initialize_access_control::*;
+pub use self::r#migrate_access_control::*;
 pub use self::r#mint_securities::*;
+pub use self::r#remove_exempt_account::*;
 pub use self::r#revoke_role::*;
 pub use self::r#set_lockup_escrow_account::*;
 pub use self::r#set_max_total_supply::*;
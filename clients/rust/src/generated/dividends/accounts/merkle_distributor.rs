@@ -49,6 +49,10 @@ pub struct MerkleDistributor {
     pub ready_to_claim: bool,
     /// IPFS hash of the serialized merkle tree.
     pub ipfs_hash: String,
+    /// Account layout version, 0 for accounts created before the version was stored.
+    pub version: u8,
+    /// Space reserved for fields added in later layout versions.
+    pub reserved: [u8; 32],
 }
 
 pub const MERKLE_DISTRIBUTOR_DISCRIMINATOR: [u8; 8] = [77, 119, 139, 70, 84, 247, 12, 26];
@@ -61,6 +61,12 @@ pub enum DividendsError {
     /// 6016 - Unauthorized ownership transfer
     #[error("Unauthorized ownership transfer")]
     UnauthorizedOwnershipTransfer = 0x1780,
+    /// 6017 - Access control is required to distribute the security token
+    #[error("Access control is required to distribute the security token")]
+    AccessControlRequired = 0x1781,
+    /// 6018 - Distributor token account is not a distribution vault of the security token
+    #[error("Distributor token account is not a distribution vault of the security token")]
+    DistributorAccountNotExempt = 0x1782,
 }
 
 impl From<DividendsError> for solana_program_error::ProgramError {
@@ -27,6 +27,11 @@ pub struct FundDividends {
     pub mint: solana_pubkey::Pubkey,
     /// SPL [Token] program.
     pub token_program: solana_pubkey::Pubkey,
+    /// Access Control of the security token.
+    /// Optional: Required when the distributed mint is the security token.
+    pub access_control: Option<solana_pubkey::Pubkey>,
+    /// Optional: Required when the distributed mint is the security token.
+    pub exempt_account_registry: Option<solana_pubkey::Pubkey>,
 }
 
 impl FundDividends {
@@ -43,7 +48,7 @@ impl FundDividends {
         args: FundDividendsInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.distributor,
             false,
@@ -62,6 +67,28 @@ impl FundDividends {
             self.token_program,
             false,
         ));
+        if let Some(access_control) = self.access_control {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                access_control,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::DIVIDENDS_ID,
+                false,
+            ));
+        }
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                exempt_account_registry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::DIVIDENDS_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = FundDividendsInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -122,6 +149,8 @@ impl FundDividendsInstructionArgs {
 ///   4. `[writable, signer]` payer
 ///   5. `[]` mint
 ///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   7. `[optional]` access_control
+///   8. `[optional]` exempt_account_registry
 #[derive(Clone, Debug, Default)]
 pub struct FundDividendsBuilder {
     distributor: Option<solana_pubkey::Pubkey>,
@@ -131,6 +160,8 @@ pub struct FundDividendsBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     mint: Option<solana_pubkey::Pubkey>,
     token_program: Option<solana_pubkey::Pubkey>,
+    access_control: Option<solana_pubkey::Pubkey>,
+    exempt_account_registry: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -181,6 +212,24 @@ impl FundDividendsBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Access Control of the security token.
+    /// Optional: Required when the distributed mint is the security token.
+    #[inline(always)]
+    pub fn access_control(&mut self, access_control: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.access_control = access_control;
+        self
+    }
+    /// `[optional account]`
+    /// Optional: Required when the distributed mint is the security token.
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.exempt_account_registry = exempt_account_registry;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -213,6 +262,8 @@ impl FundDividendsBuilder {
             token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            access_control: self.access_control,
+            exempt_account_registry: self.exempt_account_registry,
         };
         let args = FundDividendsInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -238,6 +289,11 @@ pub struct FundDividendsCpiAccounts<'a, 'b> {
     pub mint: &'b solana_account_info::AccountInfo<'a>,
     /// SPL [Token] program.
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Access Control of the security token.
+    /// Optional: Required when the distributed mint is the security token.
+    pub access_control: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional: Required when the distributed mint is the security token.
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `fund_dividends` CPI instruction.
@@ -258,6 +314,11 @@ pub struct FundDividendsCpi<'a, 'b> {
     pub mint: &'b solana_account_info::AccountInfo<'a>,
     /// SPL [Token] program.
     pub token_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Access Control of the security token.
+    /// Optional: Required when the distributed mint is the security token.
+    pub access_control: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Optional: Required when the distributed mint is the security token.
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: FundDividendsInstructionArgs,
 }
@@ -277,6 +338,8 @@ impl<'a, 'b> FundDividendsCpi<'a, 'b> {
             payer: accounts.payer,
             mint: accounts.mint,
             token_program: accounts.token_program,
+            access_control: accounts.access_control,
+            exempt_account_registry: accounts.exempt_account_registry,
             __args: args,
         }
     }
@@ -303,7 +366,7 @@ impl<'a, 'b> FundDividendsCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.distributor.key,
             false,
@@ -323,6 +386,28 @@ impl<'a, 'b> FundDividendsCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(access_control) = self.access_control {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *access_control.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::DIVIDENDS_ID,
+                false,
+            ));
+        }
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *exempt_account_registry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::DIVIDENDS_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -339,7 +424,7 @@ impl<'a, 'b> FundDividendsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.distributor.clone());
         account_infos.push(self.from.clone());
@@ -348,6 +433,12 @@ impl<'a, 'b> FundDividendsCpi<'a, 'b> {
         account_infos.push(self.payer.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(access_control) = self.access_control {
+            account_infos.push(access_control.clone());
+        }
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            account_infos.push(exempt_account_registry.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -371,6 +462,8 @@ impl<'a, 'b> FundDividendsCpi<'a, 'b> {
 ///   4. `[writable, signer]` payer
 ///   5. `[]` mint
 ///   6. `[]` token_program
+///   7. `[optional]` access_control
+///   8. `[optional]` exempt_account_registry
 #[derive(Clone, Debug)]
 pub struct FundDividendsCpiBuilder<'a, 'b> {
     instruction: Box<FundDividendsCpiBuilderInstruction<'a, 'b>>,
@@ -387,6 +480,8 @@ impl<'a, 'b> FundDividendsCpiBuilder<'a, 'b> {
             payer: None,
             mint: None,
             token_program: None,
+            access_control: None,
+            exempt_account_registry: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -439,6 +534,27 @@ impl<'a, 'b> FundDividendsCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// Access Control of the security token.
+    /// Optional: Required when the distributed mint is the security token.
+    #[inline(always)]
+    pub fn access_control(
+        &mut self,
+        access_control: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.access_control = access_control;
+        self
+    }
+    /// `[optional account]`
+    /// Optional: Required when the distributed mint is the security token.
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.exempt_account_registry = exempt_account_registry;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -503,6 +619,10 @@ impl<'a, 'b> FundDividendsCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            access_control: self.instruction.access_control,
+
+            exempt_account_registry: self.instruction.exempt_account_registry,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -522,6 +642,8 @@ struct FundDividendsCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    access_control: Option<&'b solana_account_info::AccountInfo<'a>>,
+    exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -8,6 +8,7 @@
 pub(crate) mod r#accept_reclaimer_ownership;
 pub(crate) mod r#claim;
 pub(crate) mod r#fund_dividends;
+pub(crate) mod r#migrate_distributor;
 pub(crate) mod r#new_distributor;
 pub(crate) mod r#pause;
 pub(crate) mod r#propose_reclaimer;
@@ -16,6 +17,7 @@ pub(crate) mod r#reclaim_dividends;
 pub use self::r#accept_reclaimer_ownership::*;
 pub use self::r#claim::*;
 pub use self::r#fund_dividends::*;
+pub use self::r#migrate_distributor::*;
 pub use self::r#new_distributor::*;
 pub use self::r#pause::*;
 pub use self::r#propose_reclaimer::*;
@@ -25,6 +25,9 @@ pub struct AccessControl {
     pub authority: Pubkey,
     pub max_total_supply: u64,
     pub lockup_escrow_account: Option<Pubkey>,
+    pub version: u8,
+    pub exempt_accounts_count: u8,
+    pub reserved: [u8; 31],
 }
 
 pub const ACCESS_CONTROL_DISCRIMINATOR: [u8; 8] = [147, 81, 178, 92, 223, 66, 181, 132];
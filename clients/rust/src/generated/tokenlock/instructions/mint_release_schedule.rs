@@ -39,6 +39,8 @@ pub struct MintReleaseSchedule {
     pub access_control_program: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+    /// Required when the escrow account is registered in the exempt account registry
+    pub exempt_account_registry: Option<solana_pubkey::Pubkey>,
 }
 
 impl MintReleaseSchedule {
@@ -55,7 +57,7 @@ impl MintReleaseSchedule {
         args: MintReleaseScheduleInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.tokenlock_account,
             false,
@@ -104,6 +106,17 @@ impl MintReleaseSchedule {
             self.system_program,
             false,
         ));
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                exempt_account_registry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TOKENLOCK_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = MintReleaseScheduleInstructionData::new()
             .try_to_vec()
@@ -176,6 +189,7 @@ impl MintReleaseScheduleInstructionArgs {
 ///   10. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   11. `[optional]` access_control_program (default to `4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3`)
 ///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   13. `[optional]` exempt_account_registry
 #[derive(Clone, Debug, Default)]
 pub struct MintReleaseScheduleBuilder {
     tokenlock_account: Option<solana_pubkey::Pubkey>,
@@ -191,6 +205,7 @@ pub struct MintReleaseScheduleBuilder {
     token_program: Option<solana_pubkey::Pubkey>,
     access_control_program: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    exempt_account_registry: Option<solana_pubkey::Pubkey>,
     uuid: Option<[u8; 16]>,
     amount: Option<u64>,
     commencement_timestamp: Option<u64>,
@@ -281,6 +296,16 @@ impl MintReleaseScheduleBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Required when the escrow account is registered in the exempt account registry
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.exempt_account_registry = exempt_account_registry;
+        self
+    }
     #[inline(always)]
     pub fn uuid(&mut self, uuid: [u8; 16]) -> &mut Self {
         self.uuid = Some(uuid);
@@ -350,6 +375,7 @@ impl MintReleaseScheduleBuilder {
                 system_program: self
                     .system_program
                     .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+                exempt_account_registry: self.exempt_account_registry,
             };
         let args = MintReleaseScheduleInstructionArgs {
             uuid: self.uuid.clone().expect("uuid is not set"),
@@ -396,6 +422,8 @@ pub struct MintReleaseScheduleCpiAccounts<'a, 'b> {
     pub access_control_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Required when the escrow account is registered in the exempt account registry
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `mint_release_schedule` CPI instruction.
@@ -428,6 +456,8 @@ pub struct MintReleaseScheduleCpi<'a, 'b> {
     pub access_control_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// Required when the escrow account is registered in the exempt account registry
+    pub exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: MintReleaseScheduleInstructionArgs,
 }
@@ -453,6 +483,7 @@ impl<'a, 'b> MintReleaseScheduleCpi<'a, 'b> {
             token_program: accounts.token_program,
             access_control_program: accounts.access_control_program,
             system_program: accounts.system_program,
+            exempt_account_registry: accounts.exempt_account_registry,
             __args: args,
         }
     }
@@ -479,7 +510,7 @@ impl<'a, 'b> MintReleaseScheduleCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.tokenlock_account.key,
             false,
@@ -529,6 +560,17 @@ impl<'a, 'b> MintReleaseScheduleCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *exempt_account_registry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TOKENLOCK_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -547,7 +589,7 @@ impl<'a, 'b> MintReleaseScheduleCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.tokenlock_account.clone());
         account_infos.push(self.timelock_account.clone());
@@ -562,6 +604,9 @@ impl<'a, 'b> MintReleaseScheduleCpi<'a, 'b> {
         account_infos.push(self.token_program.clone());
         account_infos.push(self.access_control_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(exempt_account_registry) = self.exempt_account_registry {
+            account_infos.push(exempt_account_registry.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -591,6 +636,7 @@ impl<'a, 'b> MintReleaseScheduleCpi<'a, 'b> {
 ///   10. `[]` token_program
 ///   11. `[]` access_control_program
 ///   12. `[]` system_program
+///   13. `[optional]` exempt_account_registry
 #[derive(Clone, Debug)]
 pub struct MintReleaseScheduleCpiBuilder<'a, 'b> {
     instruction: Box<MintReleaseScheduleCpiBuilderInstruction<'a, 'b>>,
@@ -613,6 +659,7 @@ impl<'a, 'b> MintReleaseScheduleCpiBuilder<'a, 'b> {
             token_program: None,
             access_control_program: None,
             system_program: None,
+            exempt_account_registry: None,
             uuid: None,
             amount: None,
             commencement_timestamp: None,
@@ -718,6 +765,16 @@ impl<'a, 'b> MintReleaseScheduleCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Required when the escrow account is registered in the exempt account registry
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.exempt_account_registry = exempt_account_registry;
+        self
+    }
     #[inline(always)]
     pub fn uuid(&mut self, uuid: [u8; 16]) -> &mut Self {
         self.instruction.uuid = Some(uuid);
@@ -854,6 +911,8 @@ impl<'a, 'b> MintReleaseScheduleCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            exempt_account_registry: self.instruction.exempt_account_registry,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -879,6 +938,7 @@ struct MintReleaseScheduleCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     access_control_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     uuid: Option<[u8; 16]>,
     amount: Option<u64>,
     commencement_timestamp: Option<u64>,
@@ -20,12 +20,15 @@ pub struct HolderGroup {
     )]
     pub holder: Pubkey,
     pub current_wallets_count: u64,
+    pub funded_wallets_count: u64,
+    pub version: u8,
+    pub reserved: [u8; 32],
 }
 
 pub const HOLDER_GROUP_DISCRIMINATOR: [u8; 8] = [136, 231, 252, 48, 92, 187, 25, 164];
 
 impl HolderGroup {
-    pub const LEN: usize = 56;
+    pub const LEN: usize = 97;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
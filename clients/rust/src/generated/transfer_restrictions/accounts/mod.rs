@@ -6,8 +6,13 @@
 //!
 
 pub(crate) mod r#access_control;
+pub(crate) mod r#approved_venue;
+pub(crate) mod r#attestation_issuer_registry;
+pub(crate) mod r#beneficial_owner_position;
+pub(crate) mod r#blackout_calendar;
 pub(crate) mod r#holder_group;
 pub(crate) mod r#security_associated_account;
+pub(crate) mod r#transfer_approval;
 pub(crate) mod r#transfer_restriction_data;
 pub(crate) mod r#transfer_restriction_group;
 pub(crate) mod r#transfer_restriction_holder;
@@ -15,8 +20,13 @@ pub(crate) mod r#transfer_rule;
 pub(crate) mod r#wallet_role;
 
 pub use self::r#access_control::*;
+pub use self::r#approved_venue::*;
+pub use self::r#attestation_issuer_registry::*;
+pub use self::r#beneficial_owner_position::*;
+pub use self::r#blackout_calendar::*;
 pub use self::r#holder_group::*;
 pub use self::r#security_associated_account::*;
+pub use self::r#transfer_approval::*;
 pub use self::r#transfer_restriction_data::*;
 pub use self::r#transfer_restriction_group::*;
 pub use self::r#transfer_restriction_holder::*;
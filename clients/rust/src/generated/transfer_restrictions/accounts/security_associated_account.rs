@@ -5,6 +5,8 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::AcquisitionLot;
+use crate::generated::types::WalletHold;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -15,6 +17,18 @@ pub struct SecurityAssociatedAccount {
     pub discriminator: [u8; 8],
     pub group: u64,
     pub holder: Option<Pubkey>,
+    pub acquisition_lots_count: u8,
+    pub acquisition_lots: [AcquisitionLot; 8],
+    pub funded: bool,
+    pub holder_id: u64,
+    pub omnibus: bool,
+    pub beneficial_owners_count: u64,
+    pub beneficial_owned_amount: u64,
+    pub holds_count: u8,
+    pub holds: [WalletHold; 4],
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
 }
 
 pub const SECURITY_ASSOCIATED_ACCOUNT_DISCRIMINATOR: [u8; 8] = [68, 169, 137, 56, 226, 21, 69, 124];
@@ -28,6 +28,17 @@ pub struct TransferRestrictionData {
     pub max_holders: u64,
     pub paused: bool,
     pub lockup_escrow_account: Option<Pubkey>,
+    pub count_holders_by_balance: bool,
+    pub funded_holders_count: u64,
+    pub bump: u8,
+    pub version: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub policy_program: Pubkey,
+    pub allow_intra_holder_transfers: bool,
+    pub reserved: [u8; 32],
 }
 
 pub const TRANSFER_RESTRICTION_DATA_DISCRIMINATOR: [u8; 8] =
@@ -21,12 +21,20 @@ pub struct TransferRestrictionGroup {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub transfer_restriction_data: Pubkey,
+    pub holding_period: u64,
+    pub funded_holders_count: u64,
+    pub paused_direction: u8,
+    pub venue_wallets_count: u64,
+    pub bump: u8,
+    pub version: u8,
+    pub min_holding: u64,
+    pub reserved: [u8; 24],
 }
 
 pub const TRANSFER_RESTRICTION_GROUP_DISCRIMINATOR: [u8; 8] = [61, 120, 96, 96, 113, 210, 205, 223];
 
 impl TransferRestrictionGroup {
-    pub const LEN: usize = 64;
+    pub const LEN: usize = 123;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -22,13 +22,18 @@ pub struct TransferRestrictionHolder {
     pub current_holder_group_count: u64,
     pub id: u64,
     pub active: bool,
+    pub funded_wallets_count: u64,
+    pub frozen: bool,
+    pub version: u8,
+    pub beneficial_owner_positions_count: u64,
+    pub reserved: [u8; 24],
 }
 
 pub const TRANSFER_RESTRICTION_HOLDER_DISCRIMINATOR: [u8; 8] =
     [196, 226, 112, 46, 157, 122, 48, 157];
 
 impl TransferRestrictionHolder {
-    pub const LEN: usize = 65;
+    pub const LEN: usize = 107;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::TransferRuleClosedInterval;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -21,12 +22,19 @@ pub struct TransferRule {
     pub transfer_group_id_from: u64,
     pub transfer_group_id_to: u64,
     pub locked_until: u64,
+    pub valid_until: u64,
+    pub closed_intervals_count: u8,
+    pub closed_intervals: [TransferRuleClosedInterval; 8],
+    pub bump: u8,
+    pub approval_required: bool,
+    pub version: u8,
+    pub reserved: [u8; 32],
 }
 
 pub const TRANSFER_RULE_DISCRIMINATOR: [u8; 8] = [200, 231, 114, 91, 84, 241, 109, 172];
 
 impl TransferRule {
-    pub const LEN: usize = 64;
+    pub const LEN: usize = 300;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -88,6 +88,159 @@ pub enum TransferRestrictionsError {
     /// 6025 - Holder group already initialized
     #[error("Holder group already initialized")]
     HolderGroupAlreadyInitialized = 0x1789,
+    /// 6026 - Transfer rule has expired
+    #[error("Transfer rule has expired")]
+    TransferRuleExpired = 0x178A,
+    /// 6027 - Transfer rule is inside a closed interval
+    #[error("Transfer rule is inside a closed interval")]
+    TransferRuleClosedInterval = 0x178B,
+    /// 6028 - Too many closed intervals
+    #[error("Too many closed intervals")]
+    TooManyClosedIntervals = 0x178C,
+    /// 6029 - Invalid closed interval
+    #[error("Invalid closed interval")]
+    InvalidClosedInterval = 0x178D,
+    /// 6030 - Invalid blackout window
+    #[error("Invalid blackout window")]
+    InvalidBlackoutWindow = 0x178E,
+    /// 6031 - Invalid transfer direction
+    #[error("Invalid transfer direction")]
+    InvalidTransferDirection = 0x178F,
+    /// 6032 - Too many blackout windows
+    #[error("Too many blackout windows")]
+    TooManyBlackoutWindows = 0x1790,
+    /// 6033 - Blackout window not found
+    #[error("Blackout window not found")]
+    BlackoutWindowNotFound = 0x1791,
+    /// 6034 - Transfers are blocked by a blackout window
+    #[error("Transfers are blocked by a blackout window")]
+    TransferBlackoutActive = 0x1792,
+    /// 6035 - Tokens are still inside the holding period
+    #[error("Tokens are still inside the holding period")]
+    HoldingPeriodNotElapsed = 0x1793,
+    /// 6036 - Transfer hook can be executed only during a token transfer
+    #[error("Transfer hook can be executed only during a token transfer")]
+    NotTransferring = 0x1794,
+    /// 6037 - Holder counting by balance is disabled
+    #[error("Holder counting by balance is disabled")]
+    HolderCountingByBalanceDisabled = 0x1795,
+    /// 6038 - Default transfer rule account is missing
+    #[error("Default transfer rule account is missing")]
+    DefaultTransferRuleAccountMissing = 0x1796,
+    /// 6039 - Transfer restriction group account is missing
+    #[error("Transfer restriction group account is missing")]
+    TransferRestrictionGroupMissing = 0x1797,
+    /// 6040 - Invalid transfer group id
+    #[error("Invalid transfer group id")]
+    InvalidTransferGroupId = 0x1798,
+    /// 6041 - Invalid remaining accounts
+    #[error("Invalid remaining accounts")]
+    InvalidRemainingAccounts = 0x1799,
+    /// 6042 - Invalid token account
+    #[error("Invalid token account")]
+    InvalidTokenAccount = 0x179A,
+    /// 6043 - Token account is not an omnibus account
+    #[error("Token account is not an omnibus account")]
+    NotOmnibusAccount = 0x179B,
+    /// 6044 - Omnibus account has beneficial owners
+    #[error("Omnibus account has beneficial owners")]
+    OmnibusAccountHasBeneficialOwners = 0x179C,
+    /// 6045 - Omnibus account balance does not cover beneficial owner positions
+    #[error("Omnibus account balance does not cover beneficial owner positions")]
+    OmnibusBalanceBelowBeneficialOwnership = 0x179D,
+    /// 6046 - Beneficial owner position is not empty
+    #[error("Beneficial owner position is not empty")]
+    BeneficialOwnerPositionNotEmpty = 0x179E,
+    /// 6047 - Cannot merge a holder into itself
+    #[error("Cannot merge a holder into itself")]
+    CannotMergeHolderIntoItself = 0x179F,
+    /// 6048 - Token account is not owned by the approved venue
+    #[error("Token account is not owned by the approved venue")]
+    TokenAccountNotApprovedVenue = 0x17A0,
+    /// 6049 - Token account is not a venue token account
+    #[error("Token account is not a venue token account")]
+    NotVenueTokenAccount = 0x17A1,
+    /// 6050 - Transfers of the group are paused
+    #[error("Transfers of the group are paused")]
+    GroupTransfersPaused = 0x17A2,
+    /// 6051 - Holder is frozen
+    #[error("Holder is frozen")]
+    HolderFrozen = 0x17A3,
+    /// 6052 - Invalid wallet hold
+    #[error("Invalid wallet hold")]
+    InvalidWalletHold = 0x17A4,
+    /// 6053 - Too many wallet holds
+    #[error("Too many wallet holds")]
+    TooManyWalletHolds = 0x17A5,
+    /// 6054 - Invalid wallet hold index
+    #[error("Invalid wallet hold index")]
+    InvalidWalletHoldIndex = 0x17A6,
+    /// 6055 - Transfer is blocked by a wallet hold
+    #[error("Transfer is blocked by a wallet hold")]
+    TransferBlockedByWalletHold = 0x17A7,
+    /// 6056 - Group still has wallets
+    #[error("Group still has wallets")]
+    GroupHasWallets = 0x17A8,
+    /// 6057 - Transfer requires a transfer approval
+    #[error("Transfer requires a transfer approval")]
+    TransferApprovalRequired = 0x17A9,
+    /// 6058 - Invalid transfer approval
+    #[error("Invalid transfer approval")]
+    InvalidTransferApproval = 0x17AA,
+    /// 6059 - Transfer approval expired
+    #[error("Transfer approval expired")]
+    TransferApprovalExpired = 0x17AB,
+    /// 6060 - Transfer amount exceeds the transfer approval
+    #[error("Transfer amount exceeds the transfer approval")]
+    TransferApprovalAmountExceeded = 0x17AC,
+    /// 6061 - Token account is not an exempt account
+    #[error("Token account is not an exempt account")]
+    NotExemptAccount = 0x17AD,
+    /// 6062 - Account cannot be migrated with the transfer restriction data
+    #[error("Account cannot be migrated with the transfer restriction data")]
+    InvalidMigrationAccount = 0x17AE,
+    /// 6063 - Invalid attestation issuer
+    #[error("Invalid attestation issuer")]
+    InvalidAttestationIssuer = 0x17AF,
+    /// 6064 - Attestation issuer is already registered
+    #[error("Attestation issuer is already registered")]
+    AttestationIssuerAlreadyRegistered = 0x17B0,
+    /// 6065 - Attestation issuers limit reached
+    #[error("Attestation issuers limit reached")]
+    AttestationIssuersLimitReached = 0x17B1,
+    /// 6066 - Invalid attestation issuer index
+    #[error("Invalid attestation issuer index")]
+    InvalidAttestationIssuerIndex = 0x17B2,
+    /// 6067 - Wallet has no valid attestation of a trusted issuer
+    #[error("Wallet has no valid attestation of a trusted issuer")]
+    WalletNotAttested = 0x17B3,
+    /// 6068 - Invalid policy program
+    #[error("Invalid policy program")]
+    InvalidPolicyProgram = 0x17B4,
+    /// 6069 - Sender balance after the transfer is below the minimum holding
+    #[error("Sender balance after the transfer is below the minimum holding")]
+    SenderBalanceBelowMinHolding = 0x17B5,
+    /// 6070 - Receiver balance after the transfer is below the minimum holding
+    #[error("Receiver balance after the transfer is below the minimum holding")]
+    ReceiverBalanceBelowMinHolding = 0x17B6,
+    /// 6071 - Holder has beneficial owner positions
+    #[error("Holder has beneficial owner positions")]
+    HolderHasBeneficialOwnerPositions = 0x17B7,
+    /// 6072 - Holder account does not match the holder of the wallet
+    #[error("Holder account does not match the holder of the wallet")]
+    InvalidHolderAccount = 0x17B8,
+    /// 6073 - Attestation issuers must use the same attestation program
+    #[error("Attestation issuers must use the same attestation program")]
+    AttestationProgramMismatch = 0x17B9,
+    /// 6074 - Transfer passes the transfer restrictions and depends on the policy program
+    #[error("Transfer passes the transfer restrictions and depends on the policy program")]
+    TransferDependsOnPolicyProgram = 0x17BA,
+    /// 6075 - Token account owner can be changed
+    #[error("Token account owner can be changed")]
+    MutableTokenAccountOwner = 0x17BB,
+    /// 6076 - Holders count overflow
+    #[error("Holders count overflow")]
+    HoldersCountOverflow = 0x17BC,
 }
 
 impl From<TransferRestrictionsError> for solana_program_error::ProgramError {
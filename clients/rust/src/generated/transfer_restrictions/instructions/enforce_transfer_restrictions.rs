@@ -25,18 +25,22 @@ pub struct EnforceTransferRestrictions {
     pub security_associated_account_from: solana_pubkey::Pubkey,
 
     pub security_associated_account_to: solana_pubkey::Pubkey,
-
+    /// Default transfer rule candidates are passed as remaining accounts.
     pub transfer_rule: solana_pubkey::Pubkey,
 }
 
 impl EnforceTransferRestrictions {
-    pub fn instruction(&self) -> solana_instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: EnforceTransferRestrictionsInstructionArgs,
+    ) -> solana_instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: EnforceTransferRestrictionsInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
         let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
@@ -68,9 +72,11 @@ impl EnforceTransferRestrictions {
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = EnforceTransferRestrictionsInstructionData::new()
+        let mut data = EnforceTransferRestrictionsInstructionData::new()
             .try_to_vec()
             .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         solana_instruction::Instruction {
             program_id: crate::TRANSFER_RESTRICTIONS_ID,
@@ -104,6 +110,18 @@ impl Default for EnforceTransferRestrictionsInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnforceTransferRestrictionsInstructionArgs {
+    pub amount: u64,
+}
+
+impl EnforceTransferRestrictionsInstructionArgs {
+    pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
+        borsh::to_vec(self)
+    }
+}
+
 /// Instruction builder for `EnforceTransferRestrictions`.
 ///
 /// ### Accounts:
@@ -124,6 +142,7 @@ pub struct EnforceTransferRestrictionsBuilder {
     security_associated_account_from: Option<solana_pubkey::Pubkey>,
     security_associated_account_to: Option<solana_pubkey::Pubkey>,
     transfer_rule: Option<solana_pubkey::Pubkey>,
+    amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -170,11 +189,17 @@ impl EnforceTransferRestrictionsBuilder {
         self.security_associated_account_to = Some(security_associated_account_to);
         self
     }
+    /// Default transfer rule candidates are passed as remaining accounts.
     #[inline(always)]
     pub fn transfer_rule(&mut self, transfer_rule: solana_pubkey::Pubkey) -> &mut Self {
         self.transfer_rule = Some(transfer_rule);
         self
     }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -209,8 +234,11 @@ impl EnforceTransferRestrictionsBuilder {
                 .expect("security_associated_account_to is not set"),
             transfer_rule: self.transfer_rule.expect("transfer_rule is not set"),
         };
+        let args = EnforceTransferRestrictionsInstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+        };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -227,7 +255,7 @@ pub struct EnforceTransferRestrictionsCpiAccounts<'a, 'b> {
     pub security_associated_account_from: &'b solana_account_info::AccountInfo<'a>,
 
     pub security_associated_account_to: &'b solana_account_info::AccountInfo<'a>,
-
+    /// Default transfer rule candidates are passed as remaining accounts.
     pub transfer_rule: &'b solana_account_info::AccountInfo<'a>,
 }
 
@@ -247,14 +275,17 @@ pub struct EnforceTransferRestrictionsCpi<'a, 'b> {
     pub security_associated_account_from: &'b solana_account_info::AccountInfo<'a>,
 
     pub security_associated_account_to: &'b solana_account_info::AccountInfo<'a>,
-
+    /// Default transfer rule candidates are passed as remaining accounts.
     pub transfer_rule: &'b solana_account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: EnforceTransferRestrictionsInstructionArgs,
 }
 
 impl<'a, 'b> EnforceTransferRestrictionsCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_account_info::AccountInfo<'a>,
         accounts: EnforceTransferRestrictionsCpiAccounts<'a, 'b>,
+        args: EnforceTransferRestrictionsInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -265,6 +296,7 @@ impl<'a, 'b> EnforceTransferRestrictionsCpi<'a, 'b> {
             security_associated_account_from: accounts.security_associated_account_from,
             security_associated_account_to: accounts.security_associated_account_to,
             transfer_rule: accounts.transfer_rule,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -326,9 +358,11 @@ impl<'a, 'b> EnforceTransferRestrictionsCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = EnforceTransferRestrictionsInstructionData::new()
+        let mut data = EnforceTransferRestrictionsInstructionData::new()
             .try_to_vec()
             .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         let instruction = solana_instruction::Instruction {
             program_id: crate::TRANSFER_RESTRICTIONS_ID,
@@ -383,6 +417,7 @@ impl<'a, 'b> EnforceTransferRestrictionsCpiBuilder<'a, 'b> {
             security_associated_account_from: None,
             security_associated_account_to: None,
             transfer_rule: None,
+            amount: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -432,6 +467,7 @@ impl<'a, 'b> EnforceTransferRestrictionsCpiBuilder<'a, 'b> {
         self.instruction.security_associated_account_to = Some(security_associated_account_to);
         self
     }
+    /// Default transfer rule candidates are passed as remaining accounts.
     #[inline(always)]
     pub fn transfer_rule(
         &mut self,
@@ -440,6 +476,11 @@ impl<'a, 'b> EnforceTransferRestrictionsCpiBuilder<'a, 'b> {
         self.instruction.transfer_rule = Some(transfer_rule);
         self
     }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -474,6 +515,9 @@ impl<'a, 'b> EnforceTransferRestrictionsCpiBuilder<'a, 'b> {
     #[allow(clippy::clone_on_copy)]
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
+        let args = EnforceTransferRestrictionsInstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+        };
         let instruction = EnforceTransferRestrictionsCpi {
             __program: self.instruction.__program,
 
@@ -508,6 +552,7 @@ impl<'a, 'b> EnforceTransferRestrictionsCpiBuilder<'a, 'b> {
                 .instruction
                 .transfer_rule
                 .expect("transfer_rule is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -526,6 +571,7 @@ struct EnforceTransferRestrictionsCpiBuilderInstruction<'a, 'b> {
     security_associated_account_from: Option<&'b solana_account_info::AccountInfo<'a>>,
     security_associated_account_to: Option<&'b solana_account_info::AccountInfo<'a>>,
     transfer_rule: Option<&'b solana_account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -30,6 +30,44 @@ pub struct ExecuteTransaction {
     pub security_associated_account_to: solana_pubkey::Pubkey,
 
     pub transfer_rule: solana_pubkey::Pubkey,
+
+    pub blackout_calendar: solana_pubkey::Pubkey,
+
+    pub transfer_restriction_group_from: solana_pubkey::Pubkey,
+
+    pub transfer_restriction_group_to: solana_pubkey::Pubkey,
+
+    pub transfer_restriction_holder_from: solana_pubkey::Pubkey,
+
+    pub transfer_restriction_holder_to: solana_pubkey::Pubkey,
+
+    pub holder_group_from: solana_pubkey::Pubkey,
+
+    pub holder_group_to: solana_pubkey::Pubkey,
+
+    pub transfer_rule_from_any: solana_pubkey::Pubkey,
+
+    pub transfer_rule_to_any: solana_pubkey::Pubkey,
+
+    pub transfer_rule_any: solana_pubkey::Pubkey,
+
+    pub transfer_approval: solana_pubkey::Pubkey,
+
+    pub access_control_program: solana_pubkey::Pubkey,
+
+    pub exempt_account_registry: solana_pubkey::Pubkey,
+
+    pub attestation_issuer_registry: solana_pubkey::Pubkey,
+
+    pub attestation_program: solana_pubkey::Pubkey,
+
+    pub attestation_from: solana_pubkey::Pubkey,
+
+    pub attestation_to: solana_pubkey::Pubkey,
+
+    pub policy_program: solana_pubkey::Pubkey,
+
+    pub policy_config: solana_pubkey::Pubkey,
 }
 
 impl ExecuteTransaction {
@@ -46,7 +84,7 @@ impl ExecuteTransaction {
         args: ExecuteTransactionInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(28 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.source_account,
             false,
@@ -66,15 +104,15 @@ impl ExecuteTransaction {
             self.extra_metas_account,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.transfer_restriction_data,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.security_associated_account_from,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.security_associated_account_to,
             false,
         ));
@@ -82,6 +120,82 @@ impl ExecuteTransaction {
             self.transfer_rule,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.blackout_calendar,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.transfer_restriction_group_from,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.transfer_restriction_group_to,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.transfer_restriction_holder_from,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.transfer_restriction_holder_to,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.holder_group_from,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.holder_group_to,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.transfer_rule_from_any,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.transfer_rule_to_any,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.transfer_rule_any,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.transfer_approval,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.access_control_program,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.exempt_account_registry,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.attestation_issuer_registry,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.attestation_program,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.attestation_from,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.attestation_to,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.policy_program,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.policy_config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ExecuteTransactionInstructionData::new()
             .try_to_vec()
@@ -142,10 +256,29 @@ impl ExecuteTransactionInstructionArgs {
 ///   2. `[]` destination_account
 ///   3. `[]` owner_delegate
 ///   4. `[]` extra_metas_account
-///   5. `[]` transfer_restriction_data
-///   6. `[]` security_associated_account_from
-///   7. `[]` security_associated_account_to
+///   5. `[writable]` transfer_restriction_data
+///   6. `[writable]` security_associated_account_from
+///   7. `[writable]` security_associated_account_to
 ///   8. `[]` transfer_rule
+///   9. `[]` blackout_calendar
+///   10. `[writable]` transfer_restriction_group_from
+///   11. `[writable]` transfer_restriction_group_to
+///   12. `[writable]` transfer_restriction_holder_from
+///   13. `[writable]` transfer_restriction_holder_to
+///   14. `[writable]` holder_group_from
+///   15. `[writable]` holder_group_to
+///   16. `[]` transfer_rule_from_any
+///   17. `[]` transfer_rule_to_any
+///   18. `[]` transfer_rule_any
+///   19. `[writable]` transfer_approval
+///   20. `[optional]` access_control_program (default to `4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3`)
+///   21. `[]` exempt_account_registry
+///   22. `[]` attestation_issuer_registry
+///   23. `[]` attestation_program
+///   24. `[]` attestation_from
+///   25. `[]` attestation_to
+///   26. `[]` policy_program
+///   27. `[]` policy_config
 #[derive(Clone, Debug, Default)]
 pub struct ExecuteTransactionBuilder {
     source_account: Option<solana_pubkey::Pubkey>,
@@ -157,6 +290,25 @@ pub struct ExecuteTransactionBuilder {
     security_associated_account_from: Option<solana_pubkey::Pubkey>,
     security_associated_account_to: Option<solana_pubkey::Pubkey>,
     transfer_rule: Option<solana_pubkey::Pubkey>,
+    blackout_calendar: Option<solana_pubkey::Pubkey>,
+    transfer_restriction_group_from: Option<solana_pubkey::Pubkey>,
+    transfer_restriction_group_to: Option<solana_pubkey::Pubkey>,
+    transfer_restriction_holder_from: Option<solana_pubkey::Pubkey>,
+    transfer_restriction_holder_to: Option<solana_pubkey::Pubkey>,
+    holder_group_from: Option<solana_pubkey::Pubkey>,
+    holder_group_to: Option<solana_pubkey::Pubkey>,
+    transfer_rule_from_any: Option<solana_pubkey::Pubkey>,
+    transfer_rule_to_any: Option<solana_pubkey::Pubkey>,
+    transfer_rule_any: Option<solana_pubkey::Pubkey>,
+    transfer_approval: Option<solana_pubkey::Pubkey>,
+    access_control_program: Option<solana_pubkey::Pubkey>,
+    exempt_account_registry: Option<solana_pubkey::Pubkey>,
+    attestation_issuer_registry: Option<solana_pubkey::Pubkey>,
+    attestation_program: Option<solana_pubkey::Pubkey>,
+    attestation_from: Option<solana_pubkey::Pubkey>,
+    attestation_to: Option<solana_pubkey::Pubkey>,
+    policy_program: Option<solana_pubkey::Pubkey>,
+    policy_config: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -220,6 +372,129 @@ impl ExecuteTransactionBuilder {
         self
     }
     #[inline(always)]
+    pub fn blackout_calendar(&mut self, blackout_calendar: solana_pubkey::Pubkey) -> &mut Self {
+        self.blackout_calendar = Some(blackout_calendar);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_restriction_group_from(
+        &mut self,
+        transfer_restriction_group_from: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.transfer_restriction_group_from = Some(transfer_restriction_group_from);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_restriction_group_to(
+        &mut self,
+        transfer_restriction_group_to: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.transfer_restriction_group_to = Some(transfer_restriction_group_to);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_restriction_holder_from(
+        &mut self,
+        transfer_restriction_holder_from: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.transfer_restriction_holder_from = Some(transfer_restriction_holder_from);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_restriction_holder_to(
+        &mut self,
+        transfer_restriction_holder_to: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.transfer_restriction_holder_to = Some(transfer_restriction_holder_to);
+        self
+    }
+    #[inline(always)]
+    pub fn holder_group_from(&mut self, holder_group_from: solana_pubkey::Pubkey) -> &mut Self {
+        self.holder_group_from = Some(holder_group_from);
+        self
+    }
+    #[inline(always)]
+    pub fn holder_group_to(&mut self, holder_group_to: solana_pubkey::Pubkey) -> &mut Self {
+        self.holder_group_to = Some(holder_group_to);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_rule_from_any(
+        &mut self,
+        transfer_rule_from_any: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.transfer_rule_from_any = Some(transfer_rule_from_any);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_rule_to_any(
+        &mut self,
+        transfer_rule_to_any: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.transfer_rule_to_any = Some(transfer_rule_to_any);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_rule_any(&mut self, transfer_rule_any: solana_pubkey::Pubkey) -> &mut Self {
+        self.transfer_rule_any = Some(transfer_rule_any);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_approval(&mut self, transfer_approval: solana_pubkey::Pubkey) -> &mut Self {
+        self.transfer_approval = Some(transfer_approval);
+        self
+    }
+    /// `[optional account, default to '4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3']`
+    #[inline(always)]
+    pub fn access_control_program(
+        &mut self,
+        access_control_program: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.access_control_program = Some(access_control_program);
+        self
+    }
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.exempt_account_registry = Some(exempt_account_registry);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_issuer_registry(
+        &mut self,
+        attestation_issuer_registry: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.attestation_issuer_registry = Some(attestation_issuer_registry);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_program(&mut self, attestation_program: solana_pubkey::Pubkey) -> &mut Self {
+        self.attestation_program = Some(attestation_program);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_from(&mut self, attestation_from: solana_pubkey::Pubkey) -> &mut Self {
+        self.attestation_from = Some(attestation_from);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_to(&mut self, attestation_to: solana_pubkey::Pubkey) -> &mut Self {
+        self.attestation_to = Some(attestation_to);
+        self
+    }
+    #[inline(always)]
+    pub fn policy_program(&mut self, policy_program: solana_pubkey::Pubkey) -> &mut Self {
+        self.policy_program = Some(policy_program);
+        self
+    }
+    #[inline(always)]
+    pub fn policy_config(&mut self, policy_config: solana_pubkey::Pubkey) -> &mut Self {
+        self.policy_config = Some(policy_config);
+        self
+    }
+    #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
         self
@@ -241,27 +516,75 @@ impl ExecuteTransactionBuilder {
     }
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> solana_instruction::Instruction {
-        let accounts = ExecuteTransaction {
-            source_account: self.source_account.expect("source_account is not set"),
-            mint: self.mint.expect("mint is not set"),
-            destination_account: self
-                .destination_account
-                .expect("destination_account is not set"),
-            owner_delegate: self.owner_delegate.expect("owner_delegate is not set"),
-            extra_metas_account: self
-                .extra_metas_account
-                .expect("extra_metas_account is not set"),
-            transfer_restriction_data: self
-                .transfer_restriction_data
-                .expect("transfer_restriction_data is not set"),
-            security_associated_account_from: self
-                .security_associated_account_from
-                .expect("security_associated_account_from is not set"),
-            security_associated_account_to: self
-                .security_associated_account_to
-                .expect("security_associated_account_to is not set"),
-            transfer_rule: self.transfer_rule.expect("transfer_rule is not set"),
-        };
+        let accounts =
+            ExecuteTransaction {
+                source_account: self.source_account.expect("source_account is not set"),
+                mint: self.mint.expect("mint is not set"),
+                destination_account: self
+                    .destination_account
+                    .expect("destination_account is not set"),
+                owner_delegate: self.owner_delegate.expect("owner_delegate is not set"),
+                extra_metas_account: self
+                    .extra_metas_account
+                    .expect("extra_metas_account is not set"),
+                transfer_restriction_data: self
+                    .transfer_restriction_data
+                    .expect("transfer_restriction_data is not set"),
+                security_associated_account_from: self
+                    .security_associated_account_from
+                    .expect("security_associated_account_from is not set"),
+                security_associated_account_to: self
+                    .security_associated_account_to
+                    .expect("security_associated_account_to is not set"),
+                transfer_rule: self.transfer_rule.expect("transfer_rule is not set"),
+                blackout_calendar: self
+                    .blackout_calendar
+                    .expect("blackout_calendar is not set"),
+                transfer_restriction_group_from: self
+                    .transfer_restriction_group_from
+                    .expect("transfer_restriction_group_from is not set"),
+                transfer_restriction_group_to: self
+                    .transfer_restriction_group_to
+                    .expect("transfer_restriction_group_to is not set"),
+                transfer_restriction_holder_from: self
+                    .transfer_restriction_holder_from
+                    .expect("transfer_restriction_holder_from is not set"),
+                transfer_restriction_holder_to: self
+                    .transfer_restriction_holder_to
+                    .expect("transfer_restriction_holder_to is not set"),
+                holder_group_from: self
+                    .holder_group_from
+                    .expect("holder_group_from is not set"),
+                holder_group_to: self.holder_group_to.expect("holder_group_to is not set"),
+                transfer_rule_from_any: self
+                    .transfer_rule_from_any
+                    .expect("transfer_rule_from_any is not set"),
+                transfer_rule_to_any: self
+                    .transfer_rule_to_any
+                    .expect("transfer_rule_to_any is not set"),
+                transfer_rule_any: self
+                    .transfer_rule_any
+                    .expect("transfer_rule_any is not set"),
+                transfer_approval: self
+                    .transfer_approval
+                    .expect("transfer_approval is not set"),
+                access_control_program: self.access_control_program.unwrap_or(
+                    solana_pubkey::pubkey!("4X79YRjz9KNMhdjdxXg2ZNTS3YnMGYdwJkBHnezMJwr3"),
+                ),
+                exempt_account_registry: self
+                    .exempt_account_registry
+                    .expect("exempt_account_registry is not set"),
+                attestation_issuer_registry: self
+                    .attestation_issuer_registry
+                    .expect("attestation_issuer_registry is not set"),
+                attestation_program: self
+                    .attestation_program
+                    .expect("attestation_program is not set"),
+                attestation_from: self.attestation_from.expect("attestation_from is not set"),
+                attestation_to: self.attestation_to.expect("attestation_to is not set"),
+                policy_program: self.policy_program.expect("policy_program is not set"),
+                policy_config: self.policy_config.expect("policy_config is not set"),
+            };
         let args = ExecuteTransactionInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
         };
@@ -289,6 +612,44 @@ pub struct ExecuteTransactionCpiAccounts<'a, 'b> {
     pub security_associated_account_to: &'b solana_account_info::AccountInfo<'a>,
 
     pub transfer_rule: &'b solana_account_info::AccountInfo<'a>,
+
+    pub blackout_calendar: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_restriction_holder_from: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_restriction_holder_to: &'b solana_account_info::AccountInfo<'a>,
+
+    pub holder_group_from: &'b solana_account_info::AccountInfo<'a>,
+
+    pub holder_group_to: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_rule_from_any: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_rule_to_any: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_rule_any: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_approval: &'b solana_account_info::AccountInfo<'a>,
+
+    pub access_control_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub exempt_account_registry: &'b solana_account_info::AccountInfo<'a>,
+
+    pub attestation_issuer_registry: &'b solana_account_info::AccountInfo<'a>,
+
+    pub attestation_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub attestation_from: &'b solana_account_info::AccountInfo<'a>,
+
+    pub attestation_to: &'b solana_account_info::AccountInfo<'a>,
+
+    pub policy_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub policy_config: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `execute_transaction` CPI instruction.
@@ -313,6 +674,44 @@ pub struct ExecuteTransactionCpi<'a, 'b> {
     pub security_associated_account_to: &'b solana_account_info::AccountInfo<'a>,
 
     pub transfer_rule: &'b solana_account_info::AccountInfo<'a>,
+
+    pub blackout_calendar: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_restriction_holder_from: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_restriction_holder_to: &'b solana_account_info::AccountInfo<'a>,
+
+    pub holder_group_from: &'b solana_account_info::AccountInfo<'a>,
+
+    pub holder_group_to: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_rule_from_any: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_rule_to_any: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_rule_any: &'b solana_account_info::AccountInfo<'a>,
+
+    pub transfer_approval: &'b solana_account_info::AccountInfo<'a>,
+
+    pub access_control_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub exempt_account_registry: &'b solana_account_info::AccountInfo<'a>,
+
+    pub attestation_issuer_registry: &'b solana_account_info::AccountInfo<'a>,
+
+    pub attestation_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub attestation_from: &'b solana_account_info::AccountInfo<'a>,
+
+    pub attestation_to: &'b solana_account_info::AccountInfo<'a>,
+
+    pub policy_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub policy_config: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: ExecuteTransactionInstructionArgs,
 }
@@ -334,6 +733,25 @@ impl<'a, 'b> ExecuteTransactionCpi<'a, 'b> {
             security_associated_account_from: accounts.security_associated_account_from,
             security_associated_account_to: accounts.security_associated_account_to,
             transfer_rule: accounts.transfer_rule,
+            blackout_calendar: accounts.blackout_calendar,
+            transfer_restriction_group_from: accounts.transfer_restriction_group_from,
+            transfer_restriction_group_to: accounts.transfer_restriction_group_to,
+            transfer_restriction_holder_from: accounts.transfer_restriction_holder_from,
+            transfer_restriction_holder_to: accounts.transfer_restriction_holder_to,
+            holder_group_from: accounts.holder_group_from,
+            holder_group_to: accounts.holder_group_to,
+            transfer_rule_from_any: accounts.transfer_rule_from_any,
+            transfer_rule_to_any: accounts.transfer_rule_to_any,
+            transfer_rule_any: accounts.transfer_rule_any,
+            transfer_approval: accounts.transfer_approval,
+            access_control_program: accounts.access_control_program,
+            exempt_account_registry: accounts.exempt_account_registry,
+            attestation_issuer_registry: accounts.attestation_issuer_registry,
+            attestation_program: accounts.attestation_program,
+            attestation_from: accounts.attestation_from,
+            attestation_to: accounts.attestation_to,
+            policy_program: accounts.policy_program,
+            policy_config: accounts.policy_config,
             __args: args,
         }
     }
@@ -360,7 +778,7 @@ impl<'a, 'b> ExecuteTransactionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(28 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.source_account.key,
             false,
@@ -381,15 +799,15 @@ impl<'a, 'b> ExecuteTransactionCpi<'a, 'b> {
             *self.extra_metas_account.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.transfer_restriction_data.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.security_associated_account_from.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.security_associated_account_to.key,
             false,
         ));
@@ -397,6 +815,82 @@ impl<'a, 'b> ExecuteTransactionCpi<'a, 'b> {
             *self.transfer_rule.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.blackout_calendar.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.transfer_restriction_group_from.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.transfer_restriction_group_to.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.transfer_restriction_holder_from.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.transfer_restriction_holder_to.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.holder_group_from.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.holder_group_to.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.transfer_rule_from_any.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.transfer_rule_to_any.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.transfer_rule_any.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.transfer_approval.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.access_control_program.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.exempt_account_registry.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.attestation_issuer_registry.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.attestation_program.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.attestation_from.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.attestation_to.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.policy_program.key,
+            false,
+        ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.policy_config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -415,7 +909,7 @@ impl<'a, 'b> ExecuteTransactionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(29 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.source_account.clone());
         account_infos.push(self.mint.clone());
@@ -426,6 +920,25 @@ impl<'a, 'b> ExecuteTransactionCpi<'a, 'b> {
         account_infos.push(self.security_associated_account_from.clone());
         account_infos.push(self.security_associated_account_to.clone());
         account_infos.push(self.transfer_rule.clone());
+        account_infos.push(self.blackout_calendar.clone());
+        account_infos.push(self.transfer_restriction_group_from.clone());
+        account_infos.push(self.transfer_restriction_group_to.clone());
+        account_infos.push(self.transfer_restriction_holder_from.clone());
+        account_infos.push(self.transfer_restriction_holder_to.clone());
+        account_infos.push(self.holder_group_from.clone());
+        account_infos.push(self.holder_group_to.clone());
+        account_infos.push(self.transfer_rule_from_any.clone());
+        account_infos.push(self.transfer_rule_to_any.clone());
+        account_infos.push(self.transfer_rule_any.clone());
+        account_infos.push(self.transfer_approval.clone());
+        account_infos.push(self.access_control_program.clone());
+        account_infos.push(self.exempt_account_registry.clone());
+        account_infos.push(self.attestation_issuer_registry.clone());
+        account_infos.push(self.attestation_program.clone());
+        account_infos.push(self.attestation_from.clone());
+        account_infos.push(self.attestation_to.clone());
+        account_infos.push(self.policy_program.clone());
+        account_infos.push(self.policy_config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -447,10 +960,29 @@ impl<'a, 'b> ExecuteTransactionCpi<'a, 'b> {
 ///   2. `[]` destination_account
 ///   3. `[]` owner_delegate
 ///   4. `[]` extra_metas_account
-///   5. `[]` transfer_restriction_data
-///   6. `[]` security_associated_account_from
-///   7. `[]` security_associated_account_to
+///   5. `[writable]` transfer_restriction_data
+///   6. `[writable]` security_associated_account_from
+///   7. `[writable]` security_associated_account_to
 ///   8. `[]` transfer_rule
+///   9. `[]` blackout_calendar
+///   10. `[writable]` transfer_restriction_group_from
+///   11. `[writable]` transfer_restriction_group_to
+///   12. `[writable]` transfer_restriction_holder_from
+///   13. `[writable]` transfer_restriction_holder_to
+///   14. `[writable]` holder_group_from
+///   15. `[writable]` holder_group_to
+///   16. `[]` transfer_rule_from_any
+///   17. `[]` transfer_rule_to_any
+///   18. `[]` transfer_rule_any
+///   19. `[writable]` transfer_approval
+///   20. `[]` access_control_program
+///   21. `[]` exempt_account_registry
+///   22. `[]` attestation_issuer_registry
+///   23. `[]` attestation_program
+///   24. `[]` attestation_from
+///   25. `[]` attestation_to
+///   26. `[]` policy_program
+///   27. `[]` policy_config
 #[derive(Clone, Debug)]
 pub struct ExecuteTransactionCpiBuilder<'a, 'b> {
     instruction: Box<ExecuteTransactionCpiBuilderInstruction<'a, 'b>>,
@@ -469,6 +1001,25 @@ impl<'a, 'b> ExecuteTransactionCpiBuilder<'a, 'b> {
             security_associated_account_from: None,
             security_associated_account_to: None,
             transfer_rule: None,
+            blackout_calendar: None,
+            transfer_restriction_group_from: None,
+            transfer_restriction_group_to: None,
+            transfer_restriction_holder_from: None,
+            transfer_restriction_holder_to: None,
+            holder_group_from: None,
+            holder_group_to: None,
+            transfer_rule_from_any: None,
+            transfer_rule_to_any: None,
+            transfer_rule_any: None,
+            transfer_approval: None,
+            access_control_program: None,
+            exempt_account_registry: None,
+            attestation_issuer_registry: None,
+            attestation_program: None,
+            attestation_from: None,
+            attestation_to: None,
+            policy_program: None,
+            policy_config: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -544,6 +1095,158 @@ impl<'a, 'b> ExecuteTransactionCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn blackout_calendar(
+        &mut self,
+        blackout_calendar: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.blackout_calendar = Some(blackout_calendar);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_restriction_group_from(
+        &mut self,
+        transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.transfer_restriction_group_from = Some(transfer_restriction_group_from);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_restriction_group_to(
+        &mut self,
+        transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.transfer_restriction_group_to = Some(transfer_restriction_group_to);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_restriction_holder_from(
+        &mut self,
+        transfer_restriction_holder_from: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.transfer_restriction_holder_from = Some(transfer_restriction_holder_from);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_restriction_holder_to(
+        &mut self,
+        transfer_restriction_holder_to: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.transfer_restriction_holder_to = Some(transfer_restriction_holder_to);
+        self
+    }
+    #[inline(always)]
+    pub fn holder_group_from(
+        &mut self,
+        holder_group_from: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_group_from = Some(holder_group_from);
+        self
+    }
+    #[inline(always)]
+    pub fn holder_group_to(
+        &mut self,
+        holder_group_to: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_group_to = Some(holder_group_to);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_rule_from_any(
+        &mut self,
+        transfer_rule_from_any: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.transfer_rule_from_any = Some(transfer_rule_from_any);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_rule_to_any(
+        &mut self,
+        transfer_rule_to_any: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.transfer_rule_to_any = Some(transfer_rule_to_any);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_rule_any(
+        &mut self,
+        transfer_rule_any: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.transfer_rule_any = Some(transfer_rule_any);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_approval(
+        &mut self,
+        transfer_approval: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.transfer_approval = Some(transfer_approval);
+        self
+    }
+    #[inline(always)]
+    pub fn access_control_program(
+        &mut self,
+        access_control_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.access_control_program = Some(access_control_program);
+        self
+    }
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.exempt_account_registry = Some(exempt_account_registry);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_issuer_registry(
+        &mut self,
+        attestation_issuer_registry: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.attestation_issuer_registry = Some(attestation_issuer_registry);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_program(
+        &mut self,
+        attestation_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.attestation_program = Some(attestation_program);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_from(
+        &mut self,
+        attestation_from: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.attestation_from = Some(attestation_from);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_to(
+        &mut self,
+        attestation_to: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.attestation_to = Some(attestation_to);
+        self
+    }
+    #[inline(always)]
+    pub fn policy_program(
+        &mut self,
+        policy_program: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.policy_program = Some(policy_program);
+        self
+    }
+    #[inline(always)]
+    pub fn policy_config(
+        &mut self,
+        policy_config: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.policy_config = Some(policy_config);
+        self
+    }
+    #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
         self
@@ -629,6 +1332,101 @@ impl<'a, 'b> ExecuteTransactionCpiBuilder<'a, 'b> {
                 .instruction
                 .transfer_rule
                 .expect("transfer_rule is not set"),
+
+            blackout_calendar: self
+                .instruction
+                .blackout_calendar
+                .expect("blackout_calendar is not set"),
+
+            transfer_restriction_group_from: self
+                .instruction
+                .transfer_restriction_group_from
+                .expect("transfer_restriction_group_from is not set"),
+
+            transfer_restriction_group_to: self
+                .instruction
+                .transfer_restriction_group_to
+                .expect("transfer_restriction_group_to is not set"),
+
+            transfer_restriction_holder_from: self
+                .instruction
+                .transfer_restriction_holder_from
+                .expect("transfer_restriction_holder_from is not set"),
+
+            transfer_restriction_holder_to: self
+                .instruction
+                .transfer_restriction_holder_to
+                .expect("transfer_restriction_holder_to is not set"),
+
+            holder_group_from: self
+                .instruction
+                .holder_group_from
+                .expect("holder_group_from is not set"),
+
+            holder_group_to: self
+                .instruction
+                .holder_group_to
+                .expect("holder_group_to is not set"),
+
+            transfer_rule_from_any: self
+                .instruction
+                .transfer_rule_from_any
+                .expect("transfer_rule_from_any is not set"),
+
+            transfer_rule_to_any: self
+                .instruction
+                .transfer_rule_to_any
+                .expect("transfer_rule_to_any is not set"),
+
+            transfer_rule_any: self
+                .instruction
+                .transfer_rule_any
+                .expect("transfer_rule_any is not set"),
+
+            transfer_approval: self
+                .instruction
+                .transfer_approval
+                .expect("transfer_approval is not set"),
+
+            access_control_program: self
+                .instruction
+                .access_control_program
+                .expect("access_control_program is not set"),
+
+            exempt_account_registry: self
+                .instruction
+                .exempt_account_registry
+                .expect("exempt_account_registry is not set"),
+
+            attestation_issuer_registry: self
+                .instruction
+                .attestation_issuer_registry
+                .expect("attestation_issuer_registry is not set"),
+
+            attestation_program: self
+                .instruction
+                .attestation_program
+                .expect("attestation_program is not set"),
+
+            attestation_from: self
+                .instruction
+                .attestation_from
+                .expect("attestation_from is not set"),
+
+            attestation_to: self
+                .instruction
+                .attestation_to
+                .expect("attestation_to is not set"),
+
+            policy_program: self
+                .instruction
+                .policy_program
+                .expect("policy_program is not set"),
+
+            policy_config: self
+                .instruction
+                .policy_config
+                .expect("policy_config is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -650,6 +1448,25 @@ struct ExecuteTransactionCpiBuilderInstruction<'a, 'b> {
     security_associated_account_from: Option<&'b solana_account_info::AccountInfo<'a>>,
     security_associated_account_to: Option<&'b solana_account_info::AccountInfo<'a>>,
     transfer_rule: Option<&'b solana_account_info::AccountInfo<'a>>,
+    blackout_calendar: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_restriction_group_from: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_restriction_group_to: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_restriction_holder_from: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_restriction_holder_to: Option<&'b solana_account_info::AccountInfo<'a>>,
+    holder_group_from: Option<&'b solana_account_info::AccountInfo<'a>>,
+    holder_group_to: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_rule_from_any: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_rule_to_any: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_rule_any: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transfer_approval: Option<&'b solana_account_info::AccountInfo<'a>>,
+    access_control_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    attestation_issuer_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    attestation_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    attestation_from: Option<&'b solana_account_info::AccountInfo<'a>>,
+    attestation_to: Option<&'b solana_account_info::AccountInfo<'a>>,
+    policy_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    policy_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -17,9 +17,9 @@ pub struct InitializeTransferRule {
 
     pub transfer_restriction_data: solana_pubkey::Pubkey,
 
-    pub transfer_restriction_group_from: solana_pubkey::Pubkey,
+    pub transfer_restriction_group_from: Option<solana_pubkey::Pubkey>,
 
-    pub transfer_restriction_group_to: solana_pubkey::Pubkey,
+    pub transfer_restriction_group_to: Option<solana_pubkey::Pubkey>,
 
     pub access_control_account: solana_pubkey::Pubkey,
 
@@ -55,14 +55,28 @@ impl InitializeTransferRule {
             self.transfer_restriction_data,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.transfer_restriction_group_from,
-            false,
-        ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.transfer_restriction_group_to,
-            false,
-        ));
+        if let Some(transfer_restriction_group_from) = self.transfer_restriction_group_from {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transfer_restriction_group_from,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TRANSFER_RESTRICTIONS_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restriction_group_to) = self.transfer_restriction_group_to {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transfer_restriction_group_to,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TRANSFER_RESTRICTIONS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.access_control_account,
             false,
@@ -139,8 +153,8 @@ impl InitializeTransferRuleInstructionArgs {
 ///
 ///   0. `[writable]` transfer_rule
 ///   1. `[]` transfer_restriction_data
-///   2. `[]` transfer_restriction_group_from
-///   3. `[]` transfer_restriction_group_to
+///   2. `[optional]` transfer_restriction_group_from
+///   3. `[optional]` transfer_restriction_group_to
 ///   4. `[]` access_control_account
 ///   5. `[]` authority_wallet_role
 ///   6. `[signer]` authority
@@ -180,20 +194,22 @@ impl InitializeTransferRuleBuilder {
         self.transfer_restriction_data = Some(transfer_restriction_data);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn transfer_restriction_group_from(
         &mut self,
-        transfer_restriction_group_from: solana_pubkey::Pubkey,
+        transfer_restriction_group_from: Option<solana_pubkey::Pubkey>,
     ) -> &mut Self {
-        self.transfer_restriction_group_from = Some(transfer_restriction_group_from);
+        self.transfer_restriction_group_from = transfer_restriction_group_from;
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn transfer_restriction_group_to(
         &mut self,
-        transfer_restriction_group_to: solana_pubkey::Pubkey,
+        transfer_restriction_group_to: Option<solana_pubkey::Pubkey>,
     ) -> &mut Self {
-        self.transfer_restriction_group_to = Some(transfer_restriction_group_to);
+        self.transfer_restriction_group_to = transfer_restriction_group_to;
         self
     }
     #[inline(always)]
@@ -265,12 +281,8 @@ impl InitializeTransferRuleBuilder {
             transfer_restriction_data: self
                 .transfer_restriction_data
                 .expect("transfer_restriction_data is not set"),
-            transfer_restriction_group_from: self
-                .transfer_restriction_group_from
-                .expect("transfer_restriction_group_from is not set"),
-            transfer_restriction_group_to: self
-                .transfer_restriction_group_to
-                .expect("transfer_restriction_group_to is not set"),
+            transfer_restriction_group_from: self.transfer_restriction_group_from,
+            transfer_restriction_group_to: self.transfer_restriction_group_to,
             access_control_account: self
                 .access_control_account
                 .expect("access_control_account is not set"),
@@ -305,9 +317,9 @@ pub struct InitializeTransferRuleCpiAccounts<'a, 'b> {
 
     pub transfer_restriction_data: &'b solana_account_info::AccountInfo<'a>,
 
-    pub transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+    pub transfer_restriction_group_from: Option<&'b solana_account_info::AccountInfo<'a>>,
 
-    pub transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+    pub transfer_restriction_group_to: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub access_control_account: &'b solana_account_info::AccountInfo<'a>,
 
@@ -329,9 +341,9 @@ pub struct InitializeTransferRuleCpi<'a, 'b> {
 
     pub transfer_restriction_data: &'b solana_account_info::AccountInfo<'a>,
 
-    pub transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+    pub transfer_restriction_group_from: Option<&'b solana_account_info::AccountInfo<'a>>,
 
-    pub transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+    pub transfer_restriction_group_to: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub access_control_account: &'b solana_account_info::AccountInfo<'a>,
 
@@ -398,14 +410,28 @@ impl<'a, 'b> InitializeTransferRuleCpi<'a, 'b> {
             *self.transfer_restriction_data.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.transfer_restriction_group_from.key,
-            false,
-        ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.transfer_restriction_group_to.key,
-            false,
-        ));
+        if let Some(transfer_restriction_group_from) = self.transfer_restriction_group_from {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transfer_restriction_group_from.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TRANSFER_RESTRICTIONS_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restriction_group_to) = self.transfer_restriction_group_to {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transfer_restriction_group_to.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TRANSFER_RESTRICTIONS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.access_control_account.key,
             false,
@@ -445,8 +471,12 @@ impl<'a, 'b> InitializeTransferRuleCpi<'a, 'b> {
         account_infos.push(self.__program.clone());
         account_infos.push(self.transfer_rule.clone());
         account_infos.push(self.transfer_restriction_data.clone());
-        account_infos.push(self.transfer_restriction_group_from.clone());
-        account_infos.push(self.transfer_restriction_group_to.clone());
+        if let Some(transfer_restriction_group_from) = self.transfer_restriction_group_from {
+            account_infos.push(transfer_restriction_group_from.clone());
+        }
+        if let Some(transfer_restriction_group_to) = self.transfer_restriction_group_to {
+            account_infos.push(transfer_restriction_group_to.clone());
+        }
         account_infos.push(self.access_control_account.clone());
         account_infos.push(self.authority_wallet_role.clone());
         account_infos.push(self.authority.clone());
@@ -470,8 +500,8 @@ impl<'a, 'b> InitializeTransferRuleCpi<'a, 'b> {
 ///
 ///   0. `[writable]` transfer_rule
 ///   1. `[]` transfer_restriction_data
-///   2. `[]` transfer_restriction_group_from
-///   3. `[]` transfer_restriction_group_to
+///   2. `[optional]` transfer_restriction_group_from
+///   3. `[optional]` transfer_restriction_group_to
 ///   4. `[]` access_control_account
 ///   5. `[]` authority_wallet_role
 ///   6. `[signer]` authority
@@ -518,20 +548,22 @@ impl<'a, 'b> InitializeTransferRuleCpiBuilder<'a, 'b> {
         self.instruction.transfer_restriction_data = Some(transfer_restriction_data);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn transfer_restriction_group_from(
         &mut self,
-        transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+        transfer_restriction_group_from: Option<&'b solana_account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.transfer_restriction_group_from = Some(transfer_restriction_group_from);
+        self.instruction.transfer_restriction_group_from = transfer_restriction_group_from;
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn transfer_restriction_group_to(
         &mut self,
-        transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+        transfer_restriction_group_to: Option<&'b solana_account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.transfer_restriction_group_to = Some(transfer_restriction_group_to);
+        self.instruction.transfer_restriction_group_to = transfer_restriction_group_to;
         self
     }
     #[inline(always)]
@@ -647,15 +679,9 @@ impl<'a, 'b> InitializeTransferRuleCpiBuilder<'a, 'b> {
                 .transfer_restriction_data
                 .expect("transfer_restriction_data is not set"),
 
-            transfer_restriction_group_from: self
-                .instruction
-                .transfer_restriction_group_from
-                .expect("transfer_restriction_group_from is not set"),
+            transfer_restriction_group_from: self.instruction.transfer_restriction_group_from,
 
-            transfer_restriction_group_to: self
-                .instruction
-                .transfer_restriction_group_to
-                .expect("transfer_restriction_group_to is not set"),
+            transfer_restriction_group_to: self.instruction.transfer_restriction_group_to,
 
             access_control_account: self
                 .instruction
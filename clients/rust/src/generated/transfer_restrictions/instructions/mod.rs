@@ -5,44 +5,118 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#add_attestation_issuer;
+pub(crate) mod r#add_blackout_window;
+pub(crate) mod r#add_wallet_hold;
+pub(crate) mod r#detect_transfer_restriction;
 pub(crate) mod r#enforce_transfer_restrictions;
 pub(crate) mod r#execute_transaction;
+pub(crate) mod r#initialize_approved_venue;
+pub(crate) mod r#initialize_beneficial_owner_position;
+pub(crate) mod r#initialize_blackout_calendar;
 pub(crate) mod r#initialize_default_security_accounts;
+pub(crate) mod r#initialize_exempt_security_associated_account;
 pub(crate) mod r#initialize_extra_account_meta_list;
 pub(crate) mod r#initialize_holder_group;
 pub(crate) mod r#initialize_security_associated_account;
+pub(crate) mod r#initialize_transfer_approval;
 pub(crate) mod r#initialize_transfer_restriction_group;
 pub(crate) mod r#initialize_transfer_restriction_holder;
 pub(crate) mod r#initialize_transfer_restrictions_data;
 pub(crate) mod r#initialize_transfer_rule;
+pub(crate) mod r#merge_holders;
+pub(crate) mod r#migrate_accounts;
+pub(crate) mod r#migrate_wallets_group;
+pub(crate) mod r#onboard_investors;
 pub(crate) mod r#pause;
+pub(crate) mod r#record_mint_acquisition;
+pub(crate) mod r#register_venue_token_account;
+pub(crate) mod r#remove_attestation_issuer;
+pub(crate) mod r#remove_blackout_window;
+pub(crate) mod r#remove_wallet_hold;
+pub(crate) mod r#revoke_approved_venue;
+pub(crate) mod r#revoke_beneficial_owner_position;
 pub(crate) mod r#revoke_holder;
 pub(crate) mod r#revoke_holder_group;
 pub(crate) mod r#revoke_security_associated_account;
+pub(crate) mod r#revoke_transfer_approval;
+pub(crate) mod r#revoke_transfer_restriction_group;
+pub(crate) mod r#revoke_transfer_rule;
+pub(crate) mod r#revoke_venue_token_account;
 pub(crate) mod r#set_address_permission;
+pub(crate) mod r#set_allow_intra_holder_transfers;
 pub(crate) mod r#set_allow_transfer_rule;
+pub(crate) mod r#set_count_holders_by_balance;
+pub(crate) mod r#set_group_paused;
+pub(crate) mod r#set_holder_frozen;
 pub(crate) mod r#set_holder_group_max;
 pub(crate) mod r#set_holder_max;
+pub(crate) mod r#set_holding_period;
 pub(crate) mod r#set_lockup_escrow_account;
+pub(crate) mod r#set_min_holding;
+pub(crate) mod r#set_omnibus_account;
+pub(crate) mod r#set_policy_program;
+pub(crate) mod r#set_transfer_rule_approval_required;
+pub(crate) mod r#set_transfer_rule_validity;
+pub(crate) mod r#sync_funded_wallet;
+pub(crate) mod r#update_beneficial_owner_position;
+pub(crate) mod r#update_extra_account_meta_list;
 pub(crate) mod r#update_wallet_group;
 
+pub use self::r#add_attestation_issuer::*;
+pub use self::r#add_blackout_window::*;
+pub use self::r#add_wallet_hold::*;
+pub use self::r#detect_transfer_restriction::*;
 pub use self::r#enforce_transfer_restrictions::*;
 pub use self::r#execute_transaction::*;
+pub use self::r#initialize_approved_venue::*;
+pub use self::r#initialize_beneficial_owner_position::*;
+pub use self::r#initialize_blackout_calendar::*;
 pub use self::r#initialize_default_security_accounts::*;
+pub use self::r#initialize_exempt_security_associated_account::*;
 pub use self::r#initialize_extra_account_meta_list::*;
 pub use self::r#initialize_holder_group::*;
 pub use self::r#initialize_security_associated_account::*;
+pub use self::r#initialize_transfer_approval::*;
 pub use self::r#initialize_transfer_restriction_group::*;
 pub use self::r#initialize_transfer_restriction_holder::*;
 pub use self::r#initialize_transfer_restrictions_data::*;
 pub use self::r#initialize_transfer_rule::*;
+pub use self::r#merge_holders::*;
+pub use self::r#migrate_accounts::*;
+pub use self::r#migrate_wallets_group::*;
+pub use self::r#onboard_investors::*;
 pub use self::r#pause::*;
+pub use self::r#record_mint_acquisition::*;
+pub use self::r#register_venue_token_account::*;
+pub use self::r#remove_attestation_issuer::*;
+pub use self::r#remove_blackout_window::*;
+pub use self::r#remove_wallet_hold::*;
+pub use self::r#revoke_approved_venue::*;
+pub use self::r#revoke_beneficial_owner_position::*;
 pub use self::r#revoke_holder::*;
 pub use self::r#revoke_holder_group::*;
 pub use self::r#revoke_security_associated_account::*;
+pub use self::r#revoke_transfer_approval::*;
+pub use self::r#revoke_transfer_restriction_group::*;
+pub use self::r#revoke_transfer_rule::*;
+pub use self::r#revoke_venue_token_account::*;
 pub use self::r#set_address_permission::*;
+pub use self::r#set_allow_intra_holder_transfers::*;
 pub use self::r#set_allow_transfer_rule::*;
+pub use self::r#set_count_holders_by_balance::*;
+pub use self::r#set_group_paused::*;
+pub use self::r#set_holder_frozen::*;
 pub use self::r#set_holder_group_max::*;
 pub use self::r#set_holder_max::*;
+pub use self::r#set_holding_period::*;
 pub use self::r#set_lockup_escrow_account::*;
+pub use self::r#set_min_holding::*;
+pub use self::r#set_omnibus_account::*;
+pub use self::r#set_policy_program::*;
+pub use self::r#set_transfer_rule_approval_required::*;
+pub use self::r#set_transfer_rule_validity::*;
+pub use self::r#sync_funded_wallet::*;
+pub use self::r#update_beneficial_owner_position::*;
+pub use self::r#update_extra_account_meta_list::*;
 pub use self::r#update_wallet_group::*;
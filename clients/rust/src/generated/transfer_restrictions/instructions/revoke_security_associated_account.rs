@@ -64,7 +64,7 @@ impl RevokeSecurityAssociatedAccount {
             self.security_token,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             self.transfer_restriction_data,
             false,
         ));
@@ -135,7 +135,7 @@ impl Default for RevokeSecurityAssociatedAccountInstructionData {
 ///   2. `[writable]` holder
 ///   3. `[writable]` holder_group
 ///   4. `[]` security_token
-///   5. `[]` transfer_restriction_data
+///   5. `[writable]` transfer_restriction_data
 ///   6. `[]` user_wallet
 ///   7. `[]` associated_token_account
 ///   8. `[]` authority_wallet_role
@@ -401,7 +401,7 @@ impl<'a, 'b> RevokeSecurityAssociatedAccountCpi<'a, 'b> {
             *self.security_token.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
+        accounts.push(solana_instruction::AccountMeta::new(
             *self.transfer_restriction_data.key,
             false,
         ));
@@ -477,7 +477,7 @@ impl<'a, 'b> RevokeSecurityAssociatedAccountCpi<'a, 'b> {
 ///   2. `[writable]` holder
 ///   3. `[writable]` holder_group
 ///   4. `[]` security_token
-///   5. `[]` transfer_restriction_data
+///   5. `[writable]` transfer_restriction_data
 ///   6. `[]` user_wallet
 ///   7. `[]` associated_token_account
 ///   8. `[]` authority_wallet_role
@@ -48,6 +48,8 @@ pub struct SetAddressPermission {
     pub authority: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+    /// control program
+    pub exempt_account_registry: solana_pubkey::Pubkey,
 }
 
 impl SetAddressPermission {
@@ -64,7 +66,7 @@ impl SetAddressPermission {
         args: SetAddressPermissionInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.security_associated_account,
             false,
@@ -148,6 +150,10 @@ impl SetAddressPermission {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.exempt_account_registry,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = SetAddressPermissionInstructionData::new()
             .try_to_vec()
@@ -222,6 +228,7 @@ impl SetAddressPermissionInstructionArgs {
 ///   15. `[writable, signer]` payer
 ///   16. `[signer]` authority
 ///   17. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   18. `[]` exempt_account_registry
 #[derive(Clone, Debug, Default)]
 pub struct SetAddressPermissionBuilder {
     security_associated_account: Option<solana_pubkey::Pubkey>,
@@ -242,6 +249,7 @@ pub struct SetAddressPermissionBuilder {
     payer: Option<solana_pubkey::Pubkey>,
     authority: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    exempt_account_registry: Option<solana_pubkey::Pubkey>,
     group_id: Option<u64>,
     frozen: Option<bool>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -376,6 +384,15 @@ impl SetAddressPermissionBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// control program
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.exempt_account_registry = Some(exempt_account_registry);
+        self
+    }
     #[inline(always)]
     pub fn group_id(&mut self, group_id: u64) -> &mut Self {
         self.group_id = Some(group_id);
@@ -443,6 +460,9 @@ impl SetAddressPermissionBuilder {
                 system_program: self
                     .system_program
                     .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+                exempt_account_registry: self
+                    .exempt_account_registry
+                    .expect("exempt_account_registry is not set"),
             };
         let args = SetAddressPermissionInstructionArgs {
             group_id: self.group_id.clone().expect("group_id is not set"),
@@ -490,6 +510,8 @@ pub struct SetAddressPermissionCpiAccounts<'a, 'b> {
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// control program
+    pub exempt_account_registry: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `set_address_permission` CPI instruction.
@@ -532,6 +554,8 @@ pub struct SetAddressPermissionCpi<'a, 'b> {
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// control program
+    pub exempt_account_registry: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: SetAddressPermissionInstructionArgs,
 }
@@ -562,6 +586,7 @@ impl<'a, 'b> SetAddressPermissionCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            exempt_account_registry: accounts.exempt_account_registry,
             __args: args,
         }
     }
@@ -588,7 +613,7 @@ impl<'a, 'b> SetAddressPermissionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.security_associated_account.key,
             false,
@@ -672,6 +697,10 @@ impl<'a, 'b> SetAddressPermissionCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.exempt_account_registry.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -690,7 +719,7 @@ impl<'a, 'b> SetAddressPermissionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(19 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(20 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.security_associated_account.clone());
         account_infos.push(self.transfer_restriction_group_new.clone());
@@ -714,6 +743,7 @@ impl<'a, 'b> SetAddressPermissionCpi<'a, 'b> {
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.exempt_account_registry.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -748,6 +778,7 @@ impl<'a, 'b> SetAddressPermissionCpi<'a, 'b> {
 ///   15. `[writable, signer]` payer
 ///   16. `[signer]` authority
 ///   17. `[]` system_program
+///   18. `[]` exempt_account_registry
 #[derive(Clone, Debug)]
 pub struct SetAddressPermissionCpiBuilder<'a, 'b> {
     instruction: Box<SetAddressPermissionCpiBuilderInstruction<'a, 'b>>,
@@ -775,6 +806,7 @@ impl<'a, 'b> SetAddressPermissionCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            exempt_account_registry: None,
             group_id: None,
             frozen: None,
             __remaining_accounts: Vec::new(),
@@ -921,6 +953,15 @@ impl<'a, 'b> SetAddressPermissionCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// control program
+    #[inline(always)]
+    pub fn exempt_account_registry(
+        &mut self,
+        exempt_account_registry: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.exempt_account_registry = Some(exempt_account_registry);
+        self
+    }
     #[inline(always)]
     pub fn group_id(&mut self, group_id: u64) -> &mut Self {
         self.instruction.group_id = Some(group_id);
@@ -1053,6 +1094,11 @@ impl<'a, 'b> SetAddressPermissionCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            exempt_account_registry: self
+                .instruction
+                .exempt_account_registry
+                .expect("exempt_account_registry is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -1083,6 +1129,7 @@ struct SetAddressPermissionCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    exempt_account_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
     group_id: Option<u64>,
     frozen: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
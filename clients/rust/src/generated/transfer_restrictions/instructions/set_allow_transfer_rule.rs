@@ -17,9 +17,9 @@ pub struct SetAllowTransferRule {
 
     pub transfer_restriction_data: solana_pubkey::Pubkey,
 
-    pub transfer_restriction_group_from: solana_pubkey::Pubkey,
+    pub transfer_restriction_group_from: Option<solana_pubkey::Pubkey>,
 
-    pub transfer_restriction_group_to: solana_pubkey::Pubkey,
+    pub transfer_restriction_group_to: Option<solana_pubkey::Pubkey>,
 
     pub access_control_account: solana_pubkey::Pubkey,
 
@@ -51,14 +51,28 @@ impl SetAllowTransferRule {
             self.transfer_restriction_data,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.transfer_restriction_group_from,
-            false,
-        ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.transfer_restriction_group_to,
-            false,
-        ));
+        if let Some(transfer_restriction_group_from) = self.transfer_restriction_group_from {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transfer_restriction_group_from,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TRANSFER_RESTRICTIONS_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restriction_group_to) = self.transfer_restriction_group_to {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transfer_restriction_group_to,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TRANSFER_RESTRICTIONS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.access_control_account,
             false,
@@ -127,8 +141,8 @@ impl SetAllowTransferRuleInstructionArgs {
 ///
 ///   0. `[writable]` transfer_rule
 ///   1. `[]` transfer_restriction_data
-///   2. `[]` transfer_restriction_group_from
-///   3. `[]` transfer_restriction_group_to
+///   2. `[optional]` transfer_restriction_group_from
+///   3. `[optional]` transfer_restriction_group_to
 ///   4. `[]` access_control_account
 ///   5. `[]` authority_wallet_role
 ///   6. `[writable, signer]` payer
@@ -164,20 +178,22 @@ impl SetAllowTransferRuleBuilder {
         self.transfer_restriction_data = Some(transfer_restriction_data);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn transfer_restriction_group_from(
         &mut self,
-        transfer_restriction_group_from: solana_pubkey::Pubkey,
+        transfer_restriction_group_from: Option<solana_pubkey::Pubkey>,
     ) -> &mut Self {
-        self.transfer_restriction_group_from = Some(transfer_restriction_group_from);
+        self.transfer_restriction_group_from = transfer_restriction_group_from;
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn transfer_restriction_group_to(
         &mut self,
-        transfer_restriction_group_to: solana_pubkey::Pubkey,
+        transfer_restriction_group_to: Option<solana_pubkey::Pubkey>,
     ) -> &mut Self {
-        self.transfer_restriction_group_to = Some(transfer_restriction_group_to);
+        self.transfer_restriction_group_to = transfer_restriction_group_to;
         self
     }
     #[inline(always)]
@@ -238,12 +254,8 @@ impl SetAllowTransferRuleBuilder {
             transfer_restriction_data: self
                 .transfer_restriction_data
                 .expect("transfer_restriction_data is not set"),
-            transfer_restriction_group_from: self
-                .transfer_restriction_group_from
-                .expect("transfer_restriction_group_from is not set"),
-            transfer_restriction_group_to: self
-                .transfer_restriction_group_to
-                .expect("transfer_restriction_group_to is not set"),
+            transfer_restriction_group_from: self.transfer_restriction_group_from,
+            transfer_restriction_group_to: self.transfer_restriction_group_to,
             access_control_account: self
                 .access_control_account
                 .expect("access_control_account is not set"),
@@ -274,9 +286,9 @@ pub struct SetAllowTransferRuleCpiAccounts<'a, 'b> {
 
     pub transfer_restriction_data: &'b solana_account_info::AccountInfo<'a>,
 
-    pub transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+    pub transfer_restriction_group_from: Option<&'b solana_account_info::AccountInfo<'a>>,
 
-    pub transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+    pub transfer_restriction_group_to: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub access_control_account: &'b solana_account_info::AccountInfo<'a>,
 
@@ -294,9 +306,9 @@ pub struct SetAllowTransferRuleCpi<'a, 'b> {
 
     pub transfer_restriction_data: &'b solana_account_info::AccountInfo<'a>,
 
-    pub transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+    pub transfer_restriction_group_from: Option<&'b solana_account_info::AccountInfo<'a>>,
 
-    pub transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+    pub transfer_restriction_group_to: Option<&'b solana_account_info::AccountInfo<'a>>,
 
     pub access_control_account: &'b solana_account_info::AccountInfo<'a>,
 
@@ -357,14 +369,28 @@ impl<'a, 'b> SetAllowTransferRuleCpi<'a, 'b> {
             *self.transfer_restriction_data.key,
             false,
         ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.transfer_restriction_group_from.key,
-            false,
-        ));
-        accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.transfer_restriction_group_to.key,
-            false,
-        ));
+        if let Some(transfer_restriction_group_from) = self.transfer_restriction_group_from {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transfer_restriction_group_from.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TRANSFER_RESTRICTIONS_ID,
+                false,
+            ));
+        }
+        if let Some(transfer_restriction_group_to) = self.transfer_restriction_group_to {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transfer_restriction_group_to.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TRANSFER_RESTRICTIONS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.access_control_account.key,
             false,
@@ -396,8 +422,12 @@ impl<'a, 'b> SetAllowTransferRuleCpi<'a, 'b> {
         account_infos.push(self.__program.clone());
         account_infos.push(self.transfer_rule.clone());
         account_infos.push(self.transfer_restriction_data.clone());
-        account_infos.push(self.transfer_restriction_group_from.clone());
-        account_infos.push(self.transfer_restriction_group_to.clone());
+        if let Some(transfer_restriction_group_from) = self.transfer_restriction_group_from {
+            account_infos.push(transfer_restriction_group_from.clone());
+        }
+        if let Some(transfer_restriction_group_to) = self.transfer_restriction_group_to {
+            account_infos.push(transfer_restriction_group_to.clone());
+        }
         account_infos.push(self.access_control_account.clone());
         account_infos.push(self.authority_wallet_role.clone());
         account_infos.push(self.payer.clone());
@@ -419,8 +449,8 @@ impl<'a, 'b> SetAllowTransferRuleCpi<'a, 'b> {
 ///
 ///   0. `[writable]` transfer_rule
 ///   1. `[]` transfer_restriction_data
-///   2. `[]` transfer_restriction_group_from
-///   3. `[]` transfer_restriction_group_to
+///   2. `[optional]` transfer_restriction_group_from
+///   3. `[optional]` transfer_restriction_group_to
 ///   4. `[]` access_control_account
 ///   5. `[]` authority_wallet_role
 ///   6. `[writable, signer]` payer
@@ -463,20 +493,22 @@ impl<'a, 'b> SetAllowTransferRuleCpiBuilder<'a, 'b> {
         self.instruction.transfer_restriction_data = Some(transfer_restriction_data);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn transfer_restriction_group_from(
         &mut self,
-        transfer_restriction_group_from: &'b solana_account_info::AccountInfo<'a>,
+        transfer_restriction_group_from: Option<&'b solana_account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.transfer_restriction_group_from = Some(transfer_restriction_group_from);
+        self.instruction.transfer_restriction_group_from = transfer_restriction_group_from;
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn transfer_restriction_group_to(
         &mut self,
-        transfer_restriction_group_to: &'b solana_account_info::AccountInfo<'a>,
+        transfer_restriction_group_to: Option<&'b solana_account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.transfer_restriction_group_to = Some(transfer_restriction_group_to);
+        self.instruction.transfer_restriction_group_to = transfer_restriction_group_to;
         self
     }
     #[inline(always)]
@@ -579,15 +611,9 @@ impl<'a, 'b> SetAllowTransferRuleCpiBuilder<'a, 'b> {
                 .transfer_restriction_data
                 .expect("transfer_restriction_data is not set"),
 
-            transfer_restriction_group_from: self
-                .instruction
-                .transfer_restriction_group_from
-                .expect("transfer_restriction_group_from is not set"),
+            transfer_restriction_group_from: self.instruction.transfer_restriction_group_from,
 
-            transfer_restriction_group_to: self
-                .instruction
-                .transfer_restriction_group_to
-                .expect("transfer_restriction_group_to is not set"),
+            transfer_restriction_group_to: self.instruction.transfer_restriction_group_to,
 
             access_control_account: self
                 .instruction
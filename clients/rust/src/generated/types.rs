@@ -9,3 +9,8 @@ pub use crate::generated::tokenlock::types::*;
 // Re-export dividends types
 pub use crate::generated::dividends::types::*;
 
+// Re-export access control types
+pub use crate::generated::access_control::types::*;
+
+// Re-export transfer restrictions types
+pub use crate::generated::transfer_restrictions::types::*;
@@ -89,6 +89,7 @@ const consoleLine = (symbol: string = "*") => console.log(symbol.repeat(50));
     transferRestrictionsProgram,
     mintKeypair.publicKey,
     accessControlHelper.accessControlPubkey,
+    accessControlProgram.programId,
     commitment
   );
   console.log(
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{self, ID as TOKEN_2022_PROGRAM_ID},
+    token_interface::{Mint, TokenAccount},
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::{
+    contexts::common::{WalletRole, DISCRIMINATOR_LEN},
+    AccessControl, ACCESS_CONTROL_SEED,
+};
+
+pub const EXEMPT_ACCOUNT_REGISTRY_SEED: &[u8] = b"exempt_accounts";
+pub const MAX_EXEMPT_ACCOUNTS: usize = 16;
+
+const EXEMPT_ACCOUNTS_COUNT_OFFSET: usize = DISCRIMINATOR_LEN + 32;
+const EXEMPT_ACCOUNTS_OFFSET: usize = EXEMPT_ACCOUNTS_COUNT_OFFSET + 1;
+const EXEMPT_ACCOUNT_REGISTRY_BUMP_OFFSET: usize =
+    EXEMPT_ACCOUNTS_OFFSET + MAX_EXEMPT_ACCOUNTS * ExemptAccount::INIT_SPACE;
+
+/// Exemption semantics of a registered system token account
+#[repr(u8)]
+#[derive(IntoPrimitive, TryFromPrimitive, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExemptAccountKind {
+    // Escrow of a tokenlock instance: transfers from it are validated by the tokenlock program,
+    // it can be minted to without a security associated account and cannot be burned from,
+    // frozen or force transferred
+    TokenlockEscrow = 1,
+    // Dividend or redemption vault: transfers from and to it skip the transfer rules, it cannot be
+    // frozen or force transferred
+    DistributionVault = 2,
+    // Issuer treasury: transfers from it skip the transfer rules, it cannot be frozen
+    Treasury = 3,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct ExemptAccount {
+    pub token_account: Pubkey,
+    pub kind: u8,
+}
+
+/// System token accounts of the security which are exempt from investor transfer restrictions.
+/// Fields are read at fixed offsets by the transfer hook, new fields must be appended.
+#[account]
+#[derive(InitSpace)]
+pub struct ExemptAccountRegistry {
+    pub access_control: Pubkey,
+    pub exempt_accounts_count: u8,
+    pub exempt_accounts: [ExemptAccount; MAX_EXEMPT_ACCOUNTS],
+    pub bump: u8,
+}
+
+impl ExemptAccountRegistry {
+    pub fn find_exempt_account(&self, token_account: &Pubkey) -> Option<usize> {
+        self.exempt_accounts[..self.exempt_accounts_count as usize]
+            .iter()
+            .position(|exempt_account| exempt_account.token_account == *token_account)
+    }
+
+    /// Kind of the token account read from the registry data without deserializing the registry.
+    /// Returns None when the registry is not initialized or the account is not registered.
+    pub fn read_exempt_account_kind(
+        data: &[u8],
+        token_account: &Pubkey,
+    ) -> Option<ExemptAccountKind> {
+        if data.len() <= EXEMPT_ACCOUNT_REGISTRY_BUMP_OFFSET {
+            return None;
+        }
+        let exempt_accounts_count = data[EXEMPT_ACCOUNTS_COUNT_OFFSET] as usize;
+        (0..exempt_accounts_count.min(MAX_EXEMPT_ACCOUNTS))
+            .map(|index| EXEMPT_ACCOUNTS_OFFSET + index * ExemptAccount::INIT_SPACE)
+            .find(|offset| data[*offset..*offset + 32] == token_account.to_bytes())
+            .and_then(|offset| ExemptAccountKind::try_from(data[offset + 32]).ok())
+    }
+
+    /// Canonical PDA bump read from the registry data, 0 when the registry is not initialized
+    pub fn read_bump(data: &[u8]) -> u8 {
+        data.get(EXEMPT_ACCOUNT_REGISTRY_BUMP_OFFSET)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Accounts)]
+pub struct AddExemptAccount<'info> {
+    #[account(init_if_needed, payer = payer, space = DISCRIMINATOR_LEN + ExemptAccountRegistry::INIT_SPACE,
+        seeds = [
+            EXEMPT_ACCOUNT_REGISTRY_SEED,
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub exempt_account_registry: Account<'info, ExemptAccountRegistry>,
+
+    #[account(mut,
+        seeds = [
+        ACCESS_CONTROL_SEED,
+        mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub access_control_account: Account<'info, AccessControl>,
+
+    #[account(
+        mint::token_program = TOKEN_2022_PROGRAM_ID,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = authority_wallet_role.owner == payer.key(),
+        constraint = authority_wallet_role.access_control == access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    #[account(
+        token::mint = mint,
+        token::token_program = token_2022::ID,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: implemented own serialization in order to save compute units, required for
+    /// tokenlock escrow accounts
+    #[account(
+        constraint = *tokenlock_account.owner == tokenlock_accounts::ID,
+    )]
+    pub tokenlock_account: Option<AccountInfo<'info>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
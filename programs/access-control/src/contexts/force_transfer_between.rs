@@ -6,16 +6,16 @@ use anchor_spl::{
 
 use crate::{
     contexts::common::{WalletRole, WALLET_ROLE_PREFIX},
-    AccessControl, ACCESS_CONTROL_SEED,
+    AccessControl, ACCESS_CONTROL_SEED, EXEMPT_ACCOUNT_REGISTRY_SEED,
 };
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct ForceTransferBetween<'info> {
     #[account(mut,
-      associated_token::token_program = token_program,
-      associated_token::mint = security_mint,
-      associated_token::authority = source_authority,
+      token::token_program = token_program,
+      token::mint = security_mint,
+      token::authority = source_authority,
     )]
     pub source_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -25,9 +25,9 @@ pub struct ForceTransferBetween<'info> {
     pub security_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(mut,
-      associated_token::token_program = token_program,
-      associated_token::mint = security_mint,
-      associated_token::authority = destination_authority,
+      token::token_program = token_program,
+      token::mint = security_mint,
+      token::authority = destination_authority,
     )]
     pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -58,4 +58,14 @@ pub struct ForceTransferBetween<'info> {
     /// CHECK: The destination_authority account is the account that owner of the destination_account
     pub destination_authority: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token2022>,
+    /// CHECK: ExemptAccountRegistry, can be uninitialized
+    /// Optional: Required once the exempt account registry has exempt accounts
+    #[account(
+      seeds = [
+        EXEMPT_ACCOUNT_REGISTRY_SEED,
+        security_mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub exempt_account_registry: Option<UncheckedAccount<'info>>,
 }
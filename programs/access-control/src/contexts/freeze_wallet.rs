@@ -3,7 +3,7 @@ use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
 use crate::{
     contexts::common::{WalletRole, WALLET_ROLE_PREFIX},
-    AccessControl, ACCESS_CONTROL_SEED,
+    AccessControl, ACCESS_CONTROL_SEED, EXEMPT_ACCOUNT_REGISTRY_SEED,
 };
 
 
@@ -36,12 +36,22 @@ pub struct FreezeWallet<'info> {
     pub security_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
-        associated_token::token_program = token_program,
-        associated_token::mint = security_mint,
-        associated_token::authority = target_authority,
+        token::token_program = token_program,
+        token::mint = security_mint,
+        token::authority = target_authority,
     )]
     pub target_account: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: The target_authority account is the account that owner of the target_account
     pub target_authority: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token2022>,
+    /// CHECK: ExemptAccountRegistry, can be uninitialized
+    /// Optional: Required once the exempt account registry has exempt accounts
+    #[account(
+      seeds = [
+        EXEMPT_ACCOUNT_REGISTRY_SEED,
+        security_mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub exempt_account_registry: Option<UncheckedAccount<'info>>,
 }
@@ -4,6 +4,7 @@ use anchor_spl::token_interface::{
 };
 
 use crate::contexts::common::{DISCRIMINATOR_LEN, WalletRole, WALLET_ROLE_PREFIX};
+use crate::VersionedAccount;
 
 pub const ACCESS_CONTROL_SEED: &[u8] = b"ac"; // access_control
 
@@ -16,6 +17,24 @@ pub struct AccessControl {
     pub authority: Pubkey,
     pub max_total_supply: u64,
     pub lockup_escrow_account: Option<Pubkey>,
+    // Account layout version, 0 for accounts created before the version was stored
+    pub version: u8,
+    // Accounts in the exempt account registry, the registry can be omitted while it is empty
+    pub exempt_accounts_count: u8,
+    // Space reserved for fields added in later layout versions
+    pub reserved: [u8; 31],
+}
+
+impl VersionedAccount for AccessControl {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -3,7 +3,7 @@ use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
 use crate::{
     contexts::common::{WalletRole, WALLET_ROLE_PREFIX},
-    AccessControl, ACCESS_CONTROL_SEED,
+    AccessControl, ACCESS_CONTROL_SEED, EXEMPT_ACCOUNT_REGISTRY_SEED,
 };
 
 
@@ -37,9 +37,9 @@ pub struct MintSecurities<'info> {
     pub security_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
-        associated_token::token_program = token_program,
-        associated_token::mint = security_mint,
-        associated_token::authority = destination_authority,
+        token::token_program = token_program,
+        token::mint = security_mint,
+        token::authority = destination_authority,
     )]
     pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: The destination_authority account is the account that owner of the destination_account
@@ -48,5 +48,26 @@ pub struct MintSecurities<'info> {
     /// CHECK: Security Associated Account - validated in instruction using transfer hook program ID from mint
     /// This account must be initialized before minting (holder, holder_group, and SAA must exist)
     /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    #[account(mut)]
     pub security_associated_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: ExemptAccountRegistry, can be uninitialized
+    /// Optional: Required when minting to a tokenlock escrow registered in the exempt account registry
+    #[account(
+      seeds = [
+        EXEMPT_ACCOUNT_REGISTRY_SEED,
+        security_mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub exempt_account_registry: Option<UncheckedAccount<'info>>,
+    /// CHECK: Transfer hook program of the mint, records minted tokens as an acquisition lot
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restrictions_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: TransferRestrictionData - validated by the transfer hook program
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restriction_data: Option<UncheckedAccount<'info>>,
+    /// CHECK: TransferRestrictionGroup of the security associated account - validated by the
+    /// transfer hook program
+    /// Optional: Not required when minting to lockup_escrow_account, mandatory otherwise
+    pub transfer_restriction_group: Option<UncheckedAccount<'info>>,
 }
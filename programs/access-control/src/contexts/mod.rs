@@ -30,3 +30,12 @@ pub use set_lockup_escrow_account::*;
 
 pub mod set_max_total_supply;
 pub use set_max_total_supply::*;
+
+pub mod add_exempt_account;
+pub use add_exempt_account::*;
+
+pub mod remove_exempt_account;
+pub use remove_exempt_account::*;
+
+pub mod migrate_access_control;
+pub use migrate_access_control::*;
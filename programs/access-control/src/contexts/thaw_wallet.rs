@@ -36,9 +36,9 @@ pub struct ThawWallet<'info> {
     pub security_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
-        associated_token::token_program = token_program,
-        associated_token::mint = security_mint,
-        associated_token::authority = target_authority,
+        token::token_program = token_program,
+        token::mint = security_mint,
+        token::authority = target_authority,
     )]
     pub target_account: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: The target_authority account is the account that owner of the target_account
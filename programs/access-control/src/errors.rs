@@ -38,4 +38,22 @@ pub enum AccessControlError {
     InvalidAccessControl,
     #[msg("Invalid wallet role account owner")]
     InvalidWalletRoleAccountOwner,
+    #[msg("Invalid exempt account kind")]
+    InvalidExemptAccountKind,
+    #[msg("Token account is already registered as exempt account")]
+    ExemptAccountAlreadyRegistered,
+    #[msg("Exempt accounts limit reached")]
+    ExemptAccountsLimitReached,
+    #[msg("Invalid exempt account index")]
+    InvalidExemptAccountIndex,
+    #[msg("Cannot freeze exempt account")]
+    CannotFreezeExemptAccount,
+    #[msg("Cannot force transfer between exempt accounts")]
+    CantForceTransferBetweenExemptAccounts,
+    #[msg("Transfer restriction accounts are required")]
+    TransferRestrictionAccountsRequired,
+    #[msg("Invalid transfer restrictions program")]
+    InvalidTransferRestrictionsProgram,
+    #[msg("Exempt account registry is required")]
+    ExemptAccountRegistryRequired,
 }
@@ -0,0 +1,62 @@
+use anchor_lang::{prelude::*, solana_program::program_memory::sol_memcmp, Discriminator};
+use tokenlock_accounts::{states::TokenLockData, wrappers::TokenLockDataWrapper};
+
+use crate::{
+    errors::AccessControlError, AddExemptAccount, ExemptAccount, ExemptAccountKind, Roles,
+    MAX_EXEMPT_ACCOUNTS,
+};
+
+pub fn add_exempt_account(ctx: Context<AddExemptAccount>, kind: u8) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+    let exempt_account_kind = ExemptAccountKind::try_from(kind)
+        .map_err(|_| AccessControlError::InvalidExemptAccountKind)?;
+
+    let token_account = ctx.accounts.token_account.key();
+    if exempt_account_kind == ExemptAccountKind::TokenlockEscrow {
+        let tokenlock_account = ctx
+            .accounts
+            .tokenlock_account
+            .as_ref()
+            .ok_or(AccessControlError::IncorrectTokenlockAccount)?;
+        let discriminator = TokenLockData::DISCRIMINATOR;
+        let tokenlock_account_data = tokenlock_account.try_borrow_data()?;
+        if sol_memcmp(discriminator, &tokenlock_account_data, discriminator.len()) != 0 {
+            return Err(AccessControlError::IncorrectTokenlockAccount.into());
+        }
+        if TokenLockDataWrapper::escrow_account(&tokenlock_account_data) != token_account {
+            return Err(AccessControlError::MismatchedEscrowAccount.into());
+        }
+    }
+
+    let exempt_account_registry = &mut ctx.accounts.exempt_account_registry;
+    // registry is created with the first exempt account
+    if exempt_account_registry.access_control == Pubkey::default() {
+        exempt_account_registry.access_control = ctx.accounts.access_control_account.key();
+        exempt_account_registry.bump = ctx.bumps.exempt_account_registry;
+    }
+    if exempt_account_registry
+        .find_exempt_account(&token_account)
+        .is_some()
+    {
+        return Err(AccessControlError::ExemptAccountAlreadyRegistered.into());
+    }
+    let count = exempt_account_registry.exempt_accounts_count as usize;
+    if count >= MAX_EXEMPT_ACCOUNTS {
+        return Err(AccessControlError::ExemptAccountsLimitReached.into());
+    }
+    exempt_account_registry.exempt_accounts[count] = ExemptAccount {
+        token_account,
+        kind,
+    };
+    exempt_account_registry.exempt_accounts_count += 1;
+    ctx.accounts.access_control_account.exempt_accounts_count =
+        exempt_account_registry.exempt_accounts_count;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use crate::{
-    contexts::InitializeAccessControl, update_account_lamports_to_minimum_balance,
-    InitializeAccessControlArgs, Roles,
+    contexts::InitializeAccessControl, update_account_lamports_to_minimum_balance, AccessControl,
+    InitializeAccessControlArgs, Roles, VersionedAccount,
 };
 use anchor_lang::prelude::*;
 
@@ -12,6 +12,7 @@ pub fn initialize(
     access_control.mint = *ctx.accounts.mint.to_account_info().key;
     access_control.authority = *ctx.accounts.authority.to_account_info().key;
     access_control.max_total_supply = args.max_total_supply;
+    access_control.version = AccessControl::VERSION;
 
     ctx.accounts
         .initialize_token_metadata(ctx.program_id, args.name, args.symbol, args.uri)?;
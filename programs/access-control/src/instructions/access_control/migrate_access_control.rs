@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AccessControlError, migrate_account, AccessControl, MigrateAccessControl, Roles,
+};
+
+/// Upgrade the access control account in place to the current layout version
+pub fn migrate_access_control(ctx: Context<MigrateAccessControl>) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+
+    migrate_account::<AccessControl>(
+        &ctx.accounts.access_control_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::AccessControlError, ExemptAccount, RemoveExemptAccount, Roles};
+
+pub fn remove_exempt_account(ctx: Context<RemoveExemptAccount>, index: u8) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+
+    let exempt_account_registry = &mut ctx.accounts.exempt_account_registry;
+    let count = exempt_account_registry.exempt_accounts_count as usize;
+    require!(
+        (index as usize) < count,
+        AccessControlError::InvalidExemptAccountIndex
+    );
+    exempt_account_registry
+        .exempt_accounts
+        .copy_within(index as usize + 1..count, index as usize);
+    exempt_account_registry.exempt_accounts[count - 1] = ExemptAccount::default();
+    exempt_account_registry.exempt_accounts_count -= 1;
+    ctx.accounts.access_control_account.exempt_accounts_count =
+        exempt_account_registry.exempt_accounts_count;
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{burn, Burn};
 
-use crate::{errors::AccessControlError, BurnSecurities, ACCESS_CONTROL_SEED};
+use crate::{
+    errors::AccessControlError, exempt_account_kind, BurnSecurities, ExemptAccountKind,
+    ACCESS_CONTROL_SEED,
+};
 
 pub fn burn_securities(ctx: Context<BurnSecurities>, amount: u64) -> Result<()> {
     if !ctx
@@ -12,7 +15,14 @@ pub fn burn_securities(ctx: Context<BurnSecurities>, amount: u64) -> Result<()>
         return Err(AccessControlError::Unauthorized.into());
     }
 
-    if ctx.accounts.access_control.lockup_escrow_account == Some(ctx.accounts.target_account.key())
+    if exempt_account_kind(
+        &ctx.accounts.access_control,
+        ctx.accounts
+            .exempt_account_registry
+            .as_ref()
+            .map(|exempt_account_registry| exempt_account_registry.as_ref()),
+        &ctx.accounts.target_account.key(),
+    )? == Some(ExemptAccountKind::TokenlockEscrow)
     {
         return Err(AccessControlError::CantBurnSecuritiesWithinLockup.into());
     }
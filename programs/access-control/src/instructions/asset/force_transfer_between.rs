@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
 
-use crate::{errors::AccessControlError, ForceTransferBetween, Roles, ACCESS_CONTROL_SEED};
+use crate::{
+    errors::AccessControlError, exempt_account_kind, ExemptAccountKind, ForceTransferBetween,
+    Roles, ACCESS_CONTROL_SEED,
+};
 
 pub fn force_transfer_beetween<'info>(
     ctx: Context<'_, '_, '_, 'info, ForceTransferBetween<'info>>,
@@ -15,12 +18,26 @@ pub fn force_transfer_beetween<'info>(
         return Err(AccessControlError::Unauthorized.into());
     }
 
-    if ctx.accounts.access_control_account.lockup_escrow_account
-        == Some(ctx.accounts.source_account.key())
-        || ctx.accounts.access_control_account.lockup_escrow_account
-            == Some(ctx.accounts.destination_account.key())
-    {
-        return Err(AccessControlError::CantForceTransferBetweenLockup.into());
+    for token_account in [
+        ctx.accounts.source_account.key(),
+        ctx.accounts.destination_account.key(),
+    ] {
+        match exempt_account_kind(
+            &ctx.accounts.access_control_account,
+            ctx.accounts
+                .exempt_account_registry
+                .as_ref()
+                .map(|exempt_account_registry| exempt_account_registry.as_ref()),
+            &token_account,
+        )? {
+            Some(ExemptAccountKind::TokenlockEscrow) => {
+                return Err(AccessControlError::CantForceTransferBetweenLockup.into());
+            }
+            Some(ExemptAccountKind::DistributionVault) => {
+                return Err(AccessControlError::CantForceTransferBetweenExemptAccounts.into());
+            }
+            Some(ExemptAccountKind::Treasury) | None => {}
+        }
     }
 
     let mint = ctx.accounts.security_mint.to_account_info();
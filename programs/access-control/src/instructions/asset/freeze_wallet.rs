@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{freeze_account, FreezeAccount};
 
-use crate::{errors::AccessControlError, FreezeWallet, ACCESS_CONTROL_SEED};
+use crate::{
+    errors::AccessControlError, exempt_account_kind, ExemptAccountKind, FreezeWallet,
+    ACCESS_CONTROL_SEED,
+};
 
 pub fn freeze_wallet(ctx: Context<FreezeWallet>) -> Result<()> {
     if !ctx
@@ -11,9 +14,19 @@ pub fn freeze_wallet(ctx: Context<FreezeWallet>) -> Result<()> {
     {
         return Err(AccessControlError::Unauthorized.into());
     }
-    if ctx.accounts.access_control.lockup_escrow_account == Some(ctx.accounts.target_account.key())
-    {
-        return Err(AccessControlError::CannotFreezeLockupEscrowAccount.into());
+    match exempt_account_kind(
+        &ctx.accounts.access_control,
+        ctx.accounts
+            .exempt_account_registry
+            .as_ref()
+            .map(|exempt_account_registry| exempt_account_registry.as_ref()),
+        &ctx.accounts.target_account.key(),
+    )? {
+        Some(ExemptAccountKind::TokenlockEscrow) => {
+            return Err(AccessControlError::CannotFreezeLockupEscrowAccount.into());
+        }
+        Some(_) => return Err(AccessControlError::CannotFreezeExemptAccount.into()),
+        None => {}
     }
 
     let mint = ctx.accounts.security_mint.to_account_info();
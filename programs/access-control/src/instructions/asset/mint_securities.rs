@@ -1,15 +1,28 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed},
+};
 use anchor_spl::token_interface::{mint_to, MintTo};
 use anchor_spl::{
     token_2022::spl_token_2022::extension::transfer_hook::TransferHook,
     token_interface::get_mint_extension_data,
 };
+use spl_discriminator::SplDiscriminate;
 
-use crate::{errors::AccessControlError, MintSecurities, ACCESS_CONTROL_SEED};
+use crate::{
+    errors::AccessControlError, exempt_account_kind, ExemptAccountKind, MintSecurities,
+    ACCESS_CONTROL_SEED,
+};
 
 // Security Associated Account prefix from transfer-restrictions program
 const SECURITY_ASSOCIATED_ACCOUNT_PREFIX: &str = "saa";
 
+/// Instruction of the transfer-restrictions program which records minted tokens as an acquisition
+/// lot. The discriminator matches the Anchor instruction `record_mint_acquisition(amount: u64)`.
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("global:record_mint_acquisition")]
+struct RecordMintAcquisitionInstruction;
+
 pub fn mint_securities(ctx: Context<MintSecurities>, amount: u64) -> Result<()> {
     if !ctx
         .accounts
@@ -31,7 +44,7 @@ pub fn mint_securities(ctx: Context<MintSecurities>, amount: u64) -> Result<()>
 
     // Validate SecurityAssociatedAccount is initialized and matches expected PDA
     // Transfer hook program ID is read from the mint's transfer hook extension
-    validate_security_associated_account(&ctx)?;
+    let transfer_hook_program_id = validate_security_associated_account(&ctx)?;
 
     let mint = ctx.accounts.security_mint.to_account_info();
     let accounts = MintTo {
@@ -48,15 +61,31 @@ pub fn mint_securities(ctx: Context<MintSecurities>, amount: u64) -> Result<()>
 
     mint_to(cpi_ctx.with_signer(&[&seeds[..]]), amount)?;
 
+    if let Some(transfer_hook_program_id) = transfer_hook_program_id {
+        record_mint_acquisition(&ctx, &transfer_hook_program_id, amount, &seeds[..])?;
+    }
+
     Ok(())
 }
 
-/// Validates that the SecurityAssociatedAccount is initialized for the destination
-fn validate_security_associated_account(ctx: &Context<MintSecurities>) -> Result<()> {
+/// Validates that the SecurityAssociatedAccount is initialized for the destination and returns
+/// the transfer hook program ID, `None` for escrow destinations which need no SAA
+fn validate_security_associated_account(ctx: &Context<MintSecurities>) -> Result<Option<Pubkey>> {
     // Check if destination is the lockup escrow account
     if let Some(lockup_escrow) = ctx.accounts.access_control.lockup_escrow_account {
         if ctx.accounts.destination_account.key() == lockup_escrow {
-            return Ok(());
+            return Ok(None);
+        }
+    }
+    // Check if destination is a tokenlock escrow registered in the exempt account registry
+    if let Some(exempt_account_registry) = ctx.accounts.exempt_account_registry.as_ref() {
+        if exempt_account_kind(
+            &ctx.accounts.access_control,
+            Some(exempt_account_registry),
+            &ctx.accounts.destination_account.key(),
+        )? == Some(ExemptAccountKind::TokenlockEscrow)
+        {
+            return Ok(None);
         }
     }
 
@@ -93,5 +122,66 @@ fn validate_security_associated_account(ctx: &Context<MintSecurities>) -> Result
         AccessControlError::SecurityAssociatedAccountNotInitialized
     );
 
+    Ok(Some(program_id))
+}
+
+/// Record the minted tokens as an acquisition lot of the destination wallet in the transfer hook
+/// program, which applies the holding period of the wallet group to them
+fn record_mint_acquisition(
+    ctx: &Context<MintSecurities>,
+    transfer_hook_program_id: &Pubkey,
+    amount: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let (
+        Some(transfer_restrictions_program),
+        Some(transfer_restriction_data),
+        Some(transfer_restriction_group),
+        Some(security_associated_account),
+    ) = (
+        ctx.accounts.transfer_restrictions_program.as_ref(),
+        ctx.accounts.transfer_restriction_data.as_ref(),
+        ctx.accounts.transfer_restriction_group.as_ref(),
+        ctx.accounts.security_associated_account.as_ref(),
+    )
+    else {
+        return Err(AccessControlError::TransferRestrictionAccountsRequired.into());
+    };
+    require_keys_eq!(
+        transfer_restrictions_program.key(),
+        *transfer_hook_program_id,
+        AccessControlError::InvalidTransferRestrictionsProgram
+    );
+
+    let account_infos = [
+        ctx.accounts.access_control.to_account_info(),
+        transfer_restriction_data.to_account_info(),
+        ctx.accounts.destination_account.to_account_info(),
+        security_associated_account.to_account_info(),
+        transfer_restriction_group.to_account_info(),
+    ];
+    let mut data = RecordMintAcquisitionInstruction::SPL_DISCRIMINATOR_SLICE.to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    let instruction = Instruction {
+        program_id: *transfer_hook_program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(ctx.accounts.access_control.key(), true),
+            AccountMeta::new_readonly(transfer_restriction_data.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.destination_account.key(), false),
+            AccountMeta::new(security_associated_account.key(), false),
+            AccountMeta::new_readonly(transfer_restriction_group.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &instruction,
+        &[
+            &account_infos[..],
+            &[transfer_restrictions_program.to_account_info()],
+        ]
+        .concat(),
+        &[signer_seeds],
+    )?;
+
     Ok(())
 }
@@ -61,4 +61,16 @@ pub mod access_control {
     pub fn set_max_total_supply(ctx: Context<SetMaxTotalSupply>, max_total_supply: u64) -> Result<()> {
         instructions::access_control::set_max_total_supply(ctx, max_total_supply)
     }
+
+    pub fn add_exempt_account(ctx: Context<AddExemptAccount>, kind: u8) -> Result<()> {
+        instructions::access_control::add_exempt_account(ctx, kind)
+    }
+
+    pub fn remove_exempt_account(ctx: Context<RemoveExemptAccount>, index: u8) -> Result<()> {
+        instructions::access_control::remove_exempt_account(ctx, index)
+    }
+
+    pub fn migrate_access_control(ctx: Context<MigrateAccessControl>) -> Result<()> {
+        instructions::access_control::migrate_access_control(ctx)
+    }
 }
@@ -1,18 +1,35 @@
 use anchor_lang::{
+    error::ErrorCode,
     prelude::Result,
     solana_program::{program::invoke, system_instruction::transfer},
-    Lamports,
+    AccountDeserialize, AccountSerialize, Discriminator, Lamports, Owner, Space,
 };
 
-use crate::{AccountInfo, Rent, SolanaSysvar};
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, errors::AccessControlError, AccessControl, AccountInfo,
+    ExemptAccountKind, ExemptAccountRegistry, Pubkey, Rent, SolanaSysvar,
+};
+
+/// Account with a stored layout version which can be migrated in place to the current layout.
+/// New fields are appended to the layout or taken from the reserved space and must treat zero as
+/// their default value.
+pub trait VersionedAccount {
+    /// Layout version of the accounts created by the current program
+    const VERSION: u8;
 
+    fn version(&self) -> u8;
+
+    fn set_version(&mut self, version: u8);
+}
 
 pub fn update_account_lamports_to_minimum_balance<'info>(
     account: AccountInfo<'info>,
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
 ) -> Result<()> {
-    let extra_lamports = Rent::get()?.minimum_balance(account.data_len()) - account.get_lamports();
+    let extra_lamports = Rent::get()?
+        .minimum_balance(account.data_len())
+        .saturating_sub(account.get_lamports());
     if extra_lamports > 0 {
         invoke(
             &transfer(payer.key, account.key, extra_lamports),
@@ -22,3 +39,61 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     Ok(())
 }
 
+
+/// Exemption kind of the token account. The lockup escrow account of the access control is a
+/// tokenlock escrow, other exempt accounts are read from the exempt account registry. The registry
+/// can be omitted only while it has no exempt accounts.
+pub fn exempt_account_kind(
+    access_control: &AccessControl,
+    exempt_account_registry: Option<&AccountInfo>,
+    token_account: &Pubkey,
+) -> Result<Option<ExemptAccountKind>> {
+    if access_control.lockup_escrow_account == Some(*token_account) {
+        return Ok(Some(ExemptAccountKind::TokenlockEscrow));
+    }
+    let Some(exempt_account_registry) = exempt_account_registry else {
+        if access_control.exempt_accounts_count > 0 {
+            return Err(AccessControlError::ExemptAccountRegistryRequired.into());
+        }
+        return Ok(None);
+    };
+    let exempt_account_registry_data = exempt_account_registry.try_borrow_data()?;
+    Ok(ExemptAccountRegistry::read_exempt_account_kind(
+        &exempt_account_registry_data,
+        token_account,
+    ))
+}
+
+/// Reallocate the account to the current layout of `T`, zero-initializing the fields added since
+/// the account was created, and store the current layout version. The payer funds the rent of
+/// the added space. Accounts which already have the current version are left unchanged.
+pub fn migrate_account<'info, T>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<T>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator + Owner + Space + VersionedAccount,
+{
+    if *account.owner != T::owner() {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+    if !account.try_borrow_data()?.starts_with(T::DISCRIMINATOR) {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    let space = DISCRIMINATOR_LEN + T::INIT_SPACE;
+    if account.data_len() < space {
+        account.resize(space)?;
+        update_account_lamports_to_minimum_balance(
+            account.clone(),
+            payer.clone(),
+            system_program.clone(),
+        )?;
+    }
+    let mut account_data = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    if account_data.version() < T::VERSION {
+        account_data.set_version(T::VERSION);
+        account_data.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    }
+    Ok(account_data)
+}
@@ -0,0 +1,84 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+
+declare_id!("AmTsrP2tSNz42MDhQL6qTrq9ShuG2SVvz5N5xKfaKpDA");
+
+pub const ATTESTATION_PREFIX: &str = "attestation";
+
+/// Minimal attestation issuer with the account layout expected by the attestation issuers of the
+/// transfer restrictions program. It is deployed by the tests only.
+#[program]
+pub mod attestation_stub {
+    use super::*;
+
+    /// Attest the wallet under the schema until `expires_at`, 0 when the attestation does not
+    /// expire. The wallet has a single attestation, which only its issuer can renew.
+    pub fn attest(
+        ctx: Context<Attest>,
+        schema: Pubkey,
+        wallet: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        let attestation = &mut ctx.accounts.attestation;
+        if attestation.issuer == Pubkey::default() {
+            attestation.issuer = ctx.accounts.issuer.key();
+        }
+        require_keys_eq!(
+            attestation.issuer,
+            ctx.accounts.issuer.key(),
+            AttestationStubError::Unauthorized
+        );
+        attestation.schema = schema;
+        attestation.wallet = wallet;
+        attestation.expires_at = expires_at;
+        attestation.revoked = false;
+
+        Ok(())
+    }
+
+    pub fn revoke(ctx: Context<Revoke>) -> Result<()> {
+        ctx.accounts.attestation.revoked = true;
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    pub issuer: Pubkey,
+    pub schema: Pubkey,
+    pub wallet: Pubkey,
+    pub expires_at: i64,
+    pub revoked: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(schema: Pubkey, wallet: Pubkey)]
+pub struct Attest<'info> {
+    #[account(init_if_needed, payer = issuer, space = 8 + Attestation::INIT_SPACE,
+      seeds = [
+        ATTESTATION_PREFIX.as_bytes(),
+        wallet.as_ref(),
+      ],
+      bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    #[account(mut, has_one = issuer @ AttestationStubError::Unauthorized)]
+    pub attestation: Account<'info, Attestation>,
+    pub issuer: Signer<'info>,
+}
+
+#[error_code]
+pub enum AttestationStubError {
+    #[msg("Signer is not the issuer of the attestation")]
+    Unauthorized,
+}
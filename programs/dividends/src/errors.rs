@@ -36,4 +36,8 @@ pub enum DividendsErrorCode {
     NoPendingOwnershipTransfer,
     #[msg("Unauthorized ownership transfer")]
     UnauthorizedOwnershipTransfer,
+    #[msg("Access control is required to distribute the security token")]
+    AccessControlRequired,
+    #[msg("Distributor token account is not a distribution vault of the security token")]
+    DistributorAccountNotExempt,
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
-use anchor_lang::prelude::*;
+use access_control::{
+    exempt_account_kind, AccessControl, ExemptAccountKind, EXEMPT_ACCOUNT_REGISTRY_SEED,
+};
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
 use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
@@ -45,6 +48,23 @@ pub struct FundDividends<'info> {
 
     /// SPL [Token] program.
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Access Control of the security token.
+    /// Optional: Required when the distributed mint is the security token.
+    #[account(address = distributor.access_control)]
+    pub access_control: Option<Box<Account<'info, AccessControl>>>,
+
+    /// CHECK: access_control::ExemptAccountRegistry, can be uninitialized
+    /// Optional: Required when the distributed mint is the security token.
+    #[account(
+        seeds = [
+            EXEMPT_ACCOUNT_REGISTRY_SEED,
+            &mint.key().to_bytes(),
+        ],
+        bump,
+        seeds::program = access_control::ID,
+    )]
+    pub exempt_account_registry: Option<UncheckedAccount<'info>>,
 }
 
 // anyone can fund the distributor
@@ -66,6 +86,27 @@ pub fn fund_dividends<'info>(
     let mint_data = &ctx.accounts.mint.to_account_info();
     validate_transfer_fee_mint_extension(mint_data)?;
 
+    // Security token distributions are paid from a distribution vault of the exempt account
+    // registry, other token accounts of the security are subject to the investor restrictions
+    if ctx.accounts.mint.mint_authority == COption::Some(ctx.accounts.distributor.access_control) {
+        let access_control = ctx
+            .accounts
+            .access_control
+            .as_ref()
+            .ok_or(DividendsErrorCode::AccessControlRequired)?;
+        require!(
+            exempt_account_kind(
+                access_control,
+                ctx.accounts
+                    .exempt_account_registry
+                    .as_ref()
+                    .map(|exempt_account_registry| exempt_account_registry.as_ref()),
+                &ctx.accounts.to.key(),
+            )? == Some(ExemptAccountKind::DistributionVault),
+            DividendsErrorCode::DistributorAccountNotExempt
+        );
+    }
+
     let distributor = &mut ctx.accounts.distributor;
     let treasury_amount_before = ctx.accounts.to.amount;
 
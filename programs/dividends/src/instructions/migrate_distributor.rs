@@ -0,0 +1,48 @@
+use access_control::{
+    migrate_account, program::AccessControl as AccessControlProgram, AccessControl, WalletRole,
+};
+use anchor_lang::prelude::*;
+
+use crate::{errors::DividendsErrorCode, MerkleDistributor};
+
+/// Accounts for [dividends::migrate_distributor].
+#[derive(Accounts)]
+pub struct MigrateDistributor<'info> {
+    /// [MerkleDistributor], can have an older layout.
+    /// CHECK: migrated and checked against the access control by the instruction
+    #[account(mut)]
+    pub distributor: UncheckedAccount<'info>,
+
+    /// Access Control for Security Token.
+    #[account(owner = AccessControlProgram::id())]
+    pub access_control: Account<'info, AccessControl>,
+
+    /// Authority wallet role to migrate the distributor.
+    #[account(
+        constraint = authority_wallet_role.owner == payer.key(),
+        constraint = authority_wallet_role.has_any_role(access_control::Roles::ContractAdmin as u8) @ DividendsErrorCode::Unauthorized,
+        constraint = authority_wallet_role.access_control == access_control.key(),
+        owner = AccessControlProgram::id(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    /// Payer of the rent for the added space.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_distributor(ctx: Context<MigrateDistributor>) -> Result<()> {
+    let distributor = migrate_account::<MerkleDistributor>(
+        &ctx.accounts.distributor.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    if distributor.access_control != ctx.accounts.access_control.key() {
+        return Err(DividendsErrorCode::Unauthorized.into());
+    }
+
+    Ok(())
+}
@@ -19,5 +19,8 @@ pub use accept_reclaimer_ownership::*;
 pub mod reclaim_dividends;
 pub use reclaim_dividends::*;
 
+pub mod migrate_distributor;
+pub use migrate_distributor::*;
+
 mod helpers;
 pub use helpers::*;
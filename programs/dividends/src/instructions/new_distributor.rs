@@ -1,5 +1,6 @@
 use access_control::{
-    program::AccessControl as AccessControlProgram, AccessControl, WalletRole, ACCESS_CONTROL_SEED,
+    program::AccessControl as AccessControlProgram, AccessControl, VersionedAccount, WalletRole,
+    ACCESS_CONTROL_SEED,
 };
 use anchor_lang::{prelude::*, solana_program::program_option::COption};
 use anchor_spl::{token_2022::ID as TOKEN_2022_PROGRAM_ID, token_interface::Mint};
@@ -91,6 +92,7 @@ pub fn new_distributor(
 
     distributor.base = ctx.accounts.base.key();
     distributor.bump = ctx.bumps.distributor;
+    distributor.version = MerkleDistributor::VERSION;
 
     distributor.root = root;
     distributor.mint = ctx.accounts.mint.key();
@@ -80,4 +80,9 @@ pub mod dividends {
     ) -> Result<()> {
         instructions::reclaim_dividends(ctx, _bump, index, amount, proof)
     }
+
+    /// Upgrades the [MerkleDistributor] in place to the current layout version.
+    pub fn migrate_distributor(ctx: Context<MigrateDistributor>) -> Result<()> {
+        instructions::migrate_distributor(ctx)
+    }
 }
@@ -1,3 +1,4 @@
+use access_control::VersionedAccount;
 use anchor_lang::prelude::*;
 
 pub const MAX_IPFS_HASH_LEN: usize = 64;
@@ -32,4 +33,20 @@ pub struct MerkleDistributor {
     /// IPFS hash of the serialized merkle tree.
     #[max_len(MAX_IPFS_HASH_LEN)]
     pub ipfs_hash: String,
+    /// Account layout version, 0 for accounts created before the version was stored.
+    pub version: u8,
+    /// Space reserved for fields added in later layout versions.
+    pub reserved: [u8; 32],
+}
+
+impl VersionedAccount for MerkleDistributor {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
@@ -0,0 +1,130 @@
+#![allow(unexpected_cfgs)]
+
+use access_control::{AccessControl, Roles, WalletRole, ACCESS_CONTROL_SEED};
+use anchor_lang::prelude::*;
+
+declare_id!("A58bawYSTiMgYQLzYxYhisZ6uAeNegGdLHVosXXFQvwx");
+
+pub const POLICY_CONFIG_PREFIX: &str = "policy";
+
+/// Sample policy program of the transfer restrictions transfer hook which limits the amount of a
+/// single transfer of the security. It is deployed by the tests only.
+#[program]
+pub mod sample_policy {
+    use super::*;
+
+    /// Create the policy config of the security, the Contract Admin of the security becomes the
+    /// authority of the config
+    pub fn initialize_policy_config(
+        ctx: Context<InitializePolicyConfig>,
+        max_transfer_amount: u64,
+    ) -> Result<()> {
+        let policy_config = &mut ctx.accounts.policy_config;
+        policy_config.authority = ctx.accounts.authority.key();
+        policy_config.max_transfer_amount = max_transfer_amount;
+        policy_config.bump = ctx.bumps.policy_config;
+
+        Ok(())
+    }
+
+    pub fn set_max_transfer_amount(
+        ctx: Context<SetMaxTransferAmount>,
+        max_transfer_amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.policy_config.max_transfer_amount = max_transfer_amount;
+
+        Ok(())
+    }
+
+    /// Called by the transfer hook after the built-in transfer restriction checks
+    pub fn check_transfer(ctx: Context<CheckTransfer>, amount: u64) -> Result<()> {
+        require_gte!(
+            ctx.accounts.policy_config.max_transfer_amount,
+            amount,
+            SamplePolicyError::TransferAmountExceedsPolicy
+        );
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PolicyConfig {
+    pub authority: Pubkey,
+    pub max_transfer_amount: u64,
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct InitializePolicyConfig<'info> {
+    #[account(init, payer = authority, space = 8 + PolicyConfig::INIT_SPACE,
+      seeds = [
+        POLICY_CONFIG_PREFIX.as_bytes(),
+        mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+    /// CHECK: security mint the policy is configured for
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        mint.key().as_ref(),
+      ],
+      bump,
+      seeds::program = access_control::ID,
+    )]
+    pub access_control: Account<'info, AccessControl>,
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == access_control.key(),
+      constraint = authority_wallet_role.has_role(Roles::ContractAdmin) @ SamplePolicyError::Unauthorized,
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxTransferAmount<'info> {
+    #[account(mut, has_one = authority @ SamplePolicyError::Unauthorized)]
+    pub policy_config: Account<'info, PolicyConfig>,
+    pub authority: Signer<'info>,
+}
+
+/// Accounts passed by the transfer hook, in the order of the transfer restrictions policy
+/// interface
+#[derive(Accounts)]
+pub struct CheckTransfer<'info> {
+    /// CHECK: source token account
+    pub source_account: UncheckedAccount<'info>,
+    /// CHECK: security mint
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: destination token account
+    pub destination_account: UncheckedAccount<'info>,
+    /// CHECK: owner or delegate of the source token account
+    pub owner_delegate: UncheckedAccount<'info>,
+    /// CHECK: security associated account of the source token account
+    pub security_associated_account_from: UncheckedAccount<'info>,
+    /// CHECK: security associated account of the destination token account
+    pub security_associated_account_to: UncheckedAccount<'info>,
+    #[account(
+      seeds = [
+        POLICY_CONFIG_PREFIX.as_bytes(),
+        mint.key().as_ref(),
+      ],
+      bump = policy_config.bump,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+}
+
+#[error_code]
+pub enum SamplePolicyError {
+    #[msg("Signer is not authorized to manage the policy config")]
+    Unauthorized,
+    #[msg("Transfer amount exceeds the policy limit")]
+    TransferAmountExceedsPolicy,
+}
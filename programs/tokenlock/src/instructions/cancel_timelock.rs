@@ -133,6 +133,8 @@ pub fn cancel_timelock<'info>(
             .to_account_info(),
         ctx.accounts.transfer_rule.to_account_info(),
         ctx.accounts.transfer_restrictions_program.to_account_info(),
+        ctx.remaining_accounts,
+        canceled_amount,
     )?;
 
     let split_at_pos = ctx.remaining_accounts.len() / 2;
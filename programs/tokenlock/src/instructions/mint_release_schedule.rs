@@ -88,6 +88,10 @@ pub struct MintReleaseSchedule<'info> {
     pub access_control_program: Program<'info, AccessControlProgram>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: access control exempt account registry, verified by the access control program.
+    /// Required when the escrow account is registered in the exempt account registry
+    pub exempt_account_registry: Option<UncheckedAccount<'info>>,
 }
 
 pub fn mint_release_schedule<'info>(
@@ -231,6 +235,7 @@ pub fn mint_release_schedule<'info>(
         &ctx.accounts.escrow_account_owner,
         &ctx.accounts.token_program,
         &ctx.accounts.access_control_program,
+        &ctx.accounts.exempt_account_registry,
         amount,
     )?;
 
@@ -264,6 +269,7 @@ fn mint_securities_cpi<'info>(
     escrow_account_owner: &AccountInfo<'info>,
     token_program: &Program<'info, Token2022>,
     access_control_program: &Program<'info, AccessControlProgram>,
+    exempt_account_registry: &Option<UncheckedAccount<'info>>,
     amount: u64,
 ) -> Result<()> {
     #[cfg(not(test))]
@@ -277,6 +283,12 @@ fn mint_securities_cpi<'info>(
             destination_authority: escrow_account_owner.to_account_info(),
             token_program: token_program.to_account_info(),
             security_associated_account: None,
+            exempt_account_registry: exempt_account_registry
+                .as_ref()
+                .map(|exempt_account_registry| exempt_account_registry.to_account_info()),
+            transfer_restrictions_program: None,
+            transfer_restriction_data: None,
+            transfer_restriction_group: None,
         };
 
         access_control::cpi::mint_securities(
@@ -155,6 +155,8 @@ pub fn transfer<'info>(
             .to_account_info(),
         ctx.accounts.transfer_rule.to_account_info(),
         ctx.accounts.transfer_restrictions_program.to_account_info(),
+        ctx.remaining_accounts,
+        value,
     )?;
 
     transfer_spl_from_escrow(
@@ -122,6 +122,8 @@ pub fn transfer_timelock<'info>(
             .to_account_info(),
         ctx.accounts.transfer_rule.to_account_info(),
         ctx.accounts.transfer_restrictions_program.to_account_info(),
+        ctx.remaining_accounts,
+        value,
     )?;
 
     transfer_spl_from_escrow(
@@ -322,6 +322,7 @@ impl<'a> TestFixture {
             escrow_account_owner: pda_account_info,
             payer: Signer::try_from(authority_info)?,
             system_program: Program::try_from(system_program_info)?,
+            exempt_account_registry: None,
         })
     }
 
@@ -39,6 +39,8 @@ pub fn enforce_transfer_restrictions_cpi<'info>(
     security_associated_account_to_info: AccountInfo<'info>,
     transfer_rule_info: AccountInfo<'info>,
     transfer_restrictions_program_info: AccountInfo<'info>,
+    default_transfer_rules: &[AccountInfo<'info>],
+    amount: u64,
 ) -> Result<()> {
     #[cfg(not(test))]
     {
@@ -51,10 +53,13 @@ pub fn enforce_transfer_restrictions_cpi<'info>(
             security_associated_account_to: security_associated_account_to_info,
             transfer_rule: transfer_rule_info,
         };
-        transfer_restrictions::cpi::enforce_transfer_restrictions(CpiContext::new(
-            transfer_restrictions_program_info,
-            cpi_accounts,
-        ))?;
+        // default transfer rules, the recipient group and attestation are resolved from the
+        // transfer hook extra accounts
+        transfer_restrictions::cpi::enforce_transfer_restrictions(
+            CpiContext::new(transfer_restrictions_program_info, cpi_accounts)
+                .with_remaining_accounts(default_transfer_rules.to_vec()),
+            amount,
+        )?;
     }
 
     #[cfg(test)]
@@ -64,7 +69,8 @@ pub fn enforce_transfer_restrictions_cpi<'info>(
         // Prevent unused variable warnings
         let _ = (authority_account_info, mint_address_info, to_info, transfer_restrictions_data,
                  security_associated_account_from_info, security_associated_account_to_info, 
-                 transfer_rule_info, transfer_restrictions_program_info);
+                 transfer_rule_info, transfer_restrictions_program_info, default_transfer_rules,
+                 amount);
     }
 
     Ok(())
@@ -0,0 +1,126 @@
+use access_control::{VersionedAccount, WalletRole};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, META_LIST_ACCOUNT_SEED,
+    TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+pub const ATTESTATION_ISSUER_REGISTRY_PREFIX: &str = "air"; // attestation issuer registry
+// Attestation accounts are PDAs of the issuer program derived from this prefix and the wallet, the
+// issuer program keeps a single attestation per wallet
+pub const ATTESTATION_PREFIX: &str = "attestation";
+pub const MAX_ATTESTATION_ISSUERS: usize = 2;
+
+/// Positions of the attested fields in the attestation accounts published by the issuer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, Debug, PartialEq)]
+pub struct AttestationLayout {
+    pub discriminator: [u8; 8],
+    pub schema_offset: u16,
+    pub wallet_offset: u16,
+    // Position of the i64 unix timestamp the attestation expires at, 0 when it does not expire
+    pub expires_at_offset: u16,
+    // Position of the flag which is non-zero once the issuer revoked the attestation
+    pub revoked_offset: u16,
+}
+
+/// Third-party verifier trusted to attest wallets of the security
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, Debug)]
+pub struct AttestationIssuer {
+    pub program_id: Pubkey,
+    pub schema: Pubkey,
+    pub layout: AttestationLayout,
+}
+
+impl AttestationIssuer {
+    /// Whether the attestation account data attests the wallet under the issuer schema and is
+    /// neither expired nor revoked
+    pub fn attests(&self, data: &[u8], wallet: &Pubkey, now: u64) -> bool {
+        let field = |offset: u16, len: usize| data.get(offset as usize..offset as usize + len);
+        let expires_at = field(self.layout.expires_at_offset, 8)
+            .map(|bytes| i64::from_le_bytes(bytes.try_into().unwrap()));
+        data.starts_with(&self.layout.discriminator)
+            && field(self.layout.schema_offset, 32) == Some(self.schema.as_ref())
+            && field(self.layout.wallet_offset, 32) == Some(wallet.as_ref())
+            && field(self.layout.revoked_offset, 1) == Some(&[0])
+            && expires_at.is_some_and(|expires_at| expires_at == 0 || (now as i64) < expires_at)
+    }
+}
+
+/// Attestation issuers trusted by the security. Once an issuer is registered, wallets with a
+/// holder need a valid attestation of one of the issuers to send and receive tokens. The issuers
+/// share the attestation program and are told apart by their schema.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct AttestationIssuerRegistry {
+    pub transfer_restriction_data: Pubkey,
+    pub issuers_count: u8,
+    pub issuers: [AttestationIssuer; MAX_ATTESTATION_ISSUERS],
+    pub bump: u8,
+    // Account layout version, 0 for accounts created before the version was stored
+    pub version: u8,
+    // Space reserved for fields added in later layout versions
+    pub reserved: [u8; 32],
+}
+
+impl AttestationIssuerRegistry {
+    pub fn active_issuers(&self) -> &[AttestationIssuer] {
+        &self.issuers[..self.issuers_count as usize]
+    }
+}
+
+impl VersionedAccount for AttestationIssuerRegistry {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+#[derive(Accounts)]
+pub struct AddAttestationIssuer<'info> {
+    #[account(init_if_needed, payer = payer, space = DISCRIMINATOR_LEN + AttestationIssuerRegistry::INIT_SPACE,
+      seeds = [
+        ATTESTATION_ISSUER_REGISTRY_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub attestation_issuer_registry: Account<'info, AttestationIssuerRegistry>,
+    /// CHECK: extra metas account, rewritten with the attestation accounts of the issuers
+    #[account(
+      mut,
+      seeds = [
+        META_LIST_ACCOUNT_SEED,
+        security_token.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      constraint = authority_wallet_role.owner == payer.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1 +1,20 @@
+use anchor_lang::prelude::*;
+use num_enum::IntoPrimitive;
+
 pub const DISCRIMINATOR_LEN: usize = 8;
+
+#[repr(u8)]
+#[derive(IntoPrimitive, AnchorDeserialize, AnchorSerialize, Clone, InitSpace, Copy, Debug)]
+pub enum TransferDirection {
+    Send = 1,    // 01
+    Receive = 2, // 10
+    Both = 3,    // 11
+}
+
+pub fn is_valid_transfer_direction(direction: u8) -> bool {
+    direction != 0 && direction & !(TransferDirection::Both as u8) == 0
+}
+
+pub fn blocks_direction(direction: u8, blocked: TransferDirection) -> bool {
+    direction & blocked as u8 != 0
+}
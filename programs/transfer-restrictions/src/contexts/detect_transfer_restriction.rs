@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+/// Reason code returned when the transfer is not restricted
+pub const TRANSFER_RESTRICTION_SUCCESS_CODE: u32 = 0;
+pub const TRANSFER_RESTRICTION_SUCCESS_MESSAGE: &str = "Success";
+
+/// Result of the transfer restriction detection.
+/// `code` is the error code the transfer hook would fail with or 0 when the transfer is allowed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TransferRestrictionDetection {
+    pub code: u32,
+    pub message: String,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DetectTransferRestriction<'info> {
+    #[account(
+      token::mint = mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub source_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      token::mint = mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionData>>
+    pub transfer_restriction_data: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, SecurityAssociatedAccount>>, can be uninitialized
+    pub security_associated_account_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, SecurityAssociatedAccount>>, can be uninitialized
+    pub security_associated_account_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, can be uninitialized when a default rule applies
+    pub transfer_rule: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, BlackoutCalendar>>, can be uninitialized
+    pub blackout_calendar: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionGroup>>
+    pub transfer_restriction_group_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionGroup>>
+    pub transfer_restriction_group_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionHolder>>, can be uninitialized when wallet has no holder
+    pub transfer_restriction_holder_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionHolder>>, can be uninitialized when wallet has no holder
+    pub transfer_restriction_holder_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, HolderGroup>>, can be uninitialized when wallet has no holder
+    pub holder_group_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, HolderGroup>>, can be uninitialized when wallet has no holder
+    pub holder_group_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, default rule from the sender group, can be uninitialized
+    pub transfer_rule_from_any: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, default rule to the receiver group, can be uninitialized
+    pub transfer_rule_to_any: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, security-wide default rule, can be uninitialized
+    pub transfer_rule_any: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferApproval>>, can be uninitialized when no approval is required
+    pub transfer_approval: UncheckedAccount<'info>,
+
+    pub access_control_program: Program<'info, access_control::program::AccessControl>,
+
+    /// CHECK: access_control::ExemptAccountRegistry, can be uninitialized
+    pub exempt_account_registry: UncheckedAccount<'info>,
+
+    /// CHECK: AttestationIssuerRegistry, can be uninitialized when attestations are not required
+    pub attestation_issuer_registry: UncheckedAccount<'info>,
+
+    /// CHECK: program of the attestation issuers, system program when no issuer is registered
+    pub attestation_program: UncheckedAccount<'info>,
+
+    /// CHECK: attestation of the source wallet, checked against the layouts of the issuers
+    pub attestation_from: UncheckedAccount<'info>,
+
+    /// CHECK: attestation of the destination wallet, checked against the layouts of the issuers
+    pub attestation_to: UncheckedAccount<'info>,
+}
@@ -1,9 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
-use crate::{
-    SecurityAssociatedAccount, TransferRestrictionData, TransferRule, TRANSFER_RULE_PREFIX,
-};
+use crate::{SecurityAssociatedAccount, TransferRestrictionData};
 
 use super::{SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX};
 
@@ -53,14 +51,7 @@ pub struct EnforceTransferRestrictions<'info> {
     )]
     pub security_associated_account_to: Box<Account<'info, SecurityAssociatedAccount>>,
 
-    #[account(
-      seeds = [
-          TRANSFER_RULE_PREFIX.as_bytes(),
-          &transfer_restriction_data.key().to_bytes(),
-          &security_associated_account_from.group.to_le_bytes(),
-          &security_associated_account_to.group.to_le_bytes(),
-      ],
-      bump,
-    )]
-    pub transfer_rule: Box<Account<'info, TransferRule>>,
+    /// CHECK: Box<Account<'info, TransferRule>>, can be uninitialized when a default rule applies.
+    /// Default transfer rule candidates are passed as remaining accounts.
+    pub transfer_rule: UncheckedAccount<'info>,
 }
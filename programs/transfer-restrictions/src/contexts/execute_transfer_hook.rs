@@ -33,12 +33,15 @@ pub struct ExecuteTransferHook<'info> {
     pub extra_metas_account: UncheckedAccount<'info>,
 
     /// CHECK: Box<Account<'info, TransferRestrictionData>>
+    #[account(mut)]
     pub transfer_restriction_data: UncheckedAccount<'info>,
 
     /// CHECK: Box<Account<'info, SecurityAssociatedAccount>>
+    #[account(mut)]
     pub security_associated_account_from: UncheckedAccount<'info>,
 
     /// CHECK: Box<Account<'info, SecurityAssociatedAccount>>
+    #[account(mut)]
     pub security_associated_account_to: UncheckedAccount<'info>,
 
     // #[account(
@@ -50,6 +53,69 @@ pub struct ExecuteTransferHook<'info> {
     //   ],
     //   bump,
     // )]
-    /// CHECK: Box<Account<'info, TransferRule>>
+    /// CHECK: Box<Account<'info, TransferRule>>, can be uninitialized when a default rule applies
     pub transfer_rule: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, BlackoutCalendar>>, can be uninitialized
+    pub blackout_calendar: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionGroup>>
+    #[account(mut)]
+    pub transfer_restriction_group_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionGroup>>
+    #[account(mut)]
+    pub transfer_restriction_group_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionHolder>>, can be uninitialized when wallet has no holder
+    #[account(mut)]
+    pub transfer_restriction_holder_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionHolder>>, can be uninitialized when wallet has no holder
+    #[account(mut)]
+    pub transfer_restriction_holder_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, HolderGroup>>, can be uninitialized when wallet has no holder
+    #[account(mut)]
+    pub holder_group_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, HolderGroup>>, can be uninitialized when wallet has no holder
+    #[account(mut)]
+    pub holder_group_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, default rule from the sender group, can be uninitialized
+    pub transfer_rule_from_any: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, default rule to the receiver group, can be uninitialized
+    pub transfer_rule_to_any: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, security-wide default rule, can be uninitialized
+    pub transfer_rule_any: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferApproval>>, can be uninitialized when no approval is required
+    #[account(mut)]
+    pub transfer_approval: UncheckedAccount<'info>,
+
+    pub access_control_program: Program<'info, access_control::program::AccessControl>,
+
+    /// CHECK: access_control::ExemptAccountRegistry, can be uninitialized
+    pub exempt_account_registry: UncheckedAccount<'info>,
+
+    /// CHECK: AttestationIssuerRegistry, can be uninitialized when attestations are not required
+    pub attestation_issuer_registry: UncheckedAccount<'info>,
+
+    /// CHECK: program of the attestation issuers, system program when no issuer is registered
+    pub attestation_program: UncheckedAccount<'info>,
+
+    /// CHECK: attestation of the source wallet, checked against the layouts of the issuers
+    pub attestation_from: UncheckedAccount<'info>,
+
+    /// CHECK: attestation of the destination wallet, checked against the layouts of the issuers
+    pub attestation_to: UncheckedAccount<'info>,
+
+    /// CHECK: policy program of the transfer restriction data, system program when not set
+    pub policy_program: UncheckedAccount<'info>,
+
+    /// CHECK: policy config of the security, owned and checked by the policy program
+    pub policy_config: UncheckedAccount<'info>,
 }
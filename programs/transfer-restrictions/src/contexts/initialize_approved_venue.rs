@@ -0,0 +1,60 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, TransferRestrictionGroup,
+    TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+pub const APPROVED_VENUE_PREFIX: &str = "venue"; // approved trading venue
+
+/// Program or specific token account of a regulated venue whose token accounts are mapped to `group`
+#[account]
+#[derive(Default, InitSpace)]
+pub struct ApprovedVenue {
+    pub transfer_restriction_data: Pubkey,
+    pub venue: Pubkey,
+    pub group: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(venue: Pubkey, group_id: u64)]
+pub struct InitializeApprovedVenue<'info> {
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + ApprovedVenue::INIT_SPACE,
+      seeds = [
+        APPROVED_VENUE_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &venue.to_bytes(),
+      ],
+      bump,
+    )]
+    pub approved_venue: Account<'info, ApprovedVenue>,
+    #[account(
+      constraint = group.transfer_restriction_data == transfer_restriction_data.key(),
+      constraint = group.id == group_id,
+    )]
+    pub group: Account<'info, TransferRestrictionGroup>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,90 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError, HolderGroup,
+    SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
+    TransferRestrictionHolder, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+pub const BENEFICIAL_OWNER_POSITION_PREFIX: &str = "bop"; // beneficial owner position
+
+/// Position of a beneficial owner inside an omnibus token account sub-ledger
+#[account]
+#[derive(Default, InitSpace)]
+pub struct BeneficialOwnerPosition {
+    pub omnibus_account: Pubkey,
+    pub holder: Pubkey,
+    pub group: u64,
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(group_id: u64)]
+pub struct InitializeBeneficialOwnerPosition<'info> {
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + BeneficialOwnerPosition::INIT_SPACE,
+      seeds = [
+        BENEFICIAL_OWNER_POSITION_PREFIX.as_bytes(),
+        &omnibus_security_associated_account.key().to_bytes(),
+        &holder.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub beneficial_owner_position: Account<'info, BeneficialOwnerPosition>,
+    #[account(mut,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &omnibus_token_account.key().to_bytes(),
+      ],
+      bump,
+      constraint = omnibus_security_associated_account.omnibus @ TransferRestrictionsError::NotOmnibusAccount,
+    )]
+    pub omnibus_security_associated_account: Account<'info, SecurityAssociatedAccount>,
+    #[account(
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      token::mint = security_token,
+      token::authority = operator,
+    )]
+    pub omnibus_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+      constraint = group.transfer_restriction_data == transfer_restriction_data.key(),
+      constraint = group.id == group_id,
+    )]
+    pub group: Account<'info, TransferRestrictionGroup>,
+    #[account(mut,
+      constraint = holder.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub holder: Account<'info, TransferRestrictionHolder>,
+    #[account(mut,
+      constraint = holder_group.group == group.id,
+      constraint = holder_group.holder == holder.key(),
+    )]
+    pub holder_group: Account<'info, HolderGroup>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    /// Owner of the omnibus token account maintaining its sub-ledger
+    pub operator: Signer<'info>,
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    /// Wallets Admin or Transfer Admin approving the link between the omnibus account and holder
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
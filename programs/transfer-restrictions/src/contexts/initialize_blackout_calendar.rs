@@ -0,0 +1,59 @@
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+use access_control::{self, WalletRole};
+use anchor_lang::prelude::*;
+
+pub const BLACKOUT_CALENDAR_PREFIX: &str = "tbc"; // transfer blackout calendar
+pub const MAX_BLACKOUT_WINDOWS: usize = 16;
+pub const MAX_BLACKOUT_WINDOW_GROUPS: usize = 8;
+
+/// Time range during which transfers of the listed groups are blocked.
+/// An empty `groups` list applies the window to all groups.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace, Debug, PartialEq)]
+pub struct BlackoutWindow {
+    pub start: u64,
+    pub end: u64,
+    pub direction: u8,
+    #[max_len(MAX_BLACKOUT_WINDOW_GROUPS)]
+    pub groups: Vec<u64>,
+}
+
+#[account]
+#[derive(Default, InitSpace)]
+pub struct BlackoutCalendar {
+    pub transfer_restriction_data: Pubkey,
+    #[max_len(MAX_BLACKOUT_WINDOWS)]
+    pub windows: Vec<BlackoutWindow>,
+    // Canonical PDA bump, 0 for accounts created before the bump was stored
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct InitializeBlackoutCalendar<'info> {
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + BlackoutCalendar::INIT_SPACE,
+      seeds = [
+        BLACKOUT_CALENDAR_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub blackout_calendar: Account<'info, BlackoutCalendar>,
+
+    #[account(
+      seeds = [TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(), &transfer_restriction_data.security_token_mint.key().to_bytes()],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -3,11 +3,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::{
-    contexts::common::DISCRIMINATOR_LEN, HolderGroup, SecurityAssociatedAccount,
-    TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder,
-    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
-    TRANSFER_RESTRICTION_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
-    TRANSFER_RESTRICTION_HOLDER_PREFIX,
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError, has_immutable_owner,
+    HolderGroup, SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
+    TransferRestrictionHolder, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_PREFIX,
 };
 
 #[derive(Accounts)]
@@ -76,9 +76,11 @@ pub struct InitializeDefaultSecurityAccounts<'info> {
     pub user_wallet: AccountInfo<'info>,
 
     #[account(
-      associated_token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
-      associated_token::mint = security_token,
-      associated_token::authority = user_wallet,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      token::mint = security_token,
+      token::authority = user_wallet,
+      constraint = has_immutable_owner(&associated_token_account.to_account_info())
+        @ TransferRestrictionsError::MutableTokenAccountOwner,
     )]
     pub associated_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
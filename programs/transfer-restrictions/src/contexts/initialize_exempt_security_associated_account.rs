@@ -0,0 +1,82 @@
+use access_control::{self, AccessControl, WalletRole, EXEMPT_ACCOUNT_REGISTRY_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{self, ID as TOKEN_2022_PROGRAM_ID},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    common::DISCRIMINATOR_LEN, SecurityAssociatedAccount, TransferRestrictionData,
+    TransferRestrictionGroup, SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct InitializeExemptSecurityAssociatedAccount<'info> {
+    #[account(
+        seeds = [
+            TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+            &mint.key().to_bytes(),
+        ],
+        bump = transfer_restriction_data.bump,
+        constraint = transfer_restriction_data.security_token_mint == mint.key(),
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + SecurityAssociatedAccount::INIT_SPACE,
+        seeds = [
+            SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+            &exempt_account.key().to_bytes(),
+        ],
+        bump,
+    )]
+    pub security_associated_account: Account<'info, SecurityAssociatedAccount>,
+
+    /// Group 0 which keeps the exempt account
+    #[account(mut,
+        seeds = [
+            TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+            &transfer_restriction_data.key().to_bytes(),
+            &0u64.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub group: Account<'info, TransferRestrictionGroup>,
+
+    #[account(
+        mint::token_program = TOKEN_2022_PROGRAM_ID,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        constraint = access_control_account.mint == mint.key(),
+        constraint = access_control_account.key() == transfer_restriction_data.access_control_account,
+    )]
+    pub access_control_account: Account<'info, AccessControl>,
+    #[account(
+        constraint = authority_wallet_role.owner == authority.key(),
+        constraint = authority_wallet_role.access_control == access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    #[account(
+        token::mint = mint,
+        token::token_program = token_2022::ID,
+    )]
+    pub exempt_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: access_control::ExemptAccountRegistry, can be uninitialized
+    #[account(
+        seeds = [
+            EXEMPT_ACCOUNT_REGISTRY_SEED,
+            &mint.key().to_bytes(),
+        ],
+        bump,
+        seeds::program = access_control::ID,
+    )]
+    pub exempt_account_registry: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -2,7 +2,7 @@ use crate::{
     contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, TransferRestrictionGroup,
     TransferRestrictionHolder, TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_GROUP_PREFIX,
 };
-use access_control::{self, WalletRole};
+use access_control::{self, VersionedAccount, WalletRole};
 use anchor_lang::prelude::*;
 
 // Short name is required for transfer hook meta account list specification (32 bytes limit)
@@ -14,6 +14,23 @@ pub struct HolderGroup {
     pub group: u64,
     pub holder: Pubkey,
     pub current_wallets_count: u64,
+    pub funded_wallets_count: u64,
+    // Account layout version, 0 for accounts created before the version was stored
+    pub version: u8,
+    // Space reserved for fields added in later layout versions
+    pub reserved: [u8; 32],
+}
+
+impl VersionedAccount for HolderGroup {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 #[derive(Accounts)]
@@ -1,19 +1,72 @@
-use access_control::WalletRole;
+use access_control::{VersionedAccount, WalletRole};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::{
-    contexts::common::DISCRIMINATOR_LEN, HolderGroup, TransferRestrictionData,
-    TransferRestrictionGroup, TransferRestrictionHolder, TRANSFER_RESTRICTION_DATA_PREFIX,
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError, has_immutable_owner,
+    HolderGroup, TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder,
+    TRANSFER_RESTRICTION_DATA_PREFIX,
 };
 
 pub const SECURITY_ASSOCIATED_ACCOUNT_PREFIX: &str = "saa"; // security associated account
+pub const MAX_ACQUISITION_LOTS: usize = 8;
+pub const MAX_WALLET_HOLDS: usize = 4;
+// Position of `holder_id` in the account data of a wallet with a holder, used by transfer hook meta list
+pub const SECURITY_ASSOCIATED_ACCOUNT_HOLDER_ID_OFFSET: u8 = 179;
+
+/// Amount of tokens received at `acquired_at` which is still inside the holding period
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, Debug)]
+pub struct AcquisitionLot {
+    pub acquired_at: u64,
+    pub amount: u64,
+}
+
+/// Regulatory hold blocking transfers of the wallet in `direction` until `expires_at`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, Debug)]
+pub struct WalletHold {
+    pub reason_code: u16,
+    // Hash of the off-chain order or case document the hold is based on
+    pub reference_hash: [u8; 32],
+    pub direction: u8,
+    // Unix timestamp the hold stops applying at, 0 when the hold does not expire
+    pub expires_at: u64,
+}
 
 #[account]
 #[derive(Default, InitSpace)]
 pub struct SecurityAssociatedAccount {
     pub group: u64,
     pub holder: Option<Pubkey>,
+    pub acquisition_lots_count: u8,
+    pub acquisition_lots: [AcquisitionLot; MAX_ACQUISITION_LOTS],
+    // Token account balance is non-zero and counted in funded holder counts
+    pub funded: bool,
+    pub holder_id: u64,
+    // Token account is held for beneficial owners recorded in the omnibus sub-ledger
+    pub omnibus: bool,
+    pub beneficial_owners_count: u64,
+    // Sum of beneficial owner positions which the token account balance must cover
+    pub beneficial_owned_amount: u64,
+    pub holds_count: u8,
+    pub holds: [WalletHold; MAX_WALLET_HOLDS],
+    // Canonical PDA bump, 0 for accounts created before the bump was stored
+    pub bump: u8,
+    // Account layout version, 0 for accounts created before the version was stored
+    pub version: u8,
+    // Space reserved for fields added in later layout versions
+    pub reserved: [u8; 32],
+}
+
+impl VersionedAccount for SecurityAssociatedAccount {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 #[derive(Accounts)]
@@ -58,9 +111,11 @@ pub struct InitializeSecurityAssociatedAccount<'info> {
     /// CHECK: Wallet address
     pub user_wallet: AccountInfo<'info>,
     #[account(
-      associated_token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
-      associated_token::mint = security_token,
-      associated_token::authority = user_wallet,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      token::mint = security_token,
+      token::authority = user_wallet,
+      constraint = has_immutable_owner(&associated_token_account.to_account_info())
+        @ TransferRestrictionsError::MutableTokenAccountOwner,
     )]
     pub associated_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
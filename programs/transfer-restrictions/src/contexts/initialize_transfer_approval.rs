@@ -0,0 +1,70 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData,
+    TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+pub const TRANSFER_APPROVAL_PREFIX: &str = "tap"; // transfer approval
+
+/// Transfer admin sign-off for transfers from `source_account` to `destination_account`, required
+/// by transfer rules with `approval_required`. Every approved transfer decreases `remaining_amount`.
+#[account]
+#[derive(Default, InitSpace)]
+pub struct TransferApproval {
+    pub transfer_restriction_data: Pubkey,
+    pub source_account: Pubkey,
+    pub destination_account: Pubkey,
+    pub remaining_amount: u64,
+    // Unix timestamp the approval stops applying at, 0 when the approval does not expire
+    pub expires_at: u64,
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTransferApproval<'info> {
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + TransferApproval::INIT_SPACE,
+      seeds = [
+        TRANSFER_APPROVAL_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &source_account.key().to_bytes(),
+        &destination_account.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_approval: Account<'info, TransferApproval>,
+    #[account(
+      token::mint = security_token,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub source_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      token::mint = security_token,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
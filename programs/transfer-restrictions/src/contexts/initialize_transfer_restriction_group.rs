@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use access_control::{self, AccessControl, WalletRole};
+use access_control::{self, AccessControl, VersionedAccount, WalletRole};
 use crate::{contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX};
 
 // Short name is required for transfer hook meta account list specification (32 bytes limit)
@@ -14,6 +14,34 @@ pub struct TransferRestrictionGroup {
   pub current_holders_count: u64,
   pub max_holders: u64,
   pub transfer_restriction_data: Pubkey,
+  // Seconds received tokens must be held before they can be sent out of the group, 0 disables tracking
+  pub holding_period: u64,
+  pub funded_holders_count: u64,
+  // Transfer directions blocked for the group, 0 when the group is not paused
+  pub paused_direction: u8,
+  // Venue and exempt token accounts registered in the group, they have no holder and are not
+  // counted as holders
+  pub venue_wallets_count: u64,
+  // Canonical PDA bump, 0 for accounts created before the bump was stored
+  pub bump: u8,
+  // Account layout version, 0 for accounts created before the version was stored
+  pub version: u8,
+  // Smallest non-zero balance a wallet of a holder in the group can have, 0 when not limited
+  pub min_holding: u64,
+  // Space reserved for fields added in later layout versions
+  pub reserved: [u8; 24],
+}
+
+impl VersionedAccount for TransferRestrictionGroup {
+  const VERSION: u8 = 1;
+
+  fn version(&self) -> u8 {
+    self.version
+  }
+
+  fn set_version(&mut self, version: u8) {
+    self.version = version;
+  }
 }
 
 #[derive(Accounts)]
@@ -1,7 +1,7 @@
 use crate::{
     contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX,
 };
-use access_control::{self, AccessControl, WalletRole};
+use access_control::{self, AccessControl, VersionedAccount, WalletRole};
 use anchor_lang::prelude::*;
 
 pub const TRANSFER_RESTRICTION_HOLDER_PREFIX: &str = "trh"; // transfer_restriction_holder
@@ -14,6 +14,27 @@ pub struct TransferRestrictionHolder {
     pub current_holder_group_count: u64,
     pub id: u64,
     pub active: bool,
+    pub funded_wallets_count: u64,
+    // Transfers of every wallet of the holder are rejected while frozen
+    pub frozen: bool,
+    // Account layout version, 0 for accounts created before the version was stored
+    pub version: u8,
+    // Beneficial owner positions of the holder in omnibus accounts
+    pub beneficial_owner_positions_count: u64,
+    // Space reserved for fields added in later layout versions
+    pub reserved: [u8; 24],
+}
+
+impl VersionedAccount for TransferRestrictionHolder {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 #[derive(Accounts)]
@@ -1,9 +1,12 @@
 use crate::{contexts::common::DISCRIMINATOR_LEN, TransferRestrictionGroup, TRANSFER_RESTRICTION_GROUP_PREFIX};
-use access_control::{self, AccessControl, WalletRole};
+use access_control::{self, AccessControl, VersionedAccount, WalletRole};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, Token2022};
 
 pub const TRANSFER_RESTRICTION_DATA_PREFIX: &str = "trd"; // transfer_restriction_data
+// Positions of the fixed fields read by the transfer hook without deserializing the account data
+pub const TRANSFER_RESTRICTION_DATA_PAUSED_OFFSET: usize = 96;
+pub const TRANSFER_RESTRICTION_DATA_LOCKUP_ESCROW_ACCOUNT_OFFSET: usize = 97;
 
 #[account]
 #[derive(Default, InitSpace)]
@@ -15,6 +18,32 @@ pub struct TransferRestrictionData {
     pub max_holders: u64,
     pub paused: bool,
     pub lockup_escrow_account: Option<Pubkey>,
+    // Holder counts are maintained by the transfer hook from token balances when enabled
+    pub count_holders_by_balance: bool,
+    pub funded_holders_count: u64,
+    // Canonical PDA bump, 0 for accounts created before the bump was stored
+    pub bump: u8,
+    // Account layout version, 0 for accounts created before the version was stored
+    pub version: u8,
+    // Program called by the transfer hook after the built-in checks, default key when not set.
+    // Takes the space reserved in the first layout version, so the account size is unchanged.
+    pub policy_program: Pubkey,
+    // Transfers between wallets of the same holder are not restricted by the transfer rules
+    pub allow_intra_holder_transfers: bool,
+    // Space reserved for fields added in later layout versions
+    pub reserved: [u8; 32],
+}
+
+impl VersionedAccount for TransferRestrictionData {
+    const VERSION: u8 = 3;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 #[derive(Accounts)]
@@ -5,10 +5,21 @@ use crate::{
   TransferRestrictionGroup,
   TRANSFER_RESTRICTION_DATA_PREFIX,
 };
-use access_control::{self, AccessControl, WalletRole};
+use access_control::{self, AccessControl, VersionedAccount, WalletRole};
 
 pub const TRANSFER_RULE_PREFIX: &str = "tr"; // transfer_rule
+/// Group id used by default transfer rules to match any transfer group
+pub const ANY_TRANSFER_GROUP_ID: u64 = u64::MAX;
+pub const MAX_TRANSFER_RULE_CLOSED_INTERVALS: usize = 8;
 
+/// Time range during which a transfer rule does not allow transfers.
+/// A non-zero `period` makes the interval repeat every `period` seconds starting from `start`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, Debug)]
+pub struct TransferRuleClosedInterval {
+  pub start: u64,
+  pub end: u64,
+  pub period: u64,
+}
 
 #[account]
 #[derive(Default)]
@@ -18,17 +29,40 @@ pub struct TransferRule {
   pub transfer_group_id_from: u64,
   pub transfer_group_id_to: u64,
   pub locked_until: u64,
+  pub valid_until: u64,
+  pub closed_intervals_count: u8,
+  pub closed_intervals: [TransferRuleClosedInterval; MAX_TRANSFER_RULE_CLOSED_INTERVALS],
+  // Canonical PDA bump, 0 for accounts created before the bump was stored
+  pub bump: u8,
+  // Transfers allowed by the rule also need a transfer approval of the transfer admin
+  pub approval_required: bool,
+  // Account layout version, 0 for accounts created before the version was stored
+  pub version: u8,
+  // Space reserved for fields added in later layout versions
+  pub reserved: [u8; 32],
+}
+
+impl VersionedAccount for TransferRule {
+  const VERSION: u8 = 1;
+
+  fn version(&self) -> u8 {
+    self.version
+  }
+
+  fn set_version(&mut self, version: u8) {
+    self.version = version;
+  }
 }
 
 #[derive(Accounts)]
-#[instruction(group_id_from: u64, group_id_to: u64, locked_until: u64)]
+#[instruction(transfer_group_id_from: u64, transfer_group_id_to: u64, locked_until: u64)]
 pub struct InitializeTransferRule<'info> {
   #[account(init, payer = payer, space = DISCRIMINATOR_LEN + TransferRule::INIT_SPACE,
     seeds = [
       TRANSFER_RULE_PREFIX.as_bytes(),
       &transfer_restriction_data.key().to_bytes(),
-      &transfer_restriction_group_from.id.to_le_bytes(),
-      &transfer_restriction_group_to.id.to_le_bytes(),
+      &transfer_group_id_from.to_le_bytes(),
+      &transfer_group_id_to.to_le_bytes(),
     ],
     bump,
   )]
@@ -41,14 +75,14 @@ pub struct InitializeTransferRule<'info> {
   pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
   #[account(
     constraint = transfer_restriction_group_from.transfer_restriction_data == transfer_restriction_data.key(),
-    constraint = transfer_restriction_group_from.id == group_id_from,
+    constraint = transfer_restriction_group_from.id == transfer_group_id_from,
   )]
-  pub transfer_restriction_group_from: Account<'info, TransferRestrictionGroup>,
+  pub transfer_restriction_group_from: Option<Account<'info, TransferRestrictionGroup>>,
   #[account(
     constraint = transfer_restriction_group_to.transfer_restriction_data == transfer_restriction_data.key(),
-    constraint = transfer_restriction_group_to.id == group_id_to,
+    constraint = transfer_restriction_group_to.id == transfer_group_id_to,
   )]
-  pub transfer_restriction_group_to: Account<'info, TransferRestrictionGroup>,
+  pub transfer_restriction_group_to: Option<Account<'info, TransferRestrictionGroup>>,
   pub access_control_account: Account<'info, AccessControl>,
   #[account(
     constraint = authority_wallet_role.owner == authority.key(),
@@ -0,0 +1,59 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    errors::TransferRestrictionsError, TransferRestrictionData, TransferRestrictionHolder,
+    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_HOLDER_PREFIX,
+};
+
+/// Number of remaining accounts expected for every group of the source holder:
+/// source holder group, target holder group, group
+pub const MERGE_HOLDER_GROUP_ACCOUNTS_LEN: usize = 3;
+
+#[derive(Accounts)]
+#[instruction(holder_groups_count: u8)]
+pub struct MergeHolders<'info> {
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &source_holder.id.to_le_bytes(),
+      ],
+      bump,
+      constraint = source_holder.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub source_holder: Account<'info, TransferRestrictionHolder>,
+
+    #[account(mut,
+      constraint = target_holder.transfer_restriction_data == transfer_restriction_data.key(),
+      constraint = target_holder.key() != source_holder.key() @ TransferRestrictionsError::CannotMergeHolderIntoItself,
+    )]
+    pub target_holder: Account<'info, TransferRestrictionHolder>,
+
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
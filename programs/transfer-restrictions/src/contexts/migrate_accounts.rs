@@ -0,0 +1,39 @@
+use access_control::{self, AccessControl, WalletRole};
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::ID as TOKEN_2022_PROGRAM_ID, token_interface::Mint};
+
+use crate::TRANSFER_RESTRICTION_DATA_PREFIX;
+
+#[derive(Accounts)]
+pub struct MigrateAccounts<'info> {
+    /// CHECK: TransferRestrictionData, can have an older layout, migrated by the instruction
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_mint.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_data: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = TOKEN_2022_PROGRAM_ID,
+    )]
+    pub security_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      constraint = authority_wallet_role.owner == payer.key(),
+      constraint = authority_wallet_role.access_control == access_control.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    #[account(
+      constraint = security_mint.key() == access_control.mint,
+    )]
+    pub access_control: Box<Account<'info, AccessControl>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,65 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    errors::TransferRestrictionsError, TransferRestrictionData, TransferRestrictionGroup,
+    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_GROUP_PREFIX,
+};
+
+/// Number of remaining accounts expected for every migrated wallet:
+/// security associated account, holder, current holder group, new holder group
+pub const MIGRATE_WALLET_ACCOUNTS_LEN: usize = 4;
+
+#[derive(Accounts)]
+#[instruction(current_group_id: u64, new_group_id: u64)]
+pub struct MigrateWalletsGroup<'info> {
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &current_group_id.to_le_bytes(),
+      ],
+      bump,
+      constraint = transfer_restriction_group_current.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub transfer_restriction_group_current: Account<'info, TransferRestrictionGroup>,
+
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &new_group_id.to_le_bytes(),
+      ],
+      bump,
+      constraint = transfer_restriction_group_new.transfer_restriction_data == transfer_restriction_data.key(),
+      constraint = current_group_id != new_group_id @ TransferRestrictionsError::NewGroupIsTheSameAsTheCurrentGroup,
+    )]
+    pub transfer_restriction_group_new: Account<'info, TransferRestrictionGroup>,
+
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
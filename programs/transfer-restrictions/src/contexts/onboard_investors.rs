@@ -0,0 +1,46 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX};
+
+/// Number of remaining accounts expected for every onboarding entry:
+/// user wallet, token account, holder, holder group, security associated account, group
+pub const ONBOARDING_ENTRY_ACCOUNTS_LEN: usize = 6;
+
+/// Investor wallet to onboard, accounts are passed as remaining accounts in the same order
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct OnboardingEntry {
+    pub holder_id: u64,
+    pub group_id: u64,
+}
+
+#[derive(Accounts)]
+pub struct OnboardInvestors<'info> {
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    errors::TransferRestrictionsError, SecurityAssociatedAccount, TransferRestrictionData,
+    TransferRestrictionGroup, SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct RecordMintAcquisition<'info> {
+    /// Access control account of the security, signs as the mint authority in `mint_securities`
+    pub access_control_account: Signer<'info>,
+
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &transfer_restriction_data.security_token_mint.key().to_bytes(),
+      ],
+      bump,
+      constraint = transfer_restriction_data.access_control_account == access_control_account.key() @ TransferRestrictionsError::Unauthorized,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(
+      constraint = token_account.mint == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &token_account.key().to_bytes(),
+      ],
+      bump,
+      constraint = security_associated_account.group == group.id,
+    )]
+    pub security_associated_account: Account<'info, SecurityAssociatedAccount>,
+
+    #[account(
+      constraint = group.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub group: Account<'info, TransferRestrictionGroup>,
+}
@@ -0,0 +1,74 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, ApprovedVenue, SecurityAssociatedAccount,
+    TransferRestrictionData, TransferRestrictionGroup, APPROVED_VENUE_PREFIX,
+    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX,
+};
+
+#[derive(Accounts)]
+#[instruction(authority_seeds: Vec<Vec<u8>>)]
+pub struct RegisterVenueTokenAccount<'info> {
+    #[account(
+      seeds = [
+        APPROVED_VENUE_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &approved_venue.venue.to_bytes(),
+      ],
+      bump,
+    )]
+    pub approved_venue: Account<'info, ApprovedVenue>,
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + SecurityAssociatedAccount::INIT_SPACE,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &token_account.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub security_associated_account: Account<'info, SecurityAssociatedAccount>,
+    #[account(
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      token::mint = security_token,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Owner of the token account, a program owned account or a PDA of the venue program
+    #[account(
+      constraint = venue_authority.key() == token_account.owner,
+    )]
+    pub venue_authority: UncheckedAccount<'info>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &approved_venue.group.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub group: Account<'info, TransferRestrictionGroup>,
+    /// Wallet role of the authority, not required when the venue authority signs
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Option<Account<'info, WalletRole>>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,52 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    AttestationIssuerRegistry, TransferRestrictionData, ATTESTATION_ISSUER_REGISTRY_PREFIX,
+    META_LIST_ACCOUNT_SEED, TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct RemoveAttestationIssuer<'info> {
+    #[account(mut,
+      seeds = [
+        ATTESTATION_ISSUER_REGISTRY_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump = attestation_issuer_registry.bump,
+      constraint = attestation_issuer_registry.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub attestation_issuer_registry: Account<'info, AttestationIssuerRegistry>,
+    /// CHECK: extra metas account, rewritten with the attestation accounts of the issuers
+    #[account(
+      mut,
+      seeds = [
+        META_LIST_ACCOUNT_SEED,
+        security_token.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      constraint = authority_wallet_role.owner == payer.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
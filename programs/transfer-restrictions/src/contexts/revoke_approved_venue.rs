@@ -0,0 +1,42 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    ApprovedVenue, TransferRestrictionData, APPROVED_VENUE_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct RevokeApprovedVenue<'info> {
+    #[account(mut,
+      close = payer,
+      seeds = [
+        APPROVED_VENUE_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &approved_venue.venue.to_bytes(),
+      ],
+      bump,
+    )]
+    pub approved_venue: Account<'info, ApprovedVenue>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
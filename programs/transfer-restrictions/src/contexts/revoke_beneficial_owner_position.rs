@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    BeneficialOwnerPosition, HolderGroup, SecurityAssociatedAccount, TransferRestrictionData,
+    TransferRestrictionGroup, TransferRestrictionHolder, BENEFICIAL_OWNER_POSITION_PREFIX,
+    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct RevokeBeneficialOwnerPosition<'info> {
+    #[account(mut,
+      close = payer,
+      seeds = [
+        BENEFICIAL_OWNER_POSITION_PREFIX.as_bytes(),
+        &omnibus_security_associated_account.key().to_bytes(),
+        &holder.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub beneficial_owner_position: Account<'info, BeneficialOwnerPosition>,
+    #[account(mut,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &omnibus_token_account.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub omnibus_security_associated_account: Account<'info, SecurityAssociatedAccount>,
+    #[account(
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      token::mint = security_token,
+      token::authority = operator,
+    )]
+    pub omnibus_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+      constraint = group.transfer_restriction_data == transfer_restriction_data.key(),
+      constraint = group.id == beneficial_owner_position.group,
+    )]
+    pub group: Account<'info, TransferRestrictionGroup>,
+    #[account(mut,
+      constraint = holder.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub holder: Account<'info, TransferRestrictionHolder>,
+    #[account(mut,
+      constraint = holder_group.group == group.id,
+      constraint = holder_group.holder == holder.key(),
+    )]
+    pub holder_group: Account<'info, HolderGroup>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    /// Owner of the omnibus token account maintaining its sub-ledger
+    pub operator: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -38,7 +38,7 @@ pub struct RevokeSecurityAssociatedAccount<'info> {
       token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
     )]
     pub security_token: Box<InterfaceAccount<'info, Mint>>,
-    #[account(
+    #[account(mut,
       seeds = [
         TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
         &security_token.key().to_bytes(),
@@ -49,9 +49,9 @@ pub struct RevokeSecurityAssociatedAccount<'info> {
     /// CHECK: Wallet address
     pub user_wallet: AccountInfo<'info>,
     #[account(
-      associated_token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
-      associated_token::mint = security_token,
-      associated_token::authority = user_wallet,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      token::mint = security_token,
+      token::authority = user_wallet,
     )]
     pub associated_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
@@ -0,0 +1,36 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+
+use crate::{
+    TransferRestrictionData, TransferRule, TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RULE_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct RevokeTransferRule<'info> {
+    #[account(mut,
+      close = receiver,
+      seeds = [
+        TRANSFER_RULE_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &transfer_rule.transfer_group_id_from.to_le_bytes(),
+        &transfer_rule.transfer_group_id_to.to_le_bytes(),
+      ],
+      bump,
+      constraint = transfer_rule.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub transfer_rule: Account<'info, TransferRule>,
+    #[account(
+      seeds = [TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(), &transfer_restriction_data.security_token_mint.key().to_bytes()],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    pub authority: Signer<'info>,
+    /// CHECK: Account receiving the rent of the closed transfer rule
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
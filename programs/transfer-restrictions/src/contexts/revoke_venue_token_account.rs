@@ -0,0 +1,59 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    errors::TransferRestrictionsError, SecurityAssociatedAccount, TransferRestrictionData,
+    TransferRestrictionGroup, SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct RevokeVenueTokenAccount<'info> {
+    #[account(mut,
+      close = payer,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &token_account.key().to_bytes(),
+      ],
+      bump,
+      constraint = security_associated_account.holder.is_none() @ TransferRestrictionsError::NotVenueTokenAccount,
+      constraint = transfer_restriction_data.lockup_escrow_account != Some(token_account.key()) @ TransferRestrictionsError::NotVenueTokenAccount,
+    )]
+    pub security_associated_account: Account<'info, SecurityAssociatedAccount>,
+    #[account(
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      token::mint = security_token,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &security_associated_account.group.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub group: Account<'info, TransferRestrictionGroup>,
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
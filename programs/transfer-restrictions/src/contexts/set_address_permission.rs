@@ -4,11 +4,11 @@ use anchor_lang::solana_program::program_option::COption;
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
 use crate::{
-    contexts::common::DISCRIMINATOR_LEN, HolderGroup, SecurityAssociatedAccount,
-    TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder,
-    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
-    TRANSFER_RESTRICTION_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
-    TRANSFER_RESTRICTION_HOLDER_PREFIX,
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError, has_immutable_owner,
+    HolderGroup, SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
+    TransferRestrictionHolder, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_PREFIX,
 };
 
 #[derive(Accounts)]
@@ -115,9 +115,11 @@ pub struct SetAddressPermission<'info> {
     pub user_wallet: AccountInfo<'info>,
 
     #[account(mut,
-        associated_token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
-        associated_token::mint = security_token,
-        associated_token::authority = user_wallet,
+        token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+        token::mint = security_token,
+        token::authority = user_wallet,
+        constraint = has_immutable_owner(&user_associated_token_account.to_account_info())
+          @ TransferRestrictionsError::MutableTokenAccountOwner,
     )]
     pub user_associated_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -156,5 +158,9 @@ pub struct SetAddressPermission<'info> {
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: access control exempt account registry, can be uninitialized, verified by the access
+    /// control program
+    pub exempt_account_registry: UncheckedAccount<'info>,
 }
 
@@ -6,19 +6,19 @@ use access_control::{self, AccessControl, WalletRole};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
-#[instruction(group_id_from: u64, group_id_to: u64, locked_until: u64)]
+#[instruction(transfer_group_id_from: u64, transfer_group_id_to: u64, locked_until: u64)]
 pub struct SetAllowTransferRule<'info> {
     #[account(mut,
         seeds = [
             TRANSFER_RULE_PREFIX.as_bytes(),
             &transfer_restriction_data.key().to_bytes(),
-            &transfer_restriction_group_from.id.to_le_bytes(),
-            &transfer_restriction_group_to.id.to_le_bytes(),
+            &transfer_group_id_from.to_le_bytes(),
+            &transfer_group_id_to.to_le_bytes(),
         ],
         bump,
         constraint = transfer_rule.transfer_restriction_data == transfer_restriction_data.key(),
-        constraint = transfer_rule.transfer_group_id_from == group_id_from,
-        constraint = transfer_rule.transfer_group_id_to == group_id_to,
+        constraint = transfer_rule.transfer_group_id_from == transfer_group_id_from,
+        constraint = transfer_rule.transfer_group_id_to == transfer_group_id_to,
     )]
     pub transfer_rule: Account<'info, TransferRule>,
 
@@ -31,16 +31,16 @@ pub struct SetAllowTransferRule<'info> {
     #[account(
         constraint = transfer_restriction_group_from.transfer_restriction_data == transfer_restriction_data.key(),
         constraint = transfer_restriction_group_from.id == transfer_rule.transfer_group_id_from,
-        constraint = transfer_restriction_group_from.id == group_id_from,
+        constraint = transfer_restriction_group_from.id == transfer_group_id_from,
     )]
-    pub transfer_restriction_group_from: Account<'info, TransferRestrictionGroup>,
+    pub transfer_restriction_group_from: Option<Account<'info, TransferRestrictionGroup>>,
 
     #[account(
         constraint = transfer_restriction_group_to.transfer_restriction_data == transfer_restriction_data.key(),
         constraint = transfer_restriction_group_to.id == transfer_rule.transfer_group_id_to,
-        constraint = transfer_restriction_group_to.id == group_id_to,
+        constraint = transfer_restriction_group_to.id == transfer_group_id_to,
     )]
-    pub transfer_restriction_group_to: Account<'info, TransferRestrictionGroup>,
+    pub transfer_restriction_group_to: Option<Account<'info, TransferRestrictionGroup>>,
 
     #[account(
         constraint = access_control_account.mint == transfer_restriction_data.security_token_mint,
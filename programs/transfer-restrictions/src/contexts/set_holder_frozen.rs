@@ -0,0 +1,63 @@
+use access_control::{AccessControl, WalletRole, ACCESS_CONTROL_SEED};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    TransferRestrictionData, TransferRestrictionHolder, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_PREFIX,
+};
+
+// Security associated account, token account and token account owner of each wallet to freeze
+pub const SET_HOLDER_FROZEN_WALLET_ACCOUNTS_LEN: usize = 3;
+
+#[derive(Accounts)]
+#[instruction(frozen: bool)]
+pub struct SetHolderFrozen<'info> {
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &holder.id.to_le_bytes(),
+      ],
+      bump,
+      constraint = holder.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub holder: Account<'info, TransferRestrictionHolder>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_mint.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      mut,
+      constraint = security_mint.key() == transfer_restriction_data.security_token_mint,
+      constraint = security_mint.mint_authority == COption::Some(access_control_account.key()),
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      constraint = access_control_account.key() == transfer_restriction_data.access_control_account,
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        security_mint.key().as_ref(),
+      ],
+      seeds::program = access_control_program,
+      bump,
+    )]
+    pub access_control_account: Box<Account<'info, AccessControl>>,
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    pub authority: Signer<'info>,
+    pub access_control_program: Program<'info, access_control::program::AccessControl>,
+    pub token_program: Program<'info, Token2022>,
+    /// CHECK: access control exempt account registry, can be uninitialized, verified by the access
+    /// control program
+    pub exempt_account_registry: UncheckedAccount<'info>,
+}
@@ -0,0 +1,64 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use spl_discriminator::SplDiscriminate;
+
+use crate::{
+    errors::TransferRestrictionsError, TransferRestrictionData, META_LIST_ACCOUNT_SEED,
+    TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+// Policy programs can keep their configuration of the security in a PDA derived from this prefix
+// and the mint, the transfer hook passes it to the policy program
+pub const POLICY_CONFIG_PREFIX: &str = "policy";
+
+/// Instruction of the policy program called by the transfer hook with the transferred amount as
+/// u64 argument. Accounts: source token account, mint, destination token account, owner, security
+/// associated account from, security associated account to and the policy config, all read-only.
+/// The discriminator matches the Anchor instruction `check_transfer(amount: u64)`.
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("global:check_transfer")]
+pub struct PolicyCheckTransferInstruction;
+
+#[derive(Accounts)]
+pub struct SetPolicyProgram<'info> {
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    /// CHECK: extra metas account, rewritten with the policy program accounts
+    #[account(
+      mut,
+      seeds = [
+        META_LIST_ACCOUNT_SEED,
+        security_token.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: policy program, system program to stop calling a policy program
+    #[account(
+      executable,
+      constraint = policy_program.key() != crate::ID @ TransferRestrictionsError::InvalidPolicyProgram,
+    )]
+    pub policy_program: UncheckedAccount<'info>,
+    /// CHECK: AttestationIssuerRegistry, can be uninitialized
+    pub attestation_issuer_registry: UncheckedAccount<'info>,
+    #[account(
+      constraint = authority_wallet_role.owner == payer.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
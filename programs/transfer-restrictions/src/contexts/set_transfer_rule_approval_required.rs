@@ -0,0 +1,44 @@
+use crate::{
+    TransferRestrictionData, TransferRule, TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RULE_PREFIX,
+};
+use access_control::{self, AccessControl, WalletRole};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(group_id_from: u64, group_id_to: u64)]
+pub struct SetTransferRuleApprovalRequired<'info> {
+    #[account(mut,
+        seeds = [
+            TRANSFER_RULE_PREFIX.as_bytes(),
+            &transfer_restriction_data.key().to_bytes(),
+            &group_id_from.to_le_bytes(),
+            &group_id_to.to_le_bytes(),
+        ],
+        bump,
+        constraint = transfer_rule.transfer_restriction_data == transfer_restriction_data.key(),
+        constraint = transfer_rule.transfer_group_id_from == group_id_from,
+        constraint = transfer_rule.transfer_group_id_to == group_id_to,
+    )]
+    pub transfer_rule: Account<'info, TransferRule>,
+
+    #[account(
+        seeds = [TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(), &access_control_account.mint.key().to_bytes()],
+        bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(
+        constraint = access_control_account.mint == transfer_restriction_data.security_token_mint,
+        constraint = access_control_account.key() == transfer_restriction_data.access_control_account,
+    )]
+    pub access_control_account: Account<'info, AccessControl>,
+
+    #[account(
+        constraint = authority_wallet_role.owner == payer.key(),
+        constraint = authority_wallet_role.access_control == access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
@@ -0,0 +1,33 @@
+use crate::{
+    BlackoutCalendar, TransferRestrictionData, BLACKOUT_CALENDAR_PREFIX,
+    TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+use access_control::{self, WalletRole};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateBlackoutCalendar<'info> {
+    #[account(mut,
+      seeds = [
+        BLACKOUT_CALENDAR_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+      ],
+      bump,
+      constraint = blackout_calendar.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub blackout_calendar: Account<'info, BlackoutCalendar>,
+
+    #[account(
+      seeds = [TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(), &transfer_restriction_data.security_token_mint.key().to_bytes()],
+      bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(
+      constraint = authority_wallet_role.owner == authority.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    pub authority: Signer<'info>,
+}
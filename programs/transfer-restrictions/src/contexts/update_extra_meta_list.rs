@@ -0,0 +1,55 @@
+use crate::{META_LIST_ACCOUNT_SEED, TRANSFER_RESTRICTION_DATA_PREFIX};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::ID as TOKEN_2022_PROGRAM_ID,
+    token_interface::Mint,
+};
+use access_control::{self, AccessControl, WalletRole };
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    #[account(
+      mut,
+      seeds = [
+        META_LIST_ACCOUNT_SEED,
+        security_mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    /// CHECK: extra metas account
+    pub extra_metas_account: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = TOKEN_2022_PROGRAM_ID,
+    )]
+    pub security_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      constraint = authority_wallet_role.owner == payer.key(),
+      constraint = authority_wallet_role.access_control == access_control.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    #[account(
+      constraint = security_mint.key() == access_control.mint,
+    )]
+    pub access_control: Box<Account<'info, AccessControl>>,
+
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_mint.key().to_bytes(),
+      ],
+      bump,
+    )]
+    /// CHECK: TransferRestrictionData, policy program is read at its offset
+    pub transfer_restriction_data: UncheckedAccount<'info>,
+
+    /// CHECK: AttestationIssuerRegistry, can be uninitialized
+    pub attestation_issuer_registry: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
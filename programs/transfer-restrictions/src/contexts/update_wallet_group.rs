@@ -86,9 +86,9 @@ pub struct UpdateWalletGroup<'info> {
     /// CHECK: Wallet address
     pub user_wallet: AccountInfo<'info>,
     #[account(
-      associated_token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
-      associated_token::mint = security_token,
-      associated_token::authority = user_wallet,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      token::mint = security_token,
+      token::authority = user_wallet,
     )]
     pub user_associated_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut)]
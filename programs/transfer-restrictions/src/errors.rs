@@ -54,4 +54,106 @@ pub enum TransferRestrictionsError {
     CurrentGroupRequiredForExistingWallet,
     #[msg("Holder group already initialized")]
     HolderGroupAlreadyInitialized,
+    #[msg("Transfer rule has expired")]
+    TransferRuleExpired,
+    #[msg("Transfer rule is inside a closed interval")]
+    TransferRuleClosedInterval,
+    #[msg("Too many closed intervals")]
+    TooManyClosedIntervals,
+    #[msg("Invalid closed interval")]
+    InvalidClosedInterval,
+    #[msg("Invalid blackout window")]
+    InvalidBlackoutWindow,
+    #[msg("Invalid transfer direction")]
+    InvalidTransferDirection,
+    #[msg("Too many blackout windows")]
+    TooManyBlackoutWindows,
+    #[msg("Blackout window not found")]
+    BlackoutWindowNotFound,
+    #[msg("Transfers are blocked by a blackout window")]
+    TransferBlackoutActive,
+    #[msg("Tokens are still inside the holding period")]
+    HoldingPeriodNotElapsed,
+    #[msg("Transfer hook can be executed only during a token transfer")]
+    NotTransferring,
+    #[msg("Holder counting by balance is disabled")]
+    HolderCountingByBalanceDisabled,
+    #[msg("Default transfer rule account is missing")]
+    DefaultTransferRuleAccountMissing,
+    #[msg("Transfer restriction group account is missing")]
+    TransferRestrictionGroupMissing,
+    #[msg("Invalid transfer group id")]
+    InvalidTransferGroupId,
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+    #[msg("Token account is not an omnibus account")]
+    NotOmnibusAccount,
+    #[msg("Omnibus account has beneficial owners")]
+    OmnibusAccountHasBeneficialOwners,
+    #[msg("Omnibus account balance does not cover beneficial owner positions")]
+    OmnibusBalanceBelowBeneficialOwnership,
+    #[msg("Beneficial owner position is not empty")]
+    BeneficialOwnerPositionNotEmpty,
+    #[msg("Cannot merge a holder into itself")]
+    CannotMergeHolderIntoItself,
+    #[msg("Token account is not owned by the approved venue")]
+    TokenAccountNotApprovedVenue,
+    #[msg("Token account is not a venue token account")]
+    NotVenueTokenAccount,
+    #[msg("Transfers of the group are paused")]
+    GroupTransfersPaused,
+    #[msg("Holder is frozen")]
+    HolderFrozen,
+    #[msg("Invalid wallet hold")]
+    InvalidWalletHold,
+    #[msg("Too many wallet holds")]
+    TooManyWalletHolds,
+    #[msg("Invalid wallet hold index")]
+    InvalidWalletHoldIndex,
+    #[msg("Transfer is blocked by a wallet hold")]
+    TransferBlockedByWalletHold,
+    #[msg("Group still has wallets")]
+    GroupHasWallets,
+    #[msg("Transfer requires a transfer approval")]
+    TransferApprovalRequired,
+    #[msg("Invalid transfer approval")]
+    InvalidTransferApproval,
+    #[msg("Transfer approval expired")]
+    TransferApprovalExpired,
+    #[msg("Transfer amount exceeds the transfer approval")]
+    TransferApprovalAmountExceeded,
+    #[msg("Token account is not an exempt account")]
+    NotExemptAccount,
+    #[msg("Account cannot be migrated with the transfer restriction data")]
+    InvalidMigrationAccount,
+    #[msg("Invalid attestation issuer")]
+    InvalidAttestationIssuer,
+    #[msg("Attestation issuer is already registered")]
+    AttestationIssuerAlreadyRegistered,
+    #[msg("Attestation issuers limit reached")]
+    AttestationIssuersLimitReached,
+    #[msg("Invalid attestation issuer index")]
+    InvalidAttestationIssuerIndex,
+    #[msg("Wallet has no valid attestation of a trusted issuer")]
+    WalletNotAttested,
+    #[msg("Invalid policy program")]
+    InvalidPolicyProgram,
+    #[msg("Sender balance after the transfer is below the minimum holding")]
+    SenderBalanceBelowMinHolding,
+    #[msg("Receiver balance after the transfer is below the minimum holding")]
+    ReceiverBalanceBelowMinHolding,
+    #[msg("Holder has beneficial owner positions")]
+    HolderHasBeneficialOwnerPositions,
+    #[msg("Holder account does not match the holder of the wallet")]
+    InvalidHolderAccount,
+    #[msg("Attestation issuers must use the same attestation program")]
+    AttestationProgramMismatch,
+    #[msg("Transfer passes the transfer restrictions and depends on the policy program")]
+    TransferDependsOnPolicyProgram,
+    #[msg("Token account owner can be changed")]
+    MutableTokenAccountOwner,
+    #[msg("Holders count overflow")]
+    HoldersCountOverflow,
 }
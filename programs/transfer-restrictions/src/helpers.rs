@@ -1,8 +1,21 @@
-use access_control::WalletRole;
+use access_control::{VersionedAccount, WalletRole};
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        immutable_owner::ImmutableOwner, transfer_hook::TransferHookAccount,
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+};
 
+use crate::contexts::common::{blocks_direction, TransferDirection};
 use crate::errors::TransferRestrictionsError;
-use crate::{HolderGroup, SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder};
+use crate::{
+    AcquisitionLot, AttestationIssuer, BlackoutCalendar, HolderGroup, SecurityAssociatedAccount, TransferApproval,
+    TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder, TransferRule,
+    TransferRuleClosedInterval, ANY_TRANSFER_GROUP_ID, MAX_ACQUISITION_LOTS,
+    MAX_TRANSFER_RULE_CLOSED_INTERVALS, read_account,
+};
 
 /// Check if the wallet role has any of the specified roles
 pub fn check_authorization(wallet_role: &WalletRole, allowed_roles: u8) -> Result<()> {
@@ -36,6 +49,7 @@ pub fn initialize_holder_fields(
     holder.current_wallets_count = 0;
     holder.current_holder_group_count = 0;
     holder.active = true;
+    holder.version = TransferRestrictionHolder::VERSION;
 
     Ok(())
 }
@@ -46,8 +60,10 @@ pub fn update_transfer_restriction_data_after_holder_creation(
     transfer_restriction_data: &mut Account<TransferRestrictionData>,
     holder_id: u64,
 ) -> Result<()> {
-    // Check max holders limit
-    if transfer_restriction_data.current_holders_count >= transfer_restriction_data.max_holders {
+    // Check max holders limit, holders without balance are not limited when counting by balance
+    if !transfer_restriction_data.count_holders_by_balance
+        && transfer_restriction_data.current_holders_count >= transfer_restriction_data.max_holders
+    {
         return Err(TransferRestrictionsError::MaxHoldersReached.into());
     }
 
@@ -74,6 +90,7 @@ pub fn initialize_holder_group_fields(
     holder_group.group = group.id;
     holder_group.holder = holder.key();
     holder_group.current_wallets_count = 0;
+    holder_group.version = HolderGroup::VERSION;
 }
 
 /// Increment holder's holder group count
@@ -88,11 +105,24 @@ pub fn initialize_security_associated_account_fields(
     holder_group: &mut Account<HolderGroup>,
     holder: &mut Account<TransferRestrictionHolder>,
     group_id: u64,
+    count_holders_by_balance: bool,
 ) -> Result<()> {
     // Initialize security associated account
     security_associated_account.group = group_id;
     security_associated_account.holder = Some(holder.key());
+    security_associated_account.holder_id = holder.id;
 
+    add_holder_group_wallet(group, holder_group, holder, count_holders_by_balance)
+}
+
+/// Count a new wallet or beneficial owner position in the holder and holder group and count
+/// the holder in the group if it is its first wallet there
+pub fn add_holder_group_wallet(
+    group: &mut Account<TransferRestrictionGroup>,
+    holder_group: &mut Account<HolderGroup>,
+    holder: &mut Account<TransferRestrictionHolder>,
+    count_holders_by_balance: bool,
+) -> Result<()> {
     // Update holder_group wallet count
     holder_group.current_wallets_count = holder_group.current_wallets_count.checked_add(1).unwrap();
 
@@ -101,8 +131,11 @@ pub fn initialize_security_associated_account_fields(
         group.current_holders_count = group.current_holders_count.checked_add(1).unwrap();
     }
 
-    // Check group max holders constraint
-    if group.current_holders_count > group.max_holders && group.max_holders != 0 {
+    // Check group max holders constraint, funded holders are checked by transfer hook instead
+    if !count_holders_by_balance
+        && group.current_holders_count > group.max_holders
+        && group.max_holders != 0
+    {
         return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
     }
 
@@ -112,6 +145,28 @@ pub fn initialize_security_associated_account_fields(
     Ok(())
 }
 
+/// Remove a wallet or beneficial owner position from the holder and holder group and remove
+/// the holder from the group if it was its last wallet there
+pub fn remove_holder_group_wallet(
+    group: &mut Account<TransferRestrictionGroup>,
+    holder_group: &mut Account<HolderGroup>,
+    holder: &mut Account<TransferRestrictionHolder>,
+) -> Result<()> {
+    // It is mostly not possible to have a holder or holder group with no wallets, but just in case
+    if holder_group.current_wallets_count == 0 || holder.current_wallets_count == 0 {
+        return Err(TransferRestrictionsError::NoWalletsInGroup.into());
+    }
+    holder_group.current_wallets_count = holder_group.current_wallets_count.checked_sub(1).unwrap();
+    holder.current_wallets_count = holder.current_wallets_count.checked_sub(1).unwrap();
+
+    if holder_group.current_wallets_count == 0 {
+        // Remove holder from group
+        group.current_holders_count = group.current_holders_count.checked_sub(1).unwrap();
+    }
+
+    Ok(())
+}
+
 /// Check if a wallet's group will change based on group and holder_group comparisons
 pub fn check_if_group_will_change(
     group_current_key: &Pubkey,
@@ -140,6 +195,7 @@ pub fn transfer_wallet_between_groups(
     holder_group_new: &mut Account<HolderGroup>,
     security_associated_account: &mut Account<SecurityAssociatedAccount>,
     new_group_id: u64,
+    count_holders_by_balance: bool,
 ) -> Result<()> {
     // Holder joins new group if it is the first wallet
     if holder_group_new.current_wallets_count == 0 {
@@ -147,7 +203,10 @@ pub fn transfer_wallet_between_groups(
     }
 
     // Check group max count
-    if group_new.current_holders_count > group_new.max_holders && group_new.max_holders != 0 {
+    if !count_holders_by_balance
+        && group_new.current_holders_count > group_new.max_holders
+        && group_new.max_holders != 0
+    {
         return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
     }
 
@@ -169,8 +228,403 @@ pub fn transfer_wallet_between_groups(
             .unwrap();
     }
 
+    // Funded wallet moves its holder between the groups' funded counts
+    if security_associated_account.funded {
+        holder_group_current.funded_wallets_count = holder_group_current
+            .funded_wallets_count
+            .checked_sub(1)
+            .unwrap();
+        if holder_group_current.funded_wallets_count == 0 {
+            group_current.funded_holders_count =
+                group_current.funded_holders_count.checked_sub(1).unwrap();
+        }
+        holder_group_new.funded_wallets_count = holder_group_new
+            .funded_wallets_count
+            .checked_add(1)
+            .unwrap();
+        if holder_group_new.funded_wallets_count == 1 {
+            group_new.funded_holders_count =
+                group_new.funded_holders_count.checked_add(1).unwrap();
+            if count_holders_by_balance
+                && group_new.funded_holders_count > group_new.max_holders
+                && group_new.max_holders != 0
+            {
+                return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
+            }
+        }
+    }
+
     // Update security_associated_account group
     security_associated_account.group = new_group_id;
 
     Ok(())
 }
+
+/// Check that the group account is provided for transfer rule group ids other than
+/// `ANY_TRANSFER_GROUP_ID`
+pub fn check_transfer_rule_group(
+    group_id: u64,
+    group: &Option<Account<TransferRestrictionGroup>>,
+) -> Result<()> {
+    if group_id != ANY_TRANSFER_GROUP_ID && group.is_none() {
+        return Err(TransferRestrictionsError::TransferRestrictionGroupMissing.into());
+    }
+    Ok(())
+}
+
+/// Check that a transfer rule allows transfers at the given unix timestamp
+pub fn check_transfer_rule(transfer_rule: &TransferRule, now: u64) -> Result<()> {
+    if transfer_rule.locked_until == 0 {
+        return Err(TransferRestrictionsError::TransferGroupNotApproved.into());
+    }
+    if transfer_rule.locked_until > now {
+        return Err(TransferRestrictionsError::TransferRuleNotAllowedUntilLater.into());
+    }
+    if transfer_rule.valid_until != 0 && transfer_rule.valid_until <= now {
+        return Err(TransferRestrictionsError::TransferRuleExpired.into());
+    }
+    let closed_intervals =
+        &transfer_rule.closed_intervals[..transfer_rule.closed_intervals_count as usize];
+    if closed_intervals
+        .iter()
+        .any(|interval| is_inside_closed_interval(interval, now))
+    {
+        return Err(TransferRestrictionsError::TransferRuleClosedInterval.into());
+    }
+    Ok(())
+}
+
+/// Check if the timestamp falls inside a one-off or recurring closed interval
+pub fn is_inside_closed_interval(interval: &TransferRuleClosedInterval, now: u64) -> bool {
+    if now < interval.start {
+        return false;
+    }
+    if interval.period == 0 {
+        return now < interval.end;
+    }
+    (now - interval.start) % interval.period < interval.end - interval.start
+}
+
+/// Validate closed intervals before storing them on a transfer rule
+pub fn validate_closed_intervals(closed_intervals: &[TransferRuleClosedInterval]) -> Result<()> {
+    require!(
+        closed_intervals.len() <= MAX_TRANSFER_RULE_CLOSED_INTERVALS,
+        TransferRestrictionsError::TooManyClosedIntervals
+    );
+    for interval in closed_intervals {
+        require!(
+            interval.end > interval.start,
+            TransferRestrictionsError::InvalidClosedInterval
+        );
+        // A recurring interval as long as its period would close the rule forever
+        require!(
+            interval.period == 0 || interval.end - interval.start < interval.period,
+            TransferRestrictionsError::InvalidClosedInterval
+        );
+    }
+    Ok(())
+}
+
+/// Check that no active blackout window blocks the sender or the receiver group
+pub fn check_blackout_calendar(
+    blackout_calendar: &BlackoutCalendar,
+    group_from: u64,
+    group_to: u64,
+    now: u64,
+) -> Result<()> {
+    for window in blackout_calendar.windows.iter() {
+        if now < window.start || now >= window.end {
+            continue;
+        }
+        let applies_to = |group: u64| window.groups.is_empty() || window.groups.contains(&group);
+        if (blocks_direction(window.direction, TransferDirection::Send) && applies_to(group_from))
+            || (blocks_direction(window.direction, TransferDirection::Receive)
+                && applies_to(group_to))
+        {
+            return Err(TransferRestrictionsError::TransferBlackoutActive.into());
+        }
+    }
+    Ok(())
+}
+
+/// Check if an active hold of the wallet blocks the transfer direction
+pub fn has_active_wallet_hold(
+    security_associated_account: &SecurityAssociatedAccount,
+    blocked: TransferDirection,
+    now: u64,
+) -> bool {
+    security_associated_account.holds[..security_associated_account.holds_count as usize]
+        .iter()
+        .any(|hold| {
+            (hold.expires_at == 0 || now < hold.expires_at)
+                && blocks_direction(hold.direction, blocked)
+        })
+}
+
+/// Check that no active hold of the sender blocks sending and no active hold of the receiver
+/// blocks receiving
+pub fn check_wallet_holds(
+    security_associated_account_from: &SecurityAssociatedAccount,
+    security_associated_account_to: &SecurityAssociatedAccount,
+    now: u64,
+) -> Result<()> {
+    if has_active_wallet_hold(security_associated_account_from, TransferDirection::Send, now)
+        || has_active_wallet_hold(security_associated_account_to, TransferDirection::Receive, now)
+    {
+        return Err(TransferRestrictionsError::TransferBlockedByWalletHold.into());
+    }
+    Ok(())
+}
+
+/// Check that the sender group is not paused for sending and the receiver group is not paused
+/// for receiving
+pub fn check_group_pause(
+    group_from: &TransferRestrictionGroup,
+    group_to: &TransferRestrictionGroup,
+) -> Result<()> {
+    if blocks_direction(group_from.paused_direction, TransferDirection::Send)
+        || blocks_direction(group_to.paused_direction, TransferDirection::Receive)
+    {
+        return Err(TransferRestrictionsError::GroupTransfersPaused.into());
+    }
+    Ok(())
+}
+
+/// Check that the sender balance after the transfer is zero or at least the minimum holding of the
+/// sender group and the receiver balance is at least the minimum holding of the receiver group.
+/// Wallets without a holder are not checked.
+pub fn check_min_holding(
+    group_from: &TransferRestrictionGroup,
+    group_to: &TransferRestrictionGroup,
+    security_associated_account_from: &SecurityAssociatedAccount,
+    security_associated_account_to: &SecurityAssociatedAccount,
+    source_balance: u64,
+    destination_balance: u64,
+) -> Result<()> {
+    if security_associated_account_from.holder.is_some()
+        && source_balance > 0
+        && source_balance < group_from.min_holding
+    {
+        return Err(TransferRestrictionsError::SenderBalanceBelowMinHolding.into());
+    }
+    if security_associated_account_to.holder.is_some()
+        && destination_balance < group_to.min_holding
+    {
+        return Err(TransferRestrictionsError::ReceiverBalanceBelowMinHolding.into());
+    }
+    Ok(())
+}
+
+/// Check that the balance left after a transfer still covers all lots inside the holding period
+/// and drop lots which are already free to move
+pub fn spend_acquisition_lots(
+    security_associated_account: &mut SecurityAssociatedAccount,
+    holding_period: u64,
+    balance_after_transfer: u64,
+    now: u64,
+) -> Result<()> {
+    let count = security_associated_account.acquisition_lots_count as usize;
+    let mut locked_lots = [AcquisitionLot::default(); MAX_ACQUISITION_LOTS];
+    let mut locked_count = 0;
+    let mut locked_amount: u64 = 0;
+    for lot in security_associated_account.acquisition_lots[..count].iter() {
+        if lot.acquired_at.saturating_add(holding_period) > now {
+            locked_lots[locked_count] = *lot;
+            locked_count += 1;
+            locked_amount = locked_amount.saturating_add(lot.amount);
+        }
+    }
+    if balance_after_transfer < locked_amount {
+        return Err(TransferRestrictionsError::HoldingPeriodNotElapsed.into());
+    }
+    security_associated_account.acquisition_lots = locked_lots;
+    security_associated_account.acquisition_lots_count = locked_count as u8;
+    Ok(())
+}
+
+/// Record received tokens as a new acquisition lot.
+/// When all lots are used the amount is added to the most recent lot, which keeps its acquisition
+/// time so that a sender can not restart the holding period of the receiver's tokens.
+pub fn record_acquisition_lot(
+    security_associated_account: &mut SecurityAssociatedAccount,
+    amount: u64,
+    now: u64,
+) {
+    let count = security_associated_account.acquisition_lots_count as usize;
+    if count == MAX_ACQUISITION_LOTS {
+        let last_lot = &mut security_associated_account.acquisition_lots[count - 1];
+        last_lot.amount = last_lot.amount.saturating_add(amount);
+        return;
+    }
+    security_associated_account.acquisition_lots[count] = AcquisitionLot {
+        acquired_at: now,
+        amount,
+    };
+    security_associated_account.acquisition_lots_count += 1;
+}
+
+/// Check that the omnibus account balance left after a transfer covers its beneficial owner
+/// positions
+pub fn check_omnibus_balance(
+    security_associated_account: &SecurityAssociatedAccount,
+    balance_after_transfer: u64,
+) -> Result<()> {
+    if security_associated_account.omnibus
+        && balance_after_transfer < security_associated_account.beneficial_owned_amount
+    {
+        return Err(TransferRestrictionsError::OmnibusBalanceBelowBeneficialOwnership.into());
+    }
+    Ok(())
+}
+
+/// Check that the holder of the wallet is not frozen. Wallets without a holder are not checked.
+/// A holder account which is not the holder of the wallet is rejected, e.g. for wallets created
+/// before `holder_id` was stored until the holder id is backfilled.
+pub fn check_holder_frozen(
+    security_associated_account: &SecurityAssociatedAccount,
+    holder_info: &AccountInfo,
+) -> Result<()> {
+    let Some(holder) = security_associated_account.holder else {
+        return Ok(());
+    };
+    if holder != holder_info.key() {
+        return Err(TransferRestrictionsError::InvalidHolderAccount.into());
+    }
+    let holder = read_account::<TransferRestrictionHolder>(holder_info)?;
+    if holder.frozen {
+        return Err(TransferRestrictionsError::HolderFrozen.into());
+    }
+    Ok(())
+}
+
+/// Check if the transfer moves tokens between wallets of the same holder and the security allows
+/// such transfers regardless of the transfer rules
+pub fn is_intra_holder_transfer(
+    allow_intra_holder_transfers: bool,
+    security_associated_account_from: &SecurityAssociatedAccount,
+    security_associated_account_to: &SecurityAssociatedAccount,
+) -> bool {
+    allow_intra_holder_transfers
+        && security_associated_account_from.holder.is_some()
+        && security_associated_account_from.holder == security_associated_account_to.holder
+}
+
+/// Check that the wallet is attested by one of the trusted attestation issuers. The issuers share
+/// the attestation program, which keeps a single attestation account per wallet. Wallets without a
+/// holder are system accounts and are not checked.
+pub fn check_wallet_attestation(
+    attestation_issuers: &[AttestationIssuer],
+    security_associated_account: &SecurityAssociatedAccount,
+    wallet: &Pubkey,
+    attestation: &AccountInfo,
+    now: u64,
+) -> Result<()> {
+    let Some(first_attestation_issuer) = attestation_issuers.first() else {
+        return Ok(());
+    };
+    if security_associated_account.holder.is_none() {
+        return Ok(());
+    }
+    let attested = *attestation.owner == first_attestation_issuer.program_id
+        && attestation.try_borrow_data().is_ok_and(|data| {
+            attestation_issuers
+                .iter()
+                .any(|attestation_issuer| attestation_issuer.attests(&data, wallet, now))
+        });
+    if !attested {
+        return Err(TransferRestrictionsError::WalletNotAttested.into());
+    }
+    Ok(())
+}
+
+/// Check that the transfer approval is not expired and covers the amount and decrease the
+/// approved amount by the transferred amount
+pub fn spend_transfer_approval(
+    transfer_approval: &mut TransferApproval,
+    amount: u64,
+    now: u64,
+) -> Result<()> {
+    if transfer_approval.expires_at != 0 && now >= transfer_approval.expires_at {
+        return Err(TransferRestrictionsError::TransferApprovalExpired.into());
+    }
+    transfer_approval.remaining_amount = transfer_approval
+        .remaining_amount
+        .checked_sub(amount)
+        .ok_or(TransferRestrictionsError::TransferApprovalAmountExceeded)?;
+    Ok(())
+}
+
+/// Transfer hook state can only be updated while Token-2022 is executing a transfer
+pub fn check_is_transferring(token_account: &AccountInfo) -> Result<()> {
+    let account_data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account_data)?;
+    let extension = account.get_extension::<TransferHookAccount>()?;
+    if !bool::from(extension.transferring) {
+        return Err(TransferRestrictionsError::NotTransferring.into());
+    }
+    Ok(())
+}
+
+/// Token accounts registered for a wallet keep their owner when they have the immutable owner
+/// extension, which associated token accounts of Token-2022 always have
+pub fn has_immutable_owner(token_account: &AccountInfo) -> bool {
+    let Ok(account_data) = token_account.try_borrow_data() else {
+        return false;
+    };
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account_data)
+        .is_ok_and(|account| account.get_extension::<ImmutableOwner>().is_ok())
+}
+
+/// Count a wallet whose balance became non-zero in the holder, group and total funded counts
+pub fn add_funded_wallet(
+    transfer_restriction_data: &mut TransferRestrictionData,
+    group: &mut TransferRestrictionGroup,
+    holder: &mut TransferRestrictionHolder,
+    holder_group: &mut HolderGroup,
+    check_max_holders: bool,
+) -> Result<()> {
+    holder_group.funded_wallets_count = holder_group.funded_wallets_count.checked_add(1).unwrap();
+    if holder_group.funded_wallets_count == 1 {
+        group.funded_holders_count = group.funded_holders_count.checked_add(1).unwrap();
+        if check_max_holders && group.max_holders != 0 && group.funded_holders_count > group.max_holders
+        {
+            return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
+        }
+    }
+
+    holder.funded_wallets_count = holder.funded_wallets_count.checked_add(1).unwrap();
+    if holder.funded_wallets_count == 1 {
+        transfer_restriction_data.funded_holders_count = transfer_restriction_data
+            .funded_holders_count
+            .checked_add(1)
+            .unwrap();
+        if check_max_holders
+            && transfer_restriction_data.funded_holders_count
+                > transfer_restriction_data.max_holders
+        {
+            return Err(TransferRestrictionsError::MaxHoldersReached.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove a wallet whose balance dropped to zero from the holder, group and total funded counts
+pub fn remove_funded_wallet(
+    transfer_restriction_data: &mut TransferRestrictionData,
+    group: &mut TransferRestrictionGroup,
+    holder: &mut TransferRestrictionHolder,
+    holder_group: &mut HolderGroup,
+) {
+    holder_group.funded_wallets_count = holder_group.funded_wallets_count.saturating_sub(1);
+    if holder_group.funded_wallets_count == 0 {
+        group.funded_holders_count = group.funded_holders_count.saturating_sub(1);
+    }
+
+    holder.funded_wallets_count = holder.funded_wallets_count.saturating_sub(1);
+    if holder.funded_wallets_count == 0 {
+        transfer_restriction_data.funded_holders_count = transfer_restriction_data
+            .funded_holders_count
+            .saturating_sub(1);
+    }
+}
@@ -1,100 +1,500 @@
-use access_control::common::DISCRIMINATOR_LEN;
-use anchor_lang::prelude::*;
+use access_control::{ExemptAccountKind, ExemptAccountRegistry};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke},
+};
 use anchor_spl::{
     token_2022::spl_token_2022::extension::permanent_delegate::PermanentDelegate,
     token_interface::get_mint_extension_data,
 };
 
 use crate::{
-    errors::TransferRestrictionsError, verify_pda,
-    ExecuteTransferHook, SecurityAssociatedAccount, TransferRestrictionData, TransferRule,
-    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RULE_PREFIX,
+    add_funded_wallet, check_blackout_calendar, check_group_pause, check_holder_frozen,
+    check_is_transferring, check_min_holding, check_omnibus_balance, check_transfer_rule,
+    check_wallet_attestation, check_wallet_holds, errors::TransferRestrictionsError,
+    is_intra_holder_transfer, load_attestation_issuers, load_blackout_calendar,
+    load_security_associated_account, load_transfer_approval, load_transfer_restriction_group,
+    read_account, read_transfer_restriction_data_flags, record_acquisition_lot,
+    remove_funded_wallet, resolve_transfer_rule, spend_acquisition_lots, spend_transfer_approval,
+    verify_exempt_account_registry, verify_funded_wallet_accounts, verify_pda_with_bump,
+    write_account, ExecuteTransferHook, HolderGroup, PolicyCheckTransferInstruction,
+    SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
+    TransferRestrictionHolder, TRANSFER_RESTRICTION_DATA_PREFIX,
 };
+use spl_discriminator::SplDiscriminate;
 
-pub fn handler(ctx: Context<ExecuteTransferHook>, _amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<ExecuteTransferHook>, amount: u64) -> Result<()> {
     let mint_data: &AccountInfo = &ctx.accounts.mint.to_account_info();
     let permanent_delegate_extension = get_mint_extension_data::<PermanentDelegate>(mint_data)?;
     // if permanent delegate is execute transfer hook owner delegate
     // we don't need to check transfer restrictions because it's force transfer between
-    if permanent_delegate_extension.delegate
-        == Some(ctx.accounts.owner_delegate.key()).try_into().unwrap()
-    {
-        return Ok(());
-    }
+    let is_force_transfer = permanent_delegate_extension.delegate
+        == Some(ctx.accounts.owner_delegate.key()).try_into().unwrap();
 
-    verify_pda(
+    if ctx.accounts.transfer_restriction_data.data_is_empty() {
+        return Err(TransferRestrictionsError::TransferRestrictionsAccountDataIsEmtpy.into());
+    }
+    // transfer restriction data is deserialized only when the hook updates holder counts
+    let transfer_restriction_data_flags =
+        read_transfer_restriction_data_flags(&ctx.accounts.transfer_restriction_data)?;
+    verify_pda_with_bump(
         ctx.accounts.transfer_restriction_data.key,
         &[
             TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
             &mint_data.key().to_bytes(),
         ],
-        &ctx.program_id,
+        transfer_restriction_data_flags.bump,
+        ctx.program_id,
     )?;
-    if ctx.accounts.transfer_restriction_data.data_is_empty() {
-        return Err(TransferRestrictionsError::TransferRestrictionsAccountDataIsEmtpy.into());
-    }
-    let transfer_restriction_data = TransferRestrictionData::deserialize(
-        &mut &ctx.accounts.transfer_restriction_data.data.borrow()[DISCRIMINATOR_LEN..],
+    verify_exempt_account_registry(
+        &ctx.accounts.exempt_account_registry,
+        &mint_data.key(),
+        true,
     )?;
+    let (exempt_source, exempt_destination) = {
+        let exempt_account_registry_data =
+            ctx.accounts.exempt_account_registry.try_borrow_data()?;
+        (
+            ExemptAccountRegistry::read_exempt_account_kind(
+                &exempt_account_registry_data,
+                &ctx.accounts.source_account.key(),
+            ),
+            ExemptAccountRegistry::read_exempt_account_kind(
+                &exempt_account_registry_data,
+                &ctx.accounts.destination_account.key(),
+            ),
+        )
+    };
     // transfer restriction for lockup escrow account is validated inside tokenlock program
-    if transfer_restriction_data.lockup_escrow_account == Some(ctx.accounts.source_account.key()) {
-        return Ok(());
+    if is_force_transfer
+        || transfer_restriction_data_flags.lockup_escrow_account
+            == Some(ctx.accounts.source_account.key())
+        || exempt_source == Some(ExemptAccountKind::TokenlockEscrow)
+    {
+        // tokens released from the lockup escrow are acquired by the receiver, force transfers
+        // are not acquisitions
+        return update_exempt_transfer(
+            &ctx,
+            transfer_restriction_data_flags.count_holders_by_balance,
+            (!is_force_transfer).then_some(amount),
+        );
     }
-    if transfer_restriction_data.paused {
+    if transfer_restriction_data_flags.paused {
         return Err(TransferRestrictionsError::AllTransfersPaused.into());
     }
+    // distributions from and redemptions into the vaults and issuance from the treasury are not
+    // restricted by the transfer rules, holding periods, minimum holdings and holder limits, the
+    // pause, freeze, hold and attestation checks of the wallets still apply
+    let is_exempt_transfer = matches!(
+        exempt_source,
+        Some(ExemptAccountKind::DistributionVault | ExemptAccountKind::Treasury)
+    ) || exempt_destination == Some(ExemptAccountKind::DistributionVault);
 
-    verify_pda(
-        ctx.accounts.security_associated_account_from.key,
-        &[
-            SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
-            &ctx.accounts.source_account.key().to_bytes(),
-        ],
-        &ctx.program_id,
+    let mut security_associated_account_from = load_security_associated_account(
+        &ctx.accounts.security_associated_account_from,
+        &ctx.accounts.source_account.key(),
+        ctx.program_id,
     )?;
-    if ctx.accounts.security_associated_account_from.data_is_empty() {
-        return Err(TransferRestrictionsError::SecurityAssociatedAccountDataIsEmtpy.into());
+    let mut security_associated_account_to = load_security_associated_account(
+        &ctx.accounts.security_associated_account_to,
+        &ctx.accounts.destination_account.key(),
+        ctx.program_id,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    // transfers between wallets of the same holder skip the transfer rule and its approval
+    let transfer_rule = if is_exempt_transfer
+        || is_intra_holder_transfer(
+            transfer_restriction_data_flags.allow_intra_holder_transfers,
+            &security_associated_account_from,
+            &security_associated_account_to,
+        ) {
+        None
+    } else {
+        let transfer_rule = resolve_transfer_rule(
+            &ctx.accounts.transfer_rule,
+            &[
+                ctx.accounts.transfer_rule_from_any.to_account_info(),
+                ctx.accounts.transfer_rule_to_any.to_account_info(),
+                ctx.accounts.transfer_rule_any.to_account_info(),
+            ],
+            &ctx.accounts.transfer_restriction_data.key(),
+            security_associated_account_from.group,
+            security_associated_account_to.group,
+            ctx.program_id,
+            true,
+        )?;
+        check_transfer_rule(&transfer_rule, now)?;
+        Some(transfer_rule)
+    };
+
+    // blackout calendar is optional, transfers are not restricted until it is initialized
+    if let Some(blackout_calendar) = load_blackout_calendar(
+        &ctx.accounts.blackout_calendar,
+        &ctx.accounts.transfer_restriction_data.key(),
+        ctx.program_id,
+        true,
+    )? {
+        check_blackout_calendar(
+            &blackout_calendar,
+            security_associated_account_from.group,
+            security_associated_account_to.group,
+            now,
+        )?;
     }
-    let security_associated_account_from = SecurityAssociatedAccount::deserialize(
-        &mut &ctx.accounts.security_associated_account_from.data.borrow()[DISCRIMINATOR_LEN..],
+    check_holder_frozen(
+        &security_associated_account_from,
+        &ctx.accounts.transfer_restriction_holder_from,
     )?;
-    verify_pda(
-        ctx.accounts.security_associated_account_to.key,
-        &[
-            SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
-            &ctx.accounts.destination_account.key().to_bytes(),
-        ],
-        &ctx.program_id,
+    check_holder_frozen(
+        &security_associated_account_to,
+        &ctx.accounts.transfer_restriction_holder_to,
+    )?;
+    check_wallet_holds(
+        &security_associated_account_from,
+        &security_associated_account_to,
+        now,
+    )?;
+    let attestation_issuers = load_attestation_issuers(
+        &ctx.accounts.attestation_issuer_registry,
+        &mint_data.key(),
+        ctx.program_id,
+        true,
+    )?;
+    check_wallet_attestation(
+        &attestation_issuers,
+        &security_associated_account_from,
+        &ctx.accounts.source_account.owner,
+        &ctx.accounts.attestation_from,
+        now,
+    )?;
+    check_wallet_attestation(
+        &attestation_issuers,
+        &security_associated_account_to,
+        &ctx.accounts.destination_account.owner,
+        &ctx.accounts.attestation_to,
+        now,
+    )?;
+
+    // transfer to the same token account does not change its acquisition lots
+    if ctx.accounts.source_account.key() == ctx.accounts.destination_account.key() {
+        return Ok(());
+    }
+    check_is_transferring(&ctx.accounts.source_account.to_account_info())?;
+    // source balance is already decreased by the transferred amount when the hook is executed
+    check_omnibus_balance(
+        &security_associated_account_from,
+        ctx.accounts.source_account.amount,
     )?;
-    if ctx.accounts.security_associated_account_to.data_is_empty() {
-        return Err(TransferRestrictionsError::SecurityAssociatedAccountDataIsEmtpy.into());
+    if transfer_rule.is_some_and(|transfer_rule| transfer_rule.approval_required) {
+        let mut transfer_approval = load_transfer_approval(
+            &ctx.accounts.transfer_approval,
+            &ctx.accounts.transfer_restriction_data.key(),
+            &ctx.accounts.source_account.key(),
+            &ctx.accounts.destination_account.key(),
+            ctx.program_id,
+        )?;
+        spend_transfer_approval(&mut transfer_approval, amount, now)?;
+        write_account(&ctx.accounts.transfer_approval, &transfer_approval)?;
     }
-    let security_associated_account_to = SecurityAssociatedAccount::deserialize(
-        &mut &ctx.accounts.security_associated_account_to.data.borrow()[DISCRIMINATOR_LEN..],
+
+    let transfer_restriction_group_from = load_transfer_restriction_group(
+        &ctx.accounts.transfer_restriction_group_from,
+        &ctx.accounts.transfer_restriction_data.key(),
+        security_associated_account_from.group,
+        ctx.program_id,
     )?;
+    let transfer_restriction_group_to = load_transfer_restriction_group(
+        &ctx.accounts.transfer_restriction_group_to,
+        &ctx.accounts.transfer_restriction_data.key(),
+        security_associated_account_to.group,
+        ctx.program_id,
+    )?;
+    check_group_pause(
+        &transfer_restriction_group_from,
+        &transfer_restriction_group_to,
+    )?;
+    // balances are already updated by the transfer when the hook is executed
+    if !is_exempt_transfer {
+        check_min_holding(
+            &transfer_restriction_group_from,
+            &transfer_restriction_group_to,
+            &security_associated_account_from,
+            &security_associated_account_to,
+            ctx.accounts.source_account.amount,
+            ctx.accounts.destination_account.amount,
+        )?;
+    }
+
+    // security associated accounts are stored only when their lots or funded flags change
+    let mut lots_changed_from = false;
+    if !is_exempt_transfer
+        && (transfer_restriction_group_from.holding_period > 0
+            || security_associated_account_from.acquisition_lots_count > 0)
+    {
+        let lots_count = security_associated_account_from.acquisition_lots_count;
+        spend_acquisition_lots(
+            &mut security_associated_account_from,
+            transfer_restriction_group_from.holding_period,
+            ctx.accounts.source_account.amount,
+            now,
+        )?;
+        lots_changed_from = security_associated_account_from.acquisition_lots_count != lots_count;
+    }
+    let lots_changed_to = transfer_restriction_group_to.holding_period > 0;
+    if lots_changed_to {
+        record_acquisition_lot(&mut security_associated_account_to, amount, now);
+    }
+
+    if transfer_restriction_data_flags.count_holders_by_balance {
+        update_funded_holders(
+            &ctx,
+            &mut security_associated_account_from,
+            &mut security_associated_account_to,
+            lots_changed_from,
+            lots_changed_to,
+            !is_exempt_transfer,
+        )?;
+    } else {
+        if lots_changed_from {
+            write_account(
+                &ctx.accounts.security_associated_account_from,
+                &security_associated_account_from,
+            )?;
+        }
+        if lots_changed_to {
+            write_account(
+                &ctx.accounts.security_associated_account_to,
+                &security_associated_account_to,
+            )?;
+        }
+    }
+
+    if let Some(policy_program) = transfer_restriction_data_flags
+        .policy_program
+        .filter(|_| !is_exempt_transfer)
+    {
+        invoke_policy_program(&ctx, &policy_program, amount)?;
+    }
 
-    verify_pda(
-        ctx.accounts.transfer_rule.key,
+    Ok(())
+}
+
+/// Call the policy program of the security after the built-in checks, the transfer fails when the
+/// policy program fails
+fn invoke_policy_program(
+    ctx: &Context<ExecuteTransferHook>,
+    policy_program: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    // meta list written before the policy program was set resolves another program
+    if ctx.accounts.policy_program.key() != *policy_program {
+        return Err(TransferRestrictionsError::InvalidPolicyProgram.into());
+    }
+    let account_infos = [
+        ctx.accounts.source_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.destination_account.to_account_info(),
+        ctx.accounts.owner_delegate.to_account_info(),
+        ctx.accounts
+            .security_associated_account_from
+            .to_account_info(),
+        ctx.accounts
+            .security_associated_account_to
+            .to_account_info(),
+        ctx.accounts.policy_config.to_account_info(),
+    ];
+    let mut data = PolicyCheckTransferInstruction::SPL_DISCRIMINATOR_SLICE.to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    let instruction = Instruction {
+        program_id: *policy_program,
+        accounts: account_infos
+            .iter()
+            .map(|account_info| AccountMeta::new_readonly(account_info.key(), false))
+            .collect(),
+        data,
+    };
+    invoke(
+        &instruction,
         &[
-            TRANSFER_RULE_PREFIX.as_bytes(),
-            &ctx.accounts.transfer_restriction_data.key().to_bytes(),
-            &security_associated_account_from.group.to_le_bytes(),
-            &security_associated_account_to.group.to_le_bytes(),
-        ],
-        &ctx.program_id,
+            &account_infos[..],
+            &[ctx.accounts.policy_program.to_account_info()],
+        ]
+        .concat(),
     )?;
-    if ctx.accounts.transfer_rule.data_is_empty() {
-        return Err(TransferRestrictionsError::TransferRuleAccountDataIsEmtpy.into());
+    Ok(())
+}
+
+/// Record the acquisition lot of the receiver and update funded holder counts of the transfer
+/// which is not restricted by the transfer hook. `acquired_amount` is recorded when the receiver
+/// group has a holding period. Holder counts still follow the balances, but the holder limits are
+/// not enforced.
+fn update_exempt_transfer(
+    ctx: &Context<ExecuteTransferHook>,
+    count_holders_by_balance: bool,
+    acquired_amount: Option<u64>,
+) -> Result<()> {
+    if (!count_holders_by_balance && acquired_amount.is_none())
+        || ctx.accounts.source_account.key() == ctx.accounts.destination_account.key()
+    {
+        return Ok(());
     }
-    let transfer_rule = TransferRule::deserialize(
-        &mut &ctx.accounts.transfer_rule.data.borrow()[DISCRIMINATOR_LEN..],
+    check_is_transferring(&ctx.accounts.source_account.to_account_info())?;
+    let mut security_associated_account_to = load_security_associated_account(
+        &ctx.accounts.security_associated_account_to,
+        &ctx.accounts.destination_account.key(),
+        ctx.program_id,
     )?;
-    if transfer_rule.locked_until == 0 {
-        return Err(TransferRestrictionsError::TransferGroupNotApproved.into());
+    let mut lots_changed_to = false;
+    if let Some(acquired_amount) = acquired_amount {
+        let transfer_restriction_group_to = load_transfer_restriction_group(
+            &ctx.accounts.transfer_restriction_group_to,
+            &ctx.accounts.transfer_restriction_data.key(),
+            security_associated_account_to.group,
+            ctx.program_id,
+        )?;
+        if transfer_restriction_group_to.holding_period > 0 {
+            record_acquisition_lot(
+                &mut security_associated_account_to,
+                acquired_amount,
+                Clock::get()?.unix_timestamp as u64,
+            );
+            lots_changed_to = true;
+        }
+    }
+    if !count_holders_by_balance {
+        if lots_changed_to {
+            write_account(
+                &ctx.accounts.security_associated_account_to,
+                &security_associated_account_to,
+            )?;
+        }
+        return Ok(());
     }
-    if transfer_rule.locked_until > Clock::get()?.unix_timestamp as u64 {
-        return Err(TransferRestrictionsError::TransferRuleNotAllowedUntilLater.into());
+
+    let mut security_associated_account_from = load_security_associated_account(
+        &ctx.accounts.security_associated_account_from,
+        &ctx.accounts.source_account.key(),
+        ctx.program_id,
+    )?;
+    update_funded_holders(
+        ctx,
+        &mut security_associated_account_from,
+        &mut security_associated_account_to,
+        false,
+        lots_changed_to,
+        false,
+    )
+}
+
+/// Update funded holder counts when the sender balance drops to zero or the receiver balance
+/// becomes non-zero. The transfer restriction data is loaded and stored only when a funded flag
+/// switches, and a security associated account is stored only when its lots or funded flag change.
+fn update_funded_holders(
+    ctx: &Context<ExecuteTransferHook>,
+    security_associated_account_from: &mut SecurityAssociatedAccount,
+    security_associated_account_to: &mut SecurityAssociatedAccount,
+    mut changed_from: bool,
+    mut changed_to: bool,
+    check_max_holders: bool,
+) -> Result<()> {
+    let update_from =
+        security_associated_account_from.funded && ctx.accounts.source_account.amount == 0;
+    let update_to =
+        !security_associated_account_to.funded && ctx.accounts.destination_account.amount > 0;
+    if update_from || update_to {
+        let mut transfer_restriction_data =
+            read_account::<TransferRestrictionData>(&ctx.accounts.transfer_restriction_data)?;
+        // sender is processed and stored first as both sides can share holder and group accounts
+        if update_from {
+            changed_from |= update_funded_wallet(
+                ctx,
+                &mut transfer_restriction_data,
+                security_associated_account_from,
+                &ctx.accounts.transfer_restriction_holder_from,
+                &ctx.accounts.holder_group_from,
+                &ctx.accounts.transfer_restriction_group_from,
+                check_max_holders,
+            )?;
+        }
+        if update_to {
+            changed_to |= update_funded_wallet(
+                ctx,
+                &mut transfer_restriction_data,
+                security_associated_account_to,
+                &ctx.accounts.transfer_restriction_holder_to,
+                &ctx.accounts.holder_group_to,
+                &ctx.accounts.transfer_restriction_group_to,
+                check_max_holders,
+            )?;
+        }
+        write_account(
+            &ctx.accounts.transfer_restriction_data,
+            &transfer_restriction_data,
+        )?;
     }
 
+    if changed_from {
+        write_account(
+            &ctx.accounts.security_associated_account_from,
+            security_associated_account_from,
+        )?;
+    }
+    if changed_to {
+        write_account(
+            &ctx.accounts.security_associated_account_to,
+            security_associated_account_to,
+        )?;
+    }
     Ok(())
 }
+
+/// Switch the funded flag of the wallet and update the holder, holder group and group counts.
+/// Returns false when the wallet has no holder accounts to count.
+fn update_funded_wallet(
+    ctx: &Context<ExecuteTransferHook>,
+    transfer_restriction_data: &mut TransferRestrictionData,
+    security_associated_account: &mut SecurityAssociatedAccount,
+    holder_info: &AccountInfo,
+    holder_group_info: &AccountInfo,
+    group_info: &AccountInfo,
+    check_max_holders: bool,
+) -> Result<bool> {
+    if !verify_funded_wallet_accounts(
+        &ctx.accounts.transfer_restriction_data.key(),
+        security_associated_account,
+        holder_info,
+        holder_group_info,
+        group_info,
+        ctx.program_id,
+        true,
+    )? {
+        return Ok(false);
+    }
+    let mut holder = read_account::<TransferRestrictionHolder>(holder_info)?;
+    let mut holder_group = read_account::<HolderGroup>(holder_group_info)?;
+    let mut group = read_account::<TransferRestrictionGroup>(group_info)?;
+
+    security_associated_account.funded = !security_associated_account.funded;
+    if security_associated_account.funded {
+        add_funded_wallet(
+            transfer_restriction_data,
+            &mut group,
+            &mut holder,
+            &mut holder_group,
+            check_max_holders,
+        )?;
+    } else {
+        remove_funded_wallet(
+            transfer_restriction_data,
+            &mut group,
+            &mut holder,
+            &mut holder_group,
+        );
+    }
+
+    write_account(holder_info, &holder)?;
+    write_account(holder_group_info, &holder_group)?;
+    write_account(group_info, &group)?;
+    Ok(true)
+}
@@ -1,11 +1,9 @@
 use crate::{
     contexts::InitializeExtraAccountMetaList, errors::TransferRestrictionsError,
-    get_extra_account_metas,
+    write_extra_account_metas,
 };
 use access_control::Roles;
 use anchor_lang::prelude::*;
-use spl_tlv_account_resolution::state::ExtraAccountMetaList;
-use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
 pub fn initialize_extra_account_meta_list(
     ctx: Context<InitializeExtraAccountMetaList>,
@@ -19,9 +17,9 @@ pub fn initialize_extra_account_meta_list(
     }
 
     let extra_metas_account = &ctx.accounts.extra_metas_account;
-    let metas = get_extra_account_metas()?;
     let mut data = extra_metas_account.try_borrow_mut_data()?;
-    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+    // attestation issuers, the policy program and holder counting are set once the meta list exists
+    write_extra_account_metas(&mut data, &[], &Pubkey::default(), false)?;
 
     Ok(())
 }
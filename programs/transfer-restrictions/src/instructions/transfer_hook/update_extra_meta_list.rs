@@ -0,0 +1,38 @@
+use crate::{
+    contexts::UpdateExtraAccountMetaList, errors::TransferRestrictionsError,
+    load_attestation_issuers, read_transfer_restriction_data_flags, rewrite_extra_account_metas,
+};
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+/// Rewrite the meta list of the mint with the current extra account metas of the transfer hook.
+/// The account is reallocated to the size of the current list before it is written.
+pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    // transfer restriction data is read at offsets as it can be migrated after the meta list update
+    let transfer_restriction_data_flags =
+        read_transfer_restriction_data_flags(&ctx.accounts.transfer_restriction_data)?;
+    let attestation_issuers = load_attestation_issuers(
+        &ctx.accounts.attestation_issuer_registry,
+        &ctx.accounts.security_mint.key(),
+        ctx.program_id,
+        false,
+    )?;
+    rewrite_extra_account_metas(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &attestation_issuers,
+        &transfer_restriction_data_flags
+            .policy_program
+            .unwrap_or_default(),
+        transfer_restriction_data_flags.count_holders_by_balance,
+    )
+}
@@ -0,0 +1,70 @@
+use access_control::{Roles, VersionedAccount};
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, rewrite_extra_account_metas, AddAttestationIssuer,
+    AttestationIssuer, AttestationIssuerRegistry, AttestationLayout, MAX_ATTESTATION_ISSUERS,
+};
+
+/// Trust attestations of the issuer program under the schema and add the attestation accounts of
+/// the issuer program to the transfer hook accounts. All issuers must use the same program.
+pub fn add_attestation_issuer(
+    ctx: Context<AddAttestationIssuer>,
+    program_id: Pubkey,
+    schema: Pubkey,
+    layout: AttestationLayout,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    require!(
+        program_id != Pubkey::default() && layout.discriminator != [0; 8],
+        TransferRestrictionsError::InvalidAttestationIssuer
+    );
+
+    let attestation_issuer_registry = &mut ctx.accounts.attestation_issuer_registry;
+    // registry is created with the first issuer
+    if attestation_issuer_registry.transfer_restriction_data == Pubkey::default() {
+        attestation_issuer_registry.transfer_restriction_data =
+            ctx.accounts.transfer_restriction_data.key();
+        attestation_issuer_registry.bump = ctx.bumps.attestation_issuer_registry;
+        attestation_issuer_registry.version = AttestationIssuerRegistry::VERSION;
+    }
+    if attestation_issuer_registry
+        .active_issuers()
+        .iter()
+        .any(|issuer| issuer.program_id == program_id && issuer.schema == schema)
+    {
+        return Err(TransferRestrictionsError::AttestationIssuerAlreadyRegistered.into());
+    }
+    if attestation_issuer_registry
+        .active_issuers()
+        .iter()
+        .any(|issuer| issuer.program_id != program_id)
+    {
+        return Err(TransferRestrictionsError::AttestationProgramMismatch.into());
+    }
+    let count = attestation_issuer_registry.issuers_count as usize;
+    if count >= MAX_ATTESTATION_ISSUERS {
+        return Err(TransferRestrictionsError::AttestationIssuersLimitReached.into());
+    }
+    attestation_issuer_registry.issuers[count] = AttestationIssuer {
+        program_id,
+        schema,
+        layout,
+    };
+    attestation_issuer_registry.issuers_count += 1;
+
+    rewrite_extra_account_metas(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        attestation_issuer_registry.active_issuers(),
+        &ctx.accounts.transfer_restriction_data.policy_program,
+        ctx.accounts.transfer_restriction_data.count_holders_by_balance,
+    )
+}
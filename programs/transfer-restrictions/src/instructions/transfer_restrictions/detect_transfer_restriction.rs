@@ -0,0 +1,340 @@
+use access_control::{ExemptAccountKind, ExemptAccountRegistry};
+use anchor_lang::prelude::*;
+
+use crate::{
+    add_funded_wallet, check_blackout_calendar, check_group_pause, check_holder_frozen,
+    check_min_holding, check_omnibus_balance, check_transfer_rule, check_wallet_attestation,
+    check_wallet_holds, errors::TransferRestrictionsError, is_intra_holder_transfer,
+    load_attestation_issuers, load_blackout_calendar, load_security_associated_account,
+    load_transfer_approval, load_transfer_restriction_group, read_account, remove_funded_wallet,
+    resolve_transfer_rule, spend_acquisition_lots, spend_transfer_approval,
+    verify_exempt_account_registry, verify_funded_wallet_accounts, verify_pda_with_bump,
+    DetectTransferRestriction, HolderGroup, SecurityAssociatedAccount, TransferRestrictionData,
+    TransferRestrictionDetection, TransferRestrictionGroup, TransferRestrictionHolder,
+    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_SUCCESS_CODE,
+    TRANSFER_RESTRICTION_SUCCESS_MESSAGE,
+};
+
+/// Evaluate transfer hook checks for the transfer without executing it.
+/// Restriction is returned with the error code and message the transfer hook would fail with.
+/// The policy program is not called, a transfer which passes the built-in checks of a security
+/// with a policy program is returned with the `TransferDependsOnPolicyProgram` code.
+pub fn detect_transfer_restriction(
+    ctx: Context<DetectTransferRestriction>,
+    amount: u64,
+) -> Result<TransferRestrictionDetection> {
+    match check_transfer_restrictions(&ctx, amount) {
+        Ok(()) => Ok(TransferRestrictionDetection {
+            code: TRANSFER_RESTRICTION_SUCCESS_CODE,
+            message: TRANSFER_RESTRICTION_SUCCESS_MESSAGE.to_string(),
+        }),
+        // invalid accounts are caller errors and not transfer restrictions
+        Err(Error::AnchorError(error))
+            if error.error_code_number != u32::from(TransferRestrictionsError::InvalidPDA) =>
+        {
+            Ok(TransferRestrictionDetection {
+                code: error.error_code_number,
+                message: error.error_msg,
+            })
+        }
+        Err(error) => Err(error),
+    }
+}
+
+fn check_transfer_restrictions(
+    ctx: &Context<DetectTransferRestriction>,
+    amount: u64,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    if accounts.transfer_restriction_data.data_is_empty() {
+        return Err(TransferRestrictionsError::TransferRestrictionsAccountDataIsEmtpy.into());
+    }
+    let transfer_restriction_data =
+        read_account::<TransferRestrictionData>(&accounts.transfer_restriction_data)?;
+    verify_pda_with_bump(
+        accounts.transfer_restriction_data.key,
+        &[
+            TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+            &accounts.mint.key().to_bytes(),
+        ],
+        transfer_restriction_data.bump,
+        ctx.program_id,
+    )?;
+    verify_exempt_account_registry(
+        &accounts.exempt_account_registry,
+        &accounts.mint.key(),
+        false,
+    )?;
+    let (exempt_source, exempt_destination) = {
+        let exempt_account_registry_data = accounts.exempt_account_registry.try_borrow_data()?;
+        (
+            ExemptAccountRegistry::read_exempt_account_kind(
+                &exempt_account_registry_data,
+                &accounts.source_account.key(),
+            ),
+            ExemptAccountRegistry::read_exempt_account_kind(
+                &exempt_account_registry_data,
+                &accounts.destination_account.key(),
+            ),
+        )
+    };
+    // transfer restriction for lockup escrow account is validated inside tokenlock program
+    if transfer_restriction_data.lockup_escrow_account == Some(accounts.source_account.key())
+        || exempt_source == Some(ExemptAccountKind::TokenlockEscrow)
+    {
+        return Ok(());
+    }
+    if transfer_restriction_data.paused {
+        return Err(TransferRestrictionsError::AllTransfersPaused.into());
+    }
+    // exempt vault and treasury transfers skip the same checks as in the transfer hook
+    let is_exempt_transfer = matches!(
+        exempt_source,
+        Some(ExemptAccountKind::DistributionVault | ExemptAccountKind::Treasury)
+    ) || exempt_destination == Some(ExemptAccountKind::DistributionVault);
+
+    let mut security_associated_account_from = load_security_associated_account(
+        &accounts.security_associated_account_from,
+        &accounts.source_account.key(),
+        ctx.program_id,
+    )?;
+    let security_associated_account_to = load_security_associated_account(
+        &accounts.security_associated_account_to,
+        &accounts.destination_account.key(),
+        ctx.program_id,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    let transfer_rule = if is_exempt_transfer
+        || is_intra_holder_transfer(
+            transfer_restriction_data.allow_intra_holder_transfers,
+            &security_associated_account_from,
+            &security_associated_account_to,
+        ) {
+        None
+    } else {
+        let transfer_rule = resolve_transfer_rule(
+            &accounts.transfer_rule,
+            &[
+                accounts.transfer_rule_from_any.to_account_info(),
+                accounts.transfer_rule_to_any.to_account_info(),
+                accounts.transfer_rule_any.to_account_info(),
+            ],
+            &accounts.transfer_restriction_data.key(),
+            security_associated_account_from.group,
+            security_associated_account_to.group,
+            ctx.program_id,
+            false,
+        )?;
+        check_transfer_rule(&transfer_rule, now)?;
+        Some(transfer_rule)
+    };
+
+    if let Some(blackout_calendar) = load_blackout_calendar(
+        &accounts.blackout_calendar,
+        &accounts.transfer_restriction_data.key(),
+        ctx.program_id,
+        false,
+    )? {
+        check_blackout_calendar(
+            &blackout_calendar,
+            security_associated_account_from.group,
+            security_associated_account_to.group,
+            now,
+        )?;
+    }
+    check_holder_frozen(
+        &security_associated_account_from,
+        &accounts.transfer_restriction_holder_from,
+    )?;
+    check_holder_frozen(
+        &security_associated_account_to,
+        &accounts.transfer_restriction_holder_to,
+    )?;
+    check_wallet_holds(
+        &security_associated_account_from,
+        &security_associated_account_to,
+        now,
+    )?;
+    let attestation_issuers = load_attestation_issuers(
+        &accounts.attestation_issuer_registry,
+        &accounts.mint.key(),
+        ctx.program_id,
+        false,
+    )?;
+    check_wallet_attestation(
+        &attestation_issuers,
+        &security_associated_account_from,
+        &accounts.source_account.owner,
+        &accounts.attestation_from,
+        now,
+    )?;
+    check_wallet_attestation(
+        &attestation_issuers,
+        &security_associated_account_to,
+        &accounts.destination_account.owner,
+        &accounts.attestation_to,
+        now,
+    )?;
+
+    if accounts.source_account.key() == accounts.destination_account.key() {
+        return Ok(());
+    }
+    check_omnibus_balance(
+        &security_associated_account_from,
+        accounts.source_account.amount.saturating_sub(amount),
+    )?;
+    if transfer_rule.is_some_and(|transfer_rule| transfer_rule.approval_required) {
+        let mut transfer_approval = load_transfer_approval(
+            &accounts.transfer_approval,
+            &accounts.transfer_restriction_data.key(),
+            &accounts.source_account.key(),
+            &accounts.destination_account.key(),
+            ctx.program_id,
+        )?;
+        spend_transfer_approval(&mut transfer_approval, amount, now)?;
+    }
+
+    let transfer_restriction_group_from = load_transfer_restriction_group(
+        &accounts.transfer_restriction_group_from,
+        &accounts.transfer_restriction_data.key(),
+        security_associated_account_from.group,
+        ctx.program_id,
+    )?;
+    let transfer_restriction_group_to = load_transfer_restriction_group(
+        &accounts.transfer_restriction_group_to,
+        &accounts.transfer_restriction_data.key(),
+        security_associated_account_to.group,
+        ctx.program_id,
+    )?;
+    check_group_pause(
+        &transfer_restriction_group_from,
+        &transfer_restriction_group_to,
+    )?;
+    if is_exempt_transfer {
+        return Ok(());
+    }
+    check_min_holding(
+        &transfer_restriction_group_from,
+        &transfer_restriction_group_to,
+        &security_associated_account_from,
+        &security_associated_account_to,
+        accounts.source_account.amount.saturating_sub(amount),
+        accounts.destination_account.amount.saturating_add(amount),
+    )?;
+    // transfer is not executed yet so the amount is subtracted from the source balance
+    if transfer_restriction_group_from.holding_period > 0
+        || security_associated_account_from.acquisition_lots_count > 0
+    {
+        spend_acquisition_lots(
+            &mut security_associated_account_from,
+            transfer_restriction_group_from.holding_period,
+            accounts.source_account.amount.saturating_sub(amount),
+            now,
+        )?;
+    }
+
+    let has_policy_program = transfer_restriction_data.policy_program != Pubkey::default();
+    if transfer_restriction_data.count_holders_by_balance {
+        check_funded_holders(
+            ctx,
+            transfer_restriction_data,
+            &security_associated_account_from,
+            &security_associated_account_to,
+            amount,
+        )?;
+    }
+    if has_policy_program {
+        return Err(TransferRestrictionsError::TransferDependsOnPolicyProgram.into());
+    }
+
+    Ok(())
+}
+
+/// Apply funded holder counts changes of the transfer to account copies to detect holder limits
+fn check_funded_holders(
+    ctx: &Context<DetectTransferRestriction>,
+    mut transfer_restriction_data: TransferRestrictionData,
+    security_associated_account_from: &SecurityAssociatedAccount,
+    security_associated_account_to: &SecurityAssociatedAccount,
+    amount: u64,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let transfer_restriction_data_key = accounts.transfer_restriction_data.key();
+
+    let mut released = None;
+    if security_associated_account_from.funded
+        && accounts.source_account.amount.saturating_sub(amount) == 0
+        && verify_funded_wallet_accounts(
+            &transfer_restriction_data_key,
+            security_associated_account_from,
+            &accounts.transfer_restriction_holder_from,
+            &accounts.holder_group_from,
+            &accounts.transfer_restriction_group_from,
+            ctx.program_id,
+            false,
+        )?
+    {
+        let mut holder =
+            read_account::<TransferRestrictionHolder>(&accounts.transfer_restriction_holder_from)?;
+        let mut holder_group = read_account::<HolderGroup>(&accounts.holder_group_from)?;
+        let mut group =
+            read_account::<TransferRestrictionGroup>(&accounts.transfer_restriction_group_from)?;
+        remove_funded_wallet(
+            &mut transfer_restriction_data,
+            &mut group,
+            &mut holder,
+            &mut holder_group,
+        );
+        released = Some((holder, holder_group, group));
+    }
+
+    if security_associated_account_to.funded
+        || accounts.destination_account.amount.saturating_add(amount) == 0
+        || !verify_funded_wallet_accounts(
+            &transfer_restriction_data_key,
+            security_associated_account_to,
+            &accounts.transfer_restriction_holder_to,
+            &accounts.holder_group_to,
+            &accounts.transfer_restriction_group_to,
+            ctx.program_id,
+            false,
+        )?
+    {
+        return Ok(());
+    }
+    // sender and receiver can share holder, holder group and group accounts
+    let mut holder = match &released {
+        Some((holder, _, _))
+            if accounts.transfer_restriction_holder_from.key
+                == accounts.transfer_restriction_holder_to.key =>
+        {
+            holder.clone()
+        }
+        _ => read_account::<TransferRestrictionHolder>(&accounts.transfer_restriction_holder_to)?,
+    };
+    let mut holder_group = match &released {
+        Some((_, holder_group, _))
+            if accounts.holder_group_from.key == accounts.holder_group_to.key =>
+        {
+            holder_group.clone()
+        }
+        _ => read_account::<HolderGroup>(&accounts.holder_group_to)?,
+    };
+    let mut group = match &released {
+        Some((_, _, group))
+            if accounts.transfer_restriction_group_from.key
+                == accounts.transfer_restriction_group_to.key =>
+        {
+            group.clone()
+        }
+        _ => read_account::<TransferRestrictionGroup>(&accounts.transfer_restriction_group_to)?,
+    };
+    add_funded_wallet(
+        &mut transfer_restriction_data,
+        &mut group,
+        &mut holder,
+        &mut holder_group,
+        true,
+    )
+}
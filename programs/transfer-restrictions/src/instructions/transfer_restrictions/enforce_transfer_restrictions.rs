@@ -1,22 +1,173 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    errors::TransferRestrictionsError, EnforceTransferRestrictions,
+    check_blackout_calendar, check_holder_frozen, check_transfer_rule, check_wallet_attestation,
+    check_wallet_holds,
+    contexts::common::{blocks_direction, TransferDirection},
+    errors::TransferRestrictionsError,
+    load_attestation_issuers, load_blackout_calendar, load_transfer_restriction_group,
+    resolve_transfer_rule, EnforceTransferRestrictions, SecurityAssociatedAccount,
+    ATTESTATION_ISSUER_REGISTRY_PREFIX, ATTESTATION_PREFIX, BLACKOUT_CALENDAR_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX,
 };
 
-pub fn enforce_transfer_restrictions(ctx: Context<EnforceTransferRestrictions>) -> Result<()> {
+/// Check a transfer of `amount` by the tokenlock program from its escrow account. Remaining
+/// accounts are the transfer hook extra accounts, which provide the default transfer rules, the
+/// blackout calendar, the holder accounts, the group of the recipient and the attestation of the
+/// recipient.
+pub fn enforce_transfer_restrictions(
+    ctx: Context<EnforceTransferRestrictions>,
+    amount: u64,
+) -> Result<()> {
     let transfer_restriction_data = &ctx.accounts.transfer_restriction_data;
     if transfer_restriction_data.paused {
         return Err(TransferRestrictionsError::AllTransfersPaused.into());
     }
 
-    let transfer_rule = &ctx.accounts.transfer_rule;
-    if transfer_rule.locked_until == 0 {
-        return Err(TransferRestrictionsError::TransferGroupNotApproved.into());
+    let transfer_rule = resolve_transfer_rule(
+        &ctx.accounts.transfer_rule,
+        ctx.remaining_accounts,
+        &transfer_restriction_data.key(),
+        ctx.accounts.security_associated_account_from.group,
+        ctx.accounts.security_associated_account_to.group,
+        ctx.program_id,
+        false,
+    )?;
+    let now = Clock::get()?.unix_timestamp as u64;
+    check_transfer_rule(&transfer_rule, now)?;
+    let blackout_calendar = find_pda_account(
+        ctx.remaining_accounts,
+        &[
+            BLACKOUT_CALENDAR_PREFIX.as_bytes(),
+            &transfer_restriction_data.key().to_bytes(),
+        ],
+        ctx.program_id,
+    )?;
+    // blackout calendar is found by its derived address, releases are not restricted until it is
+    // initialized
+    if let Some(blackout_calendar) = load_blackout_calendar(
+        blackout_calendar,
+        &transfer_restriction_data.key(),
+        ctx.program_id,
+        true,
+    )? {
+        check_blackout_calendar(
+            &blackout_calendar,
+            ctx.accounts.security_associated_account_from.group,
+            ctx.accounts.security_associated_account_to.group,
+            now,
+        )?;
     }
-    if transfer_rule.locked_until > Clock::get()?.unix_timestamp as u64 {
-        return Err(TransferRestrictionsError::TransferRuleNotAllowedUntilLater.into());
+    check_wallet_holds(
+        &ctx.accounts.security_associated_account_from,
+        &ctx.accounts.security_associated_account_to,
+        now,
+    )?;
+    for security_associated_account in [
+        &ctx.accounts.security_associated_account_from,
+        &ctx.accounts.security_associated_account_to,
+    ] {
+        check_wallet_holder_frozen(security_associated_account, ctx.remaining_accounts)?;
+    }
+    check_recipient(&ctx, amount, now)
+}
+
+/// Check the group pause, the minimum holding and the attestation of the recipient. Tokens are
+/// released from the escrow, so the sender side of these checks does not apply.
+fn check_recipient(
+    ctx: &Context<EnforceTransferRestrictions>,
+    amount: u64,
+    now: u64,
+) -> Result<()> {
+    let transfer_restriction_data = ctx.accounts.transfer_restriction_data.key();
+    let security_associated_account_to = &ctx.accounts.security_associated_account_to;
+    let destination_account = &ctx.accounts.destination_account;
+
+    let transfer_restriction_group_to = load_transfer_restriction_group(
+        find_pda_account(
+            ctx.remaining_accounts,
+            &[
+                TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+                &transfer_restriction_data.to_bytes(),
+                &security_associated_account_to.group.to_le_bytes(),
+            ],
+            ctx.program_id,
+        )?,
+        &transfer_restriction_data,
+        security_associated_account_to.group,
+        ctx.program_id,
+    )?;
+    if blocks_direction(
+        transfer_restriction_group_to.paused_direction,
+        TransferDirection::Receive,
+    ) {
+        return Err(TransferRestrictionsError::GroupTransfersPaused.into());
+    }
+    if security_associated_account_to.holder.is_some()
+        && destination_account.amount.saturating_add(amount)
+            < transfer_restriction_group_to.min_holding
+    {
+        return Err(TransferRestrictionsError::ReceiverBalanceBelowMinHolding.into());
+    }
+
+    let attestation_issuers = load_attestation_issuers(
+        find_pda_account(
+            ctx.remaining_accounts,
+            &[
+                ATTESTATION_ISSUER_REGISTRY_PREFIX.as_bytes(),
+                &ctx.accounts.mint.key().to_bytes(),
+            ],
+            ctx.program_id,
+        )?,
+        &ctx.accounts.mint.key(),
+        ctx.program_id,
+        true,
+    )?;
+    if let Some(attestation_issuer) = attestation_issuers.first() {
+        let attestation = find_pda_account(
+            ctx.remaining_accounts,
+            &[
+                ATTESTATION_PREFIX.as_bytes(),
+                &destination_account.owner.to_bytes(),
+            ],
+            &attestation_issuer.program_id,
+        )?;
+        check_wallet_attestation(
+            &attestation_issuers,
+            security_associated_account_to,
+            &destination_account.owner,
+            attestation,
+            now,
+        )?;
     }
 
     Ok(())
 }
+
+/// Check the freeze of the holder of the wallet with the holder account found in `accounts`
+fn check_wallet_holder_frozen(
+    security_associated_account: &SecurityAssociatedAccount,
+    accounts: &[AccountInfo],
+) -> Result<()> {
+    let Some(holder) = security_associated_account.holder else {
+        return Ok(());
+    };
+    let holder_info = accounts
+        .iter()
+        .find(|account| account.key() == holder)
+        .ok_or(TransferRestrictionsError::InvalidHolderAccount)?;
+    check_holder_frozen(security_associated_account, holder_info)
+}
+
+/// Find the account with the address derived from the seeds in `accounts`
+fn find_pda_account<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<&'a AccountInfo<'info>> {
+    let (address, _bump_seed) = Pubkey::find_program_address(seeds, program_id);
+    accounts
+        .iter()
+        .find(|account| account.key() == address)
+        .ok_or_else(|| TransferRestrictionsError::InvalidRemainingAccounts.into())
+}
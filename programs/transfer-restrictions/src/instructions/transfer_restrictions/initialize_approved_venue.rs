@@ -0,0 +1,22 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{helpers::check_authorization, InitializeApprovedVenue};
+
+pub fn initialize_approved_venue(
+    ctx: Context<InitializeApprovedVenue>,
+    venue: Pubkey,
+    group_id: u64,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8,
+    )?;
+
+    let approved_venue = &mut ctx.accounts.approved_venue;
+    approved_venue.transfer_restriction_data = ctx.accounts.transfer_restriction_data.key();
+    approved_venue.venue = venue;
+    approved_venue.group = group_id;
+
+    Ok(())
+}
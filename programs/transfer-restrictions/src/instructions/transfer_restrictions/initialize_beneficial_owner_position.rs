@@ -0,0 +1,50 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    helpers::{add_holder_group_wallet, check_authorization},
+    InitializeBeneficialOwnerPosition,
+};
+
+/// Link the holder to the omnibus account as a beneficial owner. The omnibus operator and Wallets
+/// Admin or Transfer Admin both sign, so an operator can not attach positions to any holder.
+pub fn initialize_beneficial_owner_position(
+    ctx: Context<InitializeBeneficialOwnerPosition>,
+    group_id: u64,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+
+    let beneficial_owner_position = &mut ctx.accounts.beneficial_owner_position;
+    beneficial_owner_position.omnibus_account =
+        ctx.accounts.omnibus_security_associated_account.key();
+    beneficial_owner_position.holder = ctx.accounts.holder.key();
+    beneficial_owner_position.group = group_id;
+    beneficial_owner_position.amount = 0;
+
+    // beneficial owner is counted as a wallet of its holder inside the group
+    add_holder_group_wallet(
+        &mut ctx.accounts.group,
+        &mut ctx.accounts.holder_group,
+        &mut ctx.accounts.holder,
+        ctx.accounts
+            .transfer_restriction_data
+            .count_holders_by_balance,
+    )?;
+    let holder = &mut ctx.accounts.holder;
+    holder.beneficial_owner_positions_count = holder
+        .beneficial_owner_positions_count
+        .checked_add(1)
+        .unwrap();
+
+    let omnibus_security_associated_account = &mut ctx.accounts.omnibus_security_associated_account;
+    omnibus_security_associated_account.beneficial_owners_count =
+        omnibus_security_associated_account
+            .beneficial_owners_count
+            .checked_add(1)
+            .unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{helpers::check_authorization, InitializeBlackoutCalendar};
+
+pub fn initialize_blackout_calendar(ctx: Context<InitializeBlackoutCalendar>) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+
+    let blackout_calendar = &mut ctx.accounts.blackout_calendar;
+    blackout_calendar.transfer_restriction_data = ctx.accounts.transfer_restriction_data.key();
+    blackout_calendar.windows = Vec::new();
+    blackout_calendar.bump = ctx.bumps.blackout_calendar;
+
+    Ok(())
+}
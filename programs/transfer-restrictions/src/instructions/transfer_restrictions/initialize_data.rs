@@ -1,7 +1,10 @@
-use access_control::Roles;
+use access_control::{Roles, VersionedAccount};
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, InitializeTransferRestrictionData};
+use crate::{
+    errors::TransferRestrictionsError, InitializeTransferRestrictionData, TransferRestrictionData,
+    TransferRestrictionGroup,
+};
 
 pub fn initialize_data(
     ctx: Context<InitializeTransferRestrictionData>,
@@ -20,12 +23,16 @@ pub fn initialize_data(
     transfer_restriction_data.max_holders = max_holders;
     transfer_restriction_data.paused = false;
     transfer_restriction_data.lockup_escrow_account = None;
+    transfer_restriction_data.bump = ctx.bumps.transfer_restriction_data;
+    transfer_restriction_data.version = TransferRestrictionData::VERSION;
 
     let zero_transfer_restriction_group = &mut ctx.accounts.zero_transfer_restriction_group;
     zero_transfer_restriction_group.id = 0;
     zero_transfer_restriction_group.current_holders_count = 0;
     zero_transfer_restriction_group.max_holders = 0;
     zero_transfer_restriction_group.transfer_restriction_data = transfer_restriction_data.key();
+    zero_transfer_restriction_group.bump = ctx.bumps.zero_transfer_restriction_group;
+    zero_transfer_restriction_group.version = TransferRestrictionGroup::VERSION;
 
     Ok(())
 }
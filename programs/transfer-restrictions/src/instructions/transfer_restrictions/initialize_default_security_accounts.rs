@@ -1,9 +1,9 @@
-use access_control::Roles;
+use access_control::{Roles, VersionedAccount};
 use anchor_lang::prelude::*;
 
 use crate::errors::TransferRestrictionsError;
 use crate::helpers::*;
-use crate::InitializeDefaultSecurityAccounts;
+use crate::{InitializeDefaultSecurityAccounts, SecurityAssociatedAccount};
 
 pub fn initialize_default_security_accounts(
     ctx: Context<InitializeDefaultSecurityAccounts>,
@@ -16,6 +16,7 @@ pub fn initialize_default_security_accounts(
     )?;
 
     let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    let count_holders_by_balance = transfer_restriction_data.count_holders_by_balance;
     // Initialize holder
     let transfer_restriction_holder = &mut ctx.accounts.transfer_restriction_holder;
     if !transfer_restriction_holder.active {
@@ -43,7 +44,10 @@ pub fn initialize_default_security_accounts(
         holder_group,
         transfer_restriction_holder,
         0, // group 0
+        count_holders_by_balance,
     )?;
+    ctx.accounts.security_associated_account.bump = ctx.bumps.security_associated_account;
+    ctx.accounts.security_associated_account.version = SecurityAssociatedAccount::VERSION;
 
     Ok(())
 }
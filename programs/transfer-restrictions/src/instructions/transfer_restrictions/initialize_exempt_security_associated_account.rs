@@ -0,0 +1,41 @@
+use access_control::{exempt_account_kind, Roles, VersionedAccount};
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, InitializeExemptSecurityAssociatedAccount,
+    SecurityAssociatedAccount,
+};
+
+pub fn initialize_exempt_security_associated_account(
+    ctx: Context<InitializeExemptSecurityAssociatedAccount>,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    if exempt_account_kind(
+        &ctx.accounts.access_control_account,
+        Some(&ctx.accounts.exempt_account_registry),
+        &ctx.accounts.exempt_account.key(),
+    )?
+    .is_none()
+    {
+        return Err(TransferRestrictionsError::NotExemptAccount.into());
+    }
+
+    // exempt accounts are not investor wallets and do not belong to a holder
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    security_associated_account.group = 0;
+    security_associated_account.holder = None;
+    security_associated_account.bump = ctx.bumps.security_associated_account;
+    security_associated_account.version = SecurityAssociatedAccount::VERSION;
+
+    // counted like venue token accounts so that group 0 is not closed while it is referenced
+    let group = &mut ctx.accounts.group;
+    group.venue_wallets_count = group.venue_wallets_count.checked_add(1).unwrap();
+
+    Ok(())
+}
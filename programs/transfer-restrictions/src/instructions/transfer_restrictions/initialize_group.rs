@@ -1,7 +1,10 @@
-use access_control::Roles;
+use access_control::{Roles, VersionedAccount};
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, InitializeTransferRestrictionGroup};
+use crate::{
+    errors::TransferRestrictionsError, InitializeTransferRestrictionGroup, TransferRestrictionGroup,
+    ANY_TRANSFER_GROUP_ID,
+};
 
 pub fn initialize_group(ctx: Context<InitializeTransferRestrictionGroup>, id: u64) -> Result<()> {
     if !ctx
@@ -11,6 +14,10 @@ pub fn initialize_group(ctx: Context<InitializeTransferRestrictionGroup>, id: u6
     {
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
+    // reserved for default transfer rules
+    if id == ANY_TRANSFER_GROUP_ID {
+        return Err(TransferRestrictionsError::InvalidTransferGroupId.into());
+    }
 
     let transfer_restriction_group = &mut ctx.accounts.transfer_restriction_group;
     let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
@@ -19,6 +26,8 @@ pub fn initialize_group(ctx: Context<InitializeTransferRestrictionGroup>, id: u6
     transfer_restriction_group.id = id;
     transfer_restriction_group.current_holders_count = 0;
     transfer_restriction_group.max_holders = 0;
+    transfer_restriction_group.bump = ctx.bumps.transfer_restriction_group;
+    transfer_restriction_group.version = TransferRestrictionGroup::VERSION;
 
     Ok(())
 }
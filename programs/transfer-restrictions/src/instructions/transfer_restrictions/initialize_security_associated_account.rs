@@ -1,8 +1,8 @@
-use access_control::Roles;
+use access_control::{Roles, VersionedAccount};
 use anchor_lang::prelude::*;
 
 use crate::helpers::*;
-use crate::InitializeSecurityAssociatedAccount;
+use crate::{InitializeSecurityAssociatedAccount, SecurityAssociatedAccount};
 
 pub fn initialize_security_associated_account(
     ctx: Context<InitializeSecurityAssociatedAccount>,
@@ -20,7 +20,10 @@ pub fn initialize_security_associated_account(
         &mut ctx.accounts.holder_group,
         &mut ctx.accounts.holder,
         group_id,
+        ctx.accounts.transfer_restriction_data.count_holders_by_balance,
     )?;
+    ctx.accounts.security_associated_account.bump = ctx.bumps.security_associated_account;
+    ctx.accounts.security_associated_account.version = SecurityAssociatedAccount::VERSION;
     
     Ok(())
 }
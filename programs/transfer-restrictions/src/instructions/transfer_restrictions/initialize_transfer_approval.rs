@@ -0,0 +1,36 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, helpers::check_authorization, InitializeTransferApproval,
+};
+
+/// Approve transfers of up to `amount` tokens from the source to the destination token account
+/// until `expires_at`
+pub fn initialize_transfer_approval(
+    ctx: Context<InitializeTransferApproval>,
+    amount: u64,
+    expires_at: u64,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8,
+    )?;
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(
+        amount > 0
+            && (expires_at == 0 || expires_at > now)
+            && ctx.accounts.source_account.key() != ctx.accounts.destination_account.key(),
+        TransferRestrictionsError::InvalidTransferApproval
+    );
+
+    let transfer_approval = &mut ctx.accounts.transfer_approval;
+    transfer_approval.transfer_restriction_data = ctx.accounts.transfer_restriction_data.key();
+    transfer_approval.source_account = ctx.accounts.source_account.key();
+    transfer_approval.destination_account = ctx.accounts.destination_account.key();
+    transfer_approval.remaining_amount = amount;
+    transfer_approval.expires_at = expires_at;
+    transfer_approval.bump = ctx.bumps.transfer_approval;
+
+    Ok(())
+}
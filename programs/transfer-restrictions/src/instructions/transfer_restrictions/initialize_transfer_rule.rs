@@ -1,7 +1,10 @@
-use access_control::Roles;
+use access_control::{Roles, VersionedAccount};
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, InitializeTransferRule};
+use crate::{
+    check_transfer_rule_group, errors::TransferRestrictionsError, InitializeTransferRule,
+    TransferRule,
+};
 
 pub fn initialize_transfer_rule(
     ctx: Context<InitializeTransferRule>,
@@ -16,6 +19,8 @@ pub fn initialize_transfer_rule(
     {
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
+    check_transfer_rule_group(group_id_from, &ctx.accounts.transfer_restriction_group_from)?;
+    check_transfer_rule_group(group_id_to, &ctx.accounts.transfer_restriction_group_to)?;
     let transfer_rule = &mut ctx.accounts.transfer_rule;
     let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
 
@@ -23,6 +28,8 @@ pub fn initialize_transfer_rule(
     transfer_rule.transfer_group_id_from = group_id_from;
     transfer_rule.transfer_group_id_to = group_id_to;
     transfer_rule.locked_until = locked_until;
+    transfer_rule.bump = ctx.bumps.transfer_rule;
+    transfer_rule.version = TransferRule::VERSION;
 
     Ok(())
 }
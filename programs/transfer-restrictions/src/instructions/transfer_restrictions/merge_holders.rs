@@ -0,0 +1,278 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::errors::TransferRestrictionsError;
+use crate::helpers::*;
+use crate::{
+    create_pda_account, verify_pda, HolderGroup, MergeHolders, SecurityAssociatedAccount,
+    TransferRestrictionGroup, MERGE_HOLDER_GROUP_ACCOUNTS_LEN, TRANSFER_RESTRICTION_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+};
+
+/// Holder groups of the source and the target holder inside one group, with the wallet counts
+/// before the merge used to correct the group holder counts
+struct MergedGroup<'info> {
+    source_holder_group: Account<'info, HolderGroup>,
+    target_holder_group: Account<'info, HolderGroup>,
+    group: Account<'info, TransferRestrictionGroup>,
+    holders_count: u64,
+    funded_holders_count: u64,
+}
+
+/// Re-point security associated accounts of the source holder to the target holder.
+/// Remaining accounts are the holder group accounts of every group followed by the security
+/// associated accounts to move. Emptied source holder groups are closed and the source holder is
+/// closed once it has no wallets and holder groups left. The target holder becomes frozen when the
+/// source holder is frozen. Beneficial owner positions of the source holder must be revoked
+/// before the merge.
+pub fn merge_holders<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, MergeHolders<'info>>,
+    holder_groups_count: u8,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+    require!(
+        ctx.accounts.source_holder.beneficial_owner_positions_count == 0,
+        TransferRestrictionsError::HolderHasBeneficialOwnerPositions
+    );
+    // freezing can not be escaped by moving the wallets to another holder
+    if ctx.accounts.source_holder.frozen {
+        ctx.accounts.target_holder.frozen = true;
+    }
+    let holder_groups_accounts_len = holder_groups_count as usize * MERGE_HOLDER_GROUP_ACCOUNTS_LEN;
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() < holder_groups_accounts_len {
+        return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+    }
+    let (holder_groups_accounts, security_associated_accounts) =
+        remaining_accounts.split_at(holder_groups_accounts_len);
+
+    let mut merged_groups: Vec<MergedGroup> = Vec::with_capacity(holder_groups_count as usize);
+    for accounts in holder_groups_accounts.chunks_exact(MERGE_HOLDER_GROUP_ACCOUNTS_LEN) {
+        let merged_group = load_merged_group(&mut ctx, accounts)?;
+        if merged_groups
+            .iter()
+            .any(|other| other.group.id == merged_group.group.id)
+        {
+            return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+        }
+        merged_groups.push(merged_group);
+    }
+
+    let source_holder_key = ctx.accounts.source_holder.key();
+    let target_holder_key = ctx.accounts.target_holder.key();
+    let funded_holders_count = holders_count(
+        ctx.accounts.source_holder.funded_wallets_count,
+        ctx.accounts.target_holder.funded_wallets_count,
+    );
+    for security_associated_account_info in security_associated_accounts {
+        let mut security_associated_account =
+            Account::<SecurityAssociatedAccount>::try_from(security_associated_account_info)?;
+        if security_associated_account.holder != Some(source_holder_key) {
+            return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+        }
+        let merged_group = merged_groups
+            .iter_mut()
+            .find(|merged_group| merged_group.group.id == security_associated_account.group)
+            .ok_or(TransferRestrictionsError::InvalidRemainingAccounts)?;
+
+        let source_holder = &mut ctx.accounts.source_holder;
+        let target_holder = &mut ctx.accounts.target_holder;
+        let source_holder_group = &mut merged_group.source_holder_group;
+        let target_holder_group = &mut merged_group.target_holder_group;
+        source_holder_group.current_wallets_count = source_holder_group
+            .current_wallets_count
+            .checked_sub(1)
+            .unwrap();
+        target_holder_group.current_wallets_count = target_holder_group
+            .current_wallets_count
+            .checked_add(1)
+            .unwrap();
+        source_holder.current_wallets_count =
+            source_holder.current_wallets_count.checked_sub(1).unwrap();
+        target_holder.current_wallets_count =
+            target_holder.current_wallets_count.checked_add(1).unwrap();
+        if security_associated_account.funded {
+            source_holder_group.funded_wallets_count =
+                source_holder_group.funded_wallets_count.saturating_sub(1);
+            target_holder_group.funded_wallets_count = target_holder_group
+                .funded_wallets_count
+                .checked_add(1)
+                .unwrap();
+            source_holder.funded_wallets_count =
+                source_holder.funded_wallets_count.saturating_sub(1);
+            target_holder.funded_wallets_count =
+                target_holder.funded_wallets_count.checked_add(1).unwrap();
+        }
+
+        security_associated_account.holder = Some(target_holder_key);
+        security_associated_account.holder_id = target_holder.id;
+        security_associated_account.exit(ctx.program_id)?;
+    }
+
+    let count_holders_by_balance = ctx
+        .accounts
+        .transfer_restriction_data
+        .count_holders_by_balance;
+    for merged_group in merged_groups {
+        let MergedGroup {
+            source_holder_group,
+            target_holder_group,
+            mut group,
+            holders_count: previous_holders_count,
+            funded_holders_count: previous_funded_holders_count,
+        } = merged_group;
+
+        let current_holders_count = holders_count(
+            source_holder_group.current_wallets_count,
+            target_holder_group.current_wallets_count,
+        );
+        group.current_holders_count = group
+            .current_holders_count
+            .checked_add(current_holders_count)
+            .ok_or(TransferRestrictionsError::HoldersCountOverflow)?
+            .checked_sub(previous_holders_count)
+            .unwrap();
+        let current_funded_holders_count = holders_count(
+            source_holder_group.funded_wallets_count,
+            target_holder_group.funded_wallets_count,
+        );
+        group.funded_holders_count = group
+            .funded_holders_count
+            .checked_add(current_funded_holders_count)
+            .ok_or(TransferRestrictionsError::HoldersCountOverflow)?
+            .checked_sub(previous_funded_holders_count)
+            .unwrap();
+        // holder count of a group grows when only a part of the source holder wallets is moved
+        let (grown, holders_count) = if count_holders_by_balance {
+            (
+                current_funded_holders_count > previous_funded_holders_count,
+                group.funded_holders_count,
+            )
+        } else {
+            (
+                current_holders_count > previous_holders_count,
+                group.current_holders_count,
+            )
+        };
+        if grown && group.max_holders != 0 && holders_count > group.max_holders {
+            return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
+        }
+
+        if source_holder_group.current_wallets_count == 0 {
+            source_holder_group.close(ctx.accounts.payer.to_account_info())?;
+            let source_holder = &mut ctx.accounts.source_holder;
+            source_holder.current_holder_group_count = source_holder
+                .current_holder_group_count
+                .checked_sub(1)
+                .ok_or(TransferRestrictionsError::NonPositiveHolderGroupCount)?;
+        } else {
+            source_holder_group.exit(ctx.program_id)?;
+        }
+        target_holder_group.exit(ctx.program_id)?;
+        group.exit(ctx.program_id)?;
+    }
+
+    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    transfer_restriction_data.funded_holders_count = transfer_restriction_data
+        .funded_holders_count
+        .checked_add(holders_count(
+            ctx.accounts.source_holder.funded_wallets_count,
+            ctx.accounts.target_holder.funded_wallets_count,
+        ))
+        .ok_or(TransferRestrictionsError::HoldersCountOverflow)?
+        .checked_sub(funded_holders_count)
+        .unwrap();
+
+    let source_holder = &ctx.accounts.source_holder;
+    if source_holder.current_wallets_count == 0 && source_holder.current_holder_group_count == 0 {
+        transfer_restriction_data.current_holders_count = transfer_restriction_data
+            .current_holders_count
+            .checked_sub(1)
+            .unwrap();
+        source_holder.close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+/// Number of the two holders which have at least one wallet
+fn holders_count(source_wallets_count: u64, target_wallets_count: u64) -> u64 {
+    (source_wallets_count > 0) as u64 + (target_wallets_count > 0) as u64
+}
+
+fn load_merged_group<'info>(
+    ctx: &mut Context<'_, '_, 'info, 'info, MergeHolders<'info>>,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<MergedGroup<'info>> {
+    let [source_holder_group_info, target_holder_group_info, group_info] = accounts else {
+        return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+    };
+    let group = Account::<TransferRestrictionGroup>::try_from(group_info)?;
+    verify_pda(
+        group_info.key,
+        &[
+            TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+            &ctx.accounts.transfer_restriction_data.key().to_bytes(),
+            &group.id.to_le_bytes(),
+        ],
+        ctx.program_id,
+    )?;
+
+    verify_pda(
+        source_holder_group_info.key,
+        &[
+            TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+            &ctx.accounts.source_holder.key().to_bytes(),
+            &group.id.to_le_bytes(),
+        ],
+        ctx.program_id,
+    )?;
+    let source_holder_group = Account::<HolderGroup>::try_from(source_holder_group_info)?;
+
+    let target_holder_group_seeds: &[&[u8]] = &[
+        TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+        &ctx.accounts.target_holder.key().to_bytes(),
+        &group.id.to_le_bytes(),
+    ];
+    if target_holder_group_info.data_is_empty() {
+        create_pda_account::<HolderGroup>(
+            target_holder_group_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            target_holder_group_seeds,
+            ctx.program_id,
+        )?;
+    } else {
+        verify_pda(
+            target_holder_group_info.key,
+            target_holder_group_seeds,
+            ctx.program_id,
+        )?;
+    }
+    let mut target_holder_group = Account::<HolderGroup>::try_from(target_holder_group_info)?;
+    if target_holder_group.holder != ctx.accounts.target_holder.key() {
+        initialize_holder_group_fields(
+            &mut target_holder_group,
+            &group,
+            &ctx.accounts.target_holder,
+        );
+        increment_holder_group_count(&mut ctx.accounts.target_holder);
+    }
+
+    Ok(MergedGroup {
+        holders_count: holders_count(
+            source_holder_group.current_wallets_count,
+            target_holder_group.current_wallets_count,
+        ),
+        funded_holders_count: holders_count(
+            source_holder_group.funded_wallets_count,
+            target_holder_group.funded_wallets_count,
+        ),
+        source_holder_group,
+        target_holder_group,
+        group,
+    })
+}
@@ -0,0 +1,177 @@
+use access_control::{migrate_account, Roles};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError, verify_pda,
+    AttestationIssuerRegistry, HolderGroup, MigrateAccounts, SecurityAssociatedAccount,
+    TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder, TransferRule,
+    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_PREFIX,
+};
+
+/// Upgrade the transfer restriction data and the accounts passed as remaining accounts in place
+/// to the current layout versions. Every account must belong to the security. Holder groups are
+/// linked to it by their holder, which must be passed before them in the same batch. Security
+/// associated accounts are followed by their token account of the security mint and get the id of
+/// their holder, which must be passed before them unless the stored id already resolves the holder.
+pub fn migrate_accounts<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateAccounts<'info>>,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let transfer_restriction_data = ctx.accounts.transfer_restriction_data.to_account_info();
+    migrate_account::<TransferRestrictionData>(
+        &transfer_restriction_data,
+        &payer,
+        &system_program,
+    )?;
+
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(account) = accounts.next() {
+        let discriminator = account
+            .try_borrow_data()?
+            .get(..DISCRIMINATOR_LEN)
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+        let account_transfer_restriction_data = if discriminator
+            == TransferRestrictionGroup::DISCRIMINATOR
+        {
+            migrate_account::<TransferRestrictionGroup>(account, &payer, &system_program)?
+                .transfer_restriction_data
+        } else if discriminator == TransferRestrictionHolder::DISCRIMINATOR {
+            migrate_account::<TransferRestrictionHolder>(account, &payer, &system_program)?
+                .transfer_restriction_data
+        } else if discriminator == TransferRule::DISCRIMINATOR {
+            migrate_account::<TransferRule>(account, &payer, &system_program)?
+                .transfer_restriction_data
+        } else if discriminator == AttestationIssuerRegistry::DISCRIMINATOR {
+            migrate_account::<AttestationIssuerRegistry>(account, &payer, &system_program)?
+                .transfer_restriction_data
+        } else if discriminator == SecurityAssociatedAccount::DISCRIMINATOR {
+            let token_account = accounts
+                .next()
+                .ok_or(TransferRestrictionsError::InvalidMigrationAccount)?;
+            verify_security_associated_account(
+                account,
+                token_account,
+                &ctx.accounts.security_mint.key(),
+                ctx.program_id,
+            )?;
+            let mut security_associated_account =
+                migrate_account::<SecurityAssociatedAccount>(account, &payer, &system_program)?;
+            backfill_holder_id(
+                &mut security_associated_account,
+                ctx.remaining_accounts,
+                &transfer_restriction_data.key(),
+                ctx.program_id,
+            )?;
+            security_associated_account
+                .try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+            transfer_restriction_data.key()
+        } else if discriminator == HolderGroup::DISCRIMINATOR {
+            let holder_group = migrate_account::<HolderGroup>(account, &payer, &system_program)?;
+            holder_group_transfer_restriction_data(
+                account,
+                &holder_group,
+                ctx.remaining_accounts,
+                ctx.program_id,
+            )?
+        } else {
+            return Err(TransferRestrictionsError::InvalidMigrationAccount.into());
+        };
+        if account_transfer_restriction_data != transfer_restriction_data.key() {
+            return Err(TransferRestrictionsError::InvalidMigrationAccount.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the security associated account is derived from the token account of the security
+/// mint which follows it in the batch
+fn verify_security_associated_account<'info>(
+    security_associated_account: &AccountInfo,
+    token_account: &'info AccountInfo<'info>,
+    security_mint: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let token_account_data = InterfaceAccount::<TokenAccount>::try_from(token_account)
+        .map_err(|_| TransferRestrictionsError::InvalidMigrationAccount)?;
+    if token_account_data.mint != *security_mint {
+        return Err(TransferRestrictionsError::InvalidMigrationAccount.into());
+    }
+    verify_pda(
+        security_associated_account.key,
+        &[
+            SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+            &token_account.key().to_bytes(),
+        ],
+        program_id,
+    )
+    .map_err(|_| TransferRestrictionsError::InvalidMigrationAccount.into())
+}
+
+/// Return the transfer restriction data of the holder of the holder group, the holder must be
+/// passed before the holder group in the same batch
+fn holder_group_transfer_restriction_data<'info>(
+    holder_group_info: &AccountInfo,
+    holder_group: &HolderGroup,
+    accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<Pubkey> {
+    verify_pda(
+        holder_group_info.key,
+        &[
+            TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+            &holder_group.holder.to_bytes(),
+            &holder_group.group.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .map_err(|_| TransferRestrictionsError::InvalidMigrationAccount)?;
+    let holder_info = accounts
+        .iter()
+        .find(|account| account.key() == holder_group.holder)
+        .ok_or(TransferRestrictionsError::InvalidMigrationAccount)?;
+    Ok(Account::<TransferRestrictionHolder>::try_from(holder_info)?.transfer_restriction_data)
+}
+
+/// Store the id of the holder on a security associated account created before the holder id was
+/// stored, so the transfer hook resolves the holder account of the wallet
+fn backfill_holder_id<'info>(
+    security_associated_account: &mut SecurityAssociatedAccount,
+    accounts: &'info [AccountInfo<'info>],
+    transfer_restriction_data: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let Some(holder) = security_associated_account.holder else {
+        return Ok(());
+    };
+    if let Some(holder_info) = accounts.iter().find(|account| account.key() == holder) {
+        let holder_data = Account::<TransferRestrictionHolder>::try_from(holder_info)?;
+        if holder_data.transfer_restriction_data != *transfer_restriction_data {
+            return Err(TransferRestrictionsError::InvalidMigrationAccount.into());
+        }
+        security_associated_account.holder_id = holder_data.id;
+        return Ok(());
+    }
+    verify_pda(
+        &holder,
+        &[
+            TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes(),
+            &transfer_restriction_data.to_bytes(),
+            &security_associated_account.holder_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .map_err(|_| TransferRestrictionsError::InvalidHolderAccount.into())
+}
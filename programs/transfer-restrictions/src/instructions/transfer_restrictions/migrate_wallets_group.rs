@@ -0,0 +1,142 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::errors::TransferRestrictionsError;
+use crate::helpers::*;
+use crate::{
+    create_pda_account, verify_pda, HolderGroup, MigrateWalletsGroup, SecurityAssociatedAccount,
+    TransferRestrictionHolder, MIGRATE_WALLET_ACCOUNTS_LEN,
+    TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+};
+
+/// Move security associated accounts from the current group to the new group.
+/// Remaining accounts are the security associated account, holder, current holder group and new
+/// holder group of every wallet. Missing new holder groups are created. Accounts shared by wallets
+/// of the same holder are loaded once, so the group holder limit applies to the final state.
+pub fn migrate_wallets_group<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateWalletsGroup<'info>>,
+    current_group_id: u64,
+    new_group_id: u64,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::WalletsAdmin as u8 | Roles::TransferAdmin as u8,
+    )?;
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.is_empty()
+        || !remaining_accounts
+            .len()
+            .is_multiple_of(MIGRATE_WALLET_ACCOUNTS_LEN)
+    {
+        return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+    }
+
+    let transfer_restriction_data_key = ctx.accounts.transfer_restriction_data.key();
+    let count_holders_by_balance = ctx
+        .accounts
+        .transfer_restriction_data
+        .count_holders_by_balance;
+    let mut holders: Vec<Account<TransferRestrictionHolder>> = Vec::new();
+    let mut holder_groups_current: Vec<Account<HolderGroup>> = Vec::new();
+    let mut holder_groups_new: Vec<Account<HolderGroup>> = Vec::new();
+    for accounts in remaining_accounts.chunks_exact(MIGRATE_WALLET_ACCOUNTS_LEN) {
+        let [security_associated_account_info, holder_info, holder_group_current_info, holder_group_new_info] =
+            accounts
+        else {
+            return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+        };
+        let mut security_associated_account =
+            Account::<SecurityAssociatedAccount>::try_from(security_associated_account_info)?;
+        if security_associated_account.holder != Some(holder_info.key())
+            || security_associated_account.group != current_group_id
+        {
+            return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+        }
+
+        let holder_index = find_or_load_account(&mut holders, holder_info)?;
+        if holders[holder_index].transfer_restriction_data != transfer_restriction_data_key {
+            return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+        }
+        verify_pda(
+            holder_group_current_info.key,
+            &[
+                TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+                &holder_info.key().to_bytes(),
+                &current_group_id.to_le_bytes(),
+            ],
+            ctx.program_id,
+        )?;
+        let holder_group_current_index =
+            find_or_load_account(&mut holder_groups_current, holder_group_current_info)?;
+
+        let holder_group_new_seeds: &[&[u8]] = &[
+            TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+            &holder_info.key().to_bytes(),
+            &new_group_id.to_le_bytes(),
+        ];
+        if holder_group_new_info.data_is_empty() {
+            create_pda_account::<HolderGroup>(
+                holder_group_new_info,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                holder_group_new_seeds,
+                ctx.program_id,
+            )?;
+        } else {
+            verify_pda(
+                holder_group_new_info.key,
+                holder_group_new_seeds,
+                ctx.program_id,
+            )?;
+        }
+        let holder_group_new_index =
+            find_or_load_account(&mut holder_groups_new, holder_group_new_info)?;
+        let holder_group_new = &mut holder_groups_new[holder_group_new_index];
+        if holder_group_new.holder != holder_info.key() {
+            initialize_holder_group_fields(
+                holder_group_new,
+                &ctx.accounts.transfer_restriction_group_new,
+                &holders[holder_index],
+            );
+            increment_holder_group_count(&mut holders[holder_index]);
+        }
+
+        transfer_wallet_between_groups(
+            &mut ctx.accounts.transfer_restriction_group_new,
+            &mut ctx.accounts.transfer_restriction_group_current,
+            &mut holder_groups_current[holder_group_current_index],
+            holder_group_new,
+            &mut security_associated_account,
+            new_group_id,
+            count_holders_by_balance,
+        )?;
+        security_associated_account.exit(ctx.program_id)?;
+    }
+
+    for holder in holders {
+        holder.exit(ctx.program_id)?;
+    }
+    for holder_group in holder_groups_current.into_iter().chain(holder_groups_new) {
+        holder_group.exit(ctx.program_id)?;
+    }
+
+    Ok(())
+}
+
+/// Index of the already loaded account with the key of `account_info`, loading it otherwise
+fn find_or_load_account<'info, T>(
+    accounts: &mut Vec<Account<'info, T>>,
+    account_info: &'info AccountInfo<'info>,
+) -> Result<usize>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    if let Some(index) = accounts
+        .iter()
+        .position(|account| account.key() == account_info.key())
+    {
+        return Ok(index);
+    }
+    accounts.push(Account::<T>::try_from(account_info)?);
+    Ok(accounts.len() - 1)
+}
@@ -51,3 +51,105 @@ pub use initialize_default_security_accounts::*;
 
 pub mod set_address_permission;
 pub use set_address_permission::*;
+
+pub mod set_transfer_rule_validity;
+pub use set_transfer_rule_validity::*;
+
+pub mod initialize_blackout_calendar;
+pub use initialize_blackout_calendar::*;
+
+pub mod update_blackout_calendar;
+pub use update_blackout_calendar::*;
+
+pub mod set_holding_period;
+pub use set_holding_period::*;
+
+pub mod set_count_holders_by_balance;
+pub use set_count_holders_by_balance::*;
+
+pub mod sync_funded_wallet;
+pub use sync_funded_wallet::*;
+
+pub mod detect_transfer_restriction;
+pub use detect_transfer_restriction::*;
+
+pub mod onboard_investors;
+pub use onboard_investors::*;
+
+pub mod set_omnibus_account;
+pub use set_omnibus_account::*;
+
+pub mod initialize_beneficial_owner_position;
+pub use initialize_beneficial_owner_position::*;
+
+pub mod update_beneficial_owner_position;
+pub use update_beneficial_owner_position::*;
+
+pub mod revoke_beneficial_owner_position;
+pub use revoke_beneficial_owner_position::*;
+
+pub mod merge_holders;
+pub use merge_holders::*;
+
+pub mod initialize_approved_venue;
+pub use initialize_approved_venue::*;
+
+pub mod revoke_approved_venue;
+pub use revoke_approved_venue::*;
+
+pub mod register_venue_token_account;
+pub use register_venue_token_account::*;
+
+pub mod revoke_venue_token_account;
+pub use revoke_venue_token_account::*;
+
+pub mod set_group_paused;
+pub use set_group_paused::*;
+
+pub mod set_holder_frozen;
+pub use set_holder_frozen::*;
+
+pub mod update_wallet_holds;
+pub use update_wallet_holds::*;
+
+pub mod revoke_transfer_rule;
+pub use revoke_transfer_rule::*;
+
+pub mod revoke_transfer_restriction_group;
+pub use revoke_transfer_restriction_group::*;
+
+pub mod migrate_wallets_group;
+pub use migrate_wallets_group::*;
+
+pub mod set_transfer_rule_approval_required;
+pub use set_transfer_rule_approval_required::*;
+
+pub mod initialize_transfer_approval;
+pub use initialize_transfer_approval::*;
+
+pub mod revoke_transfer_approval;
+pub use revoke_transfer_approval::*;
+
+pub mod initialize_exempt_security_associated_account;
+pub use initialize_exempt_security_associated_account::*;
+
+pub mod migrate_accounts;
+pub use migrate_accounts::*;
+
+pub mod add_attestation_issuer;
+pub use add_attestation_issuer::*;
+
+pub mod remove_attestation_issuer;
+pub use remove_attestation_issuer::*;
+
+pub mod set_policy_program;
+pub use set_policy_program::*;
+
+pub mod set_allow_intra_holder_transfers;
+pub use set_allow_intra_holder_transfers::*;
+
+pub mod set_min_holding;
+pub use set_min_holding::*;
+
+pub mod record_mint_acquisition;
+pub use record_mint_acquisition::*;
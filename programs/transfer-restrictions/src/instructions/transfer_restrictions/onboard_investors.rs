@@ -0,0 +1,177 @@
+use access_control::{Roles, VersionedAccount};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::TransferRestrictionsError;
+use crate::helpers::*;
+use crate::{
+    create_pda_account, verify_pda, HolderGroup, OnboardInvestors, OnboardingEntry,
+    SecurityAssociatedAccount, TransferRestrictionGroup, TransferRestrictionHolder,
+    ONBOARDING_ENTRY_ACCOUNTS_LEN, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_PREFIX,
+};
+
+/// Create holders, holder groups and security associated accounts for many investor wallets.
+/// Existing holders and holder groups are reused and wallets which already have a security
+/// associated account are skipped. Returns whether the wallet of each entry was onboarded.
+pub fn onboard_investors<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, OnboardInvestors<'info>>,
+    entries: Vec<OnboardingEntry>,
+) -> Result<Vec<bool>> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+    if ctx.remaining_accounts.len() != entries.len() * ONBOARDING_ENTRY_ACCOUNTS_LEN {
+        return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+    }
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let mut onboarded = Vec::with_capacity(entries.len());
+    for (entry, accounts) in entries
+        .iter()
+        .zip(remaining_accounts.chunks_exact(ONBOARDING_ENTRY_ACCOUNTS_LEN))
+    {
+        onboarded.push(onboard_investor(&mut ctx, entry, accounts)?);
+    }
+
+    Ok(onboarded)
+}
+
+fn onboard_investor<'info>(
+    ctx: &mut Context<'_, '_, 'info, 'info, OnboardInvestors<'info>>,
+    entry: &OnboardingEntry,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<bool> {
+    let [user_wallet, associated_token_account, holder_info, holder_group_info, security_associated_account_info, group_info] =
+        accounts
+    else {
+        return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+    };
+    let transfer_restriction_data_key = ctx.accounts.transfer_restriction_data.key();
+
+    // any token account of the security mint owned by the wallet, e.g. custodian accounts
+    if *associated_token_account.owner != anchor_spl::token_interface::spl_token_2022::id() {
+        return Err(TransferRestrictionsError::InvalidTokenAccount.into());
+    }
+    let token_account = InterfaceAccount::<TokenAccount>::try_from(associated_token_account)?;
+    if token_account.mint != ctx.accounts.security_token.key()
+        || token_account.owner != user_wallet.key()
+    {
+        return Err(TransferRestrictionsError::InvalidTokenAccount.into());
+    }
+    if !has_immutable_owner(associated_token_account) {
+        return Err(TransferRestrictionsError::MutableTokenAccountOwner.into());
+    }
+    let security_associated_account_seeds: &[&[u8]] = &[
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &associated_token_account.key().to_bytes(),
+    ];
+    if !security_associated_account_info.data_is_empty() {
+        verify_pda(
+            security_associated_account_info.key,
+            security_associated_account_seeds,
+            ctx.program_id,
+        )?;
+        return Ok(false);
+    }
+
+    verify_pda(
+        group_info.key,
+        &[
+            TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+            &transfer_restriction_data_key.to_bytes(),
+            &entry.group_id.to_le_bytes(),
+        ],
+        ctx.program_id,
+    )?;
+    let mut group = Account::<TransferRestrictionGroup>::try_from(group_info)?;
+
+    // accounts are stored after every entry as entries can share holders and groups
+    let mut holder = load_or_create_account::<TransferRestrictionHolder>(
+        ctx,
+        holder_info,
+        &[
+            TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes(),
+            &transfer_restriction_data_key.to_bytes(),
+            &entry.holder_id.to_le_bytes(),
+        ],
+    )?;
+    if !holder.active {
+        initialize_new_holder(
+            &mut holder,
+            &mut ctx.accounts.transfer_restriction_data,
+            entry.holder_id,
+        )?;
+    }
+
+    let mut holder_group = load_or_create_account::<HolderGroup>(
+        ctx,
+        holder_group_info,
+        &[
+            TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+            &holder.key().to_bytes(),
+            &entry.group_id.to_le_bytes(),
+        ],
+    )?;
+    if holder_group.holder != holder.key() {
+        if holder_group.holder != Pubkey::default() {
+            return Err(TransferRestrictionsError::HolderGroupAlreadyInitialized.into());
+        }
+        initialize_holder_group_fields(&mut holder_group, &group, &holder);
+        increment_holder_group_count(&mut holder);
+    }
+
+    // security associated account is always created here as existing accounts are skipped above
+    let bump = create_pda_account::<SecurityAssociatedAccount>(
+        security_associated_account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        security_associated_account_seeds,
+        ctx.program_id,
+    )?;
+    let mut security_associated_account =
+        Account::<SecurityAssociatedAccount>::try_from(security_associated_account_info)?;
+    security_associated_account.bump = bump;
+    security_associated_account.version = SecurityAssociatedAccount::VERSION;
+    initialize_security_associated_account_fields(
+        &mut security_associated_account,
+        &mut group,
+        &mut holder_group,
+        &mut holder,
+        entry.group_id,
+        ctx.accounts
+            .transfer_restriction_data
+            .count_holders_by_balance,
+    )?;
+
+    holder.exit(ctx.program_id)?;
+    holder_group.exit(ctx.program_id)?;
+    group.exit(ctx.program_id)?;
+    security_associated_account.exit(ctx.program_id)?;
+
+    Ok(true)
+}
+
+fn load_or_create_account<'info, T>(
+    ctx: &Context<'_, '_, 'info, 'info, OnboardInvestors<'info>>,
+    account: &'info AccountInfo<'info>,
+    seeds: &[&[u8]],
+) -> Result<Account<'info, T>>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone + Default + Space,
+{
+    if account.data_is_empty() {
+        create_pda_account::<T>(
+            account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            seeds,
+            ctx.program_id,
+        )?;
+    } else {
+        verify_pda(account.key, seeds, ctx.program_id)?;
+    }
+    Account::try_from(account)
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::{record_acquisition_lot, RecordMintAcquisition};
+
+/// Record minted tokens as an acquisition lot of the receiving wallet when its group has a holding
+/// period. Called by the access control program after minting.
+pub fn record_mint_acquisition(ctx: Context<RecordMintAcquisition>, amount: u64) -> Result<()> {
+    if ctx.accounts.group.holding_period == 0 {
+        return Ok(());
+    }
+    let now = Clock::get()?.unix_timestamp as u64;
+    record_acquisition_lot(&mut ctx.accounts.security_associated_account, amount, now);
+
+    Ok(())
+}
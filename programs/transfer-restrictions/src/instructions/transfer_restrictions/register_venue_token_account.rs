@@ -0,0 +1,59 @@
+use access_control::{Roles, VersionedAccount};
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, RegisterVenueTokenAccount, SecurityAssociatedAccount};
+
+/// Register a token account of an approved venue in the group of the approved venue entry without
+/// a holder. The token account is accepted when it is the approved account itself, when its owner
+/// is an account of the approved program or when its owner is a PDA of the approved program
+/// derived from `authority_seeds`. Registration is signed by the transfer admin, or by the venue
+/// program signing for its PDA derived from `authority_seeds` which owns the token account.
+pub fn register_venue_token_account(
+    ctx: Context<RegisterVenueTokenAccount>,
+    authority_seeds: Vec<Vec<u8>>,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let is_transfer_admin = ctx
+        .accounts
+        .authority_wallet_role
+        .as_ref()
+        .is_some_and(|wallet_role| wallet_role.has_role(Roles::TransferAdmin));
+    if !is_transfer_admin && authority != ctx.accounts.token_account.owner {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let venue = ctx.accounts.approved_venue.venue;
+    let token_account = &ctx.accounts.token_account;
+    let is_venue_pda = !authority_seeds.is_empty()
+        && Pubkey::create_program_address(
+            &authority_seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>(),
+            &venue,
+        )
+        .is_ok_and(|authority| authority == token_account.owner);
+    // any account can be created with the venue program as owner and sign for itself, so only the
+    // venue program signing for its PDA registers without the transfer admin
+    let is_venue_token_account = if is_transfer_admin {
+        venue == token_account.key()
+            || *ctx.accounts.venue_authority.owner == venue
+            || is_venue_pda
+    } else {
+        is_venue_pda
+    };
+    if !is_venue_token_account {
+        return Err(TransferRestrictionsError::TokenAccountNotApprovedVenue.into());
+    }
+
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    security_associated_account.group = ctx.accounts.approved_venue.group;
+    security_associated_account.holder = None;
+    security_associated_account.bump = ctx.bumps.security_associated_account;
+    security_associated_account.version = SecurityAssociatedAccount::VERSION;
+
+    let group = &mut ctx.accounts.group;
+    group.venue_wallets_count = group.venue_wallets_count.checked_add(1).unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, rewrite_extra_account_metas, AttestationIssuer,
+    RemoveAttestationIssuer,
+};
+
+/// Stop trusting the attestation issuer and remove its attestation accounts from the transfer
+/// hook accounts
+pub fn remove_attestation_issuer(ctx: Context<RemoveAttestationIssuer>, index: u8) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let attestation_issuer_registry = &mut ctx.accounts.attestation_issuer_registry;
+    let count = attestation_issuer_registry.issuers_count as usize;
+    require!(
+        (index as usize) < count,
+        TransferRestrictionsError::InvalidAttestationIssuerIndex
+    );
+    attestation_issuer_registry
+        .issuers
+        .copy_within(index as usize + 1..count, index as usize);
+    attestation_issuer_registry.issuers[count - 1] = AttestationIssuer::default();
+    attestation_issuer_registry.issuers_count -= 1;
+
+    rewrite_extra_account_metas(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        attestation_issuer_registry.active_issuers(),
+        &ctx.accounts.transfer_restriction_data.policy_program,
+        ctx.accounts.transfer_restriction_data.count_holders_by_balance,
+    )
+}
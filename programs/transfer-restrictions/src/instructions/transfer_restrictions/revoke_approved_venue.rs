@@ -0,0 +1,15 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{helpers::check_authorization, RevokeApprovedVenue};
+
+/// Stop registering new token accounts of the venue, already registered accounts are revoked
+/// separately with `revoke_venue_token_account`
+pub fn revoke_approved_venue(ctx: Context<RevokeApprovedVenue>) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8,
+    )?;
+
+    Ok(())
+}
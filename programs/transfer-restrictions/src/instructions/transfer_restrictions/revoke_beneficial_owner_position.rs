@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, helpers::remove_holder_group_wallet,
+    RevokeBeneficialOwnerPosition,
+};
+
+pub fn revoke_beneficial_owner_position(ctx: Context<RevokeBeneficialOwnerPosition>) -> Result<()> {
+    require!(
+        ctx.accounts.beneficial_owner_position.amount == 0,
+        TransferRestrictionsError::BeneficialOwnerPositionNotEmpty
+    );
+
+    remove_holder_group_wallet(
+        &mut ctx.accounts.group,
+        &mut ctx.accounts.holder_group,
+        &mut ctx.accounts.holder,
+    )?;
+    let holder = &mut ctx.accounts.holder;
+    holder.beneficial_owner_positions_count =
+        holder.beneficial_owner_positions_count.saturating_sub(1);
+
+    let omnibus_security_associated_account = &mut ctx.accounts.omnibus_security_associated_account;
+    omnibus_security_associated_account.beneficial_owners_count =
+        omnibus_security_associated_account
+            .beneficial_owners_count
+            .checked_sub(1)
+            .unwrap();
+
+    Ok(())
+}
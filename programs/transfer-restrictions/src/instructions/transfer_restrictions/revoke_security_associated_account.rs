@@ -1,7 +1,10 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, RevokeSecurityAssociatedAccount};
+use crate::{
+    errors::TransferRestrictionsError, remove_funded_wallet, remove_holder_group_wallet,
+    RevokeSecurityAssociatedAccount,
+};
 
 pub fn revoke_security_associated_account(
     ctx: Context<RevokeSecurityAssociatedAccount>,
@@ -11,19 +14,23 @@ pub fn revoke_security_associated_account(
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
 
-    let holder_group = &mut ctx.accounts.holder_group;
-    let holder = &mut ctx.accounts.holder;
-    // It is mostly not possible to have a holder or holder group with no wallets, but just in case
-    if holder_group.current_wallets_count == 0 || holder.current_wallets_count == 0 {
-        return Err(TransferRestrictionsError::NoWalletsInGroup.into());
-    }
-    holder_group.current_wallets_count = holder_group.current_wallets_count.checked_sub(1).unwrap();
-    holder.current_wallets_count = holder.current_wallets_count.checked_sub(1).unwrap();
+    require!(
+        ctx.accounts.security_associated_account.beneficial_owners_count == 0,
+        TransferRestrictionsError::OmnibusAccountHasBeneficialOwners
+    );
+    remove_holder_group_wallet(
+        &mut ctx.accounts.group,
+        &mut ctx.accounts.holder_group,
+        &mut ctx.accounts.holder,
+    )?;
 
-    if holder_group.current_wallets_count == 0 {
-        // Remove holder from group
-        let group = &mut ctx.accounts.group;
-        group.current_holders_count = group.current_holders_count.checked_sub(1).unwrap();
+    if ctx.accounts.security_associated_account.funded {
+        remove_funded_wallet(
+            &mut ctx.accounts.transfer_restriction_data,
+            &mut ctx.accounts.group,
+            &mut ctx.accounts.holder,
+            &mut ctx.accounts.holder_group,
+        );
     }
 
     Ok(())
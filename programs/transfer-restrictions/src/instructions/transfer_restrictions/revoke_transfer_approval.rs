@@ -0,0 +1,14 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{helpers::check_authorization, RevokeTransferApproval};
+
+/// Close the transfer approval, used or expired approvals are closed the same way
+pub fn revoke_transfer_approval(ctx: Context<RevokeTransferApproval>) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, helpers::check_authorization, RevokeTransferRestrictionGroup,
+};
+
+/// Close a group which has no holders and no venue or exempt token accounts left
+pub fn revoke_transfer_restriction_group(
+    ctx: Context<RevokeTransferRestrictionGroup>,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8,
+    )?;
+
+    let group = &ctx.accounts.group;
+    require!(
+        group.current_holders_count == 0
+            && group.funded_holders_count == 0
+            && group.venue_wallets_count == 0,
+        TransferRestrictionsError::GroupHasWallets
+    );
+    // lockup escrow account is kept in group 0 without a holder
+    require!(
+        group.id != 0
+            || ctx
+                .accounts
+                .transfer_restriction_data
+                .lockup_escrow_account
+                .is_none(),
+        TransferRestrictionsError::GroupHasWallets
+    );
+
+    Ok(())
+}
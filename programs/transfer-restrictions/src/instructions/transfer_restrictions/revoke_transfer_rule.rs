@@ -0,0 +1,13 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{helpers::check_authorization, RevokeTransferRule};
+
+pub fn revoke_transfer_rule(ctx: Context<RevokeTransferRule>) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{helpers::check_authorization, RevokeVenueTokenAccount};
+
+pub fn revoke_venue_token_account(ctx: Context<RevokeVenueTokenAccount>) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+
+    let group = &mut ctx.accounts.group;
+    group.venue_wallets_count = group.venue_wallets_count.checked_sub(1).unwrap();
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use access_control::Roles;
+use access_control::{Roles, VersionedAccount};
 use anchor_lang::prelude::*;
 use spl_token_2022::state::AccountState;
 
@@ -7,7 +7,7 @@ use crate::helpers::{
     check_if_group_will_change, initialize_security_associated_account_fields,
     transfer_wallet_between_groups,
 };
-use crate::SetAddressPermission;
+use crate::{SecurityAssociatedAccount, SetAddressPermission};
 
 pub fn set_address_permission(
     ctx: Context<SetAddressPermission>,
@@ -21,6 +21,9 @@ pub fn set_address_permission(
     }
 
     let security_associated_account = &mut ctx.accounts.security_associated_account;
+    // also stores the bump of accounts created before it was kept
+    security_associated_account.bump = ctx.bumps.security_associated_account;
+    security_associated_account.version = SecurityAssociatedAccount::VERSION;
     let holder = &mut ctx.accounts.transfer_restriction_holder;
     
     // Check current frozen state once (used for both new and existing wallets)
@@ -46,6 +49,7 @@ pub fn set_address_permission(
             holder_group_new,
             holder,
             group_id,
+            ctx.accounts.transfer_restriction_data.count_holders_by_balance,
         )?;
     } else {
         // Scenario 2: Existing wallet - update wallet group
@@ -99,6 +103,7 @@ pub fn set_address_permission(
                 holder_group_new,
                 security_associated_account,
                 group_id,
+                ctx.accounts.transfer_restriction_data.count_holders_by_balance,
             )?;
         }
     }
@@ -117,6 +122,7 @@ pub fn set_address_permission(
                 target_account: ctx.accounts.user_associated_token_account.to_account_info(),
                 target_authority: ctx.accounts.user_wallet.to_account_info(),
                 token_program: ctx.accounts.token_program.to_account_info(),
+                exempt_account_registry: Some(ctx.accounts.exempt_account_registry.to_account_info()),
             };
             let cpi_ctx = CpiContext::new(access_control_program.to_account_info(), cpi_accounts);
             access_control::cpi::freeze_wallet(cpi_ctx)?;
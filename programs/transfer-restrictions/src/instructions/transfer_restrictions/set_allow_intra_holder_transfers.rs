@@ -0,0 +1,27 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, SetAllowIntraHolderTransfers};
+
+/// Allow or disallow transfers between wallets of the same holder regardless of the transfer
+/// rules of their groups
+pub fn set_allow_intra_holder_transfers(
+    ctx: Context<SetAllowIntraHolderTransfers>,
+    enabled: bool,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    require!(
+        transfer_restriction_data.allow_intra_holder_transfers != enabled,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    transfer_restriction_data.allow_intra_holder_transfers = enabled;
+
+    Ok(())
+}
@@ -1,17 +1,19 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, SetAllowTransferRule};
+use crate::{check_transfer_rule_group, errors::TransferRestrictionsError, SetAllowTransferRule};
 
 pub fn set_allow_transfer_rule(
     ctx: Context<SetAllowTransferRule>,
-    _group_id_from: u64,
-    _group_id_to: u64,
+    group_id_from: u64,
+    group_id_to: u64,
     locked_until: u64,
 ) -> Result<()> {
     if !ctx.accounts.authority_wallet_role.has_role(Roles::TransferAdmin) {
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
+    check_transfer_rule_group(group_id_from, &ctx.accounts.transfer_restriction_group_from)?;
+    check_transfer_rule_group(group_id_to, &ctx.accounts.transfer_restriction_group_to)?;
     if ctx.accounts.transfer_rule.locked_until == locked_until {
         return Err(TransferRestrictionsError::ValueUnchanged.into());
     }
@@ -0,0 +1,39 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, load_attestation_issuers, rewrite_extra_account_metas,
+    SetCountHoldersByBalance,
+};
+
+/// Enable or disable holder counts maintained by the transfer hook from token balances. The meta
+/// list is rewritten so that the holder count accounts are writable only while they are counted.
+pub fn set_count_holders_by_balance(
+    ctx: Context<SetCountHoldersByBalance>,
+    enabled: bool,
+) -> Result<()> {
+    if !ctx.accounts.authority_wallet_role.has_role(Roles::TransferAdmin) {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    require!(
+        transfer_restriction_data.count_holders_by_balance != enabled,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    transfer_restriction_data.count_holders_by_balance = enabled;
+
+    let attestation_issuers = load_attestation_issuers(
+        &ctx.accounts.attestation_issuer_registry,
+        &ctx.accounts.mint.key(),
+        ctx.program_id,
+        false,
+    )?;
+    rewrite_extra_account_metas(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &attestation_issuers,
+        &transfer_restriction_data.policy_program,
+        enabled,
+    )
+}
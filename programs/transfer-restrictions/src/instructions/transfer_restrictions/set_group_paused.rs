@@ -0,0 +1,35 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    contexts::common::is_valid_transfer_direction, errors::TransferRestrictionsError,
+    SetGroupPaused,
+};
+
+/// Pause transfers of the group in the given direction, 0 direction unpauses the group
+pub fn set_group_paused(
+    ctx: Context<SetGroupPaused>,
+    _group_id: u64,
+    paused_direction: u8,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_any_role(Roles::ContractAdmin as u8 | Roles::TransferAdmin as u8)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    require!(
+        paused_direction == 0 || is_valid_transfer_direction(paused_direction),
+        TransferRestrictionsError::InvalidTransferDirection
+    );
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.paused_direction != paused_direction,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    group.paused_direction = paused_direction;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::TransferRestrictionsError;
+use crate::helpers::check_authorization;
+use crate::{
+    verify_pda, SecurityAssociatedAccount, SetHolderFrozen, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    SET_HOLDER_FROZEN_WALLET_ACCOUNTS_LEN,
+};
+
+/// Freeze or unfreeze all wallets of the holder in the transfer hook.
+/// Remaining accounts are optional wallets of the holder whose token accounts are also frozen or
+/// thawed by the access control program. Their `holder_id` is synced so the transfer hook resolves
+/// the holder of wallets created before it was stored.
+pub fn set_holder_frozen<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetHolderFrozen<'info>>,
+    frozen: bool,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+    let remaining_accounts = ctx.remaining_accounts;
+    if !remaining_accounts
+        .len()
+        .is_multiple_of(SET_HOLDER_FROZEN_WALLET_ACCOUNTS_LEN)
+    {
+        return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+    }
+    // unchanged flag is accepted to freeze token accounts of wallets added later
+    require!(
+        ctx.accounts.holder.frozen != frozen || !remaining_accounts.is_empty(),
+        TransferRestrictionsError::ValueUnchanged
+    );
+    ctx.accounts.holder.frozen = frozen;
+
+    let holder_key = ctx.accounts.holder.key();
+    let holder_id = ctx.accounts.holder.id;
+    for accounts in remaining_accounts.chunks_exact(SET_HOLDER_FROZEN_WALLET_ACCOUNTS_LEN) {
+        let [security_associated_account_info, token_account_info, token_account_owner_info] =
+            accounts
+        else {
+            return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+        };
+        verify_pda(
+            security_associated_account_info.key,
+            &[
+                SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+                &token_account_info.key.to_bytes(),
+            ],
+            ctx.program_id,
+        )?;
+        let mut security_associated_account =
+            Account::<SecurityAssociatedAccount>::try_from(security_associated_account_info)?;
+        if security_associated_account.holder != Some(holder_key) {
+            return Err(TransferRestrictionsError::InvalidRemainingAccounts.into());
+        }
+        security_associated_account.holder_id = holder_id;
+        security_associated_account.exit(ctx.program_id)?;
+
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(token_account_info)?;
+        if token_account.is_frozen() == frozen {
+            continue;
+        }
+        let access_control_program = ctx.accounts.access_control_program.to_account_info();
+        if frozen {
+            let cpi_accounts = access_control::cpi::accounts::FreezeWallet {
+                authority: ctx.accounts.authority.to_account_info(),
+                authority_wallet_role: ctx.accounts.authority_wallet_role.to_account_info(),
+                access_control: ctx.accounts.access_control_account.to_account_info(),
+                security_mint: ctx.accounts.security_mint.to_account_info(),
+                target_account: token_account_info.clone(),
+                target_authority: token_account_owner_info.clone(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                exempt_account_registry: Some(ctx.accounts.exempt_account_registry.to_account_info()),
+            };
+            access_control::cpi::freeze_wallet(CpiContext::new(
+                access_control_program,
+                cpi_accounts,
+            ))?;
+        } else {
+            let cpi_accounts = access_control::cpi::accounts::ThawWallet {
+                authority: ctx.accounts.authority.to_account_info(),
+                authority_wallet_role: ctx.accounts.authority_wallet_role.to_account_info(),
+                access_control: ctx.accounts.access_control_account.to_account_info(),
+                security_mint: ctx.accounts.security_mint.to_account_info(),
+                target_account: token_account_info.clone(),
+                target_authority: token_account_owner_info.clone(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            };
+            access_control::cpi::thaw_wallet(CpiContext::new(
+                access_control_program,
+                cpi_accounts,
+            ))?;
+        }
+    }
+
+    Ok(())
+}
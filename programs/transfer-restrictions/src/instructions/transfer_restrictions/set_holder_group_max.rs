@@ -21,8 +21,13 @@ pub fn set_holder_group_max(
         group.id != 0,
         TransferRestrictionsError::ZeroGroupHolderGroupMaxCannotBeNonZero
     );
+    let current_holders_count = if ctx.accounts.transfer_restriction_data.count_holders_by_balance {
+        group.funded_holders_count
+    } else {
+        group.current_holders_count
+    };
     require!(
-        holder_group_max >= group.current_holders_count,
+        holder_group_max >= current_holders_count,
         TransferRestrictionsError::NewHolderGroupMaxMustExceedCurrentHolderGroupCount
     );
     group.max_holders = holder_group_max;
@@ -15,8 +15,13 @@ pub fn set_holder_max(
         transfer_restriction_data.max_holders != holder_max,
         TransferRestrictionsError::ValueUnchanged
     );
+    let current_holders_count = if transfer_restriction_data.count_holders_by_balance {
+        transfer_restriction_data.funded_holders_count
+    } else {
+        transfer_restriction_data.current_holders_count
+    };
     require!(
-        holder_max >= transfer_restriction_data.current_holders_count,
+        holder_max >= current_holders_count,
         TransferRestrictionsError::NewHolderMaxMustExceedCurrentHolderCount
     );
     transfer_restriction_data.max_holders = holder_max;
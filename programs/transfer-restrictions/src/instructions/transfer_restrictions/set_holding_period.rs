@@ -0,0 +1,23 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, SetHoldingPeriod};
+
+pub fn set_holding_period(
+    ctx: Context<SetHoldingPeriod>,
+    _group_id: u64,
+    holding_period: u64,
+) -> Result<()> {
+    if !ctx.accounts.authority_wallet_role.has_role(Roles::TransferAdmin) {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.holding_period != holding_period,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    group.holding_period = holding_period;
+
+    Ok(())
+}
@@ -1,8 +1,8 @@
-use access_control::Roles;
+use access_control::{Roles, VersionedAccount};
 use anchor_lang::{prelude::*, solana_program::program_memory::sol_memcmp, Discriminator};
 use tokenlock_accounts::{states::TokenLockData, wrappers::TokenLockDataWrapper};
 
-use crate::{errors::TransferRestrictionsError, SetLockupEscrowAccount};
+use crate::{errors::TransferRestrictionsError, SecurityAssociatedAccount, SetLockupEscrowAccount};
 
 pub fn set_lockup_escrow_account(ctx: Context<SetLockupEscrowAccount>) -> Result<()> {
     if !ctx
@@ -31,6 +31,8 @@ pub fn set_lockup_escrow_account(ctx: Context<SetLockupEscrowAccount>) -> Result
         &mut ctx.accounts.escrow_security_associated_account;
     escrow_security_associated_token_account.group = 0;
     escrow_security_associated_token_account.holder = None;
+    escrow_security_associated_token_account.bump = ctx.bumps.escrow_security_associated_account;
+    escrow_security_associated_token_account.version = SecurityAssociatedAccount::VERSION;
 
     Ok(())
 }
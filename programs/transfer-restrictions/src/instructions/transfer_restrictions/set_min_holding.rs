@@ -0,0 +1,29 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, SetMinHolding};
+
+/// Set the smallest non-zero balance a wallet of a holder in the group can have after a transfer,
+/// 0 removes the limit
+pub fn set_min_holding(
+    ctx: Context<SetMinHolding>,
+    _group_id: u64,
+    min_holding: u64,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.min_holding != min_holding,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    group.min_holding = min_holding;
+
+    Ok(())
+}
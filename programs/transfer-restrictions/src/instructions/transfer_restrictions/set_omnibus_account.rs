@@ -0,0 +1,25 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, helpers::check_authorization, SetOmnibusAccount};
+
+pub fn set_omnibus_account(ctx: Context<SetOmnibusAccount>, omnibus: bool) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    require!(
+        security_associated_account.omnibus != omnibus,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    // sub-ledger must be emptied before the designation is removed
+    require!(
+        omnibus || security_associated_account.beneficial_owners_count == 0,
+        TransferRestrictionsError::OmnibusAccountHasBeneficialOwners
+    );
+    security_associated_account.omnibus = omnibus;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, load_attestation_issuers, rewrite_extra_account_metas,
+    SetPolicyProgram,
+};
+
+/// Set the program called by the transfer hook after the built-in checks and add its accounts to
+/// the transfer hook accounts. The system program removes the policy program.
+pub fn set_policy_program(ctx: Context<SetPolicyProgram>) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    transfer_restriction_data.policy_program = ctx.accounts.policy_program.key();
+
+    let attestation_issuers = load_attestation_issuers(
+        &ctx.accounts.attestation_issuer_registry,
+        &ctx.accounts.security_token.key(),
+        ctx.program_id,
+        false,
+    )?;
+    rewrite_extra_account_metas(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &attestation_issuers,
+        &transfer_restriction_data.policy_program,
+        transfer_restriction_data.count_holders_by_balance,
+    )
+}
@@ -0,0 +1,25 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, SetTransferRuleApprovalRequired};
+
+/// Require a transfer approval for every transfer allowed by the rule
+pub fn set_transfer_rule_approval_required(
+    ctx: Context<SetTransferRuleApprovalRequired>,
+    _group_id_from: u64,
+    _group_id_to: u64,
+    approval_required: bool,
+) -> Result<()> {
+    if !ctx.accounts.authority_wallet_role.has_role(Roles::TransferAdmin) {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let transfer_rule = &mut ctx.accounts.transfer_rule;
+    require!(
+        transfer_rule.approval_required != approval_required,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    transfer_rule.approval_required = approval_required;
+
+    Ok(())
+}
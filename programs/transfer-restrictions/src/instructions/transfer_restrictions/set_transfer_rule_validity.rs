@@ -0,0 +1,28 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, validate_closed_intervals, SetTransferRuleValidity,
+    TransferRuleClosedInterval,
+};
+
+pub fn set_transfer_rule_validity(
+    ctx: Context<SetTransferRuleValidity>,
+    _group_id_from: u64,
+    _group_id_to: u64,
+    valid_until: u64,
+    closed_intervals: Vec<TransferRuleClosedInterval>,
+) -> Result<()> {
+    if !ctx.accounts.authority_wallet_role.has_role(Roles::TransferAdmin) {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    validate_closed_intervals(&closed_intervals)?;
+
+    let transfer_rule = &mut ctx.accounts.transfer_rule;
+    transfer_rule.valid_until = valid_until;
+    transfer_rule.closed_intervals_count = closed_intervals.len() as u8;
+    transfer_rule.closed_intervals = Default::default();
+    transfer_rule.closed_intervals[..closed_intervals.len()].copy_from_slice(&closed_intervals);
+
+    Ok(())
+}
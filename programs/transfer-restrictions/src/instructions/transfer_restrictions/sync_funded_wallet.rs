@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{add_funded_wallet, remove_funded_wallet, SyncFundedWallet};
+
+/// Reconcile wallet funded flag and holder counts with the token account balance.
+/// Permissionless as it only reflects the on-chain balance.
+pub fn sync_funded_wallet(ctx: Context<SyncFundedWallet>) -> Result<()> {
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    // Wallets created before holder id was stored need it for transfer hook account resolution
+    security_associated_account.holder_id = ctx.accounts.holder.id;
+
+    if !ctx.accounts.transfer_restriction_data.count_holders_by_balance {
+        return Ok(());
+    }
+    let funded = ctx.accounts.token_account.amount > 0;
+    if security_associated_account.funded == funded {
+        return Ok(());
+    }
+    security_associated_account.funded = funded;
+    if funded {
+        add_funded_wallet(
+            &mut ctx.accounts.transfer_restriction_data,
+            &mut ctx.accounts.group,
+            &mut ctx.accounts.holder,
+            &mut ctx.accounts.holder_group,
+            false,
+        )?;
+    } else {
+        remove_funded_wallet(
+            &mut ctx.accounts.transfer_restriction_data,
+            &mut ctx.accounts.group,
+            &mut ctx.accounts.holder,
+            &mut ctx.accounts.holder_group,
+        );
+    }
+
+    Ok(())
+}
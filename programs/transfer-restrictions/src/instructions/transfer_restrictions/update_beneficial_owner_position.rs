@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    contexts::common::{blocks_direction, TransferDirection},
+    errors::TransferRestrictionsError,
+    helpers::{
+        add_funded_wallet, check_blackout_calendar, check_transfer_rule, has_active_wallet_hold,
+        remove_funded_wallet,
+    },
+    load_blackout_calendar, resolve_transfer_rule, UpdateBeneficialOwnerPosition,
+};
+
+/// Set the position of the beneficial owner in the omnibus account. Increasing the position is
+/// checked like a transfer from the omnibus wallet to the beneficial owner group by the transfer
+/// hook, default transfer rule candidates are passed as remaining accounts.
+pub fn update_beneficial_owner_position(
+    ctx: Context<UpdateBeneficialOwnerPosition>,
+    amount: u64,
+) -> Result<()> {
+    let previous_amount = ctx.accounts.beneficial_owner_position.amount;
+    require!(
+        previous_amount != amount,
+        TransferRestrictionsError::ValueUnchanged
+    );
+
+    // allocating tokens to a beneficial owner is a transfer from the omnibus group to its group
+    if amount > previous_amount {
+        if ctx.accounts.transfer_restriction_data.paused {
+            return Err(TransferRestrictionsError::AllTransfersPaused.into());
+        }
+        if blocks_direction(ctx.accounts.group.paused_direction, TransferDirection::Receive) {
+            return Err(TransferRestrictionsError::GroupTransfersPaused.into());
+        }
+        if ctx.accounts.holder.frozen {
+            return Err(TransferRestrictionsError::HolderFrozen.into());
+        }
+        let omnibus_group = ctx.accounts.omnibus_security_associated_account.group;
+        let now = Clock::get()?.unix_timestamp as u64;
+        let transfer_rule = resolve_transfer_rule(
+            &ctx.accounts.transfer_rule,
+            ctx.remaining_accounts,
+            &ctx.accounts.transfer_restriction_data.key(),
+            omnibus_group,
+            ctx.accounts.group.id,
+            ctx.program_id,
+            false,
+        )?;
+        check_transfer_rule(&transfer_rule, now)?;
+        if let Some(blackout_calendar) = load_blackout_calendar(
+            &ctx.accounts.blackout_calendar,
+            &ctx.accounts.transfer_restriction_data.key(),
+            ctx.program_id,
+            false,
+        )? {
+            check_blackout_calendar(
+                &blackout_calendar,
+                omnibus_group,
+                ctx.accounts.group.id,
+                now,
+            )?;
+        }
+        if has_active_wallet_hold(
+            &ctx.accounts.omnibus_security_associated_account,
+            TransferDirection::Send,
+            now,
+        ) {
+            return Err(TransferRestrictionsError::TransferBlockedByWalletHold.into());
+        }
+    }
+
+    let omnibus_security_associated_account = &mut ctx.accounts.omnibus_security_associated_account;
+    let beneficial_owned_amount = omnibus_security_associated_account
+        .beneficial_owned_amount
+        .checked_sub(previous_amount)
+        .unwrap()
+        .checked_add(amount)
+        .ok_or(TransferRestrictionsError::OmnibusBalanceBelowBeneficialOwnership)?;
+    require!(
+        beneficial_owned_amount <= ctx.accounts.omnibus_token_account.amount,
+        TransferRestrictionsError::OmnibusBalanceBelowBeneficialOwnership
+    );
+    omnibus_security_associated_account.beneficial_owned_amount = beneficial_owned_amount;
+    ctx.accounts.beneficial_owner_position.amount = amount;
+
+    // beneficial owner with a non-zero position is a funded holder
+    if ctx
+        .accounts
+        .transfer_restriction_data
+        .count_holders_by_balance
+    {
+        if previous_amount == 0 {
+            add_funded_wallet(
+                &mut ctx.accounts.transfer_restriction_data,
+                &mut ctx.accounts.group,
+                &mut ctx.accounts.holder,
+                &mut ctx.accounts.holder_group,
+                true,
+            )?;
+        } else if amount == 0 {
+            remove_funded_wallet(
+                &mut ctx.accounts.transfer_restriction_data,
+                &mut ctx.accounts.group,
+                &mut ctx.accounts.holder,
+                &mut ctx.accounts.holder_group,
+            );
+        }
+    }
+
+    Ok(())
+}
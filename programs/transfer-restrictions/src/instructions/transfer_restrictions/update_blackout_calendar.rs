@@ -0,0 +1,64 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    contexts::common::is_valid_transfer_direction, errors::TransferRestrictionsError,
+    helpers::check_authorization, BlackoutWindow, UpdateBlackoutCalendar, MAX_BLACKOUT_WINDOWS,
+    MAX_BLACKOUT_WINDOW_GROUPS,
+};
+
+pub fn add_blackout_window(
+    ctx: Context<UpdateBlackoutCalendar>,
+    window: BlackoutWindow,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+    require!(
+        window.end > window.start,
+        TransferRestrictionsError::InvalidBlackoutWindow
+    );
+    require!(
+        is_valid_transfer_direction(window.direction),
+        TransferRestrictionsError::InvalidTransferDirection
+    );
+    require!(
+        window.groups.len() <= MAX_BLACKOUT_WINDOW_GROUPS,
+        TransferRestrictionsError::InvalidBlackoutWindow
+    );
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    let blackout_calendar = &mut ctx.accounts.blackout_calendar;
+    // Windows which already ended do not affect transfers anymore
+    blackout_calendar.windows.retain(|window| window.end > now);
+    require!(
+        blackout_calendar.windows.len() < MAX_BLACKOUT_WINDOWS,
+        TransferRestrictionsError::TooManyBlackoutWindows
+    );
+    blackout_calendar.windows.push(window);
+
+    Ok(())
+}
+
+/// Remove the window equal to `window`. Windows are matched by value as adding a window removes
+/// the ended ones and shifts the positions of the others.
+pub fn remove_blackout_window(
+    ctx: Context<UpdateBlackoutCalendar>,
+    window: BlackoutWindow,
+) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+
+    let blackout_calendar = &mut ctx.accounts.blackout_calendar;
+    let index = blackout_calendar
+        .windows
+        .iter()
+        .position(|scheduled_window| *scheduled_window == window)
+        .ok_or(TransferRestrictionsError::BlackoutWindowNotFound)?;
+    blackout_calendar.windows.remove(index);
+
+    Ok(())
+}
@@ -37,6 +37,7 @@ pub fn update_wallet_group(
         &mut ctx.accounts.holder_group_new,
         &mut ctx.accounts.security_associated_account,
         new_group_id,
+        ctx.accounts.transfer_restriction_data.count_holders_by_balance,
     )?;
 
     Ok(())
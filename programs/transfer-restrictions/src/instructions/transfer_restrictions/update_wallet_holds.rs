@@ -0,0 +1,66 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    contexts::common::is_valid_transfer_direction, errors::TransferRestrictionsError,
+    helpers::check_authorization, UpdateWalletHolds, WalletHold, MAX_WALLET_HOLDS,
+};
+
+pub fn add_wallet_hold(ctx: Context<UpdateWalletHolds>, hold: WalletHold) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+    require!(
+        is_valid_transfer_direction(hold.direction),
+        TransferRestrictionsError::InvalidTransferDirection
+    );
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(
+        hold.expires_at == 0 || hold.expires_at > now,
+        TransferRestrictionsError::InvalidWalletHold
+    );
+
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    // Holds which already expired do not affect transfers anymore
+    let count = security_associated_account.holds_count as usize;
+    let mut active_holds = [WalletHold::default(); MAX_WALLET_HOLDS];
+    let mut active_count = 0;
+    for active_hold in security_associated_account.holds[..count]
+        .iter()
+        .filter(|hold| hold.expires_at == 0 || hold.expires_at > now)
+    {
+        active_holds[active_count] = *active_hold;
+        active_count += 1;
+    }
+    require!(
+        active_count < MAX_WALLET_HOLDS,
+        TransferRestrictionsError::TooManyWalletHolds
+    );
+    active_holds[active_count] = hold;
+    security_associated_account.holds = active_holds;
+    security_associated_account.holds_count = active_count as u8 + 1;
+
+    Ok(())
+}
+
+pub fn remove_wallet_hold(ctx: Context<UpdateWalletHolds>, index: u8) -> Result<()> {
+    check_authorization(
+        &ctx.accounts.authority_wallet_role,
+        Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8,
+    )?;
+
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    let count = security_associated_account.holds_count as usize;
+    require!(
+        (index as usize) < count,
+        TransferRestrictionsError::InvalidWalletHoldIndex
+    );
+    security_associated_account
+        .holds
+        .copy_within(index as usize + 1..count, index as usize);
+    security_associated_account.holds[count - 1] = WalletHold::default();
+    security_associated_account.holds_count -= 1;
+
+    Ok(())
+}
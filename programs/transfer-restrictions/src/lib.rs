@@ -89,6 +89,17 @@ pub mod transfer_restrictions {
         instructions::transfer_restrictions::set_holder_max(ctx, holder_max)
     }
 
+    pub fn set_count_holders_by_balance(
+        ctx: Context<SetCountHoldersByBalance>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_count_holders_by_balance(ctx, enabled)
+    }
+
+    pub fn sync_funded_wallet(ctx: Context<SyncFundedWallet>) -> Result<()> {
+        instructions::transfer_restrictions::sync_funded_wallet(ctx)
+    }
+
     pub fn set_holder_group_max(
         ctx: Context<SetHolderGroupMax>,
         group_id: u64,
@@ -97,6 +108,14 @@ pub mod transfer_restrictions {
         instructions::transfer_restrictions::set_holder_group_max(ctx, group_id, holder_group_max)
     }
 
+    pub fn set_holding_period(
+        ctx: Context<SetHoldingPeriod>,
+        group_id: u64,
+        holding_period: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_holding_period(ctx, group_id, holding_period)
+    }
+
     pub fn set_allow_transfer_rule(
         ctx: Context<SetAllowTransferRule>,
         transfer_group_id_from: u64,
@@ -124,8 +143,11 @@ pub mod transfer_restrictions {
         instructions::transfer_restrictions::set_lockup_escrow_account(ctx)
     }
 
-    pub fn enforce_transfer_restrictions(ctx: Context<EnforceTransferRestrictions>) -> Result<()> {
-        instructions::transfer_restrictions::enforce_transfer_restrictions(ctx)
+    pub fn enforce_transfer_restrictions(
+        ctx: Context<EnforceTransferRestrictions>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::enforce_transfer_restrictions(ctx, amount)
     }
 
     pub fn initialize_default_security_accounts(
@@ -142,4 +164,241 @@ pub mod transfer_restrictions {
     ) -> Result<()> {
         instructions::transfer_restrictions::set_address_permission(ctx, group_id, frozen)
     }
+
+    pub fn set_transfer_rule_validity(
+        ctx: Context<SetTransferRuleValidity>,
+        transfer_group_id_from: u64,
+        transfer_group_id_to: u64,
+        valid_until: u64,
+        closed_intervals: Vec<TransferRuleClosedInterval>,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_transfer_rule_validity(
+            ctx,
+            transfer_group_id_from,
+            transfer_group_id_to,
+            valid_until,
+            closed_intervals,
+        )
+    }
+
+    pub fn initialize_blackout_calendar(ctx: Context<InitializeBlackoutCalendar>) -> Result<()> {
+        instructions::transfer_restrictions::initialize_blackout_calendar(ctx)
+    }
+
+    pub fn add_blackout_window(
+        ctx: Context<UpdateBlackoutCalendar>,
+        window: BlackoutWindow,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::add_blackout_window(ctx, window)
+    }
+
+    pub fn remove_blackout_window(
+        ctx: Context<UpdateBlackoutCalendar>,
+        window: BlackoutWindow,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::remove_blackout_window(ctx, window)
+    }
+
+    pub fn detect_transfer_restriction(
+        ctx: Context<DetectTransferRestriction>,
+        amount: u64,
+    ) -> Result<TransferRestrictionDetection> {
+        instructions::transfer_restrictions::detect_transfer_restriction(ctx, amount)
+    }
+
+    pub fn onboard_investors<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OnboardInvestors<'info>>,
+        entries: Vec<OnboardingEntry>,
+    ) -> Result<Vec<bool>> {
+        instructions::transfer_restrictions::onboard_investors(ctx, entries)
+    }
+
+    pub fn set_omnibus_account(ctx: Context<SetOmnibusAccount>, omnibus: bool) -> Result<()> {
+        instructions::transfer_restrictions::set_omnibus_account(ctx, omnibus)
+    }
+
+    pub fn initialize_beneficial_owner_position(
+        ctx: Context<InitializeBeneficialOwnerPosition>,
+        group_id: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::initialize_beneficial_owner_position(ctx, group_id)
+    }
+
+    pub fn update_beneficial_owner_position(
+        ctx: Context<UpdateBeneficialOwnerPosition>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::update_beneficial_owner_position(ctx, amount)
+    }
+
+    pub fn revoke_beneficial_owner_position(
+        ctx: Context<RevokeBeneficialOwnerPosition>,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::revoke_beneficial_owner_position(ctx)
+    }
+
+    pub fn merge_holders<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MergeHolders<'info>>,
+        holder_groups_count: u8,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::merge_holders(ctx, holder_groups_count)
+    }
+
+    pub fn initialize_approved_venue(
+        ctx: Context<InitializeApprovedVenue>,
+        venue: Pubkey,
+        group_id: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::initialize_approved_venue(ctx, venue, group_id)
+    }
+
+    pub fn revoke_approved_venue(ctx: Context<RevokeApprovedVenue>) -> Result<()> {
+        instructions::transfer_restrictions::revoke_approved_venue(ctx)
+    }
+
+    pub fn register_venue_token_account(
+        ctx: Context<RegisterVenueTokenAccount>,
+        authority_seeds: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::register_venue_token_account(ctx, authority_seeds)
+    }
+
+    pub fn revoke_venue_token_account(ctx: Context<RevokeVenueTokenAccount>) -> Result<()> {
+        instructions::transfer_restrictions::revoke_venue_token_account(ctx)
+    }
+
+    pub fn set_group_paused(
+        ctx: Context<SetGroupPaused>,
+        group_id: u64,
+        paused_direction: u8,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_group_paused(ctx, group_id, paused_direction)
+    }
+
+    pub fn set_holder_frozen<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetHolderFrozen<'info>>,
+        frozen: bool,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_holder_frozen(ctx, frozen)
+    }
+
+    pub fn add_wallet_hold(ctx: Context<UpdateWalletHolds>, hold: WalletHold) -> Result<()> {
+        instructions::transfer_restrictions::add_wallet_hold(ctx, hold)
+    }
+
+    pub fn remove_wallet_hold(ctx: Context<UpdateWalletHolds>, index: u8) -> Result<()> {
+        instructions::transfer_restrictions::remove_wallet_hold(ctx, index)
+    }
+
+    pub fn revoke_transfer_rule(ctx: Context<RevokeTransferRule>) -> Result<()> {
+        instructions::transfer_restrictions::revoke_transfer_rule(ctx)
+    }
+
+    pub fn revoke_transfer_restriction_group(
+        ctx: Context<RevokeTransferRestrictionGroup>,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::revoke_transfer_restriction_group(ctx)
+    }
+
+    pub fn migrate_wallets_group<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateWalletsGroup<'info>>,
+        current_group_id: u64,
+        new_group_id: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::migrate_wallets_group(
+            ctx,
+            current_group_id,
+            new_group_id,
+        )
+    }
+
+    pub fn update_extra_account_meta_list(
+        ctx: Context<UpdateExtraAccountMetaList>,
+    ) -> Result<()> {
+        instructions::transfer_hook::update_extra_account_meta_list(ctx)
+    }
+
+    pub fn set_transfer_rule_approval_required(
+        ctx: Context<SetTransferRuleApprovalRequired>,
+        group_id_from: u64,
+        group_id_to: u64,
+        approval_required: bool,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_transfer_rule_approval_required(
+            ctx,
+            group_id_from,
+            group_id_to,
+            approval_required,
+        )
+    }
+
+    pub fn initialize_transfer_approval(
+        ctx: Context<InitializeTransferApproval>,
+        amount: u64,
+        expires_at: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::initialize_transfer_approval(ctx, amount, expires_at)
+    }
+
+    pub fn revoke_transfer_approval(ctx: Context<RevokeTransferApproval>) -> Result<()> {
+        instructions::transfer_restrictions::revoke_transfer_approval(ctx)
+    }
+
+    pub fn initialize_exempt_security_associated_account(
+        ctx: Context<InitializeExemptSecurityAssociatedAccount>,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::initialize_exempt_security_associated_account(ctx)
+    }
+
+    pub fn migrate_accounts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateAccounts<'info>>,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::migrate_accounts(ctx)
+    }
+
+    pub fn add_attestation_issuer(
+        ctx: Context<AddAttestationIssuer>,
+        program_id: Pubkey,
+        schema: Pubkey,
+        layout: AttestationLayout,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::add_attestation_issuer(
+            ctx, program_id, schema, layout,
+        )
+    }
+
+    pub fn remove_attestation_issuer(
+        ctx: Context<RemoveAttestationIssuer>,
+        index: u8,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::remove_attestation_issuer(ctx, index)
+    }
+
+    /// set the program called by the transfer hook after the built-in checks
+    pub fn set_policy_program(ctx: Context<SetPolicyProgram>) -> Result<()> {
+        instructions::transfer_restrictions::set_policy_program(ctx)
+    }
+
+    pub fn set_allow_intra_holder_transfers(
+        ctx: Context<SetAllowIntraHolderTransfers>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_allow_intra_holder_transfers(ctx, enabled)
+    }
+
+    pub fn set_min_holding(
+        ctx: Context<SetMinHolding>,
+        group_id: u64,
+        min_holding: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_min_holding(ctx, group_id, min_holding)
+    }
+
+    /// record minted tokens as an acquisition lot, called by the access control program
+    pub fn record_mint_acquisition(
+        ctx: Context<RecordMintAcquisition>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::record_mint_acquisition(ctx, amount)
+    }
 }
@@ -1,25 +1,116 @@
+use access_control::{
+    common::DISCRIMINATOR_LEN, ExemptAccountRegistry, EXEMPT_ACCOUNT_REGISTRY_SEED,
+};
 use anchor_lang::{
-    prelude::Result,
+    error::ErrorCode,
+    prelude::{CpiContext, Result},
     solana_program::{program::invoke, pubkey::Pubkey, system_instruction::transfer},
-    Lamports,
+    system_program::{create_account, CreateAccount},
+    AccountSerialize, Lamports, Space,
 };
+use spl_discriminator::SplDiscriminate;
 use spl_tlv_account_resolution::{
     account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
 };
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use spl_type_length_value::state::{TlvState, TlvStateBorrowed, TlvStateMut};
+use std::mem::size_of;
 
 use crate::{
-    errors::TransferRestrictionsError, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
-    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RULE_PREFIX,
+    errors::TransferRestrictionsError, AttestationIssuer, AttestationIssuerRegistry,
+    BlackoutCalendar, SecurityAssociatedAccount, TransferApproval, TransferRestrictionGroup, TransferRule,
+    ANY_TRANSFER_GROUP_ID, ATTESTATION_ISSUER_REGISTRY_PREFIX, ATTESTATION_PREFIX,
+    BLACKOUT_CALENDAR_PREFIX, POLICY_CONFIG_PREFIX,
+    SECURITY_ASSOCIATED_ACCOUNT_HOLDER_ID_OFFSET, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    TRANSFER_APPROVAL_PREFIX, TRANSFER_RESTRICTION_DATA_LOCKUP_ESCROW_ACCOUNT_OFFSET,
+    TRANSFER_RESTRICTION_DATA_PAUSED_OFFSET, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_PREFIX, TRANSFER_RULE_PREFIX,
 };
 
-use crate::{AccountInfo, Rent, SolanaSysvar};
+use crate::{AccountInfo, AnchorDeserialize, AnchorSerialize, Key, Rent, SolanaSysvar};
+
+/// Version of the account set returned by `get_extra_account_metas`, increased whenever the
+/// transfer hook accounts change. Meta lists written before the version was stored have no
+/// version entry.
+pub const EXTRA_ACCOUNT_METAS_VERSION: u8 = 7;
+
+/// TLV entry stored after the meta list with the version of the extra account metas
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("transfer-restrictions:extra-account-metas-version")]
+pub struct ExtraAccountMetasVersion;
 
 pub fn get_meta_list_size() -> Result<usize> {
-    Ok(ExtraAccountMetaList::size_of(get_extra_account_metas()?.len()).unwrap())
+    Ok(
+        ExtraAccountMetaList::size_of(
+            get_extra_account_metas(&[], &Pubkey::default(), false)?.len(),
+        )
+        .unwrap()
+            + TlvStateBorrowed::get_base_len()
+            + size_of::<u8>(),
+    )
+}
+
+/// Write the current extra account metas and their version into the meta list account data
+pub fn write_extra_account_metas(
+    data: &mut [u8],
+    attestation_issuers: &[AttestationIssuer],
+    policy_program: &Pubkey,
+    count_holders_by_balance: bool,
+) -> Result<()> {
+    // previous list is cleared as the meta list entry is followed by the version entry
+    data.fill(0);
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        data,
+        &get_extra_account_metas(attestation_issuers, policy_program, count_holders_by_balance)?,
+    )?;
+    let mut state = TlvStateMut::unpack(data)?;
+    let (version, _) = state.alloc::<ExtraAccountMetasVersion>(size_of::<u8>(), false)?;
+    version[0] = EXTRA_ACCOUNT_METAS_VERSION;
+    Ok(())
 }
 
-pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
-    Ok(vec![
+/// Resize the meta list account to the size of the current list and write the current extra
+/// account metas with the attestation accounts of the issuers, the policy program accounts and
+/// the holder count accounts writable when holders are counted by balance
+pub fn rewrite_extra_account_metas<'info>(
+    extra_metas_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    attestation_issuers: &[AttestationIssuer],
+    policy_program: &Pubkey,
+    count_holders_by_balance: bool,
+) -> Result<()> {
+    let meta_list_size = get_meta_list_size()?;
+    if extra_metas_account.data_len() != meta_list_size {
+        extra_metas_account.resize(meta_list_size)?;
+        update_account_lamports_to_minimum_balance(
+            extra_metas_account.clone(),
+            payer.clone(),
+            system_program.clone(),
+        )?;
+    }
+    let mut data = extra_metas_account.try_borrow_mut_data()?;
+    write_extra_account_metas(
+        &mut data,
+        attestation_issuers,
+        policy_program,
+        count_holders_by_balance,
+    )
+}
+
+/// Extra account metas of the transfer hook. The attestation accounts resolve the program of the
+/// attestation issuers and the attestations of the source and destination wallets, they resolve
+/// to accounts of the system program while no issuer is registered. The policy program accounts
+/// resolve to accounts of the system program when the policy program is not set. The transfer
+/// restriction data, groups, holders and holder groups are only written while holders are counted
+/// by balance, otherwise they are read-only so that transfers of the security do not lock them.
+pub fn get_extra_account_metas(
+    attestation_issuers: &[AttestationIssuer],
+    policy_program: &Pubkey,
+    count_holders_by_balance: bool,
+) -> Result<Vec<ExtraAccountMeta>> {
+    let mut extra_account_metas = vec![
         // [index 5, 0] transfer restrictions account
         ExtraAccountMeta::new_with_seeds(
             &[
@@ -28,8 +119,8 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
                 },
                 Seed::AccountKey { index: 1 },
             ],
-            false, // is_signer
-            false, // is_writable
+            false,                    // is_signer
+            count_holders_by_balance, // is_writable, funded holder counts are updated by the hook
         )?,
         // [index 6, 1] security associated account from
         ExtraAccountMeta::new_with_seeds(
@@ -40,7 +131,7 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
                 Seed::AccountKey { index: 0 },
             ],
             false,
-            false,
+            true, // acquisition lots are updated by the transfer hook
         )?,
         // [index 7, 2] security associated account to
         ExtraAccountMeta::new_with_seeds(
@@ -51,7 +142,7 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
                 Seed::AccountKey { index: 2 },
             ],
             false,
-            false,
+            true,
         )?,
         // [index 8, 3] transfer rule account
         ExtraAccountMeta::new_with_seeds(
@@ -74,7 +165,254 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
             false,
             false,
         )?,
-    ])
+        // [index 9, 4] blackout calendar account
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: BLACKOUT_CALENDAR_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+            ],
+            false,
+            false,
+        )?,
+        // [index 10, 5] transfer restriction group from
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 6,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            count_holders_by_balance,
+        )?,
+        // [index 11, 6] transfer restriction group to
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 7,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            count_holders_by_balance,
+        )?,
+        // [index 12, 7] transfer restriction holder from
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 6,
+                    data_index: SECURITY_ASSOCIATED_ACCOUNT_HOLDER_ID_OFFSET,
+                    length: 8,
+                },
+            ],
+            false,
+            count_holders_by_balance,
+        )?,
+        // [index 13, 8] transfer restriction holder to
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 7,
+                    data_index: SECURITY_ASSOCIATED_ACCOUNT_HOLDER_ID_OFFSET,
+                    length: 8,
+                },
+            ],
+            false,
+            count_holders_by_balance,
+        )?,
+        // [index 14, 9] holder group from
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 12 },
+                Seed::AccountData {
+                    account_index: 6,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            count_holders_by_balance,
+        )?,
+        // [index 15, 10] holder group to
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 13 },
+                Seed::AccountData {
+                    account_index: 7,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            count_holders_by_balance,
+        )?,
+        // [index 16, 11] default transfer rule from the sender group to any group
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RULE_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 6,
+                    data_index: 8,
+                    length: 8,
+                },
+                Seed::Literal {
+                    bytes: ANY_TRANSFER_GROUP_ID.to_le_bytes().to_vec(),
+                },
+            ],
+            false,
+            false,
+        )?,
+        // [index 17, 12] default transfer rule from any group to the receiver group
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RULE_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::Literal {
+                    bytes: ANY_TRANSFER_GROUP_ID.to_le_bytes().to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: 7,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // [index 18, 13] security-wide default transfer rule
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RULE_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::Literal {
+                    bytes: ANY_TRANSFER_GROUP_ID.to_le_bytes().to_vec(),
+                },
+                Seed::Literal {
+                    bytes: ANY_TRANSFER_GROUP_ID.to_le_bytes().to_vec(),
+                },
+            ],
+            false,
+            false,
+        )?,
+        // [index 19, 14] transfer approval of the source and destination token accounts
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_APPROVAL_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountKey { index: 0 },
+                Seed::AccountKey { index: 2 },
+            ],
+            false,
+            true, // is_writable, approved amount is decreased by the transfer hook
+        )?,
+        // [index 20, 15] access control program
+        ExtraAccountMeta::new_with_pubkey(&access_control::ID, false, false)?,
+        // [index 21, 16] exempt account registry of the access control
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            20,
+            &[
+                Seed::Literal {
+                    bytes: EXEMPT_ACCOUNT_REGISTRY_SEED.to_vec(),
+                },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            false,
+        )?,
+        // [index 22, 17] attestation issuer registry
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: ATTESTATION_ISSUER_REGISTRY_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            false,
+        )?,
+    ];
+    // [index 23] program of the attestation issuers
+    let attestation_program = attestation_issuers
+        .first()
+        .map(|attestation_issuer| attestation_issuer.program_id)
+        .unwrap_or_default();
+    extra_account_metas.push(ExtraAccountMeta::new_with_pubkey(
+        &attestation_program,
+        false,
+        false,
+    )?);
+    // [index 24, 25] attestations of the source and destination token account owners
+    for token_account_index in [0, 2] {
+        extra_account_metas.push(ExtraAccountMeta::new_external_pda_with_seeds(
+            23,
+            &[
+                Seed::Literal {
+                    bytes: ATTESTATION_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: token_account_index,
+                    data_index: 32,
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?);
+    }
+    // [index 26] policy program
+    extra_account_metas.push(ExtraAccountMeta::new_with_pubkey(
+        policy_program,
+        false,
+        false,
+    )?);
+    // [index 27] policy config of the security
+    extra_account_metas.push(ExtraAccountMeta::new_external_pda_with_seeds(
+        26,
+        &[
+            Seed::Literal {
+                bytes: POLICY_CONFIG_PREFIX.as_bytes().to_vec(),
+            },
+            Seed::AccountKey { index: 1 },
+        ],
+        false,
+        false,
+    )?);
+    Ok(extra_account_metas)
 }
 
 pub fn update_account_lamports_to_minimum_balance<'info>(
@@ -82,7 +420,9 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
 ) -> Result<()> {
-    let extra_lamports = Rent::get()?.minimum_balance(account.data_len()) - account.get_lamports();
+    let extra_lamports = Rent::get()?
+        .minimum_balance(account.data_len())
+        .saturating_sub(account.get_lamports());
     if extra_lamports > 0 {
         invoke(
             &transfer(payer.key, account.key, extra_lamports),
@@ -99,3 +439,401 @@ pub fn verify_pda(address: &Pubkey, seeds: &[&[u8]], program_id: &Pubkey) -> Res
     }
     Ok(())
 }
+
+/// Verify the address of an account which is not initialized and has no stored bump. Accounts of
+/// the transfer hook are resolved by Token-2022 from the extra account metas, so their addresses
+/// are already derived from the seeds and the bump search is skipped.
+pub fn verify_uninitialized_pda(
+    address: &Pubkey,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+    resolved_by_meta_list: bool,
+) -> Result<()> {
+    if resolved_by_meta_list {
+        return Ok(());
+    }
+    verify_pda(address, seeds, program_id)
+}
+
+/// Verify the PDA with the bump stored in the account data, which costs a single address
+/// derivation instead of the canonical bump search. Accounts created before the bump was stored
+/// have zero bump and are verified with the canonical bump search.
+pub fn verify_pda_with_bump(
+    address: &Pubkey,
+    seeds: &[&[u8]],
+    bump: u8,
+    program_id: &Pubkey,
+) -> Result<()> {
+    if bump == 0 {
+        return verify_pda(address, seeds, program_id);
+    }
+    let bump_seed = [bump];
+    let mut seeds_with_bump = seeds.to_vec();
+    seeds_with_bump.push(&bump_seed);
+    match Pubkey::create_program_address(&seeds_with_bump, program_id) {
+        Ok(pda) if pda == *address => Ok(()),
+        _ => Err(TransferRestrictionsError::InvalidPDA.into()),
+    }
+}
+
+/// Transfer restriction data fields checked on every transfer
+pub struct TransferRestrictionDataFlags {
+    pub paused: bool,
+    pub lockup_escrow_account: Option<Pubkey>,
+    pub count_holders_by_balance: bool,
+    pub bump: u8,
+    pub policy_program: Option<Pubkey>,
+    pub allow_intra_holder_transfers: bool,
+}
+
+/// Read the transfer restriction data fields checked on every transfer at their offsets instead of
+/// deserializing the whole account. Fields after `lockup_escrow_account` move by the size of the
+/// optional key.
+pub fn read_transfer_restriction_data_flags(
+    transfer_restriction_data: &AccountInfo,
+) -> Result<TransferRestrictionDataFlags> {
+    let data = transfer_restriction_data.try_borrow_data()?;
+    let mut offset = TRANSFER_RESTRICTION_DATA_LOCKUP_ESCROW_ACCOUNT_OFFSET + 1;
+    if data.len() <= offset {
+        return Err(ErrorCode::AccountDidNotDeserialize.into());
+    }
+    let lockup_escrow_account = if data[TRANSFER_RESTRICTION_DATA_LOCKUP_ESCROW_ACCOUNT_OFFSET] != 0
+    {
+        let key = Pubkey::try_from(&data[offset..offset + 32])
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+        offset += 32;
+        Some(key)
+    } else {
+        None
+    };
+    let count_holders_by_balance = data.get(offset).is_some_and(|flag| *flag != 0);
+    // bump follows `funded_holders_count`, accounts created before it was stored read zero bump
+    let bump = data.get(offset + 9).copied().unwrap_or_default();
+    // policy program follows `version`, default key when not set
+    let policy_program = data
+        .get(offset + 11..offset + 43)
+        .and_then(|key| Pubkey::try_from(key).ok())
+        .filter(|key| *key != Pubkey::default());
+    let allow_intra_holder_transfers = data.get(offset + 43).is_some_and(|flag| *flag != 0);
+
+    Ok(TransferRestrictionDataFlags {
+        paused: data[TRANSFER_RESTRICTION_DATA_PAUSED_OFFSET] != 0,
+        lockup_escrow_account,
+        count_holders_by_balance,
+        bump,
+        policy_program,
+        allow_intra_holder_transfers,
+    })
+}
+
+pub fn read_account<T: AnchorDeserialize>(account: &AccountInfo) -> Result<T> {
+    Ok(T::deserialize(
+        &mut &account.data.borrow()[DISCRIMINATOR_LEN..],
+    )?)
+}
+
+pub fn write_account<T: AnchorSerialize>(account: &AccountInfo, data: &T) -> Result<()> {
+    data.serialize(&mut &mut account.data.borrow_mut()[DISCRIMINATOR_LEN..])?;
+    Ok(())
+}
+
+pub fn load_security_associated_account(
+    security_associated_account: &AccountInfo,
+    token_account: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<SecurityAssociatedAccount> {
+    if security_associated_account.data_is_empty() {
+        return Err(TransferRestrictionsError::SecurityAssociatedAccountDataIsEmtpy.into());
+    }
+    let security_associated_account_data =
+        read_account::<SecurityAssociatedAccount>(security_associated_account)?;
+    verify_pda_with_bump(
+        security_associated_account.key,
+        &[
+            SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+            &token_account.to_bytes(),
+        ],
+        security_associated_account_data.bump,
+        program_id,
+    )?;
+    Ok(security_associated_account_data)
+}
+
+pub fn load_transfer_restriction_group(
+    group: &AccountInfo,
+    transfer_restriction_data: &Pubkey,
+    group_id: u64,
+    program_id: &Pubkey,
+) -> Result<TransferRestrictionGroup> {
+    if group.data_is_empty() {
+        return Err(ErrorCode::AccountNotInitialized.into());
+    }
+    let group_data = read_account::<TransferRestrictionGroup>(group)?;
+    verify_pda_with_bump(
+        group.key,
+        &[
+            TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+            &transfer_restriction_data.to_bytes(),
+            &group_id.to_le_bytes(),
+        ],
+        group_data.bump,
+        program_id,
+    )?;
+    Ok(group_data)
+}
+
+/// Load the transfer approval of the source and destination token accounts, which is required
+/// when the transfer rule is flagged with `approval_required`
+pub fn load_transfer_approval(
+    transfer_approval: &AccountInfo,
+    transfer_restriction_data: &Pubkey,
+    source_account: &Pubkey,
+    destination_account: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<TransferApproval> {
+    if transfer_approval.data_is_empty() {
+        return Err(TransferRestrictionsError::TransferApprovalRequired.into());
+    }
+    let transfer_approval_data = read_account::<TransferApproval>(transfer_approval)?;
+    verify_pda_with_bump(
+        transfer_approval.key,
+        &[
+            TRANSFER_APPROVAL_PREFIX.as_bytes(),
+            &transfer_restriction_data.to_bytes(),
+            &source_account.to_bytes(),
+            &destination_account.to_bytes(),
+        ],
+        transfer_approval_data.bump,
+        program_id,
+    )?;
+    Ok(transfer_approval_data)
+}
+
+/// Load the blackout calendar of the security, `None` while the calendar is not initialized
+pub fn load_blackout_calendar(
+    blackout_calendar: &AccountInfo,
+    transfer_restriction_data: &Pubkey,
+    program_id: &Pubkey,
+    resolved_by_meta_list: bool,
+) -> Result<Option<BlackoutCalendar>> {
+    let seeds: &[&[u8]] = &[
+        BLACKOUT_CALENDAR_PREFIX.as_bytes(),
+        &transfer_restriction_data.to_bytes(),
+    ];
+    if blackout_calendar.data_is_empty() {
+        verify_uninitialized_pda(
+            blackout_calendar.key,
+            seeds,
+            program_id,
+            resolved_by_meta_list,
+        )?;
+        return Ok(None);
+    }
+    let blackout_calendar_data = read_account::<BlackoutCalendar>(blackout_calendar)?;
+    verify_pda_with_bump(
+        blackout_calendar.key,
+        seeds,
+        blackout_calendar_data.bump,
+        program_id,
+    )?;
+    Ok(Some(blackout_calendar_data))
+}
+
+/// Verify the exempt account registry of the access control, the registry can be uninitialized
+pub fn verify_exempt_account_registry(
+    exempt_account_registry: &AccountInfo,
+    security_token_mint: &Pubkey,
+    resolved_by_meta_list: bool,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[
+        EXEMPT_ACCOUNT_REGISTRY_SEED,
+        &security_token_mint.to_bytes(),
+    ];
+    if exempt_account_registry.data_is_empty() {
+        return verify_uninitialized_pda(
+            exempt_account_registry.key,
+            seeds,
+            &access_control::ID,
+            resolved_by_meta_list,
+        );
+    }
+    let bump = ExemptAccountRegistry::read_bump(&exempt_account_registry.try_borrow_data()?);
+    verify_pda_with_bump(
+        exempt_account_registry.key,
+        seeds,
+        bump,
+        &access_control::ID,
+    )
+}
+
+/// Load the attestation issuers trusted by the security, the registry can be uninitialized when
+/// the security does not require attestations
+pub fn load_attestation_issuers(
+    attestation_issuer_registry: &AccountInfo,
+    security_token_mint: &Pubkey,
+    program_id: &Pubkey,
+    resolved_by_meta_list: bool,
+) -> Result<Vec<AttestationIssuer>> {
+    let seeds: &[&[u8]] = &[
+        ATTESTATION_ISSUER_REGISTRY_PREFIX.as_bytes(),
+        &security_token_mint.to_bytes(),
+    ];
+    if attestation_issuer_registry.data_is_empty() {
+        verify_uninitialized_pda(
+            attestation_issuer_registry.key,
+            seeds,
+            program_id,
+            resolved_by_meta_list,
+        )?;
+        return Ok(vec![]);
+    }
+    let registry = read_account::<AttestationIssuerRegistry>(attestation_issuer_registry)?;
+    verify_pda_with_bump(
+        attestation_issuer_registry.key,
+        seeds,
+        registry.bump,
+        program_id,
+    )?;
+    Ok(registry.active_issuers().to_vec())
+}
+
+/// Verify holder group and group accounts used to count the funded wallet.
+/// Returns false when the wallet is not counted by the provided holder account.
+pub fn verify_funded_wallet_accounts(
+    transfer_restriction_data: &Pubkey,
+    security_associated_account: &SecurityAssociatedAccount,
+    holder_info: &AccountInfo,
+    holder_group_info: &AccountInfo,
+    group_info: &AccountInfo,
+    program_id: &Pubkey,
+    resolved_by_meta_list: bool,
+) -> Result<bool> {
+    // wallets without a holder are not counted, wallets created before `holder_id` was stored
+    // are counted once they are synced
+    if security_associated_account.holder != Some(holder_info.key()) {
+        return Ok(false);
+    }
+    // holder groups have no stored bump
+    verify_uninitialized_pda(
+        holder_group_info.key,
+        &[
+            TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+            &holder_info.key().to_bytes(),
+            &security_associated_account.group.to_le_bytes(),
+        ],
+        program_id,
+        resolved_by_meta_list,
+    )?;
+    load_transfer_restriction_group(
+        group_info,
+        transfer_restriction_data,
+        security_associated_account.group,
+        program_id,
+    )?;
+    Ok(true)
+}
+
+/// Resolve the most specific initialized transfer rule for the group pair.
+/// Exact rule is used first, then sender group default, receiver group default and
+/// security-wide default rule. Default rule candidates are looked up in `default_transfer_rules`,
+/// the transfer hook passes them in the candidate order as resolved from the extra account metas.
+pub fn resolve_transfer_rule(
+    transfer_rule: &AccountInfo,
+    default_transfer_rules: &[AccountInfo],
+    transfer_restriction_data: &Pubkey,
+    group_id_from: u64,
+    group_id_to: u64,
+    program_id: &Pubkey,
+    resolved_by_meta_list: bool,
+) -> Result<TransferRule> {
+    let transfer_rule_seeds: &[&[u8]] = &[
+        TRANSFER_RULE_PREFIX.as_bytes(),
+        &transfer_restriction_data.to_bytes(),
+        &group_id_from.to_le_bytes(),
+        &group_id_to.to_le_bytes(),
+    ];
+    if !transfer_rule.data_is_empty() {
+        let transfer_rule_data = read_account::<TransferRule>(transfer_rule)?;
+        verify_pda_with_bump(
+            transfer_rule.key,
+            transfer_rule_seeds,
+            transfer_rule_data.bump,
+            program_id,
+        )?;
+        return Ok(transfer_rule_data);
+    }
+    // a missing rule has no stored bump
+    verify_uninitialized_pda(
+        transfer_rule.key,
+        transfer_rule_seeds,
+        program_id,
+        resolved_by_meta_list,
+    )?;
+
+    for (index, (from, to)) in [
+        (group_id_from, ANY_TRANSFER_GROUP_ID),
+        (ANY_TRANSFER_GROUP_ID, group_id_to),
+        (ANY_TRANSFER_GROUP_ID, ANY_TRANSFER_GROUP_ID),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        // every candidate is required so that a more specific rule can not be skipped
+        let default_transfer_rule = if resolved_by_meta_list {
+            default_transfer_rules.get(index)
+        } else {
+            let (address, _bump_seed) = Pubkey::find_program_address(
+                &[
+                    TRANSFER_RULE_PREFIX.as_bytes(),
+                    &transfer_restriction_data.to_bytes(),
+                    &from.to_le_bytes(),
+                    &to.to_le_bytes(),
+                ],
+                program_id,
+            );
+            default_transfer_rules
+                .iter()
+                .find(|account| account.key() == address)
+        }
+        .ok_or(TransferRestrictionsError::DefaultTransferRuleAccountMissing)?;
+        if !default_transfer_rule.data_is_empty() {
+            return read_account(default_transfer_rule);
+        }
+    }
+
+    Err(TransferRestrictionsError::TransferRuleAccountDataIsEmtpy.into())
+}
+
+/// Create a program owned account at the PDA derived from `seeds` and store default account data.
+/// Returns the canonical bump of the account.
+pub fn create_pda_account<'info, T: AccountSerialize + Default + Space>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<u8> {
+    let (pda, bump_seed) = Pubkey::find_program_address(seeds, program_id);
+    if pda != *account.key {
+        return Err(TransferRestrictionsError::InvalidPDA.into());
+    }
+    let bump_seed = [bump_seed];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump_seed);
+    let space = DISCRIMINATOR_LEN + T::INIT_SPACE;
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+            &[&signer_seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        program_id,
+    )?;
+    T::default().try_serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(bump_seed[0])
+}
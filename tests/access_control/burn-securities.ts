@@ -107,6 +107,7 @@ describe("Access Control burn securities", () => {
           targetAccount: reserveAdminTokenAccountPubkey,
           targetAuthority: testEnvironment.reserveAdmin.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
         })
         .signers([reserveAdminPretender])
         .rpc({ commitment: testEnvironment.commitment });
@@ -151,6 +152,7 @@ describe("Access Control burn securities", () => {
           targetAccount: reserveAdminTokenAccountPubkey,
           targetAuthority: testEnvironment.reserveAdmin.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
         })
         .signers([attackerEnvironment.reserveAdmin])
         .rpc({ commitment: testEnvironment.commitment });
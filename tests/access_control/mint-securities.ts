@@ -117,6 +117,10 @@ describe("Access Control mint securities", () => {
           destinationAuthority: mintRecipient.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           securityAssociatedAccount: mintRecipientSaaPubkey,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
+          transferRestrictionsProgram: null,
+          transferRestrictionData: null,
+          transferRestrictionGroup: null,
         })
         .signers([reserveAdminPretender])
         .rpc({ commitment: testEnvironment.commitment });
@@ -165,6 +169,10 @@ describe("Access Control mint securities", () => {
           destinationAuthority: mintRecipient.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           securityAssociatedAccount: mintRecipientSaaPubkey,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
+          transferRestrictionsProgram: null,
+          transferRestrictionData: null,
+          transferRestrictionGroup: null,
         })
         .signers([attackerEnvironment.reserveAdmin])
         .rpc({ commitment: testEnvironment.commitment });
@@ -175,6 +183,41 @@ describe("Access Control mint securities", () => {
     }
   });
 
+  it("fails to mint without transfer restriction accounts", async () => {
+    const amount = new anchor.BN(1_000_000);
+    const [mintRecipientSaaPubkey] = testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+      mintRecipientTokenAccount
+    );
+    try {
+      await testEnvironment.accessControlHelper.program.methods
+        .mintSecurities(amount)
+        .accountsStrict({
+          authority: testEnvironment.reserveAdmin.publicKey,
+          authorityWalletRole: reserveAdminWalletRole,
+          accessControl:
+            testEnvironment.accessControlHelper.accessControlPubkey,
+          securityMint: testEnvironment.mintKeypair.publicKey,
+          destinationAccount: mintRecipientTokenAccount,
+          destinationAuthority: mintRecipient.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          securityAssociatedAccount: mintRecipientSaaPubkey,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
+          transferRestrictionsProgram: null,
+          transferRestrictionData: null,
+          transferRestrictionGroup: null,
+        })
+        .signers([testEnvironment.reserveAdmin])
+        .rpc({ commitment: testEnvironment.commitment });
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TransferRestrictionAccountsRequired");
+      assert.equal(
+        error.errorMessage,
+        "Transfer restriction accounts are required"
+      );
+    }
+  });
+
   it("mints securities", async () => {
     const amount = new anchor.BN(1_000_000);
     const { supply: supplyBeforeMint } =
@@ -157,6 +157,8 @@ testCases.forEach(({ tokenProgramId, programName }) => {
             funder: funderKP.publicKey,
             payer: signer.publicKey,
             tokenProgram: tokenProgramId,
+            accessControl: null,
+            exemptAccountRegistry: null,
           })
           .signers([funderKP, signer])
           .rpc({ commitment });
@@ -195,6 +197,8 @@ testCases.forEach(({ tokenProgramId, programName }) => {
             funder: funderKP.publicKey,
             payer: signer.publicKey,
             tokenProgram: tokenProgramId,
+            accessControl: null,
+            exemptAccountRegistry: null,
           })
           .signers([funderKP, signer])
           .rpc({ commitment });
@@ -218,6 +222,8 @@ testCases.forEach(({ tokenProgramId, programName }) => {
             funder: funderKP.publicKey,
             payer: signer.publicKey,
             tokenProgram: tokenProgramId,
+            accessControl: null,
+            exemptAccountRegistry: null,
           })
           .signers([funderKP, signer])
           .rpc({ commitment });
@@ -241,6 +247,8 @@ testCases.forEach(({ tokenProgramId, programName }) => {
           funder: funderKP.publicKey,
           payer: signer.publicKey,
           tokenProgram: tokenProgramId,
+          accessControl: null,
+          exemptAccountRegistry: null,
         })
         .signers([funderKP, signer])
         .rpc({ commitment });
@@ -256,6 +264,8 @@ testCases.forEach(({ tokenProgramId, programName }) => {
             funder: funderKP.publicKey,
             payer: signer.publicKey,
             tokenProgram: tokenProgramId,
+            accessControl: null,
+            exemptAccountRegistry: null,
           })
           .signers([funderKP, signer])
           .rpc({ commitment });
@@ -319,6 +329,8 @@ testCases.forEach(({ tokenProgramId, programName }) => {
             funder: funderKP.publicKey,
             payer: signer.publicKey,
             tokenProgram: tokenProgramId,
+            accessControl: null,
+            exemptAccountRegistry: null,
           })
           .signers([funderKP, signer])
           .rpc({ commitment });
@@ -348,6 +360,8 @@ testCases.forEach(({ tokenProgramId, programName }) => {
             funder: funderKP.publicKey,
             payer: signer.publicKey,
             tokenProgram: tokenProgramId,
+            accessControl: null,
+            exemptAccountRegistry: null,
           })
           .signers([funderKP, signer])
           .rpc({ commitment });
@@ -382,6 +396,8 @@ testCases.forEach(({ tokenProgramId, programName }) => {
           funder: funderKP.publicKey,
           payer: signer.publicKey,
           tokenProgram: tokenProgramId,
+          accessControl: null,
+          exemptAccountRegistry: null,
         })
         .signers([funderKP, signer])
         .rpc({ commitment });
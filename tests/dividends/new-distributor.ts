@@ -125,6 +125,30 @@ testCases.forEach(({ tokenProgramId, programName }) => {
       );
       assert.equal(distributorData.totalAmountClaimed.toNumber(), 0);
       assert.isFalse(distributorData.readyToClaim);
+      assert.equal(distributorData.version, 1);
+
+      await dividendsProgram.methods
+        .migrateDistributor()
+        .accountsStrict({
+          distributor,
+          accessControl:
+            testEnvironment.accessControlHelper.accessControlPubkey,
+          authorityWalletRole:
+            testEnvironment.accessControlHelper.walletRolePDA(
+              signer.publicKey
+            )[0],
+          payer: signer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc({ commitment });
+      const migratedDistributorData =
+        await dividendsProgram.account.merkleDistributor.fetch(distributor);
+      assert.equal(migratedDistributorData.version, 1);
+      assert.equal(
+        migratedDistributorData.totalClaimAmount.toString(),
+        TOTAL_CLAIM_AMOUNT.toString()
+      );
     });
 
     it("initializes new distributor with transferAdmin wallet role", async () => {
@@ -19,6 +19,7 @@ import {
 
 export const ACCESS_CONTROL_PREFIX = "ac";
 export const WALLET_ROLE_PREFIX = "wallet_role";
+export const EXEMPT_ACCOUNT_REGISTRY_PREFIX = "exempt_accounts";
 
 export enum Roles {
   None = 0,
@@ -29,6 +30,12 @@ export enum Roles {
   All = 15,
 }
 
+export enum ExemptAccountKind {
+  TokenlockEscrow = 1,
+  DistributionVault = 2,
+  Treasury = 3,
+}
+
 // confirmOptions
 export class SetupAccessControlArgs {
   decimals: number;
@@ -45,6 +52,7 @@ export class AccessControlHelper {
   program: Program<AccessControl>;
   mintPubkey: PublicKey;
   accessControlPubkey: PublicKey;
+  exemptAccountRegistryPubkey: PublicKey;
   commitment: Commitment = "confirmed";
 
   constructor(
@@ -55,6 +63,7 @@ export class AccessControlHelper {
     this.program = accessControlProgram;
     this.mintPubkey = mintPubkey;
     this.accessControlPubkey = this.accessControlPDA()[0];
+    this.exemptAccountRegistryPubkey = this.exemptAccountRegistryPDA()[0];
     this.commitment = commitment;
   }
 
@@ -79,6 +88,16 @@ export class AccessControlHelper {
     );
   }
 
+  exemptAccountRegistryPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        utils.bytes.utf8.encode(EXEMPT_ACCOUNT_REGISTRY_PREFIX),
+        this.mintPubkey.toBuffer(),
+      ],
+      this.program.programId
+    );
+  }
+
   initializeAccessControlInstruction(
     setupAccessControlArgs: SetupAccessControlArgs
   ): any {
@@ -123,6 +142,52 @@ export class AccessControlHelper {
     );
   }
 
+  // Transfer hook accounts recording minted tokens as an acquisition lot of the wallet
+  async mintAcquisitionAccounts(
+    securityAssociatedAccountPubkey: PublicKey | null
+  ): Promise<{
+    transferRestrictionsProgram: PublicKey | null;
+    transferRestrictionData: PublicKey | null;
+    transferRestrictionGroup: PublicKey | null;
+  }> {
+    if (!securityAssociatedAccountPubkey) {
+      return {
+        transferRestrictionsProgram: null,
+        transferRestrictionData: null,
+        transferRestrictionGroup: null,
+      };
+    }
+    const connection = this.program.provider.connection;
+    const mintInfo = await getMint(
+      connection,
+      this.mintPubkey,
+      this.commitment,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const transferHookProgramId = getTransferHook(mintInfo).programId;
+    const [transferRestrictionData] = PublicKey.findProgramAddressSync(
+      [Buffer.from("trd"), this.mintPubkey.toBuffer()],
+      transferHookProgramId
+    );
+    const securityAssociatedAccountInfo = await connection.getAccountInfo(
+      securityAssociatedAccountPubkey,
+      this.commitment
+    );
+    // group id follows the account discriminator
+    const groupId = securityAssociatedAccountInfo
+      ? securityAssociatedAccountInfo.data.subarray(8, 16)
+      : Buffer.alloc(8);
+    const [transferRestrictionGroup] = PublicKey.findProgramAddressSync(
+      [Buffer.from("trg"), transferRestrictionData.toBuffer(), groupId],
+      transferHookProgramId
+    );
+    return {
+      transferRestrictionsProgram: transferHookProgramId,
+      transferRestrictionData,
+      transferRestrictionGroup,
+    };
+  }
+
   async mintSecurities(
     amount: BN,
     userWalletPubkey: PublicKey,
@@ -131,6 +196,9 @@ export class AccessControlHelper {
     securityAssociatedAccountPubkey: PublicKey  // Required except when minting to lockup_escrow_account - SAA must exist for non-escrow destinations
   ): Promise<string> {
     const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+    const mintAcquisitionAccounts = await this.mintAcquisitionAccounts(
+      securityAssociatedAccountPubkey
+    );
 
     return this.program.methods
       .mintSecurities(amount)
@@ -143,6 +211,8 @@ export class AccessControlHelper {
         destinationAuthority: userWalletPubkey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         securityAssociatedAccount: securityAssociatedAccountPubkey,
+        exemptAccountRegistry: this.exemptAccountRegistryPubkey,
+        ...mintAcquisitionAccounts,
       })
       .signers([signer])
       .rpc({ commitment: this.commitment });
@@ -166,6 +236,7 @@ export class AccessControlHelper {
         targetAccount: userWalletAssociatedAccountPubkey,
         targetAuthority: userWalletPubkey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        exemptAccountRegistry: this.exemptAccountRegistryPubkey,
       })
       .signers([signer])
       .rpc({ commitment: this.commitment });
@@ -236,6 +307,7 @@ export class AccessControlHelper {
         targetAccount: userWalletAssociatedAccountPubkey,
         targetAuthority: walletPubkey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        exemptAccountRegistry: this.exemptAccountRegistryPubkey,
       })
       .signers([signer])
       .rpc({ commitment: this.commitment });
@@ -286,6 +358,7 @@ export class AccessControlHelper {
           destinationAccount: toAccountPubkey,
           destinationAuthority: toOwnerPubkey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          exemptAccountRegistry: this.exemptAccountRegistryPubkey,
         },
       });
 
@@ -346,4 +419,67 @@ export class AccessControlHelper {
       .signers([signer])
       .rpc({ commitment: this.commitment });
   }
+
+  async addExemptAccount(
+    kind: ExemptAccountKind,
+    tokenAccountPubkey: PublicKey,
+    signer: Keypair,
+    tokenlockAccountPubkey: PublicKey | null = null
+  ): Promise<string> {
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+
+    return this.program.methods
+      .addExemptAccount(kind)
+      .accountsStrict({
+        exemptAccountRegistry: this.exemptAccountRegistryPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        tokenAccount: tokenAccountPubkey,
+        tokenlockAccount: tokenlockAccountPubkey,
+        payer: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async removeExemptAccount(index: number, signer: Keypair): Promise<string> {
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+
+    return this.program.methods
+      .removeExemptAccount(index)
+      .accountsStrict({
+        exemptAccountRegistry: this.exemptAccountRegistryPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async exemptAccountRegistryData(): Promise<any> {
+    return this.program.account.exemptAccountRegistry.fetch(
+      this.exemptAccountRegistryPubkey,
+      this.commitment
+    );
+  }
+
+  async migrateAccessControl(signer: Keypair): Promise<string> {
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+
+    return this.program.methods
+      .migrateAccessControl()
+      .accountsStrict({
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+  }
 }
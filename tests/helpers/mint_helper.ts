@@ -4,6 +4,7 @@ import {
   Connection,
   Transaction,
   Keypair,
+  SystemProgram,
   sendAndConfirmTransaction,
 } from "@solana/web3.js";
 
@@ -15,6 +16,12 @@ import {
   getAccount,
   Account,
   createAssociatedTokenAccountInstruction,
+  createInitializeAccountInstruction,
+  createInitializeImmutableOwnerInstruction,
+  ExtensionType,
+  getAccountLen,
+  getAccountTypeOfMintType,
+  getExtensionTypes,
 } from "@solana/spl-token";
 
 export class MintHelper {
@@ -107,4 +114,57 @@ export class MintHelper {
 
     return userWalletAssociatedTokenAccountPubkey;
   }
+
+  // Token account which is not associated, with the immutable owner extension unless disabled
+  async createTokenAccount(
+    userWalletPubkey: PublicKey,
+    payer: Keypair,
+    tokenAccountKeypair = Keypair.generate(),
+    immutableOwner = true
+  ): Promise<PublicKey> {
+    const mint = await this.getMint();
+    const extensions = getExtensionTypes(mint.tlvData).map(
+      getAccountTypeOfMintType
+    );
+    if (immutableOwner) {
+      extensions.push(ExtensionType.ImmutableOwner);
+    }
+    const space = getAccountLen(extensions);
+    const lamports =
+      await this.connection.getMinimumBalanceForRentExemption(space);
+
+    const transaction = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: tokenAccountKeypair.publicKey,
+        space,
+        lamports,
+        programId: this.programId,
+      })
+    );
+    if (immutableOwner) {
+      transaction.add(
+        createInitializeImmutableOwnerInstruction(
+          tokenAccountKeypair.publicKey,
+          this.programId
+        )
+      );
+    }
+    transaction.add(
+      createInitializeAccountInstruction(
+        tokenAccountKeypair.publicKey,
+        this.mintPubkey,
+        userWalletPubkey,
+        this.programId
+      )
+    );
+    await sendAndConfirmTransaction(
+      this.connection,
+      transaction,
+      [payer, tokenAccountKeypair],
+      { commitment: this.commitment }
+    );
+
+    return tokenAccountKeypair.publicKey;
+  }
 }
@@ -52,7 +52,8 @@ export class TestEnvironment {
   transferRestrictionsHelper = new TransferRestrictionsHelper(
     this.transferRestrictionsProgram,
     this.mintKeypair.publicKey,
-    this.accessControlHelper.accessControlPubkey
+    this.accessControlHelper.accessControlPubkey,
+    this.accessControlProgram.programId
   );
   superAdmin = Keypair.generate();
   contractAdmin = Keypair.generate();
@@ -727,6 +727,15 @@ export async function withdraw(
     BigInt(amount.toString()),
     "confirmed"
   );
+  // holder of the authority wallet is not an account of the escrow transfer, it is passed for
+  // the holder freeze check of the transfer restrictions
+  if (secAssocAccountFromData.holder) {
+    transferInstruction.keys.push({
+      pubkey: secAssocAccountFromData.holder,
+      isSigner: false,
+      isWritable: false,
+    });
+  }
 
   const modifyComputeUnitsInstruction =
     ComputeBudgetProgram.setComputeUnitLimit({
@@ -1,7 +1,20 @@
 import { BN, Program } from "@coral-xyz/anchor";
-import { Keypair, PublicKey, SystemProgram, Commitment } from "@solana/web3.js";
+import {
+  AccountMeta,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Commitment,
+  TransactionInstruction,
+} from "@solana/web3.js";
 import { TransferRestrictions } from "../../target/types/transfer_restrictions";
-import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  addExtraAccountMetasForExecute,
+  getAccount,
+} from "@solana/spl-token";
+import { createHash } from "crypto";
+import { EXEMPT_ACCOUNT_REGISTRY_PREFIX } from "./access-control_helper";
 
 export const EXTRA_METAS_ACCOUNT_PREFIX = "extra-account-metas";
 export const TRANSFER_RESTRICTION_GROUP_PREFIX = "trg";
@@ -10,24 +23,45 @@ export const TRANSFER_RULE_PREFIX = "tr";
 export const SECURITY_ASSOCIATED_ACCOUNT_PREFIX = "saa"; // security associated account
 export const TRANSFER_RESTRICTION_HOLDER_PREFIX = "trh"; // transfer_restriction_holder
 export const TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX = "trhg"; // transfer_restriction_holder_group
+export const BLACKOUT_CALENDAR_PREFIX = "tbc"; // transfer blackout calendar
+export const BENEFICIAL_OWNER_POSITION_PREFIX = "bop"; // beneficial owner position
+export const APPROVED_VENUE_PREFIX = "venue"; // approved trading venue
+export const TRANSFER_APPROVAL_PREFIX = "tap"; // transfer approval
+export const ANY_TRANSFER_GROUP_ID = new BN("18446744073709551615"); // u64::MAX
+export const ATTESTATION_ISSUER_REGISTRY_PREFIX = "air"; // attestation issuer registry
+export const ATTESTATION_PREFIX = "attestation";
+export const POLICY_CONFIG_PREFIX = "policy";
+// TLV type of the extra account metas version entry stored after the meta list
+export const EXTRA_ACCOUNT_METAS_VERSION_DISCRIMINATOR = createHash("sha256")
+  .update("transfer-restrictions:extra-account-metas-version")
+  .digest()
+  .subarray(0, 8);
 
 export class TransferRestrictionsHelper {
   program: Program<TransferRestrictions>;
   mintPubkey: PublicKey;
   transferRestrictionDataPubkey: PublicKey;
   accessControlPubkey: PublicKey;
+  accessControlProgramId: PublicKey;
+  exemptAccountRegistryPubkey: PublicKey;
   commitment: Commitment = "confirmed";
 
   constructor(
     transferRestrictionsProgram: Program<TransferRestrictions>,
     mintPubkey: PublicKey,
     accessControlPubkey: PublicKey,
+    accessControlProgramId: PublicKey,
     confirmOptions: Commitment = "confirmed"
   ) {
     this.program = transferRestrictionsProgram;
     this.mintPubkey = mintPubkey;
     this.transferRestrictionDataPubkey = this.transferRestrictionDataPDA()[0];
     this.accessControlPubkey = accessControlPubkey;
+    this.accessControlProgramId = accessControlProgramId;
+    this.exemptAccountRegistryPubkey = PublicKey.findProgramAddressSync(
+      [Buffer.from(EXEMPT_ACCOUNT_REGISTRY_PREFIX), mintPubkey.toBuffer()],
+      accessControlProgramId
+    )[0];
     this.commitment = confirmOptions;
   }
 
@@ -121,6 +155,11 @@ export class TransferRestrictionsHelper {
     );
   }
 
+  // default transfer rules do not reference a group account for any group
+  transferRuleGroupPubkey(groupId: BN): PublicKey | null {
+    return groupId.eq(ANY_TRANSFER_GROUP_ID) ? null : this.groupPDA(groupId)[0];
+  }
+
   securityAssociatedAccountPDA(
     userWalletAssociatedAccountPubkey: PublicKey
   ): [PublicKey, number] {
@@ -142,6 +181,203 @@ export class TransferRestrictionsHelper {
     );
   }
 
+  blackoutCalendarPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(BLACKOUT_CALENDAR_PREFIX),
+        this.transferRestrictionDataPubkey.toBuffer(),
+      ],
+      this.program.programId
+    );
+  }
+
+  async blackoutCalendarData(blackoutCalendarPubkey: PublicKey): Promise<any> {
+    return this.program.account.blackoutCalendar.fetch(
+      blackoutCalendarPubkey,
+      this.commitment
+    );
+  }
+
+  beneficialOwnerPositionPDA(
+    omnibusSecurityAssociatedAccountPubkey: PublicKey,
+    holderPubkey: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(BENEFICIAL_OWNER_POSITION_PREFIX),
+        omnibusSecurityAssociatedAccountPubkey.toBuffer(),
+        holderPubkey.toBuffer(),
+      ],
+      this.program.programId
+    );
+  }
+
+  async beneficialOwnerPositionData(
+    beneficialOwnerPositionPubkey: PublicKey
+  ): Promise<any> {
+    return this.program.account.beneficialOwnerPosition.fetch(
+      beneficialOwnerPositionPubkey,
+      this.commitment
+    );
+  }
+
+  approvedVenuePDA(venuePubkey: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(APPROVED_VENUE_PREFIX),
+        this.transferRestrictionDataPubkey.toBuffer(),
+        venuePubkey.toBuffer(),
+      ],
+      this.program.programId
+    );
+  }
+
+  async approvedVenueData(approvedVenuePubkey: PublicKey): Promise<any> {
+    return this.program.account.approvedVenue.fetch(
+      approvedVenuePubkey,
+      this.commitment
+    );
+  }
+
+  transferApprovalPDA(
+    sourcePubkey: PublicKey,
+    destinationPubkey: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(TRANSFER_APPROVAL_PREFIX),
+        this.transferRestrictionDataPubkey.toBuffer(),
+        sourcePubkey.toBuffer(),
+        destinationPubkey.toBuffer(),
+      ],
+      this.program.programId
+    );
+  }
+
+  async transferApprovalData(transferApprovalPubkey: PublicKey): Promise<any> {
+    return this.program.account.transferApproval.fetch(
+      transferApprovalPubkey,
+      this.commitment
+    );
+  }
+
+  attestationIssuerRegistryPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(ATTESTATION_ISSUER_REGISTRY_PREFIX),
+        this.mintPubkey.toBuffer(),
+      ],
+      this.program.programId
+    );
+  }
+
+  async attestationIssuerRegistryData(): Promise<any> {
+    return this.program.account.attestationIssuerRegistry.fetch(
+      this.attestationIssuerRegistryPDA()[0],
+      this.commitment
+    );
+  }
+
+  // Program of the attestation issuers, shared by all issuers, and the attestation of the wallet
+  // resolved by the transfer hook, the system program when no issuer is registered
+  async attestationAccounts(
+    walletPubkey: PublicKey
+  ): Promise<{ programId: PublicKey; attestation: PublicKey }> {
+    const registry =
+      await this.program.account.attestationIssuerRegistry.fetchNullable(
+        this.attestationIssuerRegistryPDA()[0],
+        this.commitment
+      );
+    const programId =
+      registry && registry.issuersCount > 0
+        ? registry.issuers[0].programId
+        : PublicKey.default;
+    return {
+      programId,
+      attestation: PublicKey.findProgramAddressSync(
+        [Buffer.from(ATTESTATION_PREFIX), walletPubkey.toBuffer()],
+        programId
+      )[0],
+    };
+  }
+
+  async addAttestationIssuer(
+    programId: PublicKey,
+    schema: PublicKey,
+    layout: {
+      discriminator: number[];
+      schemaOffset: number;
+      walletOffset: number;
+      expiresAtOffset: number;
+      revokedOffset: number;
+    },
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .addAttestationIssuer(programId, schema, layout)
+      .accountsStrict({
+        attestationIssuerRegistry: this.attestationIssuerRegistryPDA()[0],
+        extraMetasAccount: this.extraMetasAccountPDA()[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async removeAttestationIssuer(
+    index: number,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .removeAttestationIssuer(index)
+      .accountsStrict({
+        attestationIssuerRegistry: this.attestationIssuerRegistryPDA()[0],
+        extraMetasAccount: this.extraMetasAccountPDA()[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  // Policy config of the security derived from the policy program
+  policyConfigPDA(policyProgramId: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(POLICY_CONFIG_PREFIX), this.mintPubkey.toBuffer()],
+      policyProgramId
+    );
+  }
+
+  async setPolicyProgram(
+    policyProgramId: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setPolicyProgram()
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        extraMetasAccount: this.extraMetasAccountPDA()[0],
+        securityToken: this.mintPubkey,
+        policyProgram: policyProgramId,
+        attestationIssuerRegistry: this.attestationIssuerRegistryPDA()[0],
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
   extraMetasAccountPDA(): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [Buffer.from(EXTRA_METAS_ACCOUNT_PREFIX), this.mintPubkey.toBuffer()],
@@ -165,6 +401,86 @@ export class TransferRestrictionsHelper {
     });
   }
 
+  async updateExtraMetasAccount(
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .updateExtraAccountMetaList()
+      .accountsStrict({
+        extraMetasAccount: this.extraMetasAccountPDA()[0],
+        securityMint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        accessControl: this.accessControlPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        attestationIssuerRegistry: this.attestationIssuerRegistryPDA()[0],
+        payer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  // Version of the extra account metas of the mint, 0 when the meta list has no version entry
+  async extraAccountMetasVersion(): Promise<number> {
+    const { data } = await this.program.provider.connection.getAccountInfo(
+      this.extraMetasAccountPDA()[0],
+      this.commitment
+    );
+    let offset = 0;
+    while (offset + 12 <= data.length) {
+      const discriminator = data.subarray(offset, offset + 8);
+      const length = data.readUInt32LE(offset + 8);
+      if (discriminator.equals(EXTRA_ACCOUNT_METAS_VERSION_DISCRIMINATOR)) {
+        return data[offset + 12];
+      }
+      offset += 12 + length;
+    }
+    return 0;
+  }
+
+  // Extra accounts of the transfer hook for a transfer between the token accounts, which
+  // enforceTransferRestrictions takes as remaining accounts
+  async transferHookAccounts(
+    sourceAccountPubkey: PublicKey,
+    destinationAccountPubkey: PublicKey,
+    amount: bigint
+  ): Promise<AccountMeta[]> {
+    const connection = this.program.provider.connection;
+    const { owner } = await getAccount(
+      connection,
+      sourceAccountPubkey,
+      this.commitment,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const keys = [
+      sourceAccountPubkey,
+      this.mintPubkey,
+      destinationAccountPubkey,
+      owner,
+    ];
+    const instruction = new TransactionInstruction({
+      keys: keys.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: false,
+      })),
+      programId: this.program.programId,
+    });
+    await addExtraAccountMetasForExecute(
+      connection,
+      instruction,
+      this.program.programId,
+      sourceAccountPubkey,
+      this.mintPubkey,
+      destinationAccountPubkey,
+      owner,
+      amount,
+      this.commitment
+    );
+    return instruction.keys.slice(keys.length);
+  }
+
   initializeTransferRestrictionData(
     maxHolders: BN,
     authorityWalletRolePubkey: PublicKey,
@@ -225,8 +541,6 @@ export class TransferRestrictionsHelper {
       transferGroupFromId,
       transferGroupToId
     );
-    const [transferGroupFromPubkey] = this.groupPDA(transferGroupFromId);
-    const [transferGroupToPubkey] = this.groupPDA(transferGroupToId);
     const payerKeypair = payer || authority;
 
     return this.program.methods
@@ -234,8 +548,10 @@ export class TransferRestrictionsHelper {
       .accountsStrict({
         transferRule: transferRulePubkey,
         transferRestrictionData: this.transferRestrictionDataPubkey,
-        transferRestrictionGroupFrom: transferGroupFromPubkey,
-        transferRestrictionGroupTo: transferGroupToPubkey,
+        transferRestrictionGroupFrom:
+          this.transferRuleGroupPubkey(transferGroupFromId),
+        transferRestrictionGroupTo:
+          this.transferRuleGroupPubkey(transferGroupToId),
         accessControlAccount: this.accessControlPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         authority: authority.publicKey,
@@ -307,6 +623,50 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
+  async onboardInvestors(
+    entries: {
+      userWallet: PublicKey;
+      associatedTokenAccount: PublicKey;
+      holderId: BN;
+      groupId: BN;
+    }[],
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    const remainingAccounts = entries.flatMap((entry) => {
+      const [holderPubkey] = this.holderPDA(entry.holderId);
+      return [
+        entry.userWallet,
+        entry.associatedTokenAccount,
+        holderPubkey,
+        this.holderGroupPDA(holderPubkey, entry.groupId)[0],
+        this.securityAssociatedAccountPDA(entry.associatedTokenAccount)[0],
+        this.groupPDA(entry.groupId)[0],
+      ].map((pubkey, index) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: index > 1,
+      }));
+    });
+    return this.program.methods
+      .onboardInvestors(
+        entries.map(({ holderId, groupId }) => ({ holderId, groupId }))
+      )
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityToken: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
   async initializeHolderGroup(
     holderGroupPubkey: PublicKey,
     holderPubkey: PublicKey,
@@ -383,45 +743,38 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
-  async setHolderGroupMax(
-    maxHolders: BN,
-    groupId: BN,
+  async setCountHoldersByBalance(
+    enabled: boolean,
     authorityWalletRolePubkey: PublicKey,
     payer: Keypair
   ): Promise<string> {
-    const [groupPubkey] = this.groupPDA(groupId);
     return this.program.methods
-      .setHolderGroupMax(groupId, maxHolders)
+      .setCountHoldersByBalance(enabled)
       .accountsStrict({
         transferRestrictionData: this.transferRestrictionDataPubkey,
+        extraMetasAccount: this.extraMetasAccountPDA()[0],
+        attestationIssuerRegistry: this.attestationIssuerRegistryPDA()[0],
         accessControlAccount: this.accessControlPubkey,
         mint: this.mintPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
-        group: groupPubkey,
         payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .signers([payer])
       .rpc({ commitment: this.commitment });
   }
 
-  async setAllowTransferRule(
-    lockedUntil: BN,
-    transferRulePubkey: PublicKey,
-    transferGroupFromId: BN,
-    transferGroupToId: BN,
+  async setAllowIntraHolderTransfers(
+    enabled: boolean,
     authorityWalletRolePubkey: PublicKey,
     payer: Keypair
   ): Promise<string> {
-    const [transferRestrictionGroupFromPubkey] = this.groupPDA(transferGroupFromId);
-    const [transferRestrictionGroupToPubkey] = this.groupPDA(transferGroupToId);
     return this.program.methods
-      .setAllowTransferRule(transferGroupFromId, transferGroupToId, lockedUntil)
+      .setAllowIntraHolderTransfers(enabled)
       .accountsStrict({
         transferRestrictionData: this.transferRestrictionDataPubkey,
-        transferRule: transferRulePubkey,
-        transferRestrictionGroupFrom: transferRestrictionGroupFromPubkey,
-        transferRestrictionGroupTo: transferRestrictionGroupToPubkey,
         accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: payer.publicKey,
       })
@@ -429,81 +782,448 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
-  async revokeSecurityAssociatedAccount(
-    userWalletSecAssociatedAccountPubkey: PublicKey,
-    userWalletPubkey: PublicKey,
-    userWalletAssociatedAccountPubkey: PublicKey,
-    authorityWalletRolePubkey: PublicKey,
-    authority: Keypair,
-    payer?: Keypair
+  async syncFundedWallet(
+    tokenAccountPubkey: PublicKey,
+    holderPubkey: PublicKey,
+    groupId: BN
   ): Promise<string> {
-    const userWalletSecAssocAccountData =
-      await this.securityAssociatedAccountData(
-        userWalletSecAssociatedAccountPubkey
-      );
-    const groupId = userWalletSecAssocAccountData.group;
-    const holderPubkey = userWalletSecAssocAccountData.holder;
     const [groupPubkey] = this.groupPDA(groupId);
     const [holderGroupPubkey] = this.holderGroupPDA(holderPubkey, groupId);
-    const payerKeypair = payer || authority;
-
     return this.program.methods
-      .revokeSecurityAssociatedAccount()
+      .syncFundedWallet()
       .accountsStrict({
-        securityAssociatedAccount: userWalletSecAssociatedAccountPubkey,
+        securityAssociatedAccount:
+          this.securityAssociatedAccountPDA(tokenAccountPubkey)[0],
+        tokenAccount: tokenAccountPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
         group: groupPubkey,
         holder: holderPubkey,
         holderGroup: holderGroupPubkey,
-        securityToken: this.mintPubkey,
-        transferRestrictionData: this.transferRestrictionDataPubkey,
-        userWallet: userWalletPubkey,
-        associatedTokenAccount: userWalletAssociatedAccountPubkey,
-        authorityWalletRole: authorityWalletRolePubkey,
-        authority: authority.publicKey,
-        payer: payerKeypair.publicKey,
-        systemProgram: SystemProgram.programId,
       })
-      .signers(payer ? [authority, payerKeypair] : [authority])
       .rpc({ commitment: this.commitment });
   }
 
-  async revokeHolder(
-    holderPubkey: PublicKey,
+  async detectTransferRestriction(
+    sourcePubkey: PublicKey,
+    destinationPubkey: PublicKey,
+    amount: BN
+  ): Promise<{ code: number; message: string }> {
+    const [securityAssociatedAccountFromPubkey] =
+      this.securityAssociatedAccountPDA(sourcePubkey);
+    const [securityAssociatedAccountToPubkey] =
+      this.securityAssociatedAccountPDA(destinationPubkey);
+    const securityAssociatedAccountFrom =
+      await this.program.account.securityAssociatedAccount.fetchNullable(
+        securityAssociatedAccountFromPubkey
+      );
+    const securityAssociatedAccountTo =
+      await this.program.account.securityAssociatedAccount.fetchNullable(
+        securityAssociatedAccountToPubkey
+      );
+    const groupFromId = securityAssociatedAccountFrom?.group ?? new BN(0);
+    const groupToId = securityAssociatedAccountTo?.group ?? new BN(0);
+    const [holderFromPubkey] = this.holderPDA(
+      securityAssociatedAccountFrom?.holderId ?? new BN(0)
+    );
+    const [holderToPubkey] = this.holderPDA(
+      securityAssociatedAccountTo?.holderId ?? new BN(0)
+    );
+    const connection = this.program.provider.connection;
+    const [sourceAccount, destinationAccount] = await Promise.all(
+      [sourcePubkey, destinationPubkey].map((pubkey) =>
+        getAccount(connection, pubkey, this.commitment, TOKEN_2022_PROGRAM_ID)
+      )
+    );
+    const attestationFrom = await this.attestationAccounts(
+      sourceAccount.owner
+    );
+    const attestationTo = await this.attestationAccounts(
+      destinationAccount.owner
+    );
+    return this.program.methods
+      .detectTransferRestriction(amount)
+      .accountsStrict({
+        sourceAccount: sourcePubkey,
+        mint: this.mintPubkey,
+        destinationAccount: destinationPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
+        securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
+        transferRule: this.transferRulePDA(groupFromId, groupToId)[0],
+        transferRuleFromAny: this.transferRulePDA(
+          groupFromId,
+          ANY_TRANSFER_GROUP_ID
+        )[0],
+        transferRuleToAny: this.transferRulePDA(
+          ANY_TRANSFER_GROUP_ID,
+          groupToId
+        )[0],
+        transferRuleAny: this.transferRulePDA(
+          ANY_TRANSFER_GROUP_ID,
+          ANY_TRANSFER_GROUP_ID
+        )[0],
+        blackoutCalendar: this.blackoutCalendarPDA()[0],
+        transferRestrictionGroupFrom: this.groupPDA(groupFromId)[0],
+        transferRestrictionGroupTo: this.groupPDA(groupToId)[0],
+        transferRestrictionHolderFrom: holderFromPubkey,
+        transferRestrictionHolderTo: holderToPubkey,
+        holderGroupFrom: this.holderGroupPDA(holderFromPubkey, groupFromId)[0],
+        holderGroupTo: this.holderGroupPDA(holderToPubkey, groupToId)[0],
+        transferApproval: this.transferApprovalPDA(
+          sourcePubkey,
+          destinationPubkey
+        )[0],
+        accessControlProgram: this.accessControlProgramId,
+        exemptAccountRegistry: this.exemptAccountRegistryPubkey,
+        attestationIssuerRegistry: this.attestationIssuerRegistryPDA()[0],
+        attestationProgram: attestationFrom.programId,
+        attestationFrom: attestationFrom.attestation,
+        attestationTo: attestationTo.attestation,
+      })
+      .view({ commitment: this.commitment });
+  }
+
+  async setHolderGroupMax(
+    maxHolders: BN,
+    groupId: BN,
     authorityWalletRolePubkey: PublicKey,
-    authority: Keypair,
-    payer?: Keypair
+    payer: Keypair
   ): Promise<string> {
-    const payerKeypair = payer || authority;
-
+    const [groupPubkey] = this.groupPDA(groupId);
     return this.program.methods
-      .revokeHolder()
+      .setHolderGroupMax(groupId, maxHolders)
       .accountsStrict({
-        holder: holderPubkey,
         transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
-        authority: authority.publicKey,
-        payer: payerKeypair.publicKey,
-        systemProgram: SystemProgram.programId,
+        group: groupPubkey,
+        payer: payer.publicKey,
       })
-      .signers(payer ? [authority, payerKeypair] : [authority])
+      .signers([payer])
       .rpc({ commitment: this.commitment });
   }
 
-  async revokeHolderGroup(
-    holderPubkey: PublicKey,
+  async setHoldingPeriod(
+    holdingPeriod: BN,
     groupId: BN,
     authorityWalletRolePubkey: PublicKey,
-    authority: Keypair,
-    payer?: Keypair
+    payer: Keypair
   ): Promise<string> {
-    const [holderGroupPubkey] = this.holderGroupPDA(holderPubkey, groupId);
     const [groupPubkey] = this.groupPDA(groupId);
-    const payerKeypair = payer || authority;
-
     return this.program.methods
-      .revokeHolderGroup()
+      .setHoldingPeriod(groupId, holdingPeriod)
       .accountsStrict({
-        holder: holderPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        group: groupPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setMinHolding(
+    minHolding: BN,
+    groupId: BN,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [groupPubkey] = this.groupPDA(groupId);
+    return this.program.methods
+      .setMinHolding(groupId, minHolding)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        group: groupPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setGroupPaused(
+    pausedDirection: number,
+    groupId: BN,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [groupPubkey] = this.groupPDA(groupId);
+    return this.program.methods
+      .setGroupPaused(groupId, pausedDirection)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        group: groupPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setAllowTransferRule(
+    lockedUntil: BN,
+    transferRulePubkey: PublicKey,
+    transferGroupFromId: BN,
+    transferGroupToId: BN,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setAllowTransferRule(transferGroupFromId, transferGroupToId, lockedUntil)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        transferRule: transferRulePubkey,
+        transferRestrictionGroupFrom:
+          this.transferRuleGroupPubkey(transferGroupFromId),
+        transferRestrictionGroupTo:
+          this.transferRuleGroupPubkey(transferGroupToId),
+        accessControlAccount: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setTransferRuleValidity(
+    validUntil: BN,
+    closedIntervals: { start: BN; end: BN; period: BN }[],
+    transferGroupFromId: BN,
+    transferGroupToId: BN,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [transferRulePubkey] = this.transferRulePDA(
+      transferGroupFromId,
+      transferGroupToId
+    );
+    return this.program.methods
+      .setTransferRuleValidity(
+        transferGroupFromId,
+        transferGroupToId,
+        validUntil,
+        closedIntervals
+      )
+      .accountsStrict({
+        transferRule: transferRulePubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setTransferRuleApprovalRequired(
+    approvalRequired: boolean,
+    transferGroupFromId: BN,
+    transferGroupToId: BN,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [transferRulePubkey] = this.transferRulePDA(
+      transferGroupFromId,
+      transferGroupToId
+    );
+    return this.program.methods
+      .setTransferRuleApprovalRequired(
+        transferGroupFromId,
+        transferGroupToId,
+        approvalRequired
+      )
+      .accountsStrict({
+        transferRule: transferRulePubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async initializeBlackoutCalendar(
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .initializeBlackoutCalendar()
+      .accountsStrict({
+        blackoutCalendar: this.blackoutCalendarPDA()[0],
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: payer.publicKey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async addBlackoutWindow(
+    window: { start: BN; end: BN; direction: number; groups: BN[] },
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .addBlackoutWindow(window)
+      .accountsStrict({
+        blackoutCalendar: this.blackoutCalendarPDA()[0],
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async removeBlackoutWindow(
+    window: { start: BN; end: BN; direction: number; groups: BN[] },
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .removeBlackoutWindow(window)
+      .accountsStrict({
+        blackoutCalendar: this.blackoutCalendarPDA()[0],
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async addWalletHold(
+    tokenAccountPubkey: PublicKey,
+    hold: {
+      reasonCode: number;
+      referenceHash: number[];
+      direction: number;
+      expiresAt: BN;
+    },
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .addWalletHold(hold)
+      .accountsStrict({
+        securityAssociatedAccount:
+          this.securityAssociatedAccountPDA(tokenAccountPubkey)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        tokenAccount: tokenAccountPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async removeWalletHold(
+    tokenAccountPubkey: PublicKey,
+    index: number,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .removeWalletHold(index)
+      .accountsStrict({
+        securityAssociatedAccount:
+          this.securityAssociatedAccountPDA(tokenAccountPubkey)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        tokenAccount: tokenAccountPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeSecurityAssociatedAccount(
+    userWalletSecAssociatedAccountPubkey: PublicKey,
+    userWalletPubkey: PublicKey,
+    userWalletAssociatedAccountPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const userWalletSecAssocAccountData =
+      await this.securityAssociatedAccountData(
+        userWalletSecAssociatedAccountPubkey
+      );
+    const groupId = userWalletSecAssocAccountData.group;
+    const holderPubkey = userWalletSecAssocAccountData.holder;
+    const [groupPubkey] = this.groupPDA(groupId);
+    const [holderGroupPubkey] = this.holderGroupPDA(holderPubkey, groupId);
+    const payerKeypair = payer || authority;
+
+    return this.program.methods
+      .revokeSecurityAssociatedAccount()
+      .accountsStrict({
+        securityAssociatedAccount: userWalletSecAssociatedAccountPubkey,
+        group: groupPubkey,
+        holder: holderPubkey,
+        holderGroup: holderGroupPubkey,
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        userWallet: userWalletPubkey,
+        associatedTokenAccount: userWalletAssociatedAccountPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeHolder(
+    holderPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+
+    return this.program.methods
+      .revokeHolder()
+      .accountsStrict({
+        holder: holderPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeHolderGroup(
+    holderPubkey: PublicKey,
+    groupId: BN,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const [holderGroupPubkey] = this.holderGroupPDA(holderPubkey, groupId);
+    const [groupPubkey] = this.groupPDA(groupId);
+    const payerKeypair = payer || authority;
+
+    return this.program.methods
+      .revokeHolderGroup()
+      .accountsStrict({
+        holder: holderPubkey,
         holderGroup: holderGroupPubkey,
         transferRestrictionData: this.transferRestrictionDataPubkey,
         group: groupPubkey,
@@ -543,20 +1263,47 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
-  async initializeSecurityAssociatedAccountIfNotExists(
-    userWalletPubkey: PublicKey,
-    userWalletAssociatedAccountPubkey: PublicKey,
+  async initializeExemptSecurityAssociatedAccount(
+    exemptAccountPubkey: PublicKey,
     authorityWalletRolePubkey: PublicKey,
     authority: Keypair,
-    groupIdx: BN = new BN(0),
     payer?: Keypair
-  ) {
-    const [securityAssociatedAccountPubkey] = this.securityAssociatedAccountPDA(
-      userWalletAssociatedAccountPubkey
-    );
-
-    // Check if security_associated_account exists
-    const saaAccountInfo = await this.program.provider.connection.getAccountInfo(securityAssociatedAccountPubkey);
+  ): Promise<string> {
+    const [securityAssociatedAccountPubkey] =
+      this.securityAssociatedAccountPDA(exemptAccountPubkey);
+    return this.program.methods
+      .initializeExemptSecurityAssociatedAccount()
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityAssociatedAccount: securityAssociatedAccountPubkey,
+        group: this.groupPDA(new BN(0))[0],
+        mint: this.mintPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        exemptAccount: exemptAccountPubkey,
+        exemptAccountRegistry: this.exemptAccountRegistryPubkey,
+        payer: payer ? payer.publicKey : authority.publicKey,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(payer ? [authority, payer] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async initializeSecurityAssociatedAccountIfNotExists(
+    userWalletPubkey: PublicKey,
+    userWalletAssociatedAccountPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    groupIdx: BN = new BN(0),
+    payer?: Keypair
+  ) {
+    const [securityAssociatedAccountPubkey] = this.securityAssociatedAccountPDA(
+      userWalletAssociatedAccountPubkey
+    );
+
+    // Check if security_associated_account exists
+    const saaAccountInfo = await this.program.provider.connection.getAccountInfo(securityAssociatedAccountPubkey);
     const saaExists = saaAccountInfo !== null && saaAccountInfo.data.length > 0;
 
     if (!saaExists) {
@@ -603,4 +1350,491 @@ export class TransferRestrictionsHelper {
       );
     }
   }
+
+  async setOmnibusAccount(
+    omnibus: boolean,
+    tokenAccountPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setOmnibusAccount(omnibus)
+      .accountsStrict({
+        securityAssociatedAccount:
+          this.securityAssociatedAccountPDA(tokenAccountPubkey)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        tokenAccount: tokenAccountPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async initializeBeneficialOwnerPosition(
+    groupId: BN,
+    holderPubkey: PublicKey,
+    omnibusTokenAccountPubkey: PublicKey,
+    operator: Keypair,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const [omnibusSecurityAssociatedAccountPubkey] =
+      this.securityAssociatedAccountPDA(omnibusTokenAccountPubkey);
+    const payerKeypair = payer || operator;
+
+    return this.program.methods
+      .initializeBeneficialOwnerPosition(groupId)
+      .accountsStrict({
+        beneficialOwnerPosition: this.beneficialOwnerPositionPDA(
+          omnibusSecurityAssociatedAccountPubkey,
+          holderPubkey
+        )[0],
+        omnibusSecurityAssociatedAccount: omnibusSecurityAssociatedAccountPubkey,
+        omnibusTokenAccount: omnibusTokenAccountPubkey,
+        group: this.groupPDA(groupId)[0],
+        holder: holderPubkey,
+        holderGroup: this.holderGroupPDA(holderPubkey, groupId)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        operator: operator.publicKey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(
+        payer ? [operator, authority, payerKeypair] : [operator, authority]
+      )
+      .rpc({ commitment: this.commitment });
+  }
+
+  async updateBeneficialOwnerPosition(
+    amount: BN,
+    holderPubkey: PublicKey,
+    omnibusTokenAccountPubkey: PublicKey,
+    operator: Keypair
+  ): Promise<string> {
+    const [omnibusSecurityAssociatedAccountPubkey] =
+      this.securityAssociatedAccountPDA(omnibusTokenAccountPubkey);
+    const [beneficialOwnerPositionPubkey] = this.beneficialOwnerPositionPDA(
+      omnibusSecurityAssociatedAccountPubkey,
+      holderPubkey
+    );
+    const { group: groupId } = await this.beneficialOwnerPositionData(
+      beneficialOwnerPositionPubkey
+    );
+    const { group: omnibusGroupId } = await this.securityAssociatedAccountData(
+      omnibusSecurityAssociatedAccountPubkey
+    );
+    const defaultTransferRules = [
+      this.transferRulePDA(omnibusGroupId, ANY_TRANSFER_GROUP_ID)[0],
+      this.transferRulePDA(ANY_TRANSFER_GROUP_ID, groupId)[0],
+      this.transferRulePDA(ANY_TRANSFER_GROUP_ID, ANY_TRANSFER_GROUP_ID)[0],
+    ];
+
+    return this.program.methods
+      .updateBeneficialOwnerPosition(amount)
+      .accountsStrict({
+        beneficialOwnerPosition: beneficialOwnerPositionPubkey,
+        omnibusSecurityAssociatedAccount: omnibusSecurityAssociatedAccountPubkey,
+        omnibusTokenAccount: omnibusTokenAccountPubkey,
+        group: this.groupPDA(groupId)[0],
+        holder: holderPubkey,
+        holderGroup: this.holderGroupPDA(holderPubkey, groupId)[0],
+        transferRule: this.transferRulePDA(omnibusGroupId, groupId)[0],
+        blackoutCalendar: this.blackoutCalendarPDA()[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        operator: operator.publicKey,
+      })
+      .remainingAccounts(
+        defaultTransferRules.map((pubkey) => ({
+          pubkey,
+          isWritable: false,
+          isSigner: false,
+        }))
+      )
+      .signers([operator])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeBeneficialOwnerPosition(
+    holderPubkey: PublicKey,
+    omnibusTokenAccountPubkey: PublicKey,
+    operator: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const [omnibusSecurityAssociatedAccountPubkey] =
+      this.securityAssociatedAccountPDA(omnibusTokenAccountPubkey);
+    const [beneficialOwnerPositionPubkey] = this.beneficialOwnerPositionPDA(
+      omnibusSecurityAssociatedAccountPubkey,
+      holderPubkey
+    );
+    const { group: groupId } = await this.beneficialOwnerPositionData(
+      beneficialOwnerPositionPubkey
+    );
+    const payerKeypair = payer || operator;
+
+    return this.program.methods
+      .revokeBeneficialOwnerPosition()
+      .accountsStrict({
+        beneficialOwnerPosition: beneficialOwnerPositionPubkey,
+        omnibusSecurityAssociatedAccount: omnibusSecurityAssociatedAccountPubkey,
+        omnibusTokenAccount: omnibusTokenAccountPubkey,
+        group: this.groupPDA(groupId)[0],
+        holder: holderPubkey,
+        holderGroup: this.holderGroupPDA(holderPubkey, groupId)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        operator: operator.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(payer ? [operator, payerKeypair] : [operator])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async mergeHolders(
+    sourceHolderPubkey: PublicKey,
+    targetHolderPubkey: PublicKey,
+    groupIds: BN[],
+    securityAssociatedAccountPubkeys: PublicKey[],
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    const remainingAccounts = [
+      ...groupIds.flatMap((groupId) => [
+        this.holderGroupPDA(sourceHolderPubkey, groupId)[0],
+        this.holderGroupPDA(targetHolderPubkey, groupId)[0],
+        this.groupPDA(groupId)[0],
+      ]),
+      ...securityAssociatedAccountPubkeys,
+    ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+    return this.program.methods
+      .mergeHolders(groupIds.length)
+      .accountsStrict({
+        sourceHolder: sourceHolderPubkey,
+        targetHolder: targetHolderPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityToken: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setHolderFrozen(
+    frozen: boolean,
+    holderPubkey: PublicKey,
+    wallets: { tokenAccount: PublicKey; owner: PublicKey }[],
+    accessControlProgramId: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    const remainingAccounts = wallets.flatMap(({ tokenAccount, owner }) => [
+      {
+        pubkey: this.securityAssociatedAccountPDA(tokenAccount)[0],
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: tokenAccount, isSigner: false, isWritable: true },
+      { pubkey: owner, isSigner: false, isWritable: false },
+    ]);
+    return this.program.methods
+      .setHolderFrozen(frozen)
+      .accountsStrict({
+        holder: holderPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityMint: this.mintPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        accessControlProgram: accessControlProgramId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        exemptAccountRegistry: this.exemptAccountRegistryPubkey,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async migrateWalletsGroup(
+    currentGroupId: BN,
+    newGroupId: BN,
+    securityAssociatedAccountPubkeys: PublicKey[],
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    const remainingAccounts = [];
+    for (const securityAssociatedAccountPubkey of securityAssociatedAccountPubkeys) {
+      const { holder } = await this.securityAssociatedAccountData(
+        securityAssociatedAccountPubkey
+      );
+      remainingAccounts.push(
+        securityAssociatedAccountPubkey,
+        holder,
+        this.holderGroupPDA(holder, currentGroupId)[0],
+        this.holderGroupPDA(holder, newGroupId)[0]
+      );
+    }
+    return this.program.methods
+      .migrateWalletsGroup(currentGroupId, newGroupId)
+      .accountsStrict({
+        transferRestrictionGroupCurrent: this.groupPDA(currentGroupId)[0],
+        transferRestrictionGroupNew: this.groupPDA(newGroupId)[0],
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityToken: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(
+        remainingAccounts.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      )
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async initializeApprovedVenue(
+    venuePubkey: PublicKey,
+    groupId: BN,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    return this.program.methods
+      .initializeApprovedVenue(venuePubkey, groupId)
+      .accountsStrict({
+        approvedVenue: this.approvedVenuePDA(venuePubkey)[0],
+        group: this.groupPDA(groupId)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeApprovedVenue(
+    venuePubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    return this.program.methods
+      .revokeApprovedVenue()
+      .accountsStrict({
+        approvedVenue: this.approvedVenuePDA(venuePubkey)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+      })
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async registerVenueTokenAccount(
+    venuePubkey: PublicKey,
+    tokenAccountPubkey: PublicKey,
+    venueAuthorityPubkey: PublicKey,
+    authoritySeeds: Buffer[],
+    authorityWalletRolePubkey: PublicKey | null,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    const [approvedVenuePubkey] = this.approvedVenuePDA(venuePubkey);
+    const { group } = await this.approvedVenueData(approvedVenuePubkey);
+    return this.program.methods
+      .registerVenueTokenAccount(authoritySeeds)
+      .accountsStrict({
+        approvedVenue: approvedVenuePubkey,
+        securityAssociatedAccount:
+          this.securityAssociatedAccountPDA(tokenAccountPubkey)[0],
+        tokenAccount: tokenAccountPubkey,
+        venueAuthority: venueAuthorityPubkey,
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        group: this.groupPDA(group)[0],
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(
+        payerKeypair === authority ? [authority] : [authority, payerKeypair]
+      )
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeVenueTokenAccount(
+    tokenAccountPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    const [securityAssociatedAccountPubkey] =
+      this.securityAssociatedAccountPDA(tokenAccountPubkey);
+    const { group } = await this.securityAssociatedAccountData(
+      securityAssociatedAccountPubkey
+    );
+    return this.program.methods
+      .revokeVenueTokenAccount()
+      .accountsStrict({
+        securityAssociatedAccount: securityAssociatedAccountPubkey,
+        tokenAccount: tokenAccountPubkey,
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        group: this.groupPDA(group)[0],
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+      })
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeTransferRule(
+    groupFromId: BN,
+    groupToId: BN,
+    receiverPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .revokeTransferRule()
+      .accountsStrict({
+        transferRule: this.transferRulePDA(groupFromId, groupToId)[0],
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        receiver: receiverPubkey,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeTransferRestrictionGroup(
+    groupId: BN,
+    receiverPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .revokeTransferRestrictionGroup()
+      .accountsStrict({
+        group: this.groupPDA(groupId)[0],
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        receiver: receiverPubkey,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async initializeTransferApproval(
+    amount: BN,
+    expiresAt: BN,
+    sourcePubkey: PublicKey,
+    destinationPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    return this.program.methods
+      .initializeTransferApproval(amount, expiresAt)
+      .accountsStrict({
+        transferApproval: this.transferApprovalPDA(
+          sourcePubkey,
+          destinationPubkey
+        )[0],
+        sourceAccount: sourcePubkey,
+        destinationAccount: destinationPubkey,
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async revokeTransferApproval(
+    sourcePubkey: PublicKey,
+    destinationPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    authority: Keypair,
+    payer?: Keypair
+  ): Promise<string> {
+    const payerKeypair = payer || authority;
+    return this.program.methods
+      .revokeTransferApproval()
+      .accountsStrict({
+        transferApproval: this.transferApprovalPDA(
+          sourcePubkey,
+          destinationPubkey
+        )[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payerKeypair.publicKey,
+      })
+      .signers(payer ? [authority, payerKeypair] : [authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async migrateAccounts(
+    accountPubkeys: PublicKey[],
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .migrateAccounts()
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityMint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        accessControl: this.accessControlPubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(
+        accountPubkeys.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      )
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
 }
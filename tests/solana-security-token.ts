@@ -60,7 +60,8 @@ describe("solana-security-token", () => {
   const transferRestrictionsHelper = new TransferRestrictionsHelper(
     transferRestrictionsProgram,
     mintKeypair.publicKey,
-    accessControlHelper.accessControlPubkey
+    accessControlHelper.accessControlPubkey,
+    accessControlProgram.programId
   );
 
   const [authorityWalletRolePubkey] = accessControlHelper.walletRolePDA(
@@ -0,0 +1,340 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { createHash } from "crypto";
+
+import { Tokenlock } from "../../target/types/tokenlock";
+import { AttestationStub } from "../../target/types/attestation_stub";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { createAccount, solToLamports, topUpWallet } from "../utils";
+import {
+  createReleaseSchedule,
+  mintReleaseSchedule,
+  initializeTokenlock,
+  MAX_RELEASE_DELAY,
+  withdraw,
+  getTimelockAccount,
+} from "../helpers/tokenlock_helper";
+import { getNowTs } from "../helpers/clock_helper";
+import { fromDaysToSeconds } from "../helpers/datetime";
+import { ATTESTATION_PREFIX } from "../helpers/transfer-restrictions_helper";
+
+describe("TokenLockup release transfer restrictions", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10000,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+
+  const tokenlockProgram = anchor.workspace.Tokenlock as Program<Tokenlock>;
+  const attestationProgram = anchor.workspace
+    .AttestationStub as Program<AttestationStub>;
+
+  const group0 = new anchor.BN(0);
+  const recipient = Keypair.generate();
+  let recipientTokenAccountPubkey: PublicKey;
+  let escrowOwnerPubkey: PublicKey;
+  let tokenlockDataPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  const expectWithdrawError = async (amount: number, errorCode: string) => {
+    try {
+      await withdrawToRecipient(amount);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const hasErrorCode = error.logs.some((log: string) =>
+        log.includes(`Error Code: ${errorCode}`)
+      );
+      assert.isTrue(hasErrorCode);
+    }
+  };
+
+  const recipientBalance = async () => {
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      recipientTokenAccountPubkey
+    );
+    return amount;
+  };
+
+  const withdrawToRecipient = async (amount: number) =>
+    withdraw(
+      testEnvironment.connection,
+      new anchor.BN(amount),
+      tokenlockProgram,
+      testEnvironment.transferRestrictionsHelper.program.programId,
+      testEnvironment.mintKeypair.publicKey,
+      tokenlockDataPubkey,
+      getTimelockAccount(
+        tokenlockProgram.programId,
+        tokenlockDataPubkey,
+        recipient.publicKey
+      ),
+      escrowOwnerPubkey,
+      recipientTokenAccountPubkey,
+      testEnvironment.transferRestrictionsHelper,
+      recipient
+    );
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    await testEnvironment.mintToReserveAdmin();
+
+    const mintPubkey = testEnvironment.mintKeypair.publicKey;
+    const reserveAdmin = testEnvironment.reserveAdmin;
+    const [reserveAdminWalletRolePubkey] =
+      testEnvironment.accessControlHelper.walletRolePDA(reserveAdmin.publicKey);
+    const [contractAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.contractAdmin.publicKey
+      );
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    await topUpWallet(
+      testEnvironment.connection,
+      testEnvironment.contractAdmin.publicKey,
+      solToLamports(10)
+    );
+    tokenlockDataPubkey = await createAccount(
+      testEnvironment.connection,
+      testEnvironment.contractAdmin,
+      1 * 1024 * 1024,
+      tokenlockProgram.programId
+    );
+    [escrowOwnerPubkey] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("tokenlock"),
+        mintPubkey.toBuffer(),
+        tokenlockDataPubkey.toBuffer(),
+      ],
+      tokenlockProgram.programId
+    );
+    const escrowAccount =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        escrowOwnerPubkey,
+        testEnvironment.contractAdmin,
+        true
+      );
+    await initializeTokenlock(
+      tokenlockProgram,
+      new anchor.BN(MAX_RELEASE_DELAY),
+      new anchor.BN(50),
+      tokenlockDataPubkey,
+      escrowAccount,
+      testEnvironment.transferRestrictionsHelper.transferRestrictionDataPubkey,
+      mintPubkey,
+      contractAdminWalletRole,
+      testEnvironment.accessControlHelper.accessControlPubkey,
+      testEnvironment.contractAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.setLockupEscrowAccount(
+      escrowAccount,
+      tokenlockDataPubkey,
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+    await testEnvironment.accessControlHelper.setLockupEscrowAccount(
+      escrowAccount,
+      tokenlockDataPubkey,
+      testEnvironment.contractAdmin
+    );
+
+    recipientTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        recipient.publicKey,
+        testEnvironment.contractAdmin
+      );
+    await topUpWallet(
+      testEnvironment.connection,
+      recipient.publicKey,
+      solToLamports(1)
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      recipient.publicKey,
+      recipientTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      group0
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(1),
+      group0,
+      group0,
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      )[0],
+      testEnvironment.transferAdmin
+    );
+
+    // half of the timelock is released right away
+    const scheduleId = await createReleaseSchedule(
+      tokenlockProgram,
+      tokenlockDataPubkey,
+      2,
+      new anchor.BN(0),
+      5000,
+      new anchor.BN(fromDaysToSeconds(4)),
+      testEnvironment.accessControlHelper.accessControlPubkey,
+      reserveAdminWalletRolePubkey,
+      reserveAdmin
+    );
+    const nowTs = await getNowTs(testEnvironment.connection);
+    await mintReleaseSchedule(
+      testEnvironment.connection,
+      tokenlockProgram,
+      new anchor.BN(1_000),
+      new anchor.BN(nowTs),
+      Number(scheduleId),
+      [],
+      tokenlockDataPubkey,
+      escrowAccount,
+      escrowOwnerPubkey,
+      recipient.publicKey,
+      reserveAdmin,
+      reserveAdminWalletRolePubkey,
+      testEnvironment.accessControlHelper.accessControlPubkey,
+      mintPubkey,
+      testEnvironment.accessControlHelper.program.programId
+    );
+  });
+
+  it("fails to release tokens inside a blackout window of the recipient group", async () => {
+    await testEnvironment.transferRestrictionsHelper.initializeBlackoutCalendar(
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const now = await getNowTs(testEnvironment.connection);
+    const blackoutWindow = {
+      start: new anchor.BN(now - 10),
+      end: new anchor.BN(now + 3600),
+      direction: 2,
+      groups: [group0],
+    };
+    await testEnvironment.transferRestrictionsHelper.addBlackoutWindow(
+      blackoutWindow,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    try {
+      await withdrawToRecipient(10);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isBlackoutActive = error.logs.some((log: string) =>
+        log.includes("Error Code: TransferBlackoutActive")
+      );
+      assert.isTrue(isBlackoutActive);
+    }
+
+    await testEnvironment.transferRestrictionsHelper.removeBlackoutWindow(
+      blackoutWindow,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    await withdrawToRecipient(10);
+    assert.equal(await recipientBalance(), BigInt(10));
+  });
+
+  it("fails to release tokens while the recipient group is paused for receiving", async () => {
+    await testEnvironment.transferRestrictionsHelper.setGroupPaused(
+      2,
+      group0,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await expectWithdrawError(10, "GroupTransfersPaused");
+
+    await testEnvironment.transferRestrictionsHelper.setGroupPaused(
+      0,
+      group0,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await withdrawToRecipient(10);
+    assert.equal(await recipientBalance(), BigInt(20));
+  });
+
+  it("fails to release tokens leaving the recipient below the minimum holding", async () => {
+    await testEnvironment.transferRestrictionsHelper.setMinHolding(
+      new anchor.BN(100),
+      group0,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await expectWithdrawError(10, "ReceiverBalanceBelowMinHolding");
+
+    await withdrawToRecipient(80);
+    assert.equal(await recipientBalance(), BigInt(100));
+  });
+
+  it("fails to release tokens to a recipient without attestation", async () => {
+    const [contractAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.contractAdmin.publicKey
+      );
+    const schema = Keypair.generate().publicKey;
+    const verifier = Keypair.generate();
+    // layout of the stand-in attestation account: issuer, schema, wallet, expires_at, revoked
+    await testEnvironment.transferRestrictionsHelper.addAttestationIssuer(
+      attestationProgram.programId,
+      schema,
+      {
+        discriminator: Array.from(
+          createHash("sha256")
+            .update("account:Attestation")
+            .digest()
+            .subarray(0, 8)
+        ),
+        schemaOffset: 40,
+        walletOffset: 72,
+        expiresAtOffset: 104,
+        revokedOffset: 112,
+      },
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+    await expectWithdrawError(10, "WalletNotAttested");
+
+    await topUpWallet(
+      testEnvironment.connection,
+      verifier.publicKey,
+      solToLamports(1)
+    );
+    const now = await getNowTs(testEnvironment.connection);
+    await attestationProgram.methods
+      .attest(schema, recipient.publicKey, new anchor.BN(now + 3600))
+      .accountsStrict({
+        attestation: PublicKey.findProgramAddressSync(
+          [Buffer.from(ATTESTATION_PREFIX), recipient.publicKey.toBuffer()],
+          attestationProgram.programId
+        )[0],
+        issuer: verifier.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([verifier])
+      .rpc({ commitment: testEnvironment.commitment });
+    await withdrawToRecipient(10);
+    assert.equal(await recipientBalance(), BigInt(110));
+  });
+});
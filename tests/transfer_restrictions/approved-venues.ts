@@ -0,0 +1,302 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Approved venues", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const investorGroupId = new anchor.BN(1);
+  const venueGroupId = new anchor.BN(2);
+  const investor = Keypair.generate();
+  const venueProgramId = Keypair.generate().publicKey;
+  const [vaultAuthorityPubkey, vaultAuthorityBump] =
+    PublicKey.findProgramAddressSync([Buffer.from("vault")], venueProgramId);
+  const vaultAuthoritySeeds = [
+    Buffer.from("vault"),
+    Buffer.from([vaultAuthorityBump]),
+  ];
+  let investorTokenAccountPubkey: PublicKey;
+  let vaultTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const unit = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const groupId of [investorGroupId, venueGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      investorGroupId,
+      venueGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      investorGroupId
+    );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((unit * BigInt(5)).toString()),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      )[0]
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+    vaultTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        vaultAuthorityPubkey,
+        testEnvironment.reserveAdmin
+      );
+  });
+
+  it("fails to approve venue by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeApprovedVenue(
+        venueProgramId,
+        venueGroupId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("approves venue program by transfer admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.initializeApprovedVenue(
+      venueProgramId,
+      venueGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const approvedVenue =
+      await testEnvironment.transferRestrictionsHelper.approvedVenueData(
+        testEnvironment.transferRestrictionsHelper.approvedVenuePDA(
+          venueProgramId
+        )[0]
+      );
+    assert.equal(approvedVenue.venue.toString(), venueProgramId.toString());
+    assert.equal(approvedVenue.group.toNumber(), venueGroupId.toNumber());
+  });
+
+  it("fails to register venue token account without transfer admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.registerVenueTokenAccount(
+        venueProgramId,
+        vaultTokenAccountPubkey,
+        vaultAuthorityPubkey,
+        vaultAuthoritySeeds,
+        null,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to register token account of an account owned by the venue program without transfer admin", async () => {
+    // a keypair account assigned to the venue program can sign for itself
+    const poolState = Keypair.generate();
+    await sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: testEnvironment.reserveAdmin.publicKey,
+          newAccountPubkey: poolState.publicKey,
+          lamports:
+            await testEnvironment.connection.getMinimumBalanceForRentExemption(
+              0
+            ),
+          space: 0,
+          programId: venueProgramId,
+        })
+      ),
+      [testEnvironment.reserveAdmin, poolState],
+      { commitment: testEnvironment.commitment }
+    );
+    const poolTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        poolState.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.registerVenueTokenAccount(
+        venueProgramId,
+        poolTokenAccountPubkey,
+        poolState.publicKey,
+        [],
+        null,
+        poolState,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TokenAccountNotApprovedVenue");
+    }
+  });
+
+  it("fails to register token account which is not owned by the venue", async () => {
+    const otherTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.registerVenueTokenAccount(
+        venueProgramId,
+        otherTokenAccountPubkey,
+        investor.publicKey,
+        vaultAuthoritySeeds,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TokenAccountNotApprovedVenue");
+      assert.equal(
+        error.errorMessage,
+        "Token account is not owned by the approved venue"
+      );
+    }
+  });
+
+  it("registers venue vault without a holder", async () => {
+    const { currentHoldersCount: holdersCountBefore } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    await testEnvironment.transferRestrictionsHelper.registerVenueTokenAccount(
+      venueProgramId,
+      vaultTokenAccountPubkey,
+      vaultAuthorityPubkey,
+      vaultAuthoritySeeds,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const vaultSaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          vaultTokenAccountPubkey
+        )[0]
+      );
+    assert.equal(vaultSaa.group.toNumber(), venueGroupId.toNumber());
+    assert.isNull(vaultSaa.holder);
+    const { currentHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(
+      currentHoldersCount.toNumber(),
+      holdersCountBefore.toNumber()
+    );
+  });
+
+  it("transfers to the venue vault", async () => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        investorTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        vaultTokenAccountPubkey,
+        investor.publicKey,
+        unit,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    await sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [investor],
+      { commitment: testEnvironment.commitment }
+    );
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      vaultTokenAccountPubkey
+    );
+    assert.equal(amount.toString(), unit.toString());
+  });
+
+  it("revokes venue and its vault", async () => {
+    await testEnvironment.transferRestrictionsHelper.revokeApprovedVenue(
+      venueProgramId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.revokeVenueTokenAccount(
+      vaultTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    for (const pubkey of [
+      testEnvironment.transferRestrictionsHelper.approvedVenuePDA(
+        venueProgramId
+      )[0],
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        vaultTokenAccountPubkey
+      )[0],
+    ]) {
+      assert.isNull(await testEnvironment.connection.getAccountInfo(pubkey));
+    }
+  });
+});
@@ -0,0 +1,349 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { createHash } from "crypto";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import { AttestationStub } from "../../target/types/attestation_stub";
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { ATTESTATION_PREFIX } from "../helpers/transfer-restrictions_helper";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Attestation issuers", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  const attestationProgram = anchor.workspace
+    .AttestationStub as Program<AttestationStub>;
+  // layout of the stand-in attestation account: issuer, schema, wallet, expires_at, revoked
+  const attestationLayout = {
+    discriminator: Array.from(
+      createHash("sha256").update("account:Attestation").digest().subarray(0, 8)
+    ),
+    schemaOffset: 40,
+    walletOffset: 72,
+    expiresAtOffset: 104,
+    revokedOffset: 112,
+  };
+  const schema = Keypair.generate().publicKey;
+  const verifier = Keypair.generate();
+  const secondSchema = Keypair.generate().publicKey;
+  const secondVerifier = Keypair.generate();
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const recipient = Keypair.generate();
+  const secondRecipient = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let recipientTokenAccountPubkey: PublicKey;
+  let secondRecipientTokenAccountPubkey: PublicKey;
+  let contractAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const transferAmount = BigInt(
+    1_000 * 10 ** testEnvironmentParams.mint.decimals
+  );
+
+  const attestationPDA = (wallet: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from(ATTESTATION_PREFIX), wallet.toBuffer()],
+      attestationProgram.programId
+    )[0];
+
+  const attest = async (
+    wallet: PublicKey,
+    expiresAt: number,
+    issuerSchema = schema,
+    issuer = verifier
+  ) =>
+    attestationProgram.methods
+      .attest(issuerSchema, wallet, new anchor.BN(expiresAt))
+      .accountsStrict({
+        attestation: attestationPDA(wallet),
+        issuer: issuer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([issuer])
+      .rpc({ commitment: testEnvironment.commitment });
+
+  const transfer = async (destination = recipientTokenAccountPubkey) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        investorTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        destination,
+        investor.publicKey,
+        transferAmount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [investor],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const assertTransferNotAttested = async () => {
+    try {
+      await transfer();
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isNotAttested = error.logs.some((log: string) =>
+        log.includes("Error Code: WalletNotAttested")
+      );
+      assert.isTrue(isNotAttested);
+    }
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    const [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    [contractAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.contractAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    recipientTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        recipient.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    secondRecipientTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        secondRecipient.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccountPubkey] of [
+      [investor.publicKey, investorTokenAccountPubkey],
+      [recipient.publicKey, recipientTokenAccountPubkey],
+      [secondRecipient.publicKey, secondRecipientTokenAccountPubkey],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+    }
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((transferAmount * BigInt(10)).toString()),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      )[0]
+    );
+    for (const wallet of [investor, verifier, secondVerifier]) {
+      await topUpWallet(
+        testEnvironment.connection,
+        wallet.publicKey,
+        solToLamports(1)
+      );
+    }
+  });
+
+  it("fails to add attestation issuer by transfer admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.addAttestationIssuer(
+        attestationProgram.programId,
+        schema,
+        attestationLayout,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("adds attestation issuer by contract admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.addAttestationIssuer(
+      attestationProgram.programId,
+      schema,
+      attestationLayout,
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+
+    const registry =
+      await testEnvironment.transferRestrictionsHelper.attestationIssuerRegistryData();
+    assert.equal(registry.issuersCount, 1);
+    assert.equal(
+      registry.issuers[0].programId.toBase58(),
+      attestationProgram.programId.toBase58()
+    );
+    assert.equal(registry.issuers[0].schema.toBase58(), schema.toBase58());
+    assert.equal(
+      await testEnvironment.transferRestrictionsHelper.extraAccountMetasVersion(),
+      7
+    );
+  });
+
+  it("fails to add the same attestation issuer twice", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.addAttestationIssuer(
+        attestationProgram.programId,
+        schema,
+        attestationLayout,
+        contractAdminWalletRole,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "AttestationIssuerAlreadyRegistered");
+    }
+  });
+
+  it("fails to transfer to a wallet without attestation", async () => {
+    await attest(investor.publicKey, 0);
+
+    await assertTransferNotAttested();
+    const restriction =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        investorTokenAccountPubkey,
+        recipientTokenAccountPubkey,
+        new anchor.BN(transferAmount.toString())
+      );
+    assert.equal(
+      restriction.message,
+      "Wallet has no valid attestation of a trusted issuer"
+    );
+  });
+
+  it("fails to transfer to a wallet with expired attestation", async () => {
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await attest(recipient.publicKey, tsNow - 1);
+
+    await assertTransferNotAttested();
+  });
+
+  it("transfers between attested wallets", async () => {
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await attest(recipient.publicKey, tsNow + 3600);
+
+    await transfer();
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      recipientTokenAccountPubkey
+    );
+    assert.equal(amount, transferAmount);
+  });
+
+  it("fails to add attestation issuer of another attestation program", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.addAttestationIssuer(
+        Keypair.generate().publicKey,
+        secondSchema,
+        attestationLayout,
+        contractAdminWalletRole,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "AttestationProgramMismatch");
+    }
+  });
+
+  it("transfers between wallets attested by different issuers", async () => {
+    await testEnvironment.transferRestrictionsHelper.addAttestationIssuer(
+      attestationProgram.programId,
+      secondSchema,
+      attestationLayout,
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+    const registry =
+      await testEnvironment.transferRestrictionsHelper.attestationIssuerRegistryData();
+    assert.equal(registry.issuersCount, 2);
+
+    await attest(secondRecipient.publicKey, 0, secondSchema, secondVerifier);
+    await transfer(secondRecipientTokenAccountPubkey);
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      secondRecipientTokenAccountPubkey
+    );
+    assert.equal(amount, transferAmount);
+  });
+
+  it("fails to transfer once the verifier revoked the attestation", async () => {
+    await attestationProgram.methods
+      .revoke()
+      .accountsStrict({
+        attestation: attestationPDA(investor.publicKey),
+        issuer: verifier.publicKey,
+      })
+      .signers([verifier])
+      .rpc({ commitment: testEnvironment.commitment });
+
+    await assertTransferNotAttested();
+  });
+
+  it("transfers without attestations once the issuers are removed", async () => {
+    for (const index of [1, 0]) {
+      await testEnvironment.transferRestrictionsHelper.removeAttestationIssuer(
+        index,
+        contractAdminWalletRole,
+        testEnvironment.contractAdmin
+      );
+    }
+    const registry =
+      await testEnvironment.transferRestrictionsHelper.attestationIssuerRegistryData();
+    assert.equal(registry.issuersCount, 0);
+
+    await transfer();
+  });
+});
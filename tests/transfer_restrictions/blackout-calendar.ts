@@ -0,0 +1,344 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Blackout calendar", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const transferAmount = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+
+  const transferFromInvestor = async () => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        investorTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        reserveAdminTokenAccountPubkey,
+        investor.publicKey,
+        transferAmount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [investor],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+    const [reserveAdminSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((transferAmount * BigInt(10)).toString()),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      reserveAdminSaaPubkey
+    );
+    await testEnvironment.accessControlHelper.forceTransferBetween(
+      transferAmount * BigInt(5),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.connection
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  it("allows transfers before the blackout calendar is initialized", async () => {
+    await transferFromInvestor();
+  });
+
+  it("fails to initialize blackout calendar by reserve admin", async () => {
+    const signer = testEnvironment.reserveAdmin;
+    const [authorityWalletRolePubkey] =
+      testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeBlackoutCalendar(
+        authorityWalletRolePubkey,
+        signer
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("initializes blackout calendar by wallets admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.initializeBlackoutCalendar(
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const [blackoutCalendarPubkey] =
+      testEnvironment.transferRestrictionsHelper.blackoutCalendarPDA();
+    const blackoutCalendar =
+      await testEnvironment.transferRestrictionsHelper.blackoutCalendarData(
+        blackoutCalendarPubkey
+      );
+    assert.equal(
+      blackoutCalendar.transferRestrictionData.toString(),
+      testEnvironment.transferRestrictionsHelper.transferRestrictionDataPubkey.toString()
+    );
+    assert.equal(blackoutCalendar.windows.length, 0);
+  });
+
+  it("fails to add blackout window which ends before it starts", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.addBlackoutWindow(
+        {
+          start: new anchor.BN(now + 100),
+          end: new anchor.BN(now),
+          direction: 3,
+          groups: [],
+        },
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidBlackoutWindow");
+      assert.equal(error.errorMessage, "Invalid blackout window");
+    }
+  });
+
+  it("fails to add blackout window with invalid direction", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.addBlackoutWindow(
+        {
+          start: new anchor.BN(now),
+          end: new anchor.BN(now + 100),
+          direction: 4,
+          groups: [],
+        },
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidTransferDirection");
+      assert.equal(error.errorMessage, "Invalid transfer direction");
+    }
+  });
+
+  it("fails to add blackout window by reserve admin", async () => {
+    const signer = testEnvironment.reserveAdmin;
+    const [authorityWalletRolePubkey] =
+      testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
+    const now = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.addBlackoutWindow(
+        {
+          start: new anchor.BN(now),
+          end: new anchor.BN(now + 100),
+          direction: 3,
+          groups: [],
+        },
+        authorityWalletRolePubkey,
+        signer
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("allows transfers when blackout window applies to other groups", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.addBlackoutWindow(
+      {
+        start: new anchor.BN(now - 100),
+        end: new anchor.BN(now + 10_000),
+        direction: 3,
+        groups: [new anchor.BN(2)],
+      },
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    await transferFromInvestor();
+  });
+
+  it("blocks sending from a group inside an active blackout window", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.addBlackoutWindow(
+      {
+        start: new anchor.BN(now - 100),
+        end: new anchor.BN(now + 10_000),
+        direction: 1,
+        groups: [groupId],
+      },
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const [blackoutCalendarPubkey] =
+      testEnvironment.transferRestrictionsHelper.blackoutCalendarPDA();
+    const blackoutCalendar =
+      await testEnvironment.transferRestrictionsHelper.blackoutCalendarData(
+        blackoutCalendarPubkey
+      );
+    assert.equal(blackoutCalendar.windows.length, 2);
+    assert.equal(blackoutCalendar.windows[1].direction, 1);
+
+    try {
+      await transferFromInvestor();
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isBlackoutActive = error.logs.some((log: string) =>
+        log.includes("Error Code: TransferBlackoutActive")
+      );
+      assert.isTrue(isBlackoutActive);
+    }
+  });
+
+  it("fails to remove blackout window which is not scheduled", async () => {
+    const [blackoutCalendarPubkey] =
+      testEnvironment.transferRestrictionsHelper.blackoutCalendarPDA();
+    const { windows } =
+      await testEnvironment.transferRestrictionsHelper.blackoutCalendarData(
+        blackoutCalendarPubkey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.removeBlackoutWindow(
+        { ...windows[1], direction: 2 },
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "BlackoutWindowNotFound");
+      assert.equal(error.errorMessage, "Blackout window not found");
+    }
+  });
+
+  it("allows transfers again after blackout window is removed", async () => {
+    const [blackoutCalendarPubkey] =
+      testEnvironment.transferRestrictionsHelper.blackoutCalendarPDA();
+    const { windows } =
+      await testEnvironment.transferRestrictionsHelper.blackoutCalendarData(
+        blackoutCalendarPubkey
+      );
+    await testEnvironment.transferRestrictionsHelper.removeBlackoutWindow(
+      windows[1],
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const blackoutCalendar =
+      await testEnvironment.transferRestrictionsHelper.blackoutCalendarData(
+        blackoutCalendarPubkey
+      );
+    assert.equal(blackoutCalendar.windows.length, 1);
+    assert.equal(blackoutCalendar.windows[0].direction, 3);
+
+    await transferFromInvestor();
+  });
+
+  it("allows transfers before a scheduled blackout window starts", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.addBlackoutWindow(
+      {
+        start: new anchor.BN(now + 10_000),
+        end: new anchor.BN(now + 20_000),
+        direction: 3,
+        groups: [],
+      },
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    await transferFromInvestor();
+  });
+});
@@ -0,0 +1,311 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  getExtraAccountMetas,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Count holders by balance", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investorA = Keypair.generate();
+  const investorB = Keypair.generate();
+  const investorC = Keypair.generate();
+  let investorATokenAccountPubkey: PublicKey;
+  let investorBTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const unit = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+  // the transfer restriction data is the first extra account meta of the transfer hook
+  const transferRestrictionDataMetaIsWritable = async () => {
+    const accountInfo = await testEnvironment.connection.getAccountInfo(
+      testEnvironment.transferRestrictionsHelper.extraMetasAccountPDA()[0]
+    );
+    return getExtraAccountMetas(accountInfo)[0].isWritable;
+  };
+
+  const transfer = async (
+    owner: Keypair,
+    sourcePubkey: PublicKey,
+    destinationPubkey: PublicKey,
+    amount: bigint
+  ) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        sourcePubkey,
+        testEnvironment.mintKeypair.publicKey,
+        destinationPubkey,
+        owner.publicKey,
+        amount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [owner],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const holderOf = async (tokenAccountPubkey: PublicKey) => {
+    const [saaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        tokenAccountPubkey
+      );
+    const { holder } =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        saaPubkey
+      );
+    return holder as PublicKey;
+  };
+
+  const fundedCounts = async () => {
+    const { fundedHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    const [groupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+    const group = await testEnvironment.transferRestrictionsHelper.groupData(
+      groupPubkey
+    );
+    return {
+      total: fundedHoldersCount.toNumber(),
+      group: group.fundedHoldersCount.toNumber(),
+    };
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("fails to enable counting holders by balance by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setCountHoldersByBalance(
+        true,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("enables counting holders by balance by transfer admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.setCountHoldersByBalance(
+      true,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { countHoldersByBalance } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.isTrue(countHoldersByBalance);
+  });
+
+  it("write-locks the holder count accounts only while counting holders by balance", async () => {
+    assert.isTrue(await transferRestrictionDataMetaIsWritable());
+
+    await testEnvironment.transferRestrictionsHelper.setCountHoldersByBalance(
+      false,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    assert.isFalse(await transferRestrictionDataMetaIsWritable());
+
+    await testEnvironment.transferRestrictionsHelper.setCountHoldersByBalance(
+      true,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    assert.isTrue(await transferRestrictionDataMetaIsWritable());
+  });
+
+  it("registers more holders than allowed while they have no balance", async () => {
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    investorATokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorA.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    investorBTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorB.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const investorCTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorC.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccount] of [
+      [testEnvironment.reserveAdmin.publicKey, reserveAdminTokenAccountPubkey],
+      [investorA.publicKey, investorATokenAccountPubkey],
+      [investorB.publicKey, investorBTokenAccountPubkey],
+      [investorC.publicKey, investorCTokenAccountPubkey],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+    }
+    const { currentHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.isAbove(
+      currentHoldersCount.toNumber(),
+      testEnvironmentParams.maxHolders
+    );
+    assert.deepEqual(await fundedCounts(), { total: 0, group: 0 });
+
+    await testEnvironment.transferRestrictionsHelper.setHolderGroupMax(
+      new anchor.BN(2),
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("counts minted balance after syncing the wallet", async () => {
+    const [reserveAdminSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((unit * BigInt(10)).toString()),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      reserveAdminSaaPubkey
+    );
+    assert.deepEqual(await fundedCounts(), { total: 0, group: 0 });
+
+    await testEnvironment.transferRestrictionsHelper.syncFundedWallet(
+      reserveAdminTokenAccountPubkey,
+      await holderOf(reserveAdminTokenAccountPubkey),
+      groupId
+    );
+    assert.deepEqual(await fundedCounts(), { total: 1, group: 1 });
+    const { funded } =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        reserveAdminSaaPubkey
+      );
+    assert.isTrue(funded);
+  });
+
+  it("counts receiver when its balance becomes non-zero", async () => {
+    await topUpWallet(
+      testEnvironment.connection,
+      testEnvironment.reserveAdmin.publicKey,
+      solToLamports(1)
+    );
+    await transfer(
+      testEnvironment.reserveAdmin,
+      reserveAdminTokenAccountPubkey,
+      investorATokenAccountPubkey,
+      unit
+    );
+    assert.deepEqual(await fundedCounts(), { total: 2, group: 2 });
+  });
+
+  it("fails to transfer to a new holder over the group limit", async () => {
+    try {
+      await transfer(
+        testEnvironment.reserveAdmin,
+        reserveAdminTokenAccountPubkey,
+        investorBTokenAccountPubkey,
+        unit
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isMaxHoldersReached = error.logs.some((log: string) =>
+        log.includes("Error Code: MaxHoldersReachedInsideTheGroup")
+      );
+      assert.isTrue(isMaxHoldersReached);
+    }
+    assert.deepEqual(await fundedCounts(), { total: 2, group: 2 });
+  });
+
+  it("stops counting sender once its balance is zero", async () => {
+    await topUpWallet(
+      testEnvironment.connection,
+      investorA.publicKey,
+      solToLamports(1)
+    );
+    await transfer(
+      investorA,
+      investorATokenAccountPubkey,
+      investorBTokenAccountPubkey,
+      unit
+    );
+    assert.deepEqual(await fundedCounts(), { total: 2, group: 2 });
+
+    const holderA =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        await holderOf(investorATokenAccountPubkey)
+      );
+    assert.equal(holderA.fundedWalletsCount.toNumber(), 0);
+    const holderB =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        await holderOf(investorBTokenAccountPubkey)
+      );
+    assert.equal(holderB.fundedWalletsCount.toNumber(), 1);
+  });
+});
@@ -0,0 +1,211 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Custodian token accounts", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const custodian = Keypair.generate();
+  const investor = Keypair.generate();
+  let custodianTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const unit = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    custodianTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        custodian.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    assert.notEqual(
+      custodianTokenAccountPubkey.toBase58(),
+      testEnvironment.mintHelper
+        .getAssocciatedTokenAddress(custodian.publicKey)
+        .toBase58()
+    );
+  });
+
+  it("fails to register token account owned by another wallet", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        investor.publicKey,
+        custodianTokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ConstraintTokenOwner");
+    }
+  });
+
+  it("fails to register token account whose owner can be changed", async () => {
+    const mutableOwnerTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        custodian.publicKey,
+        testEnvironment.reserveAdmin,
+        Keypair.generate(),
+        false
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        custodian.publicKey,
+        mutableOwnerTokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "MutableTokenAccountOwner");
+      assert.equal(error.errorMessage, "Token account owner can be changed");
+    }
+  });
+
+  it("registers non-associated token account of the custodian", async () => {
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      custodian.publicKey,
+      custodianTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+    const [custodianSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        custodianTokenAccountPubkey
+      );
+    const custodianSaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        custodianSaaPubkey
+      );
+    assert.equal(custodianSaa.group.toNumber(), groupId.toNumber());
+  });
+
+  it("mints securities to the custodian token account", async () => {
+    const [custodianSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        custodianTokenAccountPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((unit * BigInt(5)).toString()),
+      custodian.publicKey,
+      custodianTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      custodianSaaPubkey
+    );
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      custodianTokenAccountPubkey
+    );
+    assert.equal(amount.toString(), (unit * BigInt(5)).toString());
+  });
+
+  it("transfers from the custodian token account", async () => {
+    await topUpWallet(
+      testEnvironment.connection,
+      custodian.publicKey,
+      solToLamports(1)
+    );
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        custodianTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        investorTokenAccountPubkey,
+        custodian.publicKey,
+        unit,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    await sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [custodian],
+      { commitment: testEnvironment.commitment }
+    );
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      investorTokenAccountPubkey
+    );
+    assert.equal(amount.toString(), unit.toString());
+  });
+});
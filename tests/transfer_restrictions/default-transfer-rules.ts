@@ -0,0 +1,309 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+import { ANY_TRANSFER_GROUP_ID } from "../helpers/transfer-restrictions_helper";
+
+describe("Default transfer rules", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 4,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const reserveGroupId = new anchor.BN(1);
+  const investorGroupId = new anchor.BN(2);
+  const lockedGroupId = new anchor.BN(3);
+  const investor = Keypair.generate();
+  const lockedInvestor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let lockedInvestorTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const unit = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+
+  const transfer = async (
+    owner: Keypair,
+    sourcePubkey: PublicKey,
+    destinationPubkey: PublicKey,
+    amount: bigint
+  ) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        sourcePubkey,
+        testEnvironment.mintKeypair.publicKey,
+        destinationPubkey,
+        owner.publicKey,
+        amount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [owner],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const groupId of [reserveGroupId, investorGroupId, lockedGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    lockedInvestorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        lockedInvestor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccount, groupId] of [
+      [
+        testEnvironment.reserveAdmin.publicKey,
+        reserveAdminTokenAccountPubkey,
+        reserveGroupId,
+      ],
+      [investor.publicKey, investorTokenAccountPubkey, investorGroupId],
+      [
+        lockedInvestor.publicKey,
+        lockedInvestorTokenAccountPubkey,
+        lockedGroupId,
+      ],
+    ] as [PublicKey, PublicKey, anchor.BN][]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+    }
+    const [reserveAdminSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((unit * BigInt(10)).toString()),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      reserveAdminSaaPubkey
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      testEnvironment.reserveAdmin.publicKey,
+      solToLamports(1)
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  it("fails to initialize group with the reserved any group id", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        ANY_TRANSFER_GROUP_ID,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidTransferGroupId");
+      assert.equal(error.errorMessage, "Invalid transfer group id");
+    }
+  });
+
+  it("fails to initialize transfer rule without group account", async () => {
+    const [transferRulePubkey] =
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        reserveGroupId,
+        ANY_TRANSFER_GROUP_ID
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.program.methods
+        .initializeTransferRule(
+          reserveGroupId,
+          ANY_TRANSFER_GROUP_ID,
+          new anchor.BN(1)
+        )
+        .accountsStrict({
+          transferRule: transferRulePubkey,
+          transferRestrictionData:
+            testEnvironment.transferRestrictionsHelper
+              .transferRestrictionDataPubkey,
+          transferRestrictionGroupFrom: null,
+          transferRestrictionGroupTo: null,
+          accessControlAccount:
+            testEnvironment.accessControlHelper.accessControlPubkey,
+          authorityWalletRole: transferAdminWalletRole,
+          authority: testEnvironment.transferAdmin.publicKey,
+          payer: testEnvironment.transferAdmin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([testEnvironment.transferAdmin])
+        .rpc({ commitment: testEnvironment.commitment });
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TransferRestrictionGroupMissing");
+      assert.equal(
+        error.errorMessage,
+        "Transfer restriction group account is missing"
+      );
+    }
+  });
+
+  it("fails to transfer when no transfer rule applies", async () => {
+    try {
+      await transfer(
+        testEnvironment.reserveAdmin,
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        unit
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isTransferRuleMissing = error.logs.some((log: string) =>
+        log.includes("Error Code: TransferRuleAccountDataIsEmtpy")
+      );
+      assert.isTrue(isTransferRuleMissing);
+    }
+  });
+
+  it("transfers to any group with the sender group default rule", async () => {
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      reserveGroupId,
+      ANY_TRANSFER_GROUP_ID,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const [transferRulePubkey] =
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        reserveGroupId,
+        ANY_TRANSFER_GROUP_ID
+      );
+    const transferRule =
+      await testEnvironment.transferRestrictionsHelper.transferRuleData(
+        transferRulePubkey
+      );
+    assert.equal(
+      transferRule.transferGroupIdTo.toString(),
+      ANY_TRANSFER_GROUP_ID.toString()
+    );
+
+    await transfer(
+      testEnvironment.reserveAdmin,
+      reserveAdminTokenAccountPubkey,
+      investorTokenAccountPubkey,
+      unit * BigInt(2)
+    );
+    await transfer(
+      testEnvironment.reserveAdmin,
+      reserveAdminTokenAccountPubkey,
+      lockedInvestorTokenAccountPubkey,
+      unit
+    );
+  });
+
+  it("uses exact transfer rule before the default rule", async () => {
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow + 100_000),
+      reserveGroupId,
+      lockedGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    try {
+      await transfer(
+        testEnvironment.reserveAdmin,
+        reserveAdminTokenAccountPubkey,
+        lockedInvestorTokenAccountPubkey,
+        unit
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isTransferRuleLocked = error.logs.some((log: string) =>
+        log.includes("Error Code: TransferRuleNotAllowedUntilLater")
+      );
+      assert.isTrue(isTransferRuleLocked);
+    }
+  });
+
+  it("transfers between any groups with the security-wide default rule", async () => {
+    const { code } =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        investorTokenAccountPubkey,
+        lockedInvestorTokenAccountPubkey,
+        new anchor.BN(unit.toString())
+      );
+    assert.notEqual(code, 0);
+
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      ANY_TRANSFER_GROUP_ID,
+      ANY_TRANSFER_GROUP_ID,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await transfer(
+      investor,
+      investorTokenAccountPubkey,
+      lockedInvestorTokenAccountPubkey,
+      unit
+    );
+  });
+});
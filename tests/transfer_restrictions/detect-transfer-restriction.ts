@@ -0,0 +1,191 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Detect transfer restriction", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 4,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const lockedGroupId = new anchor.BN(2);
+  const investor = Keypair.generate();
+  const lockedInvestor = Keypair.generate();
+  const unregisteredInvestor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let lockedInvestorTokenAccountPubkey: PublicKey;
+  let unregisteredInvestorTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const amount = new anchor.BN(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+
+  const errorCode = (name: string) =>
+    testEnvironment.transferRestrictionsHelper.program.idl.errors.find(
+      (error) => error.name.toLowerCase() === name.toLowerCase()
+    ).code;
+
+  const pause = async (paused: boolean) =>
+    testEnvironment.transferRestrictionsHelper.program.methods
+      .pause(paused)
+      .accountsStrict({
+        securityMint: testEnvironment.mintKeypair.publicKey,
+        transferRestrictionData:
+          testEnvironment.transferRestrictionsHelper
+            .transferRestrictionDataPubkey,
+        accessControlAccount:
+          testEnvironment.accessControlHelper.accessControlPubkey,
+        authorityWalletRole: transferAdminWalletRole,
+        payer: testEnvironment.transferAdmin.publicKey,
+      })
+      .signers([testEnvironment.transferAdmin])
+      .rpc({ commitment: testEnvironment.commitment });
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const id of [groupId, lockedGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        id,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    lockedInvestorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        lockedInvestor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    unregisteredInvestorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        unregisteredInvestor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccount, id] of [
+      [
+        testEnvironment.reserveAdmin.publicKey,
+        reserveAdminTokenAccountPubkey,
+        groupId,
+      ],
+      [investor.publicKey, investorTokenAccountPubkey, groupId],
+      [lockedInvestor.publicKey, lockedInvestorTokenAccountPubkey, lockedGroupId],
+    ] as [PublicKey, PublicKey, anchor.BN][]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        id
+      );
+    }
+    const [reserveAdminSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      amount.muln(10),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      reserveAdminSaaPubkey
+    );
+
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow + 100_000),
+      groupId,
+      lockedGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("returns success when transfer is allowed", async () => {
+    const result =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        amount
+      );
+    assert.equal(result.code, 0);
+    assert.equal(result.message, "Success");
+  });
+
+  it("returns reason when destination is not registered", async () => {
+    const result =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        unregisteredInvestorTokenAccountPubkey,
+        amount
+      );
+    assert.equal(
+      result.code,
+      errorCode("SecurityAssociatedAccountDataIsEmtpy")
+    );
+    assert.equal(result.message, "Security associated account data is empty");
+  });
+
+  it("returns reason when transfer rule is locked", async () => {
+    const result =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        lockedInvestorTokenAccountPubkey,
+        amount
+      );
+    assert.equal(result.code, errorCode("TransferRuleNotAllowedUntilLater"));
+    assert.equal(result.message, "Transfer rule not allowed until later");
+  });
+
+  it("returns reason when transfers are paused", async () => {
+    await pause(true);
+    const result =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        amount
+      );
+    assert.equal(result.code, errorCode("AllTransfersPaused"));
+    assert.equal(result.message, "All transfers are paused");
+    await pause(false);
+  });
+});
@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { assert } from "chai";
-import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { AccountMeta, Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 
 import {
   TestEnvironment,
@@ -35,6 +35,7 @@ describe("Enforce transfer restrictions", () => {
   let securityAssociatedAccountToPubkey: PublicKey;
   let transferRulePubkey: PublicKey;
   let transferGroupPubkey: PublicKey;
+  let transferHookAccounts: AccountMeta[];
 
   before(async () => {
     testEnvironment = new TestEnvironment(testEnvironmentParams);
@@ -195,12 +196,18 @@ describe("Enforce transfer restrictions", () => {
       );
     [transferGroupPubkey] =
       testEnvironment.transferRestrictionsHelper.groupPDA(firstGroupIdx);
+    transferHookAccounts =
+      await testEnvironment.transferRestrictionsHelper.transferHookAccounts(
+        investorWallet0AssociatedAccount,
+        investorWallet1AssociatedAccount,
+        BigInt(1)
+      );
   });
 
   describe("when transfer rule is unlocked and transfers unpaused", () => {
     it("execute transaction successfully without error", async () => {
       await testEnvironment.transferRestrictionsHelper.program.methods
-        .enforceTransferRestrictions()
+        .enforceTransferRestrictions(new anchor.BN(1))
         .accountsStrict({
           sourceAccount: investorWallet0AssociatedAccount,
           mint: testEnvironment.mintKeypair.publicKey,
@@ -212,6 +219,7 @@ describe("Enforce transfer restrictions", () => {
           securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
           transferRule: transferRulePubkey,
         })
+        .remainingAccounts(transferHookAccounts)
         .signers([])
         .rpc({ commitment: testEnvironment.commitment });
       assert.ok(true);
@@ -235,7 +243,7 @@ describe("Enforce transfer restrictions", () => {
     it("fails transaction with error", async () => {
       try {
         await testEnvironment.transferRestrictionsHelper.program.methods
-          .enforceTransferRestrictions()
+          .enforceTransferRestrictions(new anchor.BN(1))
           .accountsStrict({
             sourceAccount: investorWallet0AssociatedAccount,
             mint: testEnvironment.mintKeypair.publicKey,
@@ -247,6 +255,7 @@ describe("Enforce transfer restrictions", () => {
             securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
             transferRule: transferRulePubkey,
           })
+          .remainingAccounts(transferHookAccounts)
           .signers([])
           .rpc({ commitment: testEnvironment.commitment });
         assert.fail("Expect an error");
@@ -276,7 +285,7 @@ describe("Enforce transfer restrictions", () => {
     it("fails transaction with error", async () => {
       try {
         await testEnvironment.transferRestrictionsHelper.program.methods
-          .enforceTransferRestrictions()
+          .enforceTransferRestrictions(new anchor.BN(1))
           .accountsStrict({
             sourceAccount: investorWallet0AssociatedAccount,
             mint: testEnvironment.mintKeypair.publicKey,
@@ -288,6 +297,7 @@ describe("Enforce transfer restrictions", () => {
             securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
             transferRule: transferRulePubkey,
           })
+          .remainingAccounts(transferHookAccounts)
           .signers([])
           .rpc({ commitment: testEnvironment.commitment });
         assert.fail("Expect an error");
@@ -325,7 +335,7 @@ describe("Enforce transfer restrictions", () => {
     it("fails transaction with error", async () => {
       try {
         await testEnvironment.transferRestrictionsHelper.program.methods
-          .enforceTransferRestrictions()
+          .enforceTransferRestrictions(new anchor.BN(1))
           .accountsStrict({
             sourceAccount: investorWallet0AssociatedAccount,
             mint: testEnvironment.mintKeypair.publicKey,
@@ -337,6 +347,7 @@ describe("Enforce transfer restrictions", () => {
             securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
             transferRule: transferRulePubkey,
           })
+          .remainingAccounts(transferHookAccounts)
           .signers([])
           .rpc({ commitment: testEnvironment.commitment });
         assert.fail("Expect an error");
@@ -0,0 +1,302 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { ExemptAccountKind } from "../helpers/access-control_helper";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Exempt accounts", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const vaultOwner = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let vaultTokenAccountPubkey: PublicKey;
+  let contractAdminWalletRole: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  const transferAmount = BigInt(
+    1_000 * 10 ** testEnvironmentParams.mint.decimals
+  );
+
+  const transfer = async (
+    source: PublicKey,
+    destination: PublicKey,
+    owner: Keypair
+  ) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        source,
+        testEnvironment.mintKeypair.publicKey,
+        destination,
+        owner.publicKey,
+        transferAmount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [owner],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    const [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    [contractAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.contractAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    vaultTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        vaultOwner.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((transferAmount * BigInt(10)).toString()),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      )[0]
+    );
+    for (const wallet of [investor, vaultOwner]) {
+      await topUpWallet(
+        testEnvironment.connection,
+        wallet.publicKey,
+        solToLamports(1)
+      );
+    }
+  });
+
+  it("fails to register exempt account by reserve admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.addExemptAccount(
+        ExemptAccountKind.DistributionVault,
+        vaultTokenAccountPubkey,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to register exempt account of unknown kind", async () => {
+    try {
+      await testEnvironment.accessControlHelper.addExemptAccount(
+        4,
+        vaultTokenAccountPubkey,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidExemptAccountKind");
+    }
+  });
+
+  it("fails to initialize security associated account of not exempt account", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeExemptSecurityAssociatedAccount(
+        vaultTokenAccountPubkey,
+        contractAdminWalletRole,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "NotExemptAccount");
+    }
+  });
+
+  it("registers distribution vault by contract admin", async () => {
+    await testEnvironment.accessControlHelper.addExemptAccount(
+      ExemptAccountKind.DistributionVault,
+      vaultTokenAccountPubkey,
+      testEnvironment.contractAdmin
+    );
+    const exemptAccountRegistry =
+      await testEnvironment.accessControlHelper.exemptAccountRegistryData();
+    assert.equal(exemptAccountRegistry.exemptAccountsCount, 1);
+    assert.equal(
+      exemptAccountRegistry.exemptAccounts[0].tokenAccount.toBase58(),
+      vaultTokenAccountPubkey.toBase58()
+    );
+    assert.equal(
+      exemptAccountRegistry.exemptAccounts[0].kind,
+      ExemptAccountKind.DistributionVault
+    );
+    const accessControlData =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(accessControlData.exemptAccountsCount, 1);
+
+    await testEnvironment.transferRestrictionsHelper.initializeExemptSecurityAssociatedAccount(
+      vaultTokenAccountPubkey,
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+  });
+
+  it("fails to register the same account twice", async () => {
+    try {
+      await testEnvironment.accessControlHelper.addExemptAccount(
+        ExemptAccountKind.Treasury,
+        vaultTokenAccountPubkey,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ExemptAccountAlreadyRegistered");
+    }
+  });
+
+  it("transfers to and from the vault without transfer rules", async () => {
+    await transfer(
+      investorTokenAccountPubkey,
+      vaultTokenAccountPubkey,
+      investor
+    );
+    await transfer(
+      vaultTokenAccountPubkey,
+      investorTokenAccountPubkey,
+      vaultOwner
+    );
+
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      vaultTokenAccountPubkey
+    );
+    assert.equal(amount, BigInt(0));
+  });
+
+  it("fails to transfer to the vault from a held wallet", async () => {
+    await testEnvironment.transferRestrictionsHelper.addWalletHold(
+      investorTokenAccountPubkey,
+      {
+        reasonCode: 1,
+        referenceHash: Array(32).fill(0),
+        direction: 1,
+        expiresAt: new anchor.BN(0),
+      },
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    try {
+      await transfer(
+        investorTokenAccountPubkey,
+        vaultTokenAccountPubkey,
+        investor
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isBlockedByWalletHold = error.logs.some((log: string) =>
+        log.includes("Error Code: TransferBlockedByWalletHold")
+      );
+      assert.isTrue(isBlockedByWalletHold);
+    }
+    await testEnvironment.transferRestrictionsHelper.removeWalletHold(
+      investorTokenAccountPubkey,
+      0,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+  });
+
+  it("fails to freeze the vault", async () => {
+    try {
+      await testEnvironment.accessControlHelper.freezeWallet(
+        vaultOwner.publicKey,
+        vaultTokenAccountPubkey,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "CannotFreezeExemptAccount");
+    }
+  });
+
+  it("restricts transfers to the vault once removed from the registry", async () => {
+    await testEnvironment.accessControlHelper.removeExemptAccount(
+      0,
+      testEnvironment.contractAdmin
+    );
+    const exemptAccountRegistry =
+      await testEnvironment.accessControlHelper.exemptAccountRegistryData();
+    assert.equal(exemptAccountRegistry.exemptAccountsCount, 0);
+    const accessControlData =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(accessControlData.exemptAccountsCount, 0);
+
+    const restriction =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        investorTokenAccountPubkey,
+        vaultTokenAccountPubkey,
+        new anchor.BN(transferAmount.toString())
+      );
+    assert.notEqual(restriction.code, 0);
+  });
+});
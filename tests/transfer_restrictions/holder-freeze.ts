@@ -0,0 +1,294 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Holder freeze", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let investorSecondTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let holderPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const transferAmount = BigInt(
+    1_000 * 10 ** testEnvironmentParams.mint.decimals
+  );
+
+  const transfer = async (
+    source: PublicKey,
+    destination: PublicKey,
+    owner: Keypair
+  ) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        source,
+        testEnvironment.mintKeypair.publicKey,
+        destination,
+        owner.publicKey,
+        transferAmount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [owner],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const assertHolderFrozen = async (transferPromise: Promise<string>) => {
+    try {
+      await transferPromise;
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isHolderFrozen = error.logs.some((log: string) =>
+        log.includes("Error Code: HolderFrozen")
+      );
+      assert.isTrue(isHolderFrozen);
+    }
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((transferAmount * BigInt(10)).toString()),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      )[0]
+    );
+
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    investorSecondTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    await testEnvironment.transferRestrictionsHelper.onboardInvestors(
+      [investorTokenAccountPubkey, investorSecondTokenAccountPubkey].map(
+        (associatedTokenAccount) => ({
+          userWallet: investor.publicKey,
+          associatedTokenAccount,
+          holderId: holderIds,
+          groupId,
+        })
+      ),
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    [holderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderIds);
+    await testEnvironment.accessControlHelper.forceTransferBetween(
+      transferAmount * BigInt(5),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.connection
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  it("fails to freeze holder by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHolderFrozen(
+        true,
+        holderPubkey,
+        [],
+        testEnvironment.accessControlProgram.programId,
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("freezes all wallets of the holder in the transfer hook", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderFrozen(
+      true,
+      holderPubkey,
+      [],
+      testEnvironment.accessControlProgram.programId,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const holder = await testEnvironment.transferRestrictionsHelper.holderData(
+      holderPubkey
+    );
+    assert.isTrue(holder.frozen);
+
+    await assertHolderFrozen(
+      transfer(
+        investorTokenAccountPubkey,
+        reserveAdminTokenAccountPubkey,
+        investor
+      )
+    );
+    // receiving wallet of the holder is rejected without freezing its token account
+    await assertHolderFrozen(
+      transfer(
+        reserveAdminTokenAccountPubkey,
+        investorSecondTokenAccountPubkey,
+        testEnvironment.reserveAdmin
+      )
+    );
+  });
+
+  it("freezes supplied token accounts of the frozen holder", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderFrozen(
+      true,
+      holderPubkey,
+      [investorTokenAccountPubkey, investorSecondTokenAccountPubkey].map(
+        (tokenAccount) => ({ tokenAccount, owner: investor.publicKey })
+      ),
+      testEnvironment.accessControlProgram.programId,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    for (const tokenAccountPubkey of [
+      investorTokenAccountPubkey,
+      investorSecondTokenAccountPubkey,
+    ]) {
+      const { isFrozen } = await testEnvironment.mintHelper.getAccount(
+        tokenAccountPubkey
+      );
+      assert.isTrue(isFrozen);
+    }
+  });
+
+  it("fails to freeze wallet of another holder", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHolderFrozen(
+        true,
+        holderPubkey,
+        [
+          {
+            tokenAccount: reserveAdminTokenAccountPubkey,
+            owner: testEnvironment.reserveAdmin.publicKey,
+          },
+        ],
+        testEnvironment.accessControlProgram.programId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidRemainingAccounts");
+    }
+  });
+
+  it("unfreezes holder and thaws its token accounts", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderFrozen(
+      false,
+      holderPubkey,
+      [investorTokenAccountPubkey, investorSecondTokenAccountPubkey].map(
+        (tokenAccount) => ({ tokenAccount, owner: investor.publicKey })
+      ),
+      testEnvironment.accessControlProgram.programId,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const holder = await testEnvironment.transferRestrictionsHelper.holderData(
+      holderPubkey
+    );
+    assert.isFalse(holder.frozen);
+
+    await transfer(
+      investorTokenAccountPubkey,
+      investorSecondTokenAccountPubkey,
+      investor
+    );
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      investorSecondTokenAccountPubkey
+    );
+    assert.equal(amount.toString(), transferAmount.toString());
+  });
+});
@@ -0,0 +1,380 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Holding period", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 4,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const holdingPeriod = new anchor.BN(10_000);
+  const investorA = Keypair.generate();
+  const investorB = Keypair.generate();
+  let investorATokenAccountPubkey: PublicKey;
+  let investorBTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const unit = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+
+  const transfer = async (
+    owner: Keypair,
+    sourcePubkey: PublicKey,
+    destinationPubkey: PublicKey,
+    amount: bigint
+  ) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        sourcePubkey,
+        testEnvironment.mintKeypair.publicKey,
+        destinationPubkey,
+        owner.publicKey,
+        amount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [owner],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    investorATokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorA.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    investorBTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorB.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccount] of [
+      [testEnvironment.reserveAdmin.publicKey, reserveAdminTokenAccountPubkey],
+      [investorA.publicKey, investorATokenAccountPubkey],
+      [investorB.publicKey, investorBTokenAccountPubkey],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+    }
+    const [reserveAdminSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((unit * BigInt(10)).toString()),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      reserveAdminSaaPubkey
+    );
+    await testEnvironment.accessControlHelper.forceTransferBetween(
+      unit * BigInt(5),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      investorA.publicKey,
+      investorATokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.connection
+    );
+    await testEnvironment.accessControlHelper.forceTransferBetween(
+      unit * BigInt(2),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      investorB.publicKey,
+      investorBTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.connection
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investorA.publicKey,
+      solToLamports(1)
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investorB.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  it("fails to set holding period by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHoldingPeriod(
+        holdingPeriod,
+        groupId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("sets holding period by transfer admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHoldingPeriod(
+      holdingPeriod,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const [groupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+    const group = await testEnvironment.transferRestrictionsHelper.groupData(
+      groupPubkey
+    );
+    assert.equal(group.holdingPeriod.toString(), holdingPeriod.toString());
+  });
+
+  it("fails to set the same holding period", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHoldingPeriod(
+        holdingPeriod,
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("records acquisition lot for the receiver", async () => {
+    await transfer(
+      investorA,
+      investorATokenAccountPubkey,
+      investorBTokenAccountPubkey,
+      unit
+    );
+    const [investorBSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorBTokenAccountPubkey
+      );
+    const investorBSaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        investorBSaaPubkey
+      );
+    assert.equal(investorBSaa.acquisitionLotsCount, 1);
+    assert.equal(
+      investorBSaa.acquisitionLots[0].amount.toString(),
+      unit.toString()
+    );
+  });
+
+  it("allows sending tokens which are not inside the holding period", async () => {
+    await transfer(
+      investorB,
+      investorBTokenAccountPubkey,
+      investorATokenAccountPubkey,
+      unit * BigInt(2)
+    );
+  });
+
+  it("fails to send tokens inside the holding period", async () => {
+    try {
+      await transfer(
+        investorB,
+        investorBTokenAccountPubkey,
+        investorATokenAccountPubkey,
+        unit
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isHoldingPeriodNotElapsed = error.logs.some((log: string) =>
+        log.includes("Error Code: HoldingPeriodNotElapsed")
+      );
+      assert.isTrue(isHoldingPeriodNotElapsed);
+    }
+  });
+
+  it("records minted tokens as an acquisition lot", async () => {
+    const [investorASaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorATokenAccountPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(unit.toString()),
+      investorA.publicKey,
+      investorATokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      investorASaaPubkey
+    );
+    const investorASaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        investorASaaPubkey
+      );
+    assert.equal(investorASaa.acquisitionLotsCount, 2);
+    assert.equal(
+      investorASaa.acquisitionLots[1].amount.toString(),
+      unit.toString()
+    );
+  });
+
+  it("fails to send minted tokens inside the holding period", async () => {
+    try {
+      await transfer(
+        investorA,
+        investorATokenAccountPubkey,
+        investorBTokenAccountPubkey,
+        unit * BigInt(5)
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isHoldingPeriodNotElapsed = error.logs.some((log: string) =>
+        log.includes("Error Code: HoldingPeriodNotElapsed")
+      );
+      assert.isTrue(isHoldingPeriodNotElapsed);
+    }
+  });
+
+  it("adds tokens to the latest lot without restarting it when all lots are used", async () => {
+    const [investorASaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorATokenAccountPubkey
+      );
+    let investorASaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        investorASaaPubkey
+      );
+    const maxAcquisitionLots = investorASaa.acquisitionLots.length;
+    for (
+      let lotsCount = investorASaa.acquisitionLotsCount;
+      lotsCount < maxAcquisitionLots;
+      lotsCount++
+    ) {
+      await transfer(
+        testEnvironment.reserveAdmin,
+        reserveAdminTokenAccountPubkey,
+        investorATokenAccountPubkey,
+        BigInt(1)
+      );
+    }
+    investorASaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        investorASaaPubkey
+      );
+    assert.equal(investorASaa.acquisitionLotsCount, maxAcquisitionLots);
+    const latestLot = investorASaa.acquisitionLots[maxAcquisitionLots - 1];
+
+    await transfer(
+      testEnvironment.reserveAdmin,
+      reserveAdminTokenAccountPubkey,
+      investorATokenAccountPubkey,
+      BigInt(1)
+    );
+    investorASaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        investorASaaPubkey
+      );
+    assert.equal(investorASaa.acquisitionLotsCount, maxAcquisitionLots);
+    const mergedLot = investorASaa.acquisitionLots[maxAcquisitionLots - 1];
+    assert.equal(
+      mergedLot.acquiredAt.toString(),
+      latestLot.acquiredAt.toString()
+    );
+    assert.equal(
+      mergedLot.amount.toString(),
+      latestLot.amount.addn(1).toString()
+    );
+  });
+
+  it("allows sending all tokens once holding period is removed", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHoldingPeriod(
+      new anchor.BN(0),
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await transfer(
+      investorB,
+      investorBTokenAccountPubkey,
+      investorATokenAccountPubkey,
+      unit
+    );
+    const [investorBSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorBTokenAccountPubkey
+      );
+    const investorBSaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        investorBSaaPubkey
+      );
+    assert.equal(investorBSaa.acquisitionLotsCount, 0);
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      investorBTokenAccountPubkey
+    );
+    assert.equal(amount.toString(), "0");
+  });
+});
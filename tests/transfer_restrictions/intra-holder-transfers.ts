@@ -0,0 +1,269 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Intra-holder transfers", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const firstGroupId = new anchor.BN(1);
+  const secondGroupId = new anchor.BN(2);
+  const investor = Keypair.generate();
+  const otherInvestor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let investorSecondTokenAccountPubkey: PublicKey;
+  let otherInvestorTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const transferAmount = BigInt(
+    1_000 * 10 ** testEnvironmentParams.mint.decimals
+  );
+
+  const transfer = async (destination: PublicKey) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        investorTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        destination,
+        investor.publicKey,
+        transferAmount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [investor],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const detectRestrictionCode = async (destination: PublicKey) => {
+    const { code } =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        investorTokenAccountPubkey,
+        destination,
+        new anchor.BN(transferAmount.toString())
+      );
+    return code;
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const groupId of [firstGroupId, secondGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    investorSecondTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    otherInvestorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        otherInvestor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    // investor holds a wallet in each group, no transfer rule connects the groups
+    await testEnvironment.transferRestrictionsHelper.onboardInvestors(
+      [
+        {
+          userWallet: investor.publicKey,
+          associatedTokenAccount: investorTokenAccountPubkey,
+          holderId: holderIds,
+          groupId: firstGroupId,
+        },
+        {
+          userWallet: investor.publicKey,
+          associatedTokenAccount: investorSecondTokenAccountPubkey,
+          holderId: holderIds,
+          groupId: secondGroupId,
+        },
+        {
+          userWallet: otherInvestor.publicKey,
+          associatedTokenAccount: otherInvestorTokenAccountPubkey,
+          holderId: holderIds.addn(1),
+          groupId: secondGroupId,
+        },
+      ],
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((transferAmount * BigInt(10)).toString()),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      )[0]
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  it("restricts transfers between own wallets without a transfer rule", async () => {
+    assert.notEqual(
+      await detectRestrictionCode(investorSecondTokenAccountPubkey),
+      0
+    );
+  });
+
+  it("fails to allow intra-holder transfers by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setAllowIntraHolderTransfers(
+        true,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("allows intra-holder transfers by transfer admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.setAllowIntraHolderTransfers(
+      true,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { allowIntraHolderTransfers } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.isTrue(allowIntraHolderTransfers);
+  });
+
+  it("fails to set the same value", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setAllowIntraHolderTransfers(
+        true,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+    }
+  });
+
+  it("transfers between own wallets in different groups", async () => {
+    assert.equal(
+      await detectRestrictionCode(investorSecondTokenAccountPubkey),
+      0
+    );
+    await transfer(investorSecondTokenAccountPubkey);
+
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      investorSecondTokenAccountPubkey
+    );
+    assert.equal(amount, transferAmount);
+  });
+
+  it("restricts transfers to another holder in the group", async () => {
+    assert.notEqual(
+      await detectRestrictionCode(otherInvestorTokenAccountPubkey),
+      0
+    );
+  });
+
+  it("fails to transfer between own wallets while the group is paused", async () => {
+    await testEnvironment.transferRestrictionsHelper.setGroupPaused(
+      2,
+      secondGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    try {
+      await transfer(investorSecondTokenAccountPubkey);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isGroupPaused = error.logs.some((log: string) =>
+        log.includes("Error Code: GroupTransfersPaused")
+      );
+      assert.isTrue(isGroupPaused);
+    }
+
+    await testEnvironment.transferRestrictionsHelper.setGroupPaused(
+      0,
+      secondGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("fails to transfer between own wallets with a wallet hold", async () => {
+    await testEnvironment.transferRestrictionsHelper.addWalletHold(
+      investorTokenAccountPubkey,
+      {
+        reasonCode: 7,
+        referenceHash: Array.from(Buffer.alloc(32, 1)),
+        direction: 1,
+        expiresAt: new anchor.BN(0),
+      },
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    try {
+      await transfer(investorSecondTokenAccountPubkey);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isBlockedByHold = error.logs.some((log: string) =>
+        log.includes("Error Code: TransferBlockedByWalletHold")
+      );
+      assert.isTrue(isBlockedByHold);
+    }
+  });
+});
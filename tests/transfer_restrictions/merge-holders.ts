@@ -0,0 +1,240 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+
+describe("Merge holders", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 4,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const firstGroupId = new anchor.BN(1);
+  const secondGroupId = new anchor.BN(2);
+  const investorA = Keypair.generate();
+  const investorB = Keypair.generate();
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  let holderAPubkey: PublicKey;
+  let holderBPubkey: PublicKey;
+  let investorASaaPubkey: PublicKey;
+  let investorBSaaPubkeys: PublicKey[];
+
+  const groupHoldersCount = async (groupId: anchor.BN) => {
+    const group = await testEnvironment.transferRestrictionsHelper.groupData(
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId)[0]
+    );
+    return group.currentHoldersCount.toNumber();
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const groupId of [firstGroupId, secondGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+
+    const investorATokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorA.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const investorBTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorB.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const investorBSecondTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        investorB.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    const holderBId = holderIds.addn(1);
+    // same person registered twice, once with a wallet in each group
+    await testEnvironment.transferRestrictionsHelper.onboardInvestors(
+      [
+        {
+          userWallet: investorA.publicKey,
+          associatedTokenAccount: investorATokenAccountPubkey,
+          holderId: holderIds,
+          groupId: firstGroupId,
+        },
+        {
+          userWallet: investorB.publicKey,
+          associatedTokenAccount: investorBTokenAccountPubkey,
+          holderId: holderBId,
+          groupId: firstGroupId,
+        },
+        {
+          userWallet: investorB.publicKey,
+          associatedTokenAccount: investorBSecondTokenAccountPubkey,
+          holderId: holderBId,
+          groupId: secondGroupId,
+        },
+      ],
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    [holderAPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderIds);
+    [holderBPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderBId);
+    [investorASaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorATokenAccountPubkey
+      );
+    investorBSaaPubkeys = [
+      investorBTokenAccountPubkey,
+      investorBSecondTokenAccountPubkey,
+    ].map(
+      (tokenAccountPubkey) =>
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          tokenAccountPubkey
+        )[0]
+    );
+  });
+
+  it("fails to merge holders by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.mergeHolders(
+        holderBPubkey,
+        holderAPubkey,
+        [firstGroupId, secondGroupId],
+        investorBSaaPubkeys,
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to merge holder into itself", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.mergeHolders(
+        holderBPubkey,
+        holderBPubkey,
+        [firstGroupId, secondGroupId],
+        investorBSaaPubkeys,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "CannotMergeHolderIntoItself");
+      assert.equal(error.errorMessage, "Cannot merge a holder into itself");
+    }
+  });
+
+  it("fails to merge wallet of another holder", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.mergeHolders(
+        holderBPubkey,
+        holderAPubkey,
+        [firstGroupId, secondGroupId],
+        [...investorBSaaPubkeys, investorASaaPubkey],
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidRemainingAccounts");
+    }
+  });
+
+  it("merges duplicate holder into the target holder", async () => {
+    const { currentHoldersCount: holdersCountBefore } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(await groupHoldersCount(firstGroupId), 2);
+    assert.equal(await groupHoldersCount(secondGroupId), 1);
+    await testEnvironment.transferRestrictionsHelper.setHolderFrozen(
+      true,
+      holderBPubkey,
+      [],
+      testEnvironment.accessControlProgram.programId,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    await testEnvironment.transferRestrictionsHelper.mergeHolders(
+      holderBPubkey,
+      holderAPubkey,
+      [firstGroupId, secondGroupId],
+      investorBSaaPubkeys,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    const { currentHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(
+      currentHoldersCount.toNumber(),
+      holdersCountBefore.toNumber() - 1
+    );
+    assert.equal(await groupHoldersCount(firstGroupId), 1);
+    assert.equal(await groupHoldersCount(secondGroupId), 1);
+
+    for (const saaPubkey of investorBSaaPubkeys) {
+      const saa =
+        await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+          saaPubkey
+        );
+      assert.equal(saa.holder.toString(), holderAPubkey.toString());
+    }
+    const holderA = await testEnvironment.transferRestrictionsHelper.holderData(
+      holderAPubkey
+    );
+    assert.equal(holderA.currentWalletsCount.toNumber(), 3);
+    assert.equal(holderA.currentHolderGroupCount.toNumber(), 2);
+    assert.isTrue(holderA.frozen);
+
+    for (const pubkey of [
+      holderBPubkey,
+      testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+        holderBPubkey,
+        firstGroupId
+      )[0],
+      testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+        holderBPubkey,
+        secondGroupId
+      )[0],
+    ]) {
+      const accountInfo = await testEnvironment.connection.getAccountInfo(
+        pubkey
+      );
+      assert.isNull(accountInfo);
+    }
+  });
+});
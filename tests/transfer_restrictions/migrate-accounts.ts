@@ -0,0 +1,225 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Migrate accounts", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let contractAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  let accountPubkeys: PublicKey[];
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    const [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    [contractAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.contractAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+
+    const [securityAssociatedAccountPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      );
+    const { holder } =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        securityAssociatedAccountPubkey
+      );
+    accountPubkeys = [
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId)[0],
+      holder,
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        groupId,
+        groupId
+      )[0],
+      securityAssociatedAccountPubkey,
+      investorTokenAccountPubkey,
+      testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+        holder,
+        groupId
+      )[0],
+    ];
+  });
+
+  it("initializes accounts with the current layout version", async () => {
+    const { version: accessControlVersion } =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(accessControlVersion, 1);
+    const { version: transferRestrictionDataVersion } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(transferRestrictionDataVersion, 3);
+    const [
+      groupPubkey,
+      holderPubkey,
+      transferRulePubkey,
+      saaPubkey,
+      ,
+      holderGroupPubkey,
+    ] = accountPubkeys;
+    const group =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(group.version, 1);
+    const holder =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        holderPubkey
+      );
+    assert.equal(holder.version, 1);
+    const transferRule =
+      await testEnvironment.transferRestrictionsHelper.transferRuleData(
+        transferRulePubkey
+      );
+    assert.equal(transferRule.version, 1);
+    const securityAssociatedAccount =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        saaPubkey
+      );
+    assert.equal(securityAssociatedAccount.version, 1);
+    const holderGroup =
+      await testEnvironment.transferRestrictionsHelper.holderGroupData(
+        holderGroupPubkey
+      );
+    assert.equal(holderGroup.version, 1);
+  });
+
+  it("fails to migrate accounts by transfer admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateAccounts(
+        accountPubkeys,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("fails to migrate access control by transfer admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.migrateAccessControl(
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("fails to migrate account of another program", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateAccounts(
+        [testEnvironment.accessControlHelper.accessControlPubkey],
+        contractAdminWalletRole,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidMigrationAccount");
+    }
+  });
+
+  it("fails to migrate security associated account without its token account", async () => {
+    const [, , , saaPubkey, , holderGroupPubkey] = accountPubkeys;
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateAccounts(
+        [saaPubkey, holderGroupPubkey],
+        contractAdminWalletRole,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidMigrationAccount");
+    }
+  });
+
+  it("fails to migrate holder group without its holder", async () => {
+    const [, , , , , holderGroupPubkey] = accountPubkeys;
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateAccounts(
+        [holderGroupPubkey],
+        contractAdminWalletRole,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidMigrationAccount");
+    }
+  });
+
+  it("migrates current accounts without changes by contract admin", async () => {
+    const accountInfosBefore =
+      await testEnvironment.connection.getMultipleAccountsInfo(accountPubkeys);
+
+    await testEnvironment.accessControlHelper.migrateAccessControl(
+      testEnvironment.contractAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.migrateAccounts(
+      accountPubkeys,
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+
+    const accountInfosAfter =
+      await testEnvironment.connection.getMultipleAccountsInfo(accountPubkeys);
+    accountInfosAfter.forEach((accountInfo, index) => {
+      assert.deepEqual(accountInfo.data, accountInfosBefore[index].data);
+      assert.equal(accountInfo.lamports, accountInfosBefore[index].lamports);
+    });
+    const { version } =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(version, 1);
+  });
+});
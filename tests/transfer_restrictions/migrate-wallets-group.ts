@@ -0,0 +1,208 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+
+describe("Migrate wallets group", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 4,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const regDGroupId = new anchor.BN(1);
+  const tradableGroupId = new anchor.BN(2);
+  const investorA = Keypair.generate();
+  const investorB = Keypair.generate();
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  let holderAPubkey: PublicKey;
+  let securityAssociatedAccountPubkeys: PublicKey[];
+
+  const groupHoldersCount = async (groupId: anchor.BN) => {
+    const group = await testEnvironment.transferRestrictionsHelper.groupData(
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId)[0]
+    );
+    return group.currentHoldersCount.toNumber();
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const groupId of [regDGroupId, tradableGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+
+    const investorATokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorA.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const investorASecondTokenAccountPubkey =
+      await testEnvironment.mintHelper.createTokenAccount(
+        investorA.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const investorBTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorB.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    await testEnvironment.transferRestrictionsHelper.onboardInvestors(
+      [
+        {
+          userWallet: investorA.publicKey,
+          associatedTokenAccount: investorATokenAccountPubkey,
+          holderId: holderIds,
+          groupId: regDGroupId,
+        },
+        {
+          userWallet: investorA.publicKey,
+          associatedTokenAccount: investorASecondTokenAccountPubkey,
+          holderId: holderIds,
+          groupId: regDGroupId,
+        },
+        {
+          userWallet: investorB.publicKey,
+          associatedTokenAccount: investorBTokenAccountPubkey,
+          holderId: holderIds.addn(1),
+          groupId: regDGroupId,
+        },
+      ],
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    [holderAPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderIds);
+    securityAssociatedAccountPubkeys = [
+      investorATokenAccountPubkey,
+      investorASecondTokenAccountPubkey,
+      investorBTokenAccountPubkey,
+    ].map(
+      (tokenAccountPubkey) =>
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          tokenAccountPubkey
+        )[0]
+    );
+  });
+
+  it("fails to migrate wallets by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateWalletsGroup(
+        regDGroupId,
+        tradableGroupId,
+        securityAssociatedAccountPubkeys,
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to migrate wallets into the same group", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateWalletsGroup(
+        regDGroupId,
+        regDGroupId,
+        securityAssociatedAccountPubkeys,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "NewGroupIsTheSameAsTheCurrentGroup");
+    }
+  });
+
+  it("fails to migrate more holders than the new group allows", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderGroupMax(
+      new anchor.BN(1),
+      tradableGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateWalletsGroup(
+        regDGroupId,
+        tradableGroupId,
+        securityAssociatedAccountPubkeys,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "MaxHoldersReachedInsideTheGroup");
+    }
+  });
+
+  it("migrates all wallets of the cohort in one instruction", async () => {
+    // two wallets of investor A count as a single holder of the new group
+    await testEnvironment.transferRestrictionsHelper.setHolderGroupMax(
+      new anchor.BN(2),
+      tradableGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.migrateWalletsGroup(
+      regDGroupId,
+      tradableGroupId,
+      securityAssociatedAccountPubkeys,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    assert.equal(await groupHoldersCount(regDGroupId), 0);
+    assert.equal(await groupHoldersCount(tradableGroupId), 2);
+    for (const saaPubkey of securityAssociatedAccountPubkeys) {
+      const saa =
+        await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+          saaPubkey
+        );
+      assert.equal(saa.group.toNumber(), tradableGroupId.toNumber());
+    }
+    const holderGroup =
+      await testEnvironment.transferRestrictionsHelper.holderGroupData(
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderAPubkey,
+          tradableGroupId
+        )[0]
+      );
+    assert.equal(holderGroup.currentWalletsCount.toNumber(), 2);
+    const holderA = await testEnvironment.transferRestrictionsHelper.holderData(
+      holderAPubkey
+    );
+    assert.equal(holderA.currentHolderGroupCount.toNumber(), 2);
+  });
+});
@@ -0,0 +1,209 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Minimum holding", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const recipient = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let recipientTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const lotAmount = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+  const minHolding = lotAmount * BigInt(2);
+  const mintedAmount = lotAmount * BigInt(10);
+
+  const transfer = async (amount: bigint) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        investorTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        recipientTokenAccountPubkey,
+        investor.publicKey,
+        amount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [investor],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const assertTransferFails = async (amount: bigint, errorCode: string) => {
+    try {
+      await transfer(amount);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isBelowMinHolding = error.logs.some((log: string) =>
+        log.includes(`Error Code: ${errorCode}`)
+      );
+      assert.isTrue(isBelowMinHolding);
+    }
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    recipientTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        recipient.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccountPubkey] of [
+      [investor.publicKey, investorTokenAccountPubkey],
+      [recipient.publicKey, recipientTokenAccountPubkey],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+    }
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount.toString()),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      )[0]
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  it("fails to set minimum holding by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setMinHolding(
+        new anchor.BN(minHolding.toString()),
+        groupId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("sets minimum holding by transfer admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.setMinHolding(
+      new anchor.BN(minHolding.toString()),
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const group = await testEnvironment.transferRestrictionsHelper.groupData(
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId)[0]
+    );
+    assert.equal(group.minHolding.toString(), minHolding.toString());
+  });
+
+  it("fails to transfer less than the minimum holding to an empty wallet", async () => {
+    const restriction =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        investorTokenAccountPubkey,
+        recipientTokenAccountPubkey,
+        new anchor.BN(lotAmount.toString())
+      );
+    assert.equal(
+      restriction.message,
+      "Receiver balance after the transfer is below the minimum holding"
+    );
+
+    await assertTransferFails(lotAmount, "ReceiverBalanceBelowMinHolding");
+  });
+
+  it("transfers the minimum holding", async () => {
+    await transfer(minHolding);
+
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      recipientTokenAccountPubkey
+    );
+    assert.equal(amount, minHolding);
+  });
+
+  it("fails to leave the sender with less than the minimum holding", async () => {
+    await assertTransferFails(
+      mintedAmount - minHolding - lotAmount,
+      "SenderBalanceBelowMinHolding"
+    );
+  });
+
+  it("transfers the whole balance of the sender", async () => {
+    await transfer(mintedAmount - minHolding);
+
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      investorTokenAccountPubkey
+    );
+    assert.equal(amount, BigInt(0));
+  });
+});
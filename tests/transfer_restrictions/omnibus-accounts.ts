@@ -0,0 +1,466 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Omnibus accounts", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const brokerGroupId = new anchor.BN(1);
+  const retailGroupId = new anchor.BN(2);
+  const broker = Keypair.generate();
+  let brokerTokenAccountPubkey: PublicKey;
+  let brokerSaaPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  let beneficialOwner1Pubkey: PublicKey;
+  let beneficialOwner2Pubkey: PublicKey;
+  const unit = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+
+  const initializeBeneficialOwner = async () => {
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+      holderIds,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const [holderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderIds);
+    await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+      testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+        holderPubkey,
+        retailGroupId
+      )[0],
+      holderPubkey,
+      testEnvironment.transferRestrictionsHelper.groupPDA(retailGroupId)[0],
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    return holderPubkey;
+  };
+
+  const transferFromBroker = async (amount: bigint) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        brokerTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        reserveAdminTokenAccountPubkey,
+        broker.publicKey,
+        amount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [broker],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const retailGroupHoldersCount = async () => {
+    const group = await testEnvironment.transferRestrictionsHelper.groupData(
+      testEnvironment.transferRestrictionsHelper.groupPDA(retailGroupId)[0]
+    );
+    return group.currentHoldersCount.toNumber();
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const groupId of [brokerGroupId, retailGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      brokerGroupId,
+      brokerGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    brokerTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        broker.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccount] of [
+      [testEnvironment.reserveAdmin.publicKey, reserveAdminTokenAccountPubkey],
+      [broker.publicKey, brokerTokenAccountPubkey],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        brokerGroupId
+      );
+    }
+    [brokerSaaPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        brokerTokenAccountPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((unit * BigInt(10)).toString()),
+      broker.publicKey,
+      brokerTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      brokerSaaPubkey
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      broker.publicKey,
+      solToLamports(1)
+    );
+    await testEnvironment.transferRestrictionsHelper.setHolderGroupMax(
+      new anchor.BN(1),
+      retailGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    beneficialOwner1Pubkey = await initializeBeneficialOwner();
+    beneficialOwner2Pubkey = await initializeBeneficialOwner();
+  });
+
+  it("fails to add beneficial owner to non-omnibus account", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeBeneficialOwnerPosition(
+        retailGroupId,
+        beneficialOwner1Pubkey,
+        brokerTokenAccountPubkey,
+        broker,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "NotOmnibusAccount");
+      assert.equal(
+        error.errorMessage,
+        "Token account is not an omnibus account"
+      );
+    }
+  });
+
+  it("fails to designate omnibus account by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.setOmnibusAccount(
+        true,
+        brokerTokenAccountPubkey,
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("designates omnibus account by wallets admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.setOmnibusAccount(
+      true,
+      brokerTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const brokerSaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        brokerSaaPubkey
+      );
+    assert.isTrue(brokerSaa.omnibus);
+  });
+
+  it("fails to add beneficial owner without approval of wallets admin or transfer admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeBeneficialOwnerPosition(
+        retailGroupId,
+        beneficialOwner1Pubkey,
+        brokerTokenAccountPubkey,
+        broker,
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("counts beneficial owner in its group", async () => {
+    await testEnvironment.transferRestrictionsHelper.initializeBeneficialOwnerPosition(
+      retailGroupId,
+      beneficialOwner1Pubkey,
+      brokerTokenAccountPubkey,
+      broker,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    assert.equal(await retailGroupHoldersCount(), 1);
+    const brokerSaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        brokerSaaPubkey
+      );
+    assert.equal(brokerSaa.beneficialOwnersCount.toNumber(), 1);
+    const holder = await testEnvironment.transferRestrictionsHelper.holderData(
+      beneficialOwner1Pubkey
+    );
+    assert.equal(holder.currentWalletsCount.toNumber(), 1);
+  });
+
+  it("fails to add beneficial owner over the group limit", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeBeneficialOwnerPosition(
+        retailGroupId,
+        beneficialOwner2Pubkey,
+        brokerTokenAccountPubkey,
+        broker,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "MaxHoldersReachedInsideTheGroup");
+    }
+    assert.equal(await retailGroupHoldersCount(), 1);
+  });
+
+  it("fails to allocate position without transfer rule to beneficial owner group", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateBeneficialOwnerPosition(
+        new anchor.BN((unit * BigInt(4)).toString()),
+        beneficialOwner1Pubkey,
+        brokerTokenAccountPubkey,
+        broker
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TransferRuleAccountDataIsEmtpy");
+    }
+  });
+
+  it("fails to allocate more than omnibus balance", async () => {
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      brokerGroupId,
+      retailGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateBeneficialOwnerPosition(
+        new anchor.BN((unit * BigInt(11)).toString()),
+        beneficialOwner1Pubkey,
+        brokerTokenAccountPubkey,
+        broker
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(
+        error.errorCode.code,
+        "OmnibusBalanceBelowBeneficialOwnership"
+      );
+    }
+  });
+
+  it("fails to allocate position while omnibus wallet has a send hold", async () => {
+    await testEnvironment.transferRestrictionsHelper.addWalletHold(
+      brokerTokenAccountPubkey,
+      {
+        reasonCode: 1,
+        referenceHash: Array(32).fill(0),
+        direction: 1,
+        expiresAt: new anchor.BN(0),
+      },
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateBeneficialOwnerPosition(
+        new anchor.BN((unit * BigInt(4)).toString()),
+        beneficialOwner1Pubkey,
+        brokerTokenAccountPubkey,
+        broker
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TransferBlockedByWalletHold");
+    }
+    await testEnvironment.transferRestrictionsHelper.removeWalletHold(
+      brokerTokenAccountPubkey,
+      0,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+  });
+
+  it("allocates position to beneficial owner", async () => {
+    await testEnvironment.transferRestrictionsHelper.updateBeneficialOwnerPosition(
+      new anchor.BN((unit * BigInt(4)).toString()),
+      beneficialOwner1Pubkey,
+      brokerTokenAccountPubkey,
+      broker
+    );
+    const brokerSaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        brokerSaaPubkey
+      );
+    assert.equal(
+      brokerSaa.beneficialOwnedAmount.toString(),
+      (unit * BigInt(4)).toString()
+    );
+  });
+
+  it("fails to transfer tokens allocated to beneficial owners", async () => {
+    try {
+      await transferFromBroker(unit * BigInt(7));
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isBelowBeneficialOwnership = error.logs.some((log: string) =>
+        log.includes("Error Code: OmnibusBalanceBelowBeneficialOwnership")
+      );
+      assert.isTrue(isBelowBeneficialOwnership);
+    }
+  });
+
+  it("transfers tokens which are not allocated", async () => {
+    await transferFromBroker(unit * BigInt(6));
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      brokerTokenAccountPubkey
+    );
+    assert.equal(amount.toString(), (unit * BigInt(4)).toString());
+  });
+
+  it("fails to remove designation or revoke position while sub-ledger is used", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.revokeBeneficialOwnerPosition(
+        beneficialOwner1Pubkey,
+        brokerTokenAccountPubkey,
+        broker
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "BeneficialOwnerPositionNotEmpty");
+    }
+    try {
+      await testEnvironment.transferRestrictionsHelper.setOmnibusAccount(
+        false,
+        brokerTokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "OmnibusAccountHasBeneficialOwners");
+    }
+  });
+
+  it("revokes empty position and releases the group slot", async () => {
+    await testEnvironment.transferRestrictionsHelper.updateBeneficialOwnerPosition(
+      new anchor.BN(0),
+      beneficialOwner1Pubkey,
+      brokerTokenAccountPubkey,
+      broker
+    );
+    await testEnvironment.transferRestrictionsHelper.revokeBeneficialOwnerPosition(
+      beneficialOwner1Pubkey,
+      brokerTokenAccountPubkey,
+      broker
+    );
+    assert.equal(await retailGroupHoldersCount(), 0);
+    const brokerSaa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        brokerSaaPubkey
+      );
+    assert.equal(brokerSaa.beneficialOwnersCount.toNumber(), 0);
+    assert.equal(brokerSaa.beneficialOwnedAmount.toNumber(), 0);
+
+    await testEnvironment.transferRestrictionsHelper.initializeBeneficialOwnerPosition(
+      retailGroupId,
+      beneficialOwner2Pubkey,
+      brokerTokenAccountPubkey,
+      broker,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    assert.equal(await retailGroupHoldersCount(), 1);
+  });
+
+  it("fails to merge holder with beneficial owner positions", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.mergeHolders(
+        beneficialOwner2Pubkey,
+        beneficialOwner1Pubkey,
+        [retailGroupId],
+        [],
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "HolderHasBeneficialOwnerPositions");
+      assert.equal(
+        error.errorMessage,
+        "Holder has beneficial owner positions"
+      );
+    }
+  });
+});
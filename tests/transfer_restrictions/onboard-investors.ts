@@ -0,0 +1,180 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+
+describe("Onboard investors", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investors = [...Array(5)].map(() => Keypair.generate());
+  const tokenAccounts: PublicKey[] = [];
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  const entry = (investorIndex: number, holderId: anchor.BN) => ({
+    userWallet: investors[investorIndex].publicKey,
+    associatedTokenAccount: tokenAccounts[investorIndex],
+    holderId,
+    groupId,
+  });
+
+  const onboardedFlags = async (signature: string) => {
+    const transaction = await testEnvironment.connection.getTransaction(
+      signature,
+      {
+        commitment: testEnvironment.commitment as anchor.web3.Finality,
+        maxSupportedTransactionVersion: 0,
+      }
+    );
+    const returnData = Buffer.from(
+      transaction.meta.returnData.data[0],
+      "base64"
+    );
+    const length = returnData.readUInt32LE(0);
+    return [...returnData.subarray(4, 4 + length)].map((flag) => flag === 1);
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    for (const investor of investors) {
+      tokenAccounts.push(
+        await testEnvironment.mintHelper.createAssociatedTokenAccount(
+          investor.publicKey,
+          testEnvironment.reserveAdmin
+        )
+      );
+    }
+  });
+
+  it("fails to onboard investors by reserve admin", async () => {
+    const signer = testEnvironment.reserveAdmin;
+    const [authorityWalletRolePubkey] =
+      testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    try {
+      await testEnvironment.transferRestrictionsHelper.onboardInvestors(
+        [entry(0, holderIds)],
+        authorityWalletRolePubkey,
+        signer
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("onboards wallets of new and shared holders in one instruction", async () => {
+    const { holderIds, currentHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    const holderId = holderIds;
+    const nextHolderId = holderIds.addn(1);
+    const signature =
+      await testEnvironment.transferRestrictionsHelper.onboardInvestors(
+        [entry(0, holderId), entry(1, holderId), entry(2, nextHolderId)],
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    assert.deepEqual(await onboardedFlags(signature), [true, true, true]);
+
+    const transferRestrictionData =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(
+      transferRestrictionData.currentHoldersCount.toNumber(),
+      currentHoldersCount.toNumber() + 2
+    );
+    assert.equal(
+      transferRestrictionData.holderIds.toNumber(),
+      holderIds.toNumber() + 2
+    );
+    const [holderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+    const holder = await testEnvironment.transferRestrictionsHelper.holderData(
+      holderPubkey
+    );
+    assert.equal(holder.currentWalletsCount.toNumber(), 2);
+    assert.equal(holder.currentHolderGroupCount.toNumber(), 1);
+    const [groupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+    const group = await testEnvironment.transferRestrictionsHelper.groupData(
+      groupPubkey
+    );
+    assert.equal(group.currentHoldersCount.toNumber(), 2);
+    const [securityAssociatedAccountPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        tokenAccounts[1]
+      );
+    const securityAssociatedAccount =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        securityAssociatedAccountPubkey
+      );
+    assert.equal(securityAssociatedAccount.group.toNumber(), groupId.toNumber());
+    assert.equal(
+      securityAssociatedAccount.holder.toString(),
+      holderPubkey.toString()
+    );
+  });
+
+  it("skips already onboarded wallets", async () => {
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    const signature =
+      await testEnvironment.transferRestrictionsHelper.onboardInvestors(
+        [entry(2, holderIds.subn(1)), entry(3, holderIds)],
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    assert.deepEqual(await onboardedFlags(signature), [false, true]);
+  });
+
+  it("fails to onboard more holders than allowed", async () => {
+    const { holderIds, currentHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(
+      currentHoldersCount.toNumber(),
+      testEnvironmentParams.maxHolders
+    );
+    try {
+      await testEnvironment.transferRestrictionsHelper.onboardInvestors(
+        [entry(4, holderIds)],
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "MaxHoldersReached");
+      assert.equal(error.errorMessage, "Max holders reached");
+    }
+  });
+});
@@ -0,0 +1,288 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import { SamplePolicy } from "../../target/types/sample_policy";
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Policy program", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  const policyProgram = anchor.workspace.SamplePolicy as Program<SamplePolicy>;
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const recipient = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let recipientTokenAccountPubkey: PublicKey;
+  let policyConfigPubkey: PublicKey;
+  let contractAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const transferAmount = BigInt(
+    1_000 * 10 ** testEnvironmentParams.mint.decimals
+  );
+
+  const initializePolicyConfig = async (
+    authorityWalletRole: PublicKey,
+    authority: Keypair
+  ) =>
+    policyProgram.methods
+      .initializePolicyConfig(new anchor.BN(transferAmount.toString()))
+      .accountsStrict({
+        policyConfig: policyConfigPubkey,
+        mint: testEnvironment.mintKeypair.publicKey,
+        accessControl: testEnvironment.accessControlHelper.accessControlPubkey,
+        authorityWalletRole,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: testEnvironment.commitment });
+
+  const transfer = async () => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        investorTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        recipientTokenAccountPubkey,
+        investor.publicKey,
+        transferAmount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [investor],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const assertTransferFails = async (errorCode: string) => {
+    try {
+      await transfer();
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isRejected = error.logs.some((log: string) =>
+        log.includes(`Error Code: ${errorCode}`)
+      );
+      assert.isTrue(isRejected);
+    }
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    const [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    [contractAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.contractAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    recipientTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        recipient.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccountPubkey] of [
+      [investor.publicKey, investorTokenAccountPubkey],
+      [recipient.publicKey, recipientTokenAccountPubkey],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+    }
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((transferAmount * BigInt(10)).toString()),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      )[0]
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+    [policyConfigPubkey] =
+      testEnvironment.transferRestrictionsHelper.policyConfigPDA(
+        policyProgram.programId
+      );
+  });
+
+  it("fails to set policy program by transfer admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setPolicyProgram(
+        policyProgram.programId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("fails to set the transfer restrictions program as policy program", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setPolicyProgram(
+        testEnvironment.transferRestrictionsHelper.program.programId,
+        contractAdminWalletRole,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidPolicyProgram");
+    }
+  });
+
+  it("sets policy program by contract admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.setPolicyProgram(
+      policyProgram.programId,
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+
+    const transferRestrictionData =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(
+      transferRestrictionData.policyProgram.toBase58(),
+      policyProgram.programId.toBase58()
+    );
+    assert.equal(
+      await testEnvironment.transferRestrictionsHelper.extraAccountMetasVersion(),
+      7
+    );
+  });
+
+  it("fails to transfer while the policy config is not initialized", async () => {
+    await assertTransferFails("AccountNotInitialized");
+  });
+
+  it("fails to initialize policy config by transfer admin", async () => {
+    try {
+      await initializePolicyConfig(
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("transfers within the policy limit", async () => {
+    await initializePolicyConfig(
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+
+    const restriction =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        investorTokenAccountPubkey,
+        recipientTokenAccountPubkey,
+        new anchor.BN(transferAmount.toString())
+      );
+    assert.equal(
+      restriction.message,
+      "Transfer passes the transfer restrictions and depends on the policy program"
+    );
+
+    await transfer();
+    const { amount } = await testEnvironment.mintHelper.getAccount(
+      recipientTokenAccountPubkey
+    );
+    assert.equal(amount, transferAmount);
+  });
+
+  it("fails to transfer above the policy limit", async () => {
+    await policyProgram.methods
+      .setMaxTransferAmount(
+        new anchor.BN((transferAmount - BigInt(1)).toString())
+      )
+      .accountsStrict({
+        policyConfig: policyConfigPubkey,
+        authority: testEnvironment.contractAdmin.publicKey,
+      })
+      .signers([testEnvironment.contractAdmin])
+      .rpc({ commitment: testEnvironment.commitment });
+
+    await assertTransferFails("TransferAmountExceedsPolicy");
+  });
+
+  it("transfers without the policy once the policy program is removed", async () => {
+    await testEnvironment.transferRestrictionsHelper.setPolicyProgram(
+      SystemProgram.programId,
+      contractAdminWalletRole,
+      testEnvironment.contractAdmin
+    );
+    const transferRestrictionData =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(
+      transferRestrictionData.policyProgram.toBase58(),
+      PublicKey.default.toBase58()
+    );
+
+    await transfer();
+  });
+});
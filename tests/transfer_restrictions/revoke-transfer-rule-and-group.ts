@@ -0,0 +1,140 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Revoke transfer rule and group", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const temporaryGroupId = new anchor.BN(2);
+  const investor = Keypair.generate();
+  const receiver = Keypair.generate();
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const id of [groupId, temporaryGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        id,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      temporaryGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    const investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin,
+      groupId
+    );
+  });
+
+  it("fails to revoke transfer rule by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.revokeTransferRule(
+        groupId,
+        temporaryGroupId,
+        receiver.publicKey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("revokes transfer rule and returns rent to the receiver", async () => {
+    const [transferRulePubkey] =
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        groupId,
+        temporaryGroupId
+      );
+    const { lamports: rent } = await testEnvironment.connection.getAccountInfo(
+      transferRulePubkey
+    );
+    await testEnvironment.transferRestrictionsHelper.revokeTransferRule(
+      groupId,
+      temporaryGroupId,
+      receiver.publicKey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    assert.isNull(
+      await testEnvironment.connection.getAccountInfo(transferRulePubkey)
+    );
+    assert.equal(
+      await testEnvironment.connection.getBalance(receiver.publicKey),
+      rent
+    );
+  });
+
+  it("fails to revoke group which has holders", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.revokeTransferRestrictionGroup(
+        groupId,
+        receiver.publicKey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "GroupHasWallets");
+      assert.equal(error.errorMessage, "Group still has wallets");
+    }
+  });
+
+  it("revokes empty group", async () => {
+    const [groupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(temporaryGroupId);
+    await testEnvironment.transferRestrictionsHelper.revokeTransferRestrictionGroup(
+      temporaryGroupId,
+      receiver.publicKey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    assert.isNull(await testEnvironment.connection.getAccountInfo(groupPubkey));
+  });
+});
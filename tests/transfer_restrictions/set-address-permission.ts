@@ -155,6 +155,7 @@ describe("Set Address Permission", () => {
           systemProgram: SystemProgram.programId,
           transferRestrictionGroupCurrent: null,
           holderGroupCurrent: null,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
         })
         .signers([payer, authority])
         .rpc({ commitment: testEnvironment.commitment });
@@ -328,6 +329,7 @@ describe("Set Address Permission", () => {
           systemProgram: SystemProgram.programId,
           transferRestrictionGroupCurrent: null,
           holderGroupCurrent: null,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
         })
         .signers([signer])
         .rpc({ commitment: testEnvironment.commitment });
@@ -517,6 +519,7 @@ describe("Set Address Permission", () => {
           transferRestrictionHolder: existingHolderPubkey,
           holderGroupNew: existingHolderGroupPubkey,
           holderGroupCurrent: holderGroupCurrentPubkey,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
           securityToken: testEnvironment.mintKeypair.publicKey,
           transferRestrictionData:
             testEnvironment.transferRestrictionsHelper
@@ -646,6 +649,7 @@ describe("Set Address Permission", () => {
           transferRestrictionHolder: existingHolderPubkey,
           holderGroupNew: existingHolderGroupPubkey,
           holderGroupCurrent: holderGroupCurrentPubkey,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
           securityToken: testEnvironment.mintKeypair.publicKey,
           transferRestrictionData:
             testEnvironment.transferRestrictionsHelper
@@ -790,6 +794,7 @@ describe("Set Address Permission", () => {
           transferRestrictionHolder: transferRestrictionHolderPubkey,
           holderGroupNew: holderGroupNewPubkey,
           holderGroupCurrent: existingHolderGroupPubkey,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
           securityToken: testEnvironment.mintKeypair.publicKey,
           transferRestrictionData:
             testEnvironment.transferRestrictionsHelper
@@ -957,6 +962,7 @@ describe("Set Address Permission", () => {
             systemProgram: SystemProgram.programId,
             transferRestrictionGroupCurrent: null,
             holderGroupCurrent: null,
+            exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
           })
           .signers([signer])
           .rpc({ commitment: testEnvironment.commitment });
@@ -1002,6 +1008,7 @@ describe("Set Address Permission", () => {
             transferRestrictionHolder: existingHolderPubkey,
             holderGroupNew: holderGroupCurrentPubkey,
             holderGroupCurrent: holderGroupCurrentPubkey,
+            exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
             securityToken: testEnvironment.mintKeypair.publicKey,
             transferRestrictionData:
               testEnvironment.transferRestrictionsHelper
@@ -1084,6 +1091,7 @@ describe("Set Address Permission", () => {
             transferRestrictionHolder: existingHolderPubkey,
             holderGroupNew: group1HolderGroupPubkey,
             holderGroupCurrent: currentHolderGroupPubkey,
+            exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
             securityToken: testEnvironment.mintKeypair.publicKey,
             transferRestrictionData:
               testEnvironment.transferRestrictionsHelper
@@ -1160,6 +1168,7 @@ describe("Set Address Permission", () => {
           transferRestrictionHolder: existingHolderPubkey,
           holderGroupNew: existingHolderGroupPubkey,
           holderGroupCurrent: holderGroupCurrentPubkey,
+          exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
           securityToken: testEnvironment.mintKeypair.publicKey,
           transferRestrictionData:
             testEnvironment.transferRestrictionsHelper
@@ -1306,6 +1315,7 @@ describe("Set Address Permission", () => {
             transferRestrictionHolder: existingHolderPubkey,
             holderGroupNew: existingHolderGroupPubkey,
             holderGroupCurrent: holderGroupCurrentPubkey,
+            exemptAccountRegistry: testEnvironment.accessControlHelper.exemptAccountRegistryPubkey,
             securityToken: testEnvironment.mintKeypair.publicKey,
             transferRestrictionData:
               testEnvironment.transferRestrictionsHelper
@@ -0,0 +1,329 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Set transfer rule validity", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupIdx = new anchor.BN(1);
+  const investorWallet0 = Keypair.generate();
+  const investorWallet1 = Keypair.generate();
+  let investorWallet0AssociatedAccount: PublicKey;
+  let investorWallet1AssociatedAccount: PublicKey;
+  let transferAdminRole: PublicKey;
+  let transferRulePubkey: PublicKey;
+
+  const enforceTransferRestrictions = async () =>
+    testEnvironment.transferRestrictionsHelper.program.methods
+      .enforceTransferRestrictions(new anchor.BN(1))
+      .accountsStrict({
+        sourceAccount: investorWallet0AssociatedAccount,
+        mint: testEnvironment.mintKeypair.publicKey,
+        destinationAccount: investorWallet1AssociatedAccount,
+        transferRestrictionData:
+          testEnvironment.transferRestrictionsHelper
+            .transferRestrictionDataPubkey,
+        securityAssociatedAccountFrom:
+          testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+            investorWallet0AssociatedAccount
+          )[0],
+        securityAssociatedAccountTo:
+          testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+            investorWallet1AssociatedAccount
+          )[0],
+        transferRule: transferRulePubkey,
+      })
+      .remainingAccounts(
+        await testEnvironment.transferRestrictionsHelper.transferHookAccounts(
+          investorWallet0AssociatedAccount,
+          investorWallet1AssociatedAccount,
+          BigInt(1)
+        )
+      )
+      .rpc({ commitment: testEnvironment.commitment });
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [transferAdminRole] = testEnvironment.accessControlHelper.walletRolePDA(
+      testEnvironment.transferAdmin.publicKey
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupIdx,
+      transferAdminRole,
+      testEnvironment.transferAdmin
+    );
+    investorWallet0AssociatedAccount =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorWallet0.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    investorWallet1AssociatedAccount =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investorWallet1.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      investorWallet0.publicKey,
+      investorWallet0AssociatedAccount,
+      transferAdminRole,
+      testEnvironment.transferAdmin,
+      groupIdx
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+      investorWallet1.publicKey,
+      investorWallet1AssociatedAccount,
+      transferAdminRole,
+      testEnvironment.transferAdmin,
+      groupIdx
+    );
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      groupIdx,
+      groupIdx,
+      transferAdminRole,
+      testEnvironment.transferAdmin
+    );
+    [transferRulePubkey] =
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        groupIdx,
+        groupIdx
+      );
+  });
+
+  it("fails to set transfer rule validity by wallets admin", async () => {
+    const signer = testEnvironment.walletsAdmin;
+    const [authorityWalletRolePubkey] =
+      testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
+    try {
+      await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+        new anchor.BN(0),
+        [],
+        groupIdx,
+        groupIdx,
+        authorityWalletRolePubkey,
+        signer
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to set closed interval which ends before it starts", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+        new anchor.BN(0),
+        [
+          {
+            start: new anchor.BN(now + 100),
+            end: new anchor.BN(now),
+            period: new anchor.BN(0),
+          },
+        ],
+        groupIdx,
+        groupIdx,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidClosedInterval");
+      assert.equal(error.errorMessage, "Invalid closed interval");
+    }
+  });
+
+  it("fails to set recurring closed interval covering the whole period", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+        new anchor.BN(0),
+        [
+          {
+            start: new anchor.BN(now),
+            end: new anchor.BN(now + 86400),
+            period: new anchor.BN(86400),
+          },
+        ],
+        groupIdx,
+        groupIdx,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidClosedInterval");
+      assert.equal(error.errorMessage, "Invalid closed interval");
+    }
+  });
+
+  it("fails to set more closed intervals than allowed", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    const closedIntervals = Array.from({ length: 9 }, (_, i) => ({
+      start: new anchor.BN(now + 1000 * (i + 1)),
+      end: new anchor.BN(now + 1000 * (i + 1) + 10),
+      period: new anchor.BN(0),
+    }));
+    try {
+      await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+        new anchor.BN(0),
+        closedIntervals,
+        groupIdx,
+        groupIdx,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TooManyClosedIntervals");
+      assert.equal(error.errorMessage, "Too many closed intervals");
+    }
+  });
+
+  it("stores validity end and closed intervals by transfer admin", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    const validUntil = new anchor.BN(now + 10_000);
+    await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+      validUntil,
+      [
+        {
+          start: new anchor.BN(now + 1000),
+          end: new anchor.BN(now + 2000),
+          period: new anchor.BN(0),
+        },
+      ],
+      groupIdx,
+      groupIdx,
+      transferAdminRole,
+      testEnvironment.transferAdmin
+    );
+    const transferRule =
+      await testEnvironment.transferRestrictionsHelper.transferRuleData(
+        transferRulePubkey
+      );
+    assert.equal(transferRule.validUntil.toString(), validUntil.toString());
+    assert.equal(transferRule.closedIntervalsCount, 1);
+    assert.equal(
+      transferRule.closedIntervals[0].start.toNumber(),
+      now + 1000
+    );
+    assert.equal(transferRule.closedIntervals[0].end.toNumber(), now + 2000);
+
+    await enforceTransferRestrictions();
+  });
+
+  it("fails to enforce transfer restrictions inside a closed interval", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+      new anchor.BN(0),
+      [
+        {
+          start: new anchor.BN(now - 100),
+          end: new anchor.BN(now + 1000),
+          period: new anchor.BN(0),
+        },
+      ],
+      groupIdx,
+      groupIdx,
+      transferAdminRole,
+      testEnvironment.transferAdmin
+    );
+    try {
+      await enforceTransferRestrictions();
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TransferRuleClosedInterval");
+      assert.equal(
+        error.errorMessage,
+        "Transfer rule is inside a closed interval"
+      );
+    }
+  });
+
+  it("fails to enforce transfer restrictions inside a recurring closed interval", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    const week = 7 * 86400;
+    await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+      new anchor.BN(0),
+      [
+        {
+          start: new anchor.BN(now - 2 * week - 100),
+          end: new anchor.BN(now - 2 * week + 1000),
+          period: new anchor.BN(week),
+        },
+      ],
+      groupIdx,
+      groupIdx,
+      transferAdminRole,
+      testEnvironment.transferAdmin
+    );
+    try {
+      await enforceTransferRestrictions();
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TransferRuleClosedInterval");
+      assert.equal(
+        error.errorMessage,
+        "Transfer rule is inside a closed interval"
+      );
+    }
+  });
+
+  it("fails to enforce transfer restrictions after the rule expired", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+      new anchor.BN(now - 1),
+      [],
+      groupIdx,
+      groupIdx,
+      transferAdminRole,
+      testEnvironment.transferAdmin
+    );
+    try {
+      await enforceTransferRestrictions();
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TransferRuleExpired");
+      assert.equal(error.errorMessage, "Transfer rule has expired");
+    }
+  });
+
+  it("allows transfers again once validity end is removed", async () => {
+    await testEnvironment.transferRestrictionsHelper.setTransferRuleValidity(
+      new anchor.BN(0),
+      [],
+      groupIdx,
+      groupIdx,
+      transferAdminRole,
+      testEnvironment.transferAdmin
+    );
+    const transferRule =
+      await testEnvironment.transferRestrictionsHelper.transferRuleData(
+        transferRulePubkey
+      );
+    assert.equal(transferRule.validUntil.toNumber(), 0);
+    assert.equal(transferRule.closedIntervalsCount, 0);
+
+    await enforceTransferRestrictions();
+  });
+});
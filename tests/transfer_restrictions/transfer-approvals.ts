@@ -0,0 +1,291 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Transfer approvals", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const transferAmount = BigInt(
+    1_000 * 10 ** testEnvironmentParams.mint.decimals
+  );
+
+  const transfer = async (
+    source: PublicKey,
+    destination: PublicKey,
+    owner: Keypair,
+    amount: bigint = transferAmount
+  ) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        source,
+        testEnvironment.mintKeypair.publicKey,
+        destination,
+        owner.publicKey,
+        amount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [owner],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const expectTransferError = async (amount: bigint, errorCode: string) => {
+    try {
+      await transfer(
+        investorTokenAccountPubkey,
+        reserveAdminTokenAccountPubkey,
+        investor,
+        amount
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isExpectedError = error.logs.some((log: string) =>
+        log.includes(`Error Code: ${errorCode}`)
+      );
+      assert.isTrue(isExpectedError);
+    }
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccount] of [
+      [testEnvironment.reserveAdmin.publicKey, reserveAdminTokenAccountPubkey],
+      [investor.publicKey, investorTokenAccountPubkey],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+    }
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((transferAmount * BigInt(10)).toString()),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      )[0]
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  it("fails to require approvals by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setTransferRuleApprovalRequired(
+        true,
+        groupId,
+        groupId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("requires an approval for transfers allowed by the rule", async () => {
+    await testEnvironment.transferRestrictionsHelper.setTransferRuleApprovalRequired(
+      true,
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const transferRule =
+      await testEnvironment.transferRestrictionsHelper.transferRuleData(
+        testEnvironment.transferRestrictionsHelper.transferRulePDA(
+          groupId,
+          groupId
+        )[0]
+      );
+    assert.isTrue(transferRule.approvalRequired);
+
+    await expectTransferError(transferAmount, "TransferApprovalRequired");
+  });
+
+  it("fails to approve transfer by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferApproval(
+        new anchor.BN((transferAmount * BigInt(2)).toString()),
+        new anchor.BN(0),
+        investorTokenAccountPubkey,
+        reserveAdminTokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+
+  it("fails to approve transfer which already expired", async () => {
+    const tsNow = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferApproval(
+        new anchor.BN((transferAmount * BigInt(2)).toString()),
+        new anchor.BN(tsNow - 1),
+        investorTokenAccountPubkey,
+        reserveAdminTokenAccountPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidTransferApproval");
+    }
+  });
+
+  it("spends the approval with approved transfers", async () => {
+    await testEnvironment.transferRestrictionsHelper.initializeTransferApproval(
+      new anchor.BN((transferAmount * BigInt(2)).toString()),
+      new anchor.BN(0),
+      investorTokenAccountPubkey,
+      reserveAdminTokenAccountPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const [transferApprovalPubkey] =
+      testEnvironment.transferRestrictionsHelper.transferApprovalPDA(
+        investorTokenAccountPubkey,
+        reserveAdminTokenAccountPubkey
+      );
+
+    await transfer(
+      investorTokenAccountPubkey,
+      reserveAdminTokenAccountPubkey,
+      investor
+    );
+    const transferApproval =
+      await testEnvironment.transferRestrictionsHelper.transferApprovalData(
+        transferApprovalPubkey
+      );
+    assert.equal(
+      transferApproval.remainingAmount.toString(),
+      transferAmount.toString()
+    );
+  });
+
+  it("fails to transfer more than the approved amount", async () => {
+    await expectTransferError(
+      transferAmount * BigInt(2),
+      "TransferApprovalAmountExceeded"
+    );
+    const restriction =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        investorTokenAccountPubkey,
+        reserveAdminTokenAccountPubkey,
+        new anchor.BN((transferAmount * BigInt(2)).toString())
+      );
+    assert.equal(
+      restriction.message,
+      "Transfer amount exceeds the transfer approval"
+    );
+  });
+
+  it("revokes the approval", async () => {
+    await testEnvironment.transferRestrictionsHelper.revokeTransferApproval(
+      investorTokenAccountPubkey,
+      reserveAdminTokenAccountPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await expectTransferError(transferAmount, "TransferApprovalRequired");
+  });
+
+  it("transfers without approval once the rule does not require it", async () => {
+    await testEnvironment.transferRestrictionsHelper.setTransferRuleApprovalRequired(
+      false,
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await transfer(
+      investorTokenAccountPubkey,
+      reserveAdminTokenAccountPubkey,
+      investor
+    );
+  });
+});
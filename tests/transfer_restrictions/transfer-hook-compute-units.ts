@@ -0,0 +1,234 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { ANY_TRANSFER_GROUP_ID } from "../helpers/transfer-restrictions_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+// compute units limit of a transaction without a compute budget instruction
+const DEFAULT_COMPUTE_UNITS_LIMIT = 200_000;
+// compute units budget of the transfer hook documented in the README
+const TRANSFER_HOOK_COMPUTE_UNITS_BUDGET = 40_000;
+
+describe("Transfer hook compute units", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 5,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const defaultRuleGroupId = new anchor.BN(2);
+  const sender = Keypair.generate();
+  const recipient = Keypair.generate();
+  const defaultRuleSender = Keypair.generate();
+  let senderTokenAccountPubkey: PublicKey;
+  let recipientTokenAccountPubkey: PublicKey;
+  let defaultRuleSenderTokenAccountPubkey: PublicKey;
+  const unit = BigInt(1_000 * 10 ** testEnvironmentParams.mint.decimals);
+
+  const transactionComputeUnits = async (signature: string) => {
+    const transaction = await testEnvironment.connection.getTransaction(
+      signature,
+      {
+        commitment: testEnvironment.commitment as anchor.web3.Finality,
+        maxSupportedTransactionVersion: 0,
+      }
+    );
+    const programId =
+      testEnvironment.transferRestrictionsHelper.program.programId.toBase58();
+    const consumedLog = transaction.meta.logMessages.find((log) =>
+      log.startsWith(`Program ${programId} consumed `)
+    );
+    assert.isDefined(consumedLog, "Transfer hook consumed log is missing");
+    return {
+      transferHook: parseInt(consumedLog.split(" ")[3]),
+      transaction: transaction.meta.computeUnitsConsumed,
+    };
+  };
+
+  const transfer = async (
+    fromTokenAccountPubkey: PublicKey,
+    toTokenAccountPubkey: PublicKey,
+    owner: Keypair
+  ) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        fromTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        toTokenAccountPubkey,
+        owner.publicKey,
+        unit,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [owner],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    const [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    const [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    for (const id of [groupId, defaultRuleGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        id,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      defaultRuleGroupId,
+      ANY_TRANSFER_GROUP_ID,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    senderTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        sender.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    recipientTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        recipient.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    defaultRuleSenderTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        defaultRuleSender.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccountPubkey, walletGroupId] of [
+      [sender.publicKey, senderTokenAccountPubkey, groupId],
+      [recipient.publicKey, recipientTokenAccountPubkey, groupId],
+      [
+        defaultRuleSender.publicKey,
+        defaultRuleSenderTokenAccountPubkey,
+        defaultRuleGroupId,
+      ],
+    ] as [PublicKey, PublicKey, anchor.BN][]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        walletGroupId
+      );
+    }
+    for (const [wallet, tokenAccountPubkey] of [
+      [sender.publicKey, senderTokenAccountPubkey],
+      [defaultRuleSender.publicKey, defaultRuleSenderTokenAccountPubkey],
+    ]) {
+      await testEnvironment.accessControlHelper.mintSecurities(
+        new anchor.BN((unit * BigInt(5)).toString()),
+        wallet,
+        tokenAccountPubkey,
+        testEnvironment.reserveAdmin,
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          tokenAccountPubkey
+        )[0]
+      );
+      await topUpWallet(testEnvironment.connection, wallet, solToLamports(1));
+    }
+  });
+
+  it("stores canonical bumps of the accounts verified by the transfer hook", async () => {
+    const helper = testEnvironment.transferRestrictionsHelper;
+    const [, transferRestrictionDataBump] = helper.transferRestrictionDataPDA();
+    const transferRestrictionData = await helper.transferRestrictionData();
+    assert.equal(transferRestrictionData.bump, transferRestrictionDataBump);
+
+    const [groupPubkey, groupBump] = helper.groupPDA(groupId);
+    assert.equal((await helper.groupData(groupPubkey)).bump, groupBump);
+
+    const [transferRulePubkey, transferRuleBump] = helper.transferRulePDA(
+      groupId,
+      groupId
+    );
+    assert.equal(
+      (await helper.transferRuleData(transferRulePubkey)).bump,
+      transferRuleBump
+    );
+
+    const [saaPubkey, saaBump] = helper.securityAssociatedAccountPDA(
+      senderTokenAccountPubkey
+    );
+    assert.equal(
+      (await helper.securityAssociatedAccountData(saaPubkey)).bump,
+      saaBump
+    );
+  });
+
+  it("transfers with an exact transfer rule within the compute units budget", async () => {
+    const signature = await transfer(
+      senderTokenAccountPubkey,
+      recipientTokenAccountPubkey,
+      sender
+    );
+    const computeUnits = await transactionComputeUnits(signature);
+    assert.isAtMost(
+      computeUnits.transferHook,
+      TRANSFER_HOOK_COMPUTE_UNITS_BUDGET
+    );
+    assert.isBelow(computeUnits.transaction, DEFAULT_COMPUTE_UNITS_LIMIT);
+  });
+
+  it("transfers with a default transfer rule within the compute units budget", async () => {
+    const signature = await transfer(
+      defaultRuleSenderTokenAccountPubkey,
+      recipientTokenAccountPubkey,
+      defaultRuleSender
+    );
+    const computeUnits = await transactionComputeUnits(signature);
+    assert.isAtMost(
+      computeUnits.transferHook,
+      TRANSFER_HOOK_COMPUTE_UNITS_BUDGET
+    );
+    assert.isBelow(computeUnits.transaction, DEFAULT_COMPUTE_UNITS_LIMIT);
+  });
+});
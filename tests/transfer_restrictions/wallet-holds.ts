@@ -0,0 +1,247 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Wallet holds", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+  const transferAmount = BigInt(
+    1_000 * 10 ** testEnvironmentParams.mint.decimals
+  );
+
+  const transfer = async (
+    source: PublicKey,
+    destination: PublicKey,
+    owner: Keypair
+  ) => {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        source,
+        testEnvironment.mintKeypair.publicKey,
+        destination,
+        owner.publicKey,
+        transferAmount,
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [owner],
+      { commitment: testEnvironment.commitment }
+    );
+  };
+
+  const sendOnlyHold = (expiresAt: anchor.BN) => ({
+    reasonCode: 7,
+    referenceHash: Array.from(Buffer.alloc(32, 1)),
+    direction: 1,
+    expiresAt,
+  });
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const tsNow = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(tsNow),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    for (const [wallet, tokenAccount] of [
+      [testEnvironment.reserveAdmin.publicKey, reserveAdminTokenAccountPubkey],
+      [investor.publicKey, investorTokenAccountPubkey],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccountIfNotExists(
+        wallet,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin,
+        groupId
+      );
+    }
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN((transferAmount * BigInt(10)).toString()),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      )[0]
+    );
+    await testEnvironment.accessControlHelper.forceTransferBetween(
+      transferAmount * BigInt(5),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.connection
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  it("fails to add hold by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.addWalletHold(
+        investorTokenAccountPubkey,
+        sendOnlyHold(new anchor.BN(0)),
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to add hold which already expired", async () => {
+    const tsNow = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.addWalletHold(
+        investorTokenAccountPubkey,
+        sendOnlyHold(new anchor.BN(tsNow - 1)),
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidWalletHold");
+      assert.equal(error.errorMessage, "Invalid wallet hold");
+    }
+  });
+
+  it("blocks sending while still allowing the wallet to receive", async () => {
+    await testEnvironment.transferRestrictionsHelper.addWalletHold(
+      investorTokenAccountPubkey,
+      sendOnlyHold(new anchor.BN(0)),
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const saa =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          investorTokenAccountPubkey
+        )[0]
+      );
+    assert.equal(saa.holdsCount, 1);
+    assert.equal(saa.holds[0].reasonCode, 7);
+
+    try {
+      await transfer(
+        investorTokenAccountPubkey,
+        reserveAdminTokenAccountPubkey,
+        investor
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isBlockedByHold = error.logs.some((log: string) =>
+        log.includes("Error Code: TransferBlockedByWalletHold")
+      );
+      assert.isTrue(isBlockedByHold);
+    }
+    await transfer(
+      reserveAdminTokenAccountPubkey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+  });
+
+  it("fails to remove hold with invalid index", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.removeWalletHold(
+        investorTokenAccountPubkey,
+        1,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidWalletHoldIndex");
+    }
+  });
+
+  it("removes hold and allows sending again", async () => {
+    await testEnvironment.transferRestrictionsHelper.removeWalletHold(
+      investorTokenAccountPubkey,
+      0,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    await transfer(
+      investorTokenAccountPubkey,
+      reserveAdminTokenAccountPubkey,
+      investor
+    );
+  });
+});